@@ -0,0 +1,188 @@
+//! Describe widgets to assistive technologies.
+use crate::widget::Tree;
+
+use std::ops::RangeInclusive;
+use std::sync::atomic::{self, AtomicU64};
+
+/// The identifier of an accessible widget.
+///
+/// Every [`Tree`] has one; so it stays the same for as long as the state of
+/// its widget is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates a new unique [`Id`].
+    pub fn unique() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+
+    /// Returns the raw value of the [`Id`].
+    pub fn raw(self) -> u64 {
+        self.0
+    }
+}
+
+/// The description of a widget for assistive technologies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The name of the widget; like the label of a button.
+    pub name: Option<String>,
+
+    /// The current value of the widget; like the contents of a text input.
+    pub value: Option<String>,
+
+    /// The numeric value of the widget; like the value of a slider.
+    pub numeric: Option<Numeric>,
+
+    /// Whether the widget is toggled; like a checked checkbox.
+    pub toggled: Option<bool>,
+
+    /// Whether the widget has the keyboard focus.
+    pub is_focused: bool,
+
+    /// The [`Action`]s that assistive technologies can request on the widget.
+    pub actions: Vec<Action>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            name: None,
+            value: None,
+            numeric: None,
+            toggled: None,
+            is_focused: false,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the [`Node`].
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the value of the [`Node`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the numeric value of the [`Node`], together with its range and
+    /// the step of its [`Action::Increment`] and [`Action::Decrement`].
+    pub fn numeric(
+        mut self,
+        value: f64,
+        range: RangeInclusive<f64>,
+        step: f64,
+    ) -> Self {
+        self.numeric = Some(Numeric { value, range, step });
+        self
+    }
+
+    /// Sets whether the [`Node`] is toggled.
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    /// Sets whether the [`Node`] has the keyboard focus.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Adds an [`Action`] that can be requested on the [`Node`].
+    ///
+    /// Requested actions are delivered to the widget as an
+    /// [`Event::Accessibility`](crate::Event::Accessibility).
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+}
+
+/// The numeric value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Numeric {
+    /// The current value.
+    pub value: f64,
+
+    /// The range of the value.
+    pub range: RangeInclusive<f64>,
+
+    /// The step of the value.
+    pub step: f64,
+}
+
+/// The role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// A button.
+    Button,
+    /// A checkbox.
+    CheckBox,
+    /// A group of other widgets.
+    Group,
+    /// An image.
+    Image,
+    /// A static piece of text.
+    Label,
+    /// A link.
+    Link,
+    /// A list of options to pick from.
+    ListBox,
+    /// A progress bar.
+    ProgressIndicator,
+    /// A radio button.
+    RadioButton,
+    /// A scrollable area.
+    ScrollView,
+    /// A slider.
+    Slider,
+    /// A toggler.
+    Switch,
+    /// An editable piece of text.
+    TextInput,
+    /// Some other kind of widget; like a canvas.
+    Unknown,
+}
+
+/// An action that assistive technologies can request on a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Click the widget.
+    Click,
+    /// Focus the widget.
+    Focus,
+    /// Increment the value of the widget.
+    Increment,
+    /// Decrement the value of the widget.
+    Decrement,
+}
+
+/// A request of assistive technologies to perform an [`Action`] on a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Request {
+    /// The requested [`Action`].
+    pub action: Action,
+
+    /// The [`Id`] of the targeted widget.
+    pub target: Id,
+}
+
+impl Request {
+    /// Returns true if the [`Request`] targets the widget with the given
+    /// [`Tree`].
+    pub fn is_for(&self, tree: &Tree) -> bool {
+        self.target == tree.accessibility_id()
+    }
+}
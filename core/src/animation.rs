@@ -1,8 +1,37 @@
 //! Animate your applications.
+mod spring;
+mod timeline;
+
+pub use spring::Spring;
+pub use timeline::{Keyframe, Timeline};
+
 use crate::time::{Duration, Instant};
 
+use std::sync::atomic::{self, AtomicBool};
+
 pub use lilt::{Easing, FloatRepresentable as Float, Interpolable};
 
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether motion should be reduced across the whole application.
+///
+/// When enabled, non-essential animations finish immediately; [`Spring`]s
+/// jump to their targets and widget transitions are skipped. Animations
+/// marked as [`essential`] and [`Timeline`]s keep playing.
+///
+/// The runtime keeps this in sync with the reduced motion preference of
+/// the native system.
+///
+/// [`essential`]: Animation::essential
+pub fn reduce_motion(reduce: bool) {
+    REDUCED_MOTION.store(reduce, atomic::Ordering::Relaxed);
+}
+
+/// Returns true if motion should be reduced; see [`reduce_motion`].
+pub fn is_motion_reduced() -> bool {
+    REDUCED_MOTION.load(atomic::Ordering::Relaxed)
+}
+
 /// The animation of some particular state.
 ///
 /// It tracks state changes and allows projecting interpolated values
@@ -14,6 +43,8 @@ where
 {
     raw: lilt::Animated<T, Instant>,
     duration: Duration, // TODO: Expose duration getter in `lilt`
+    delay: Duration,
+    is_essential: bool,
 }
 
 impl<T> Animation<T>
@@ -25,6 +56,8 @@ where
         Self {
             raw: lilt::Animated::new(state),
             duration: Duration::from_millis(100),
+            delay: Duration::ZERO,
+            is_essential: false,
         }
     }
 
@@ -67,6 +100,16 @@ where
     /// Sets a delay for the [`Animation`].
     pub fn delay(mut self, duration: Duration) -> Self {
         self.raw = self.raw.delay(duration.as_secs_f64() as f32 * 1000.0);
+        self.delay = duration;
+        self
+    }
+
+    /// Marks the [`Animation`] as essential, so it keeps playing even
+    /// when motion is reduced.
+    ///
+    /// See [`reduce_motion`] for more details.
+    pub fn essential(mut self) -> Self {
+        self.is_essential = true;
         self
     }
 
@@ -100,6 +143,23 @@ where
     /// Transitions the [`Animation`] from its current state to the given new state
     /// at the given time, by reference.
     pub fn go_mut(&mut self, new_state: T, at: Instant) {
+        self.transition(new_state, at, is_motion_reduced());
+    }
+
+    fn transition(
+        &mut self,
+        new_state: T,
+        at: Instant,
+        is_motion_reduced: bool,
+    ) {
+        // Non-essential transitions are started in the past when motion
+        // is reduced, so they are already finished
+        let at = if is_motion_reduced && !self.is_essential {
+            at.checked_sub(self.duration + self.delay).unwrap_or(at)
+        } else {
+            at
+        };
+
         self.raw.transition(new_state, at);
     }
 
@@ -148,3 +208,240 @@ impl Animation<bool> {
         ))
     }
 }
+
+/// An animated value that keeps track of time by itself.
+///
+/// Unlike an [`Animation`], an [`Animated`] value does not need an
+/// [`Instant`] to be projected. Instead, it is moved forward in time
+/// with [`Animated::tick`]; usually on every frame while
+/// [`Animated::is_animating`] returns true.
+#[derive(Debug, Clone)]
+pub struct Animated<T>
+where
+    T: Clone + Copy + PartialEq + Float,
+{
+    animation: Animation<T>,
+    now: Instant,
+}
+
+impl<T> Animated<T>
+where
+    T: Clone + Copy + PartialEq + Float,
+{
+    /// Creates a new [`Animated`] value with the given initial state.
+    pub fn new(state: T) -> Self {
+        Self {
+            animation: Animation::new(state),
+            now: Instant::now(),
+        }
+    }
+
+    /// Sets the [`Easing`] function of the [`Animated`] value.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.animation = self.animation.easing(easing);
+        self
+    }
+
+    /// Sets the duration of the transitions of the [`Animated`] value.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.animation = self.animation.duration(duration);
+        self
+    }
+
+    /// Sets a delay for the transitions of the [`Animated`] value.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.animation = self.animation.delay(delay);
+        self
+    }
+
+    /// Marks the [`Animated`] value as essential, so it keeps transitioning
+    /// even when motion is reduced.
+    pub fn essential(mut self) -> Self {
+        self.animation = self.animation.essential();
+        self
+    }
+
+    /// Transitions the [`Animated`] value to the given new state, starting
+    /// right now.
+    pub fn go(&mut self, new_state: T) {
+        self.now = Instant::now();
+        self.animation.go_mut(new_state, self.now);
+    }
+
+    /// Moves the [`Animated`] value forward to the given [`Instant`].
+    pub fn tick(&mut self, now: Instant) {
+        self.now = self.now.max(now);
+    }
+
+    /// Returns true if the [`Animated`] value is currently transitioning
+    /// to a different state.
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_animating(self.now)
+    }
+
+    /// Projects the [`Animated`] value into an interpolated value; using
+    /// the closure provided to calculate the different keyframes of
+    /// interpolated values.
+    pub fn interpolate_with<I>(&self, f: impl Fn(T) -> I) -> I
+    where
+        I: Interpolable,
+    {
+        self.animation.interpolate_with(f, self.now)
+    }
+
+    /// Returns the state the [`Animated`] value is transitioning to.
+    pub fn value(&self) -> T {
+        self.animation.value()
+    }
+}
+
+impl Animated<bool> {
+    /// Projects the [`Animated`] value into an interpolated value; using
+    /// the `start` and `end` values as the origin and destination keyframes.
+    pub fn interpolate<I>(&self, start: I, end: I) -> I
+    where
+        I: Interpolable + Clone,
+    {
+        self.animation.interpolate(start, end, self.now)
+    }
+}
+
+/// A timed transition between the states of some value; like the
+/// status of a widget.
+///
+/// Unlike an [`Animation`], a [`Transition`] does not interpolate the
+/// states directly. Instead, it can be used to blend values derived from
+/// them; like the styles of a widget for each status.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition<T> {
+    from: T,
+    to: T,
+    start: Option<Instant>,
+    now: Option<Instant>,
+    duration: Duration,
+}
+
+impl<T> Transition<T>
+where
+    T: Copy + PartialEq,
+{
+    /// Creates a new [`Transition`] with the given initial state and
+    /// duration.
+    pub fn new(state: T, duration: Duration) -> Self {
+        Self {
+            from: state,
+            to: state,
+            start: None,
+            now: None,
+            duration,
+        }
+    }
+
+    /// Transitions to the given state at the given time, if it's different
+    /// from the current one.
+    ///
+    /// Returns true if the [`Transition`] is still in progress.
+    pub fn update(&mut self, state: T, now: Instant) -> bool {
+        if state != self.to {
+            let progress = self.linear_progress();
+
+            if is_motion_reduced() {
+                self.from = state;
+                self.start = None;
+            } else if state == self.from && progress < 1.0 {
+                // Reverse the transition in progress smoothly
+                self.from = self.to;
+                self.start = now
+                    .checked_sub(self.duration.mul_f32(1.0 - progress))
+                    .or(Some(now));
+            } else {
+                self.from = self.to;
+                self.start = Some(now);
+            }
+
+            self.to = state;
+        }
+
+        self.now = Some(now);
+        self.is_animating()
+    }
+
+    /// Returns true if the [`Transition`] is currently in progress.
+    pub fn is_animating(&self) -> bool {
+        self.linear_progress() < 1.0
+    }
+
+    /// Returns the state the [`Transition`] started from.
+    pub fn from(&self) -> T {
+        self.from
+    }
+
+    /// Returns the state the [`Transition`] is heading to.
+    pub fn to(&self) -> T {
+        self.to
+    }
+
+    /// Returns the eased progress of the [`Transition`], from `0.0`
+    /// to `1.0`.
+    pub fn progress(&self) -> f32 {
+        let t = self.linear_progress();
+
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    /// Blends the values derived from both states of the [`Transition`]
+    /// with the given `mix` function, according to its progress.
+    pub fn interpolate<V>(
+        &self,
+        f: impl Fn(T) -> V,
+        mix: impl FnOnce(V, V, f32) -> V,
+    ) -> V {
+        if self.is_animating() {
+            mix(f(self.from), f(self.to), self.progress())
+        } else {
+            f(self.to)
+        }
+    }
+
+    fn linear_progress(&self) -> f32 {
+        match (self.start, self.now) {
+            (Some(start), Some(now)) if !self.duration.is_zero() => {
+                (now.saturating_duration_since(start).as_secs_f32()
+                    / self.duration.as_secs_f32())
+                .min(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduced_motion_finishes_transitions() {
+        let now = Instant::now();
+        let mut animation = Animation::new(false)
+            .duration(Duration::from_millis(200))
+            .delay(Duration::from_millis(100));
+
+        animation.transition(true, now, true);
+
+        assert!(!animation.is_animating(now));
+        assert_eq!(animation.interpolate(0.0, 1.0, now), 1.0);
+    }
+
+    #[test]
+    fn reduced_motion_keeps_essential_transitions() {
+        let now = Instant::now();
+        let mut animation = Animation::new(false)
+            .duration(Duration::from_millis(200))
+            .essential();
+
+        animation.transition(true, now, true);
+
+        assert!(animation.is_animating(now + Duration::from_millis(100)));
+        assert!(!animation.is_animating(now + Duration::from_millis(300)));
+    }
+}
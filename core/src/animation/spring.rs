@@ -0,0 +1,293 @@
+use crate::time::{Duration, Instant};
+
+/// A value driven by the physics of a damped spring.
+///
+/// A [`Spring`] does not have a fixed duration. Instead, it accelerates
+/// towards its target and settles down according to its stiffness and
+/// damping; overshooting it when underdamped.
+///
+/// Changing the target of a [`Spring`] mid-flight preserves its velocity,
+/// so interrupted transitions continue smoothly. This makes it a great fit
+/// for draggable elements that should keep the momentum of the gesture
+/// when released.
+///
+/// ```
+/// # use iced_core::animation::Spring;
+/// # use iced_core::time::{Duration, Instant};
+/// let start = Instant::now();
+/// let mut spring = Spring::new(0.0);
+///
+/// spring.go(100.0, start);
+/// spring.tick(start + Duration::from_secs(2));
+///
+/// assert!(!spring.is_animating());
+/// assert_eq!(spring.value(), 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    value: f32,
+    velocity: f32,
+    target: f32,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    precision: f32,
+    last_tick: Option<Instant>,
+}
+
+impl Spring {
+    /// The step used to integrate the motion of a [`Spring`].
+    const STEP: Duration = Duration::from_micros(4_000);
+
+    /// The maximum amount of time integrated in a single tick.
+    ///
+    /// Any [`Spring`] is considered settled after it; so long pauses,
+    /// like a suspended application, do not integrate forever.
+    const MAX_ELAPSED: Duration = Duration::from_secs(60);
+
+    /// Creates a new [`Spring`] at rest with the given value.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            velocity: 0.0,
+            target: value,
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            precision: 0.01,
+            last_tick: None,
+        }
+    }
+
+    /// Sets the stiffness of the [`Spring`].
+    ///
+    /// Stiffer springs move faster.
+    pub fn stiffness(self, stiffness: f32) -> Self {
+        Self {
+            stiffness: stiffness.max(0.0),
+            ..self
+        }
+    }
+
+    /// Sets the damping of the [`Spring`].
+    ///
+    /// Springs with less damping oscillate more before settling.
+    pub fn damping(self, damping: f32) -> Self {
+        Self {
+            damping: damping.max(0.0),
+            ..self
+        }
+    }
+
+    /// Sets the mass attached to the [`Spring`].
+    ///
+    /// Heavier masses move slower and carry more momentum.
+    pub fn mass(self, mass: f32) -> Self {
+        Self {
+            mass: mass.max(f32::EPSILON),
+            ..self
+        }
+    }
+
+    /// Sets the precision of the [`Spring`]; that is, how close to its
+    /// target it needs to be to come to rest.
+    ///
+    /// By default, it is `0.01`.
+    pub fn precision(self, precision: f32) -> Self {
+        Self {
+            precision: precision.abs(),
+            ..self
+        }
+    }
+
+    /// Creates a gentle [`Spring`] that settles without overshooting.
+    pub fn gentle(value: f32) -> Self {
+        Self::new(value).stiffness(120.0).damping(22.0)
+    }
+
+    /// Creates a bouncy [`Spring`] that overshoots its target.
+    pub fn bouncy(value: f32) -> Self {
+        Self::new(value).stiffness(180.0).damping(12.0)
+    }
+
+    /// Creates a stiff [`Spring`] that moves quickly.
+    pub fn stiff(value: f32) -> Self {
+        Self::new(value).stiffness(300.0).damping(30.0)
+    }
+
+    /// Makes the [`Spring`] move towards the given target from the
+    /// given time.
+    ///
+    /// The current velocity of the [`Spring`] is preserved. If motion is
+    /// [reduced], the [`Spring`] jumps to the target instead.
+    ///
+    /// [reduced]: super::reduce_motion
+    pub fn go(&mut self, target: f32, now: Instant) {
+        self.transition(target, now, super::is_motion_reduced());
+    }
+
+    fn transition(
+        &mut self,
+        target: f32,
+        now: Instant,
+        is_motion_reduced: bool,
+    ) {
+        if is_motion_reduced {
+            self.set(target);
+            self.last_tick = Some(now);
+            return;
+        }
+
+        self.tick(now);
+        self.target = target;
+        self.last_tick = Some(now);
+    }
+
+    /// Moves the [`Spring`] directly to the given value at the given time;
+    /// like when dragging it around.
+    ///
+    /// The velocity of the [`Spring`] is estimated from the last movement,
+    /// so it keeps its momentum once it is released with [`go`].
+    ///
+    /// [`go`]: Self::go
+    pub fn drag(&mut self, value: f32, now: Instant) {
+        self.velocity = match self.last_tick {
+            Some(last_tick) if now > last_tick => {
+                (value - self.value)
+                    / now.duration_since(last_tick).as_secs_f32()
+            }
+            _ => self.velocity,
+        };
+
+        self.value = value;
+        self.target = value;
+        self.last_tick = Some(now);
+    }
+
+    /// Sets the value of the [`Spring`] immediately, leaving it at rest.
+    pub fn set(&mut self, value: f32) {
+        self.value = value;
+        self.target = value;
+        self.velocity = 0.0;
+    }
+
+    /// Simulates the motion of the [`Spring`] up to the given time.
+    pub fn tick(&mut self, now: Instant) {
+        let Some(last_tick) = self.last_tick else {
+            self.last_tick = Some(now);
+            return;
+        };
+
+        if now <= last_tick {
+            return;
+        }
+
+        self.last_tick = Some(now);
+
+        if !self.is_animating() {
+            return;
+        }
+
+        let elapsed = now.duration_since(last_tick);
+        let mut remaining = elapsed.min(Self::MAX_ELAPSED);
+
+        // Big steps are split into fixed substeps, until the spring settles
+        while !remaining.is_zero() && self.is_animating() {
+            let step = remaining.min(Self::STEP);
+            let dt = step.as_secs_f32();
+
+            let force = -self.stiffness * (self.value - self.target)
+                - self.damping * self.velocity;
+
+            self.velocity += force / self.mass * dt;
+            self.value += self.velocity * dt;
+
+            remaining -= step;
+        }
+
+        if elapsed > Self::MAX_ELAPSED || !self.is_animating() {
+            self.value = self.target;
+            self.velocity = 0.0;
+        }
+    }
+
+    /// Returns true if the [`Spring`] is still moving towards its target.
+    pub fn is_animating(&self) -> bool {
+        (self.value - self.target).abs() > self.precision
+            || self.velocity.abs() > self.precision
+    }
+
+    /// Returns the current value of the [`Spring`].
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns the current velocity of the [`Spring`], in units
+    /// per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Returns the target of the [`Spring`].
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interruption_preserves_velocity() {
+        let start = Instant::now();
+        let mut spring = Spring::new(0.0);
+
+        spring.go(100.0, start);
+        spring.tick(start + Duration::from_millis(100));
+
+        let velocity = spring.velocity();
+        assert!(velocity > 0.0);
+
+        spring.go(0.0, start + Duration::from_millis(100));
+        assert_eq!(spring.velocity(), velocity);
+
+        spring.tick(start + Duration::from_secs(3));
+        assert!(!spring.is_animating());
+        assert_eq!(spring.value(), 0.0);
+    }
+
+    #[test]
+    fn bouncy_spring_overshoots() {
+        let start = Instant::now();
+        let mut spring = Spring::bouncy(0.0);
+        let mut max: f32 = 0.0;
+
+        spring.go(1.0, start);
+
+        for frame in 1..120 {
+            spring.tick(start + Duration::from_millis(frame * 16));
+            max = max.max(spring.value());
+        }
+
+        assert!(max > 1.0);
+    }
+
+    #[test]
+    fn reduced_motion_jumps_to_target() {
+        let start = Instant::now();
+        let mut spring = Spring::bouncy(0.0);
+
+        spring.go(100.0, start);
+        spring.tick(start + Duration::from_millis(100));
+        assert!(spring.is_animating());
+
+        spring.transition(50.0, start + Duration::from_millis(100), true);
+        assert!(!spring.is_animating());
+        assert_eq!(spring.value(), 50.0);
+        assert_eq!(spring.velocity(), 0.0);
+
+        spring.tick(start + Duration::from_secs(1));
+        assert_eq!(spring.value(), 50.0);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::animation::Easing;
+use crate::time::{Duration, Instant};
+
+/// A sequence of keyframes that animates multiple properties over time.
+///
+/// Each [`Keyframe`] sets the values of some properties at a specific
+/// point of the [`Timeline`]. The value of a property is interpolated
+/// between the keyframes that set it; using the [`Easing`] of the
+/// destination [`Keyframe`] for each segment.
+///
+/// Properties are identified by a key of your choice; like an `enum`.
+///
+/// ```
+/// # use iced_core::animation::{Easing, Keyframe, Timeline};
+/// # use iced_core::time::{Duration, Instant};
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Toast {
+///     Offset,
+///     Opacity,
+/// }
+///
+/// let ms = Duration::from_millis;
+///
+/// // Slide in, stay for a while, and then fade out
+/// let mut timeline = Timeline::new()
+///     .keyframe(
+///         Keyframe::at(ms(0))
+///             .set(Toast::Offset, 40.0)
+///             .set(Toast::Opacity, 1.0),
+///     )
+///     .keyframe(
+///         Keyframe::at(ms(300))
+///             .easing(Easing::EaseOut)
+///             .set(Toast::Offset, 0.0),
+///     )
+///     .keyframe(Keyframe::at(ms(3_000)).set(Toast::Opacity, 1.0))
+///     .keyframe(
+///         Keyframe::at(ms(3_300))
+///             .easing(Easing::EaseIn)
+///             .set(Toast::Opacity, 0.0),
+///     );
+///
+/// let start = Instant::now();
+/// timeline.start(start);
+///
+/// assert_eq!(timeline.value(Toast::Offset, start), 40.0);
+/// assert_eq!(timeline.value(Toast::Offset, start + ms(1_000)), 0.0);
+/// assert_eq!(timeline.value(Toast::Opacity, start + ms(1_000)), 1.0);
+/// assert_eq!(timeline.value(Toast::Opacity, start + ms(4_000)), 0.0);
+/// assert!(!timeline.is_animating(start + ms(4_000)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timeline<P> {
+    keyframes: Vec<Keyframe<P>>,
+    start: Option<Instant>,
+}
+
+impl<P> Timeline<P>
+where
+    P: Copy + PartialEq,
+{
+    /// Creates a new empty [`Timeline`].
+    pub fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            start: None,
+        }
+    }
+
+    /// Adds a [`Keyframe`] to the [`Timeline`].
+    pub fn keyframe(mut self, keyframe: Keyframe<P>) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|candidate| candidate.at <= keyframe.at);
+
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    /// Starts playing the [`Timeline`] from the beginning at the given time.
+    pub fn start(&mut self, now: Instant) {
+        self.start = Some(now);
+    }
+
+    /// Stops the [`Timeline`]; returning all of its properties to their
+    /// initial values.
+    pub fn stop(&mut self) {
+        self.start = None;
+    }
+
+    /// Returns the total [`Duration`] of the [`Timeline`].
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .last()
+            .map(|keyframe| keyframe.at)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the [`Timeline`] has started and not reached
+    /// its last [`Keyframe`] yet.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.start.is_some() && self.elapsed(now) < self.duration()
+    }
+
+    /// Returns true if the [`Timeline`] has started and reached its
+    /// last [`Keyframe`].
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.start.is_some() && self.elapsed(now) >= self.duration()
+    }
+
+    /// Returns the value of the given property at the given time.
+    ///
+    /// Properties hold the value of the first [`Keyframe`] that sets them
+    /// until it is reached, and the value of the last one afterwards.
+    /// If no [`Keyframe`] sets the property, `0.0` is returned.
+    pub fn value(&self, property: P, now: Instant) -> f32 {
+        let elapsed = self.elapsed(now);
+
+        let mut previous: Option<(Duration, f32)> = None;
+
+        for keyframe in &self.keyframes {
+            let Some(value) = keyframe.get(property) else {
+                continue;
+            };
+
+            if keyframe.at <= elapsed {
+                previous = Some((keyframe.at, value));
+                continue;
+            }
+
+            let Some((from_at, from)) = previous else {
+                return value;
+            };
+
+            let segment = (keyframe.at - from_at).as_secs_f32();
+            let progress = (elapsed - from_at).as_secs_f32() / segment;
+            let eased = keyframe.easing.value(progress.clamp(0.0, 1.0));
+
+            return from + (value - from) * eased;
+        }
+
+        previous.map(|(_, value)| value).unwrap_or_default()
+    }
+
+    fn elapsed(&self, now: Instant) -> Duration {
+        self.start
+            .map(|start| now.saturating_duration_since(start))
+            .unwrap_or_default()
+    }
+}
+
+impl<P> Default for Timeline<P>
+where
+    P: Copy + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A point of a [`Timeline`] that sets the values of some properties.
+#[derive(Debug, Clone)]
+pub struct Keyframe<P> {
+    at: Duration,
+    easing: Easing,
+    values: Vec<(P, f32)>,
+}
+
+impl<P> Keyframe<P>
+where
+    P: Copy + PartialEq,
+{
+    /// Creates a new [`Keyframe`] at the given offset from the start
+    /// of a [`Timeline`].
+    pub fn at(offset: Duration) -> Self {
+        Self {
+            at: offset,
+            easing: Easing::Linear,
+            values: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Easing`] used to reach the [`Keyframe`] from the
+    /// previous one.
+    pub fn easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Sets the value of the given property at the [`Keyframe`].
+    pub fn set(mut self, property: P, value: f32) -> Self {
+        if let Some((_, current)) = self
+            .values
+            .iter_mut()
+            .find(|(candidate, _)| *candidate == property)
+        {
+            *current = value;
+        } else {
+            self.values.push((property, value));
+        }
+
+        self
+    }
+
+    fn get(&self, property: P) -> Option<f32> {
+        self.values
+            .iter()
+            .find(|(candidate, _)| *candidate == property)
+            .map(|(_, value)| *value)
+    }
+}
@@ -1,3 +1,4 @@
+//! Fill elements with colors, gradients, or blurred backdrops.
 use crate::Color;
 use crate::gradient::{self, Gradient};
 
@@ -8,6 +9,8 @@ pub enum Background {
     Color(Color),
     /// Linearly interpolate between several colors.
     Gradient(Gradient),
+    /// Blur the content behind the element; like frosted glass.
+    Blur(Blur),
     // TODO: Add image variant
 }
 
@@ -20,6 +23,29 @@ impl Background {
             Self::Gradient(gradient) => {
                 Self::Gradient(gradient.scale_alpha(factor))
             }
+            Self::Blur(blur) => Self::Blur(Blur {
+                tint: blur.tint.scale_alpha(factor),
+                ..blur
+            }),
+        }
+    }
+}
+
+impl Background {
+    /// Interpolates between the [`Background`] and the `other` one by the
+    /// given factor, from `0.0` to `1.0`.
+    ///
+    /// Only solid colors and blur tints are blended; any other pair of
+    /// backgrounds will switch halfway.
+    pub fn mix(self, other: Self, factor: f32) -> Self {
+        match (self, other) {
+            (Self::Color(a), Self::Color(b)) => Self::Color(a.mix(b, factor)),
+            (Self::Blur(a), Self::Blur(b)) => Self::Blur(Blur {
+                radius: a.radius + (b.radius - a.radius) * factor,
+                tint: a.tint.mix(b.tint, factor),
+            }),
+            _ if factor < 0.5 => self,
+            _ => other,
         }
     }
 }
@@ -41,3 +67,34 @@ impl From<gradient::Linear> for Background {
         Background::Gradient(Gradient::Linear(gradient))
     }
 }
+
+impl From<Blur> for Background {
+    fn from(blur: Blur) -> Self {
+        Background::Blur(blur)
+    }
+}
+
+/// A blur of the content behind an element.
+///
+/// Useful for translucent sidebars and overlays. Only the content drawn
+/// in previous layers will be blurred; so the element should be drawn
+/// on top of the content, like in a [`Stack`].
+///
+/// [`Stack`]: https://docs.rs/iced/latest/iced/widget/struct.Stack.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Blur {
+    /// The blur radius, in logical pixels.
+    pub radius: f32,
+    /// The [`Color`] drawn on top of the blurred content.
+    pub tint: Color,
+}
+
+impl Blur {
+    /// Creates a new [`Blur`] with the given radius and tint [`Color`].
+    pub fn new(radius: f32, tint: impl Into<Color>) -> Self {
+        Self {
+            radius,
+            tint: tint.into(),
+        }
+    }
+}
@@ -2,6 +2,10 @@
 use crate::{Color, Pixels};
 
 /// A border.
+///
+/// Its `width` and `color` apply to every side, unless overridden by its
+/// [`Sides`]; use [`top`](Self::top()), [`right`](Self::right()),
+/// [`bottom`](Self::bottom()) and [`left`](Self::left()) to override them.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Border {
     /// The color of the border.
@@ -12,6 +16,9 @@ pub struct Border {
 
     /// The [`Radius`] of the border.
     pub radius: Radius,
+
+    /// The per-side overrides of the `width` and `color` of the border.
+    pub sides: Sides,
 }
 
 /// Creates a new [`Border`] with the given [`Radius`].
@@ -50,10 +57,24 @@ pub fn width(width: impl Into<Pixels>) -> Border {
 }
 
 impl Border {
-    /// Sets the [`Color`] of the [`Border`].
+    /// Sets the [`Color`] of the [`Border`]; including any overridden
+    /// [`Side`].
+    ///
+    /// ```
+    /// # use iced_core::border::Border;
+    /// # use iced_core::Color;
+    /// #
+    /// let focused = Border::default().bottom(1, Color::BLACK).color(Color::WHITE);
+    ///
+    /// assert_eq!(focused.colors(), [Color::WHITE; 4]);
+    /// assert_eq!(focused.widths(), [0.0, 0.0, 1.0, 0.0]);
+    /// ```
     pub fn color(self, color: impl Into<Color>) -> Self {
+        let color = color.into();
+
         Self {
-            color: color.into(),
+            color,
+            sides: self.sides.map(|side| Side { color, ..side }),
             ..self
         }
     }
@@ -67,16 +88,244 @@ impl Border {
     }
 
     /// Sets the width of the [`Border`].
+    ///
+    /// If some sides are overridden, only the sides with a positive width
+    /// are widened; so an underline stays an underline.
     pub fn width(self, width: impl Into<Pixels>) -> Self {
+        let width = width.into().0;
+
+        if self.sides.is_empty() {
+            return Self { width, ..self };
+        }
+
+        let widen = |current: f32| if current > 0.0 { width } else { 0.0 };
+
         Self {
-            width: width.into().0,
+            width: widen(self.width),
+            sides: self.sides.map(|side| Side {
+                width: widen(side.width),
+                ..side
+            }),
             ..self
         }
     }
+
+    /// Overrides the top [`Side`] of the [`Border`].
+    pub fn top(
+        self,
+        width: impl Into<Pixels>,
+        color: impl Into<Color>,
+    ) -> Self {
+        self.side(|sides| &mut sides.top, width, color)
+    }
+
+    /// Overrides the right [`Side`] of the [`Border`].
+    pub fn right(
+        self,
+        width: impl Into<Pixels>,
+        color: impl Into<Color>,
+    ) -> Self {
+        self.side(|sides| &mut sides.right, width, color)
+    }
+
+    /// Overrides the bottom [`Side`] of the [`Border`].
+    ///
+    /// ```
+    /// # use iced_core::border::Border;
+    /// # use iced_core::Color;
+    /// #
+    /// let underline = Border::default().bottom(1, Color::BLACK);
+    ///
+    /// assert_eq!(underline.widths(), [0.0, 0.0, 1.0, 0.0]);
+    /// ```
+    pub fn bottom(
+        self,
+        width: impl Into<Pixels>,
+        color: impl Into<Color>,
+    ) -> Self {
+        self.side(|sides| &mut sides.bottom, width, color)
+    }
+
+    /// Overrides the left [`Side`] of the [`Border`].
+    pub fn left(
+        self,
+        width: impl Into<Pixels>,
+        color: impl Into<Color>,
+    ) -> Self {
+        self.side(|sides| &mut sides.left, width, color)
+    }
+
+    /// Returns the [`Side`] of each edge of the [`Border`] in the order:
+    /// top, right, bottom, left; with the uniform `width` and `color` for
+    /// the sides that are not overridden.
+    pub fn resolved_sides(&self) -> [Side; 4] {
+        let uniform = Side {
+            width: self.width,
+            color: self.color,
+        };
+
+        [
+            self.sides.top.unwrap_or(uniform),
+            self.sides.right.unwrap_or(uniform),
+            self.sides.bottom.unwrap_or(uniform),
+            self.sides.left.unwrap_or(uniform),
+        ]
+    }
+
+    /// Returns the widths of the [`Border`] in the order:
+    /// top, right, bottom, left.
+    pub fn widths(&self) -> [f32; 4] {
+        self.resolved_sides().map(|side| side.width)
+    }
+
+    /// Returns the colors of the [`Border`] in the order:
+    /// top, right, bottom, left.
+    pub fn colors(&self) -> [Color; 4] {
+        self.resolved_sides().map(|side| side.color)
+    }
+
+    /// Returns true if any side of the [`Border`] has a positive width.
+    pub fn has_width(&self) -> bool {
+        self.widths().into_iter().any(|width| width > 0.0)
+    }
+
+    /// Returns true if any side of the [`Border`] has a positive width and
+    /// a color that is not transparent.
+    pub fn is_visible(&self) -> bool {
+        self.resolved_sides()
+            .into_iter()
+            .any(|side| side.width > 0.0 && side.color.a > 0.0)
+    }
+
+    /// Returns true if every side of the [`Border`] has the same
+    /// width and color.
+    pub fn is_uniform(&self) -> bool {
+        let [top, right, bottom, left] = self.resolved_sides();
+
+        top == right && right == bottom && bottom == left
+    }
+
+    /// Interpolates between the [`Border`] and the `other` one by the
+    /// given factor, from `0.0` to `1.0`.
+    pub fn mix(self, other: Self, factor: f32) -> Self {
+        let lerp = |a: f32, b: f32| a + (b - a) * factor.clamp(0.0, 1.0);
+
+        let a = <[f32; 4]>::from(self.radius);
+        let b = <[f32; 4]>::from(other.radius);
+
+        let side = |a: Side, b: Side| Side {
+            width: lerp(a.width, b.width),
+            color: a.color.mix(b.color, factor),
+        };
+
+        let radius = Radius {
+            top_left: lerp(a[0], b[0]),
+            top_right: lerp(a[1], b[1]),
+            bottom_right: lerp(a[2], b[2]),
+            bottom_left: lerp(a[3], b[3]),
+            smoothing: lerp(self.radius.smoothing, other.radius.smoothing),
+        };
+
+        let mixed = Self {
+            color: self.color.mix(other.color, factor),
+            width: lerp(self.width, other.width),
+            radius,
+            sides: Sides::default(),
+        };
+
+        if self.sides.is_empty() && other.sides.is_empty() {
+            return mixed;
+        }
+
+        let [top_a, right_a, bottom_a, left_a] = self.resolved_sides();
+        let [top_b, right_b, bottom_b, left_b] = other.resolved_sides();
+
+        Self {
+            sides: Sides {
+                top: Some(side(top_a, top_b)),
+                right: Some(side(right_a, right_b)),
+                bottom: Some(side(bottom_a, bottom_b)),
+                left: Some(side(left_a, left_b)),
+            },
+            ..mixed
+        }
+    }
+
+    fn side(
+        mut self,
+        f: impl FnOnce(&mut Sides) -> &mut Option<Side>,
+        width: impl Into<Pixels>,
+        color: impl Into<Color>,
+    ) -> Self {
+        *f(&mut self.sides) = Some(Side {
+            width: width.into().0,
+            color: color.into(),
+        });
+
+        self
+    }
+}
+
+/// The per-side overrides of a [`Border`].
+///
+/// A side that is `None` uses the uniform `width` and `color` of the
+/// [`Border`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Sides {
+    /// The top [`Side`], if overridden.
+    pub top: Option<Side>,
+    /// The right [`Side`], if overridden.
+    pub right: Option<Side>,
+    /// The bottom [`Side`], if overridden.
+    pub bottom: Option<Side>,
+    /// The left [`Side`], if overridden.
+    pub left: Option<Side>,
+}
+
+impl Sides {
+    /// Creates new [`Sides`] overriding each edge with the same [`Side`].
+    pub const fn new(side: Side) -> Self {
+        Self {
+            top: Some(side),
+            right: Some(side),
+            bottom: Some(side),
+            left: Some(side),
+        }
+    }
+
+    /// Returns true if no [`Side`] is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.top.is_none()
+            && self.right.is_none()
+            && self.bottom.is_none()
+            && self.left.is_none()
+    }
+
+    /// Applies the given function to every overridden [`Side`].
+    pub fn map(self, f: impl Fn(Side) -> Side) -> Self {
+        Self {
+            top: self.top.map(&f),
+            right: self.right.map(&f),
+            bottom: self.bottom.map(&f),
+            left: self.left.map(&f),
+        }
+    }
+}
+
+/// A single side of a [`Border`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Side {
+    /// The width of the [`Side`].
+    pub width: f32,
+    /// The [`Color`] of the [`Side`].
+    pub color: Color,
 }
 
 /// The border radii for the corners of a graphics primitive in the order:
 /// top-left, top-right, bottom-right, bottom-left.
+///
+/// The corners can be smoothed into a continuous curve, similar to the
+/// "squircles" found in iOS; see [`Radius::smooth`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Radius {
     /// Top left radius
@@ -87,6 +336,9 @@ pub struct Radius {
     pub bottom_right: f32,
     /// Bottom left radius
     pub bottom_left: f32,
+    /// The smoothing of the corners, from `0.0` (circular) to `1.0`
+    /// (fully continuous).
+    pub smoothing: f32,
 }
 
 /// Creates a new [`Radius`] with the same value for each corner.
@@ -94,6 +346,12 @@ pub fn radius(value: impl Into<Pixels>) -> Radius {
     Radius::new(value)
 }
 
+/// Creates a new [`Radius`] with the same value for each corner and
+/// the given smoothing.
+pub fn smooth(value: impl Into<Pixels>, smoothing: f32) -> Radius {
+    Radius::new(value).smooth(smoothing)
+}
+
 /// Creates a new [`Radius`] with the given top left value.
 pub fn top_left(value: impl Into<Pixels>) -> Radius {
     Radius::default().top_left(value)
@@ -144,6 +402,20 @@ impl Radius {
             top_right: value,
             bottom_right: value,
             bottom_left: value,
+            smoothing: 0.0,
+        }
+    }
+
+    /// Sets the smoothing of the corners of the [`Radius`], from `0.0`
+    /// to `1.0`.
+    ///
+    /// A smoothing of `0.0` produces circular corners, while higher values
+    /// turn them into a superellipse; easing the transition between the
+    /// straight edges and the curve.
+    pub fn smooth(self, smoothing: f32) -> Self {
+        Self {
+            smoothing: smoothing.clamp(0.0, 1.0),
+            ..self
         }
     }
 
@@ -231,6 +503,7 @@ impl From<f32> for Radius {
             top_right: radius,
             bottom_right: radius,
             bottom_left: radius,
+            smoothing: 0.0,
         }
     }
 }
@@ -263,3 +536,72 @@ impl From<Radius> for [f32; 4] {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_overrides_apply_to_sides() {
+        let active = Border::default()
+            .bottom(1, Color::BLACK)
+            .top(2, Color::BLACK);
+
+        let hovered = active.color(Color::WHITE);
+
+        assert_eq!(hovered.colors(), [Color::WHITE; 4]);
+        assert_eq!(hovered.widths(), active.widths());
+
+        let focused = active.color(Color::WHITE).width(3);
+
+        assert_eq!(focused.colors(), [Color::WHITE; 4]);
+        assert_eq!(focused.widths(), [3.0, 0.0, 3.0, 0.0]);
+        assert!(!focused.is_uniform());
+    }
+
+    #[test]
+    fn uniform_builders_apply_to_every_side() {
+        let border = width(2).color(Color::BLACK);
+
+        assert_eq!(border.widths(), [2.0; 4]);
+        assert_eq!(border.colors(), [Color::BLACK; 4]);
+        assert!(border.is_uniform());
+
+        let underline = border.top(0, Color::BLACK).width(1);
+
+        assert_eq!(underline.widths(), [0.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn uniform_fields_apply_to_sides_without_overrides() {
+        let border = Border {
+            color: Color::BLACK,
+            width: 2.0,
+            ..Border::default()
+        }
+        .left(4, Color::WHITE);
+
+        assert_eq!(border.widths(), [2.0, 2.0, 2.0, 4.0]);
+        assert_eq!(
+            border.colors(),
+            [Color::BLACK, Color::BLACK, Color::BLACK, Color::WHITE]
+        );
+        assert!(!border.is_uniform());
+
+        let border = Border {
+            width: 4.0,
+            ..border
+        };
+
+        assert_eq!(border.widths(), [4.0; 4]);
+    }
+
+    #[test]
+    fn mixing_resolves_overridden_sides() {
+        let a = width(2).color(Color::BLACK);
+        let b = a.bottom(4, Color::BLACK);
+
+        assert_eq!(a.mix(a, 0.5), a);
+        assert_eq!(a.mix(b, 0.5).widths(), [2.0, 2.0, 3.0, 2.0]);
+    }
+}
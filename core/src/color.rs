@@ -195,6 +195,20 @@ impl Color {
             ..self
         }
     }
+
+    /// Linearly interpolates between the [`Color`] and the `other` one
+    /// by the given factor, from `0.0` to `1.0`.
+    pub fn mix(self, other: Color, factor: f32) -> Color {
+        let factor = factor.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| a + (b - a) * factor;
+
+        Self {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+            a: lerp(self.a, other.a),
+        }
+    }
 }
 
 impl From<[f32; 3]> for Color {
@@ -1,7 +1,9 @@
 //! Handle events of a user interface.
+use crate::accessibility;
 use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
+use crate::system;
 use crate::touch;
 use crate::window;
 
@@ -27,6 +29,12 @@ pub enum Event {
 
     /// An input method event
     InputMethod(input_method::Event),
+
+    /// A system event
+    System(system::Event),
+
+    /// A request of assistive technologies
+    Accessibility(accessibility::Request),
 }
 
 /// The status of an [`Event`] after being processed.
@@ -1,7 +1,7 @@
 //! Load and draw raster graphics.
 pub use bytes::Bytes;
 
-use crate::{Radians, Rectangle, Size};
+use crate::{Color, Radians, Rectangle, Size};
 
 use rustc_hash::FxHasher;
 use std::hash::{Hash, Hasher};
@@ -29,6 +29,9 @@ pub struct Image<H = Handle> {
     /// This can avoid graphical glitches, specially when using
     /// [`FilterMethod::Nearest`].
     pub snap: bool,
+
+    /// The [`Filter`] effects applied to the image.
+    pub filter: Filter,
 }
 
 impl Image<Handle> {
@@ -40,6 +43,7 @@ impl Image<Handle> {
             rotation: Radians(0.0),
             opacity: 1.0,
             snap: false,
+            filter: Filter::NONE,
         }
     }
 
@@ -66,6 +70,12 @@ impl Image<Handle> {
         self.snap = snap;
         self
     }
+
+    /// Sets the [`Filter`] effects of the [`Image`].
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
 }
 
 impl From<&Handle> for Image {
@@ -107,6 +117,24 @@ pub enum Handle {
         /// The pixels.
         pixels: Bytes,
     },
+
+    /// A handle pointing to pixels that live outside of the application
+    /// memory; like a video frame decoded into a GPU texture.
+    ///
+    /// The pixels must be provided to the renderer by other means. Renderers
+    /// that do not know about the pixels of the handle draw nothing.
+    ///
+    /// Use [`from_external`] to create this variant.
+    ///
+    /// [`from_external`]: Self::from_external
+    External {
+        /// The id of this handle.
+        id: Id,
+        /// The width of the image.
+        width: u32,
+        /// The height of the image.
+        height: u32,
+    },
 }
 
 impl Handle {
@@ -149,12 +177,53 @@ impl Handle {
         }
     }
 
+    /// Creates an image [`Handle`] for pixels of the given dimensions that
+    /// live outside of the application memory.
+    ///
+    /// This is meant to be used by renderers capable of importing external
+    /// pixel data; like GPU textures. You most likely want to use the import
+    /// functions of your renderer instead.
+    pub fn from_external(width: u32, height: u32) -> Handle {
+        Self::External {
+            id: Id::unique(),
+            width,
+            height,
+        }
+    }
+
+    /// Sets whether the EXIF orientation metadata of the image is honored
+    /// when decoding it; rotating and flipping its pixels accordingly.
+    ///
+    /// By default, it is honored; so photos taken with phones are not
+    /// displayed sideways.
+    pub fn exif_orientation(mut self, enabled: bool) -> Self {
+        match &mut self {
+            Handle::Path(id, _)
+            | Handle::Bytes(id, _)
+            | Handle::Rgba { id, .. }
+            | Handle::External { id, .. } => {
+                id.ignores_orientation = !enabled;
+            }
+        }
+
+        self
+    }
+
+    /// Returns true if the EXIF orientation metadata of the image is honored
+    /// when decoding it.
+    ///
+    /// See [`exif_orientation`](Self::exif_orientation).
+    pub fn honors_exif_orientation(&self) -> bool {
+        !self.id().ignores_orientation
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> Id {
         match self {
             Handle::Path(id, _)
             | Handle::Bytes(id, _)
-            | Handle::Rgba { id, .. } => *id,
+            | Handle::Rgba { id, .. }
+            | Handle::External { id, .. } => *id,
         }
     }
 }
@@ -182,13 +251,19 @@ impl std::fmt::Debug for Handle {
             Self::Rgba { width, height, .. } => {
                 write!(f, "Pixels({width} * {height})")
             }
+            Self::External { width, height, .. } => {
+                write!(f, "External({width} * {height})")
+            }
         }
     }
 }
 
 /// The unique identifier of some [`Handle`] data.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Id(_Id);
+pub struct Id {
+    raw: _Id,
+    ignores_orientation: bool,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum _Id {
@@ -202,7 +277,10 @@ impl Id {
 
         static NEXT_ID: AtomicU64 = AtomicU64::new(0);
 
-        Self(_Id::Unique(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed)))
+        Self {
+            raw: _Id::Unique(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed)),
+            ignores_orientation: false,
+        }
     }
 
     fn path(path: impl AsRef<Path>) -> Self {
@@ -213,7 +291,10 @@ impl Id {
             hasher.finish()
         };
 
-        Self(_Id::Hash(hash))
+        Self {
+            raw: _Id::Hash(hash),
+            ignores_orientation: false,
+        }
     }
 }
 
@@ -227,6 +308,144 @@ pub enum FilterMethod {
     Nearest,
 }
 
+/// The effects applied to the pixels of an [`Image`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Filter {
+    /// The radius of the blur of the image, in logical pixels.
+    pub blur: f32,
+
+    /// The amount of grayscale of the image.
+    ///
+    /// 0 keeps the original colors. 1 means completely gray.
+    pub grayscale: f32,
+
+    /// The brightness multiplier of the image.
+    ///
+    /// 1 keeps the original brightness.
+    pub brightness: f32,
+
+    /// The [`Color`] tint of the image.
+    ///
+    /// The alpha channel of the [`Color`] controls the strength of the tint.
+    pub tint: Option<Color>,
+}
+
+impl Filter {
+    /// A [`Filter`] without any effects.
+    pub const NONE: Self = Self {
+        blur: 0.0,
+        grayscale: 0.0,
+        brightness: 1.0,
+        tint: None,
+    };
+
+    /// Sets the blur radius of the [`Filter`], in logical pixels.
+    pub fn blur(self, blur: f32) -> Self {
+        Self { blur, ..self }
+    }
+
+    /// Sets the amount of grayscale of the [`Filter`].
+    pub fn grayscale(self, grayscale: f32) -> Self {
+        Self { grayscale, ..self }
+    }
+
+    /// Sets the brightness multiplier of the [`Filter`].
+    pub fn brightness(self, brightness: f32) -> Self {
+        Self { brightness, ..self }
+    }
+
+    /// Sets the [`Color`] tint of the [`Filter`].
+    pub fn tint(self, tint: impl Into<Color>) -> Self {
+        Self {
+            tint: Some(tint.into()),
+            ..self
+        }
+    }
+
+    /// Returns true if the [`Filter`] has no effects.
+    pub fn is_none(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// The memory budget of the texture atlas where a renderer caches images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Budget {
+    /// The maximum size of the atlas, in bytes.
+    ///
+    /// Once set, images are kept in the atlas even when they are not drawn;
+    /// and the least recently drawn images are evicted to make room for new
+    /// ones when the atlas reaches this size. Images that still do not fit
+    /// will not be drawn until some space is freed.
+    ///
+    /// By default, it is `None`; meaning the atlas can grow unbounded.
+    pub max_size: Option<u64>,
+
+    /// The [`Growth`] strategy of the atlas.
+    pub growth: Growth,
+}
+
+impl Budget {
+    /// Sets the maximum size of the [`Budget`], in bytes.
+    pub fn max_size(self, max_size: u64) -> Self {
+        Self {
+            max_size: Some(max_size),
+            ..self
+        }
+    }
+
+    /// Sets the [`Growth`] strategy of the [`Budget`].
+    pub fn growth(self, growth: Growth) -> Self {
+        Self { growth, ..self }
+    }
+}
+
+/// The strategy used to grow the texture atlas of a renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Growth {
+    /// The atlas only grows by the space needed.
+    ///
+    /// This minimizes memory usage, but the atlas will be copied more often.
+    #[default]
+    Exact,
+
+    /// The atlas doubles its size when it runs out of space.
+    ///
+    /// This reduces copies when many images are loaded at once.
+    Double,
+}
+
+/// The memory used by the images cached by a renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    /// The size of the texture atlas, in bytes.
+    pub allocated: u64,
+
+    /// The size of the texture atlas that is occupied by images, in bytes.
+    pub used: u64,
+
+    /// The amount of raster images in the texture atlas.
+    pub images: usize,
+}
+
+impl std::ops::Add for Usage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            allocated: self.allocated + other.allocated,
+            used: self.used + other.used,
+            images: self.images + other.images,
+        }
+    }
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
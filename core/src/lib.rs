@@ -9,8 +9,10 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
+pub mod accessibility;
 pub mod alignment;
 pub mod animation;
+pub mod background;
 pub mod border;
 pub mod clipboard;
 pub mod event;
@@ -24,7 +26,9 @@ pub mod mouse;
 pub mod overlay;
 pub mod padding;
 pub mod renderer;
+pub mod shadow;
 pub mod svg;
+pub mod system;
 pub mod text;
 pub mod theme;
 pub mod time;
@@ -33,7 +37,7 @@ pub mod widget;
 pub mod window;
 
 mod angle;
-mod background;
+mod antialiasing;
 mod color;
 mod content_fit;
 mod element;
@@ -43,7 +47,6 @@ mod point;
 mod rectangle;
 mod rotation;
 mod settings;
-mod shadow;
 mod shell;
 mod size;
 mod transformation;
@@ -52,6 +55,7 @@ mod vector;
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
 pub use animation::Animation;
+pub use antialiasing::Antialiasing;
 pub use background::Background;
 pub use border::Border;
 pub use clipboard::Clipboard;
@@ -0,0 +1,89 @@
+//! Use your own images as mouse cursors.
+use crate::{Point, Size};
+
+use std::sync::{Arc, Mutex};
+
+/// A custom cursor image; shown with [`Interaction::Custom`].
+///
+/// A [`Custom`] cursor is a cheap handle that can be freely copied; its
+/// pixels are kept alive for the whole lifetime of the application. Create
+/// your cursors once and reuse them.
+///
+/// [`Interaction::Custom`]: super::Interaction::Custom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Custom(usize);
+
+/// The pixels of a [`Custom`] cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    /// The RGBA pixels of the cursor.
+    pub rgba: Vec<u8>,
+    /// The size of the cursor, in pixels.
+    pub size: Size<u32>,
+    /// The point of the cursor that is aimed at; like the tip of an arrow.
+    pub hotspot: Point<u32>,
+}
+
+static CURSORS: Mutex<Vec<Arc<Data>>> = Mutex::new(Vec::new());
+
+impl Custom {
+    /// Creates a [`Custom`] cursor from its RGBA pixels, its size, and its
+    /// hotspot.
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+        hotspot: Point<u32>,
+    ) -> Result<Self, Error> {
+        if rgba.len() != width as usize * height as usize * 4 {
+            return Err(Error::InvalidSize {
+                byte_count: rgba.len(),
+                width,
+                height,
+            });
+        }
+
+        if hotspot.x >= width || hotspot.y >= height {
+            return Err(Error::HotspotOutOfBounds { hotspot });
+        }
+
+        let mut cursors = CURSORS.lock().expect("Lock custom cursors");
+
+        cursors.push(Arc::new(Data {
+            rgba,
+            size: Size::new(width, height),
+            hotspot,
+        }));
+
+        Ok(Self(cursors.len() - 1))
+    }
+
+    /// Returns the [`Data`] of the [`Custom`] cursor.
+    pub fn data(self) -> Arc<Data> {
+        CURSORS.lock().expect("Lock custom cursors")[self.0].clone()
+    }
+}
+
+/// An error produced when creating a [`Custom`] cursor.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The amount of RGBA bytes does not match the given size.
+    #[error(
+        "The provided RGBA data (with length {byte_count}) does not match \
+        the provided dimensions ({width}x{height})"
+    )]
+    InvalidSize {
+        /// The length of the provided RGBA data.
+        byte_count: usize,
+        /// The provided width.
+        width: u32,
+        /// The provided height.
+        height: u32,
+    },
+    /// The hotspot is outside of the cursor.
+    #[error("The hotspot ({}, {}) is outside of the cursor", hotspot.x, hotspot.y)]
+    HotspotOutOfBounds {
+        /// The provided hotspot.
+        hotspot: Point<u32>,
+    },
+}
@@ -1,3 +1,5 @@
+use crate::mouse::Custom;
+
 /// The interaction of a mouse cursor.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Default)]
 #[allow(missing_docs)]
@@ -22,4 +24,6 @@ pub enum Interaction {
     Move,
     Copy,
     Help,
+    /// A [`Custom`] cursor image.
+    Custom(Custom),
 }
@@ -2,9 +2,10 @@
 #[cfg(debug_assertions)]
 mod null;
 
+use crate::shadow::Shadows;
 use crate::{
-    Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size,
-    Transformation, Vector,
+    Background, Border, Color, Font, Pixels, Rectangle, Size, Transformation,
+    Vector,
 };
 
 /// A component that can be used by widgets to draw themselves on a screen.
@@ -57,11 +58,44 @@ pub trait Renderer {
         );
     }
 
+    /// Starts recording with the given opacity.
+    ///
+    /// The primitives recorded until [`end_opacity`](Self::end_opacity) is
+    /// called are grouped and composited together with this opacity, which
+    /// is multiplied with the one currently in effect.
+    ///
+    /// By default, the opacity is ignored.
+    fn start_opacity(&mut self, _opacity: f32) {}
+
+    /// Ends recording with the current opacity.
+    fn end_opacity(&mut self) {}
+
+    /// Applies the given opacity to the primitives recorded in the given closure.
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        self.start_opacity(opacity);
+        f(self);
+        self.end_opacity();
+    }
+
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
     /// Clears all of the recorded primitives in the [`Renderer`].
     fn clear(&mut self);
+
+    /// Hints the [`Renderer`] about the scale factor of the surface it
+    /// will draw into.
+    ///
+    /// By default, the hint is ignored.
+    fn hint(&mut self, _scale_factor: f32) {}
+
+    /// Returns the last scale factor hinted to the [`Renderer`], if any.
+    ///
+    /// Widgets that rasterize their own contents can use it to produce
+    /// crisp results in high DPI displays.
+    fn scale_factor(&self) -> Option<f32> {
+        None
+    }
 }
 
 /// A polygon with four sides.
@@ -73,8 +107,11 @@ pub struct Quad {
     /// The [`Border`] of the [`Quad`]. The border is drawn on the inside of the [`Quad`].
     pub border: Border,
 
-    /// The [`Shadow`] of the [`Quad`].
-    pub shadow: Shadow,
+    /// The [`Shadows`] of the [`Quad`].
+    ///
+    /// [`Shadows`] can be cast both around and inside the [`Quad`]. A
+    /// [`Quad`] casts at most [`Shadows::MAX`] shadows.
+    pub shadow: Shadows,
 
     /// Whether the [`Quad`] should be snapped to the pixel grid.
     pub snap: bool,
@@ -85,7 +122,7 @@ impl Default for Quad {
         Self {
             bounds: Rectangle::with_size(Size::ZERO),
             border: Border::default(),
-            shadow: Shadow::default(),
+            shadow: Shadows::default(),
             snap: cfg!(feature = "crisp"),
         }
     }
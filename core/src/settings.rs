@@ -1,4 +1,6 @@
 //! Configure your application.
+use crate::image;
+use crate::window;
 use crate::{Font, Pixels};
 
 use std::borrow::Cow;
@@ -33,6 +35,17 @@ pub struct Settings {
     ///
     /// By default, it is enabled.
     pub antialiasing: bool,
+
+    /// The memory [`Budget`](image::Budget) of the images cached by the
+    /// renderer.
+    ///
+    /// By default, the image atlas can grow unbounded.
+    pub image_budget: image::Budget,
+
+    /// The default [`FrameRate`](window::FrameRate) of the windows.
+    ///
+    /// By default, it is unlimited.
+    pub frame_rate: window::FrameRate,
 }
 
 impl Default for Settings {
@@ -43,6 +56,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: true,
+            image_budget: image::Budget::default(),
+            frame_rate: window::FrameRate::UNLIMITED,
         }
     }
 }
@@ -1,3 +1,4 @@
+//! Cast shadows around or inside containers.
 use crate::{Color, Vector};
 
 /// A shadow.
@@ -11,4 +12,132 @@ pub struct Shadow {
 
     /// The blur radius of the shadow.
     pub blur_radius: f32,
+
+    /// Whether the shadow is cast inside the bounds of its container,
+    /// instead of around it.
+    pub inset: bool,
+}
+
+impl Shadow {
+    /// Turns the [`Shadow`] into an inset [`Shadow`].
+    pub fn inset(self) -> Self {
+        Self {
+            inset: true,
+            ..self
+        }
+    }
+
+    /// Returns true if the [`Shadow`] is visible.
+    pub fn is_visible(&self) -> bool {
+        self.color.a > 0.0
+    }
+}
+
+/// A stack of [`Shadow`]s.
+///
+/// The first [`Shadow`] of the stack is drawn on top of the rest. A stack
+/// holds up to [`Shadows::MAX`] shadows; so it can be copied and uploaded
+/// to the GPU as part of a single quad.
+///
+/// ```
+/// # use iced_core::shadow::{Shadow, Shadows};
+/// # use iced_core::{Color, Vector};
+/// #
+/// let shadows = Shadows::from(Shadow {
+///     color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+///     offset: Vector::new(0.0, 1.0),
+///     blur_radius: 2.0,
+///     ..Shadow::default()
+/// })
+/// .with(Shadow {
+///     color: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+///     offset: Vector::new(0.0, 8.0),
+///     blur_radius: 16.0,
+///     ..Shadow::default()
+/// });
+///
+/// assert_eq!(shadows.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Shadows {
+    shadows: [Shadow; Shadows::MAX],
+    len: usize,
+}
+
+impl Shadows {
+    /// The maximum amount of [`Shadow`]s that can be stacked.
+    pub const MAX: usize = 4;
+
+    /// Creates an empty stack of [`Shadows`].
+    pub const fn new() -> Self {
+        Self {
+            shadows: [Shadow {
+                color: Color::TRANSPARENT,
+                offset: Vector::ZERO,
+                blur_radius: 0.0,
+                inset: false,
+            }; Self::MAX],
+            len: 0,
+        }
+    }
+
+    /// Stacks a new [`Shadow`] below the current ones.
+    ///
+    /// At most [`Shadows::MAX`] shadows can be stacked; any [`Shadow`] over
+    /// the limit is ignored and logged as a warning.
+    ///
+    /// # Panics
+    /// In debug builds, it panics if the stack is already full.
+    pub fn with(mut self, shadow: impl Into<Shadow>) -> Self {
+        debug_assert!(
+            self.len < Self::MAX,
+            "Shadows cannot stack more than {} shadows",
+            Self::MAX,
+        );
+
+        if self.len < Self::MAX {
+            self.shadows[self.len] = shadow.into();
+            self.len += 1;
+        } else {
+            log::warn!(
+                "Ignoring shadow over the limit of {} shadows",
+                Self::MAX,
+            );
+        }
+
+        self
+    }
+
+    /// Returns the amount of [`Shadow`]s in the stack.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no [`Shadow`]s in the stack.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if any [`Shadow`] in the stack is visible.
+    pub fn is_visible(&self) -> bool {
+        self.iter().any(Shadow::is_visible)
+    }
+
+    /// Returns an iterator over the [`Shadow`]s in the stack, from
+    /// top to bottom.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Shadow> {
+        self.shadows[..self.len].iter()
+    }
+}
+
+impl From<Shadow> for Shadows {
+    fn from(shadow: Shadow) -> Self {
+        Self::new().with(shadow)
+    }
+}
+
+impl<const N: usize> From<[Shadow; N]> for Shadows {
+    fn from(shadows: [Shadow; N]) -> Self {
+        shadows.into_iter().fold(Self::new(), Self::with)
+    }
 }
@@ -1,4 +1,5 @@
 //! Load and draw vector graphics.
+use crate::time::Duration;
 use crate::{Color, Radians, Rectangle, Size};
 
 use rustc_hash::FxHasher;
@@ -72,6 +73,8 @@ impl From<&Handle> for Svg {
 pub struct Handle {
     id: u64,
     data: Arc<Data>,
+    style_sheet: Option<Arc<str>>,
+    time: Option<Duration>,
 }
 
 impl Handle {
@@ -97,18 +100,63 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             data: Arc::new(data),
+            style_sheet: None,
+            time: None,
         }
     }
 
+    /// Injects the given CSS style sheet into the SVG of the [`Handle`].
+    ///
+    /// The style sheet is applied after the styles of the SVG itself, so
+    /// its rules win over the ones of the SVG with the same specificity;
+    /// which is useful to recolor the elements of an icon by class or
+    /// to set the `currentColor` of the SVG.
+    pub fn with_style_sheet(
+        mut self,
+        style_sheet: impl Into<Arc<str>>,
+    ) -> Self {
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the time at which the animations of the SVG of the [`Handle`]
+    /// will be drawn.
+    ///
+    /// By default, the SVG is drawn without playing its animations.
+    pub fn with_time(mut self, time: Duration) -> Self {
+        self.time = Some(time);
+        self
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> u64 {
-        self.id
+        if self.style_sheet.is_none() && self.time.is_none() {
+            return self.id;
+        }
+
+        let mut hasher = FxHasher::default();
+        self.id.hash(&mut hasher);
+        self.style_sheet.hash(&mut hasher);
+        self.time.map(|time| time.as_millis()).hash(&mut hasher);
+
+        hasher.finish()
     }
 
     /// Returns a reference to the SVG [`Data`].
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the CSS style sheet injected into the SVG, if any.
+    pub fn style_sheet(&self) -> Option<&str> {
+        self.style_sheet.as_deref()
+    }
+
+    /// Returns the time at which the animations of the SVG will be drawn,
+    /// if any.
+    pub fn time(&self) -> Option<Duration> {
+        self.time
+    }
 }
 
 impl<T> From<T> for Handle
@@ -122,7 +170,7 @@ where
 
 impl Hash for Handle {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.id.hash(state);
+        self.id().hash(state);
     }
 }
 
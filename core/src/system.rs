@@ -0,0 +1,67 @@
+//! Query and listen to the preferences of the native system.
+use crate::Color;
+
+/// The appearance preferences of the native system.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Appearance {
+    /// The preferred color [`Mode`].
+    pub mode: Mode,
+
+    /// The accent [`Color`] chosen by the user, if available.
+    ///
+    /// It is only detected on Windows; it is always `None` elsewhere.
+    pub accent_color: Option<Color>,
+
+    /// Whether the user prefers high contrast colors.
+    pub high_contrast: bool,
+
+    /// Whether the user prefers reduced motion.
+    pub reduced_motion: bool,
+}
+
+/// The color mode preferred by the native system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Mode {
+    /// The system has no preference, or it could not be detected.
+    #[default]
+    Unspecified,
+    /// The system prefers light colors.
+    Light,
+    /// The system prefers dark colors.
+    Dark,
+}
+
+impl Mode {
+    /// Returns true if the [`Mode`] is [`Mode::Dark`].
+    pub fn is_dark(self) -> bool {
+        self == Self::Dark
+    }
+}
+
+/// The network connectivity of the native system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Network {
+    /// Whether the system has a route to the internet.
+    ///
+    /// A route does not guarantee the internet is reachable on every
+    /// platform; for instance, behind a captive portal.
+    pub is_online: bool,
+
+    /// Whether the connection is metered; `None` if it cannot be detected.
+    ///
+    /// It is only detected on Windows and on Linux with NetworkManager.
+    pub is_metered: Option<bool>,
+}
+
+/// A system-related event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The [`Appearance`] of the system has changed.
+    AppearanceChanged(Appearance),
+
+    /// The color [`Mode`] or the accent color of the system have changed.
+    ///
+    /// Unlike [`Event::AppearanceChanged`], this is not produced when only
+    /// the accessibility preferences change.
+    ThemeChanged(Appearance),
+}
@@ -1,9 +1,24 @@
 //! Use the built-in theme and styles.
+pub mod elevation;
+pub mod extensions;
 pub mod palette;
+pub mod styles;
+pub mod tokens;
 
+#[cfg(feature = "serde")]
+pub mod definition;
+
+pub use elevation::Elevation;
+pub use extensions::Extensions;
 pub use palette::Palette;
+pub use styles::Styles;
+pub use tokens::Tokens;
+
+#[cfg(feature = "serde")]
+pub use definition::Definition;
 
 use crate::Color;
+use crate::system::{Appearance, Mode};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -105,6 +120,30 @@ impl Theme {
         Self::Custom(Arc::new(Custom::with_fn(name, palette, generate)))
     }
 
+    /// Parses a custom [`Theme`] from a TOML [`Definition`].
+    #[cfg(feature = "theme-file")]
+    pub fn from_toml(toml: &str) -> Result<Self, definition::Error> {
+        Definition::from_toml(toml).map(|definition| definition.theme())
+    }
+
+    /// Parses a custom [`Theme`] from a JSON [`Definition`].
+    #[cfg(feature = "theme-file")]
+    pub fn from_json(json: &str) -> Result<Self, definition::Error> {
+        Definition::from_json(json).map(|definition| definition.theme())
+    }
+
+    /// Exports the [`Theme`] as a TOML [`Definition`].
+    #[cfg(feature = "theme-file")]
+    pub fn to_toml(&self) -> String {
+        Definition::from_theme(self).to_toml()
+    }
+
+    /// Exports the [`Theme`] as a JSON [`Definition`].
+    #[cfg(feature = "theme-file")]
+    pub fn to_json(&self) -> String {
+        Definition::from_theme(self).to_json()
+    }
+
     /// Returns the [`Palette`] of the [`Theme`].
     pub fn palette(&self) -> Palette {
         match self {
@@ -134,6 +173,116 @@ impl Theme {
         }
     }
 
+    /// Returns the built-in [`Theme`] that matches the given system [`Mode`].
+    ///
+    /// This is useful to follow the [`Appearance`] of the system.
+    ///
+    /// [`Appearance`]: crate::system::Appearance
+    pub fn from_mode(mode: Mode) -> Self {
+        match mode {
+            Mode::Dark => Self::Dark,
+            Mode::Light | Mode::Unspecified => Self::Light,
+        }
+    }
+
+    /// Returns the built-in [`Theme`] that matches the given system
+    /// [`Appearance`]; including its high contrast preference.
+    pub fn from_appearance(appearance: Appearance) -> Self {
+        let theme = Self::from_mode(appearance.mode);
+
+        if appearance.high_contrast {
+            theme.high_contrast()
+        } else {
+            theme
+        }
+    }
+
+    /// Returns a high contrast variant of the [`Theme`].
+    ///
+    /// The colors of the variant meet minimum contrast ratios, and the
+    /// built-in widget styles draw stronger outlines; specially when
+    /// focused.
+    pub fn high_contrast(&self) -> Self {
+        if self.is_high_contrast() {
+            return self.clone();
+        }
+
+        let mut custom = Custom::new(
+            format!("{self} (High Contrast)"),
+            self.palette().high_contrast(),
+        );
+
+        custom.is_high_contrast = true;
+
+        Self::Custom(Arc::new(custom))
+    }
+
+    /// Returns true if the [`Theme`] is a high contrast variant.
+    pub fn is_high_contrast(&self) -> bool {
+        match self {
+            Self::Custom(custom) => custom.is_high_contrast,
+            _ => false,
+        }
+    }
+
+    /// Returns the design [`Tokens`] of the [`Theme`].
+    pub fn tokens(&self) -> Tokens {
+        match self {
+            Self::Custom(custom) => custom.tokens,
+            _ => Tokens::generate(self.extended_palette()),
+        }
+    }
+
+    /// Returns a new [`Theme`] with the same colors, but using the
+    /// given design [`Tokens`].
+    pub fn with_tokens(self, tokens: Tokens) -> Self {
+        Self::Custom(Arc::new(Custom {
+            tokens,
+            ..self.into_custom()
+        }))
+    }
+
+    /// Returns the extension of the given type of the [`Theme`], if present.
+    ///
+    /// See [`Extensions`] for more details.
+    pub fn extension<T>(&self) -> Option<&T>
+    where
+        T: std::any::Any,
+    {
+        match self {
+            Self::Custom(custom) => custom.extensions.get(),
+            _ => None,
+        }
+    }
+
+    /// Returns a new [`Theme`] with the same colors, carrying the given
+    /// extension.
+    ///
+    /// Any previous extension of the same type will be replaced.
+    pub fn with_extension<T>(self, extension: T) -> Self
+    where
+        T: std::any::Any + Send + Sync,
+    {
+        let mut custom = self.into_custom();
+        custom.extensions.insert(extension);
+
+        Self::Custom(Arc::new(custom))
+    }
+
+    fn into_custom(self) -> Custom {
+        match self {
+            Self::Custom(custom) => Custom::clone(&custom),
+            theme => Custom {
+                name: Cow::Owned(theme.to_string()),
+                palette: theme.palette(),
+                extended: *theme.extended_palette(),
+                tokens: theme.tokens(),
+                extensions: Extensions::new(),
+                is_high_contrast: false,
+            },
+        }
+    }
+
     /// Returns the [`palette::Extended`] of the [`Theme`].
     pub fn extended_palette(&self) -> &palette::Extended {
         match self {
@@ -227,6 +376,9 @@ pub struct Custom {
     name: Cow<'static, str>,
     palette: Palette,
     extended: palette::Extended,
+    tokens: Tokens,
+    extensions: Extensions,
+    is_high_contrast: bool,
 }
 
 impl Custom {
@@ -242,10 +394,15 @@ impl Custom {
         palette: Palette,
         generate: impl FnOnce(Palette) -> palette::Extended,
     ) -> Self {
+        let extended = generate(palette);
+
         Self {
             name: name.into(),
             palette,
-            extended: generate(palette),
+            extended,
+            tokens: Tokens::generate(&extended),
+            extensions: Extensions::new(),
+            is_high_contrast: false,
         }
     }
 }
@@ -0,0 +1,733 @@
+//! Describe a [`Theme`] with plain data that can be stored in a file.
+use crate::Color;
+use crate::theme::palette::{self, Pair};
+use crate::theme::tokens::{Radii, Spacing, Typography};
+use crate::theme::{Palette, Styles, Theme, Tokens};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::sync::Arc;
+
+/// The plain data description of a [`Theme`].
+///
+/// A [`Definition`] contains the base colors of a [`Palette`] and some
+/// optional [`Overrides`] of the [`palette::Extended`] colors generated
+/// from it; which widgets use for styling. It may also change the
+/// spacing, radius, and text scales of the [`Tokens`] of the [`Theme`],
+/// and override the styles of the built-in widgets with [`Styles`].
+///
+/// Any [`Theme`] can be exported with [`Definition::from_theme`] and
+/// imported back into another application with [`Definition::theme`].
+///
+/// Colors are written as hex strings:
+///
+/// ```toml
+/// name = "Ocean"
+///
+/// [palette]
+/// background = "#0f1b2d"
+/// text = "#e6edf3"
+/// primary = "#3b82f6"
+/// success = "#22c55e"
+/// warning = "#eab308"
+/// danger = "#ef4444"
+///
+/// [overrides.primary.strong]
+/// color = "#1d4ed8"
+///
+/// [styles.button]
+/// radius = 8
+/// border_width = 1
+/// border_color = "#1d4ed8"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Definition {
+    /// The name of the [`Theme`].
+    pub name: String,
+
+    /// The base colors of the [`Theme`].
+    #[serde(with = "palette_hex")]
+    pub palette: Palette,
+
+    /// The overrides of the generated [`palette::Extended`] colors.
+    #[serde(default, skip_serializing_if = "Overrides::is_empty")]
+    pub overrides: Overrides,
+
+    /// The [`Spacing`] scale of the [`Theme`], if not the default one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spacing: Option<Spacing>,
+
+    /// The [`Radii`] scale of the [`Theme`], if not the default one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius: Option<Radii>,
+
+    /// The [`Typography`] scale of the [`Theme`], if not the default one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<Typography>,
+
+    /// The overrides of the styles of the built-in widgets.
+    #[serde(default, skip_serializing_if = "Styles::is_empty")]
+    pub styles: Styles,
+}
+
+impl Definition {
+    /// Creates a new [`Definition`] with the given name and [`Palette`].
+    pub fn new(name: impl Into<String>, palette: Palette) -> Self {
+        Self {
+            name: name.into(),
+            palette,
+            overrides: Overrides::default(),
+            spacing: None,
+            radius: None,
+            text: None,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Creates the [`Definition`] of the given [`Theme`].
+    ///
+    /// The [`Definition`] is fully resolved; any color of the
+    /// [`palette::Extended`] of the [`Theme`] that differs from the
+    /// generated one is stored as an override. Therefore, the [`Theme`]
+    /// built from it will look the same as the original one.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let palette = theme.palette();
+        let generated = palette::Extended::generate(palette);
+        let tokens = theme.tokens();
+
+        Self {
+            name: theme.to_string(),
+            palette,
+            overrides: Overrides::between(&generated, theme.extended_palette()),
+            spacing: changed(tokens.spacing, Spacing::DEFAULT),
+            radius: changed(tokens.radius, Radii::DEFAULT),
+            text: changed(tokens.text, Typography::DEFAULT),
+            styles: theme.extension::<Styles>().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Builds the [`Theme`] described by the [`Definition`].
+    pub fn theme(&self) -> Theme {
+        let overrides = self.overrides.clone();
+
+        let theme = Theme::custom_with_fn(
+            self.name.clone(),
+            self.palette,
+            move |palette| {
+                let mut extended = palette::Extended::generate(palette);
+                overrides.apply(&mut extended);
+
+                extended
+            },
+        );
+
+        let theme = if self.spacing.is_none()
+            && self.radius.is_none()
+            && self.text.is_none()
+        {
+            theme
+        } else {
+            let tokens = theme.tokens();
+
+            theme.with_tokens(Tokens {
+                spacing: self.spacing.unwrap_or(tokens.spacing),
+                radius: self.radius.unwrap_or(tokens.radius),
+                text: self.text.unwrap_or(tokens.text),
+                ..tokens
+            })
+        };
+
+        if self.styles.is_empty() {
+            theme
+        } else {
+            theme.with_extension(self.styles.clone())
+        }
+    }
+
+    /// Parses a [`Definition`] from TOML.
+    #[cfg(feature = "theme-file")]
+    pub fn from_toml(toml: &str) -> Result<Self, Error> {
+        toml::from_str(toml).map_err(|error| Error::Toml(Arc::new(error)))
+    }
+
+    /// Parses a [`Definition`] from JSON.
+    #[cfg(feature = "theme-file")]
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|error| Error::Json(Arc::new(error)))
+    }
+
+    /// Serializes the [`Definition`] to TOML.
+    #[cfg(feature = "theme-file")]
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("Serialize theme definition")
+    }
+
+    /// Serializes the [`Definition`] to JSON.
+    #[cfg(feature = "theme-file")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Serialize theme definition")
+    }
+}
+
+impl From<&Theme> for Definition {
+    fn from(theme: &Theme) -> Self {
+        Self::from_theme(theme)
+    }
+}
+
+/// A set of optional overrides of the colors of a [`palette::Extended`].
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Overrides {
+    /// The overrides of the background colors.
+    #[serde(skip_serializing_if = "Shades::is_empty")]
+    pub background: Shades,
+    /// The overrides of the primary colors.
+    #[serde(skip_serializing_if = "Shades::is_empty")]
+    pub primary: Shades,
+    /// The overrides of the secondary colors.
+    #[serde(skip_serializing_if = "Shades::is_empty")]
+    pub secondary: Shades,
+    /// The overrides of the success colors.
+    #[serde(skip_serializing_if = "Shades::is_empty")]
+    pub success: Shades,
+    /// The overrides of the warning colors.
+    #[serde(skip_serializing_if = "Shades::is_empty")]
+    pub warning: Shades,
+    /// The overrides of the danger colors.
+    #[serde(skip_serializing_if = "Shades::is_empty")]
+    pub danger: Shades,
+}
+
+impl Overrides {
+    /// Returns true if the [`Overrides`] do not change any color.
+    pub fn is_empty(&self) -> bool {
+        self.background.is_empty()
+            && self.primary.is_empty()
+            && self.secondary.is_empty()
+            && self.success.is_empty()
+            && self.warning.is_empty()
+            && self.danger.is_empty()
+    }
+
+    /// Returns the [`Overrides`] needed to turn the `generated`
+    /// [`palette::Extended`] into the `target` one.
+    pub fn between(
+        generated: &palette::Extended,
+        target: &palette::Extended,
+    ) -> Self {
+        let background = Shades {
+            base: PairOverride::between(
+                generated.background.base,
+                target.background.base,
+            ),
+            weakest: PairOverride::between(
+                generated.background.weakest,
+                target.background.weakest,
+            ),
+            weak: PairOverride::between(
+                generated.background.weak,
+                target.background.weak,
+            ),
+            strong: PairOverride::between(
+                generated.background.strong,
+                target.background.strong,
+            ),
+            strongest: PairOverride::between(
+                generated.background.strongest,
+                target.background.strongest,
+            ),
+        };
+
+        Self {
+            background,
+            primary: Shades::between(
+                [
+                    generated.primary.base,
+                    generated.primary.weak,
+                    generated.primary.strong,
+                ],
+                [
+                    target.primary.base,
+                    target.primary.weak,
+                    target.primary.strong,
+                ],
+            ),
+            secondary: Shades::between(
+                [
+                    generated.secondary.base,
+                    generated.secondary.weak,
+                    generated.secondary.strong,
+                ],
+                [
+                    target.secondary.base,
+                    target.secondary.weak,
+                    target.secondary.strong,
+                ],
+            ),
+            success: Shades::between(
+                [
+                    generated.success.base,
+                    generated.success.weak,
+                    generated.success.strong,
+                ],
+                [
+                    target.success.base,
+                    target.success.weak,
+                    target.success.strong,
+                ],
+            ),
+            warning: Shades::between(
+                [
+                    generated.warning.base,
+                    generated.warning.weak,
+                    generated.warning.strong,
+                ],
+                [
+                    target.warning.base,
+                    target.warning.weak,
+                    target.warning.strong,
+                ],
+            ),
+            danger: Shades::between(
+                [
+                    generated.danger.base,
+                    generated.danger.weak,
+                    generated.danger.strong,
+                ],
+                [target.danger.base, target.danger.weak, target.danger.strong],
+            ),
+        }
+    }
+
+    /// Applies the [`Overrides`] to the given [`palette::Extended`].
+    pub fn apply(&self, extended: &mut palette::Extended) {
+        let background = &mut extended.background;
+
+        self.background.base.apply(&mut background.base);
+        self.background.weakest.apply(&mut background.weakest);
+        self.background.weak.apply(&mut background.weak);
+        self.background.strong.apply(&mut background.strong);
+        self.background.strongest.apply(&mut background.strongest);
+
+        self.primary.apply(
+            &mut extended.primary.base,
+            &mut extended.primary.weak,
+            &mut extended.primary.strong,
+        );
+
+        self.secondary.apply(
+            &mut extended.secondary.base,
+            &mut extended.secondary.weak,
+            &mut extended.secondary.strong,
+        );
+
+        self.success.apply(
+            &mut extended.success.base,
+            &mut extended.success.weak,
+            &mut extended.success.strong,
+        );
+
+        self.warning.apply(
+            &mut extended.warning.base,
+            &mut extended.warning.weak,
+            &mut extended.warning.strong,
+        );
+
+        self.danger.apply(
+            &mut extended.danger.base,
+            &mut extended.danger.weak,
+            &mut extended.danger.strong,
+        );
+    }
+}
+
+/// The overrides of a set of [`Pair`]s of a [`palette::Extended`].
+///
+/// The `weakest` and `strongest` shades are only available
+/// for background colors.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Shades {
+    /// The override of the base [`Pair`].
+    #[serde(skip_serializing_if = "PairOverride::is_empty")]
+    pub base: PairOverride,
+    /// The override of the weakest [`Pair`].
+    #[serde(skip_serializing_if = "PairOverride::is_empty")]
+    pub weakest: PairOverride,
+    /// The override of the weak [`Pair`].
+    #[serde(skip_serializing_if = "PairOverride::is_empty")]
+    pub weak: PairOverride,
+    /// The override of the strong [`Pair`].
+    #[serde(skip_serializing_if = "PairOverride::is_empty")]
+    pub strong: PairOverride,
+    /// The override of the strongest [`Pair`].
+    #[serde(skip_serializing_if = "PairOverride::is_empty")]
+    pub strongest: PairOverride,
+}
+
+impl Shades {
+    /// Returns true if the [`Shades`] do not change any color.
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+            && self.weakest.is_empty()
+            && self.weak.is_empty()
+            && self.strong.is_empty()
+            && self.strongest.is_empty()
+    }
+
+    fn between(
+        [generated_base, generated_weak, generated_strong]: [Pair; 3],
+        [base, weak, strong]: [Pair; 3],
+    ) -> Self {
+        Self {
+            base: PairOverride::between(generated_base, base),
+            weak: PairOverride::between(generated_weak, weak),
+            strong: PairOverride::between(generated_strong, strong),
+            ..Self::default()
+        }
+    }
+
+    fn apply(&self, base: &mut Pair, weak: &mut Pair, strong: &mut Pair) {
+        self.base.apply(base);
+        self.weak.apply(weak);
+        self.strong.apply(strong);
+    }
+}
+
+/// The override of a [`Pair`] of colors.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PairOverride {
+    /// The new background [`Color`] of the [`Pair`].
+    #[serde(with = "option_hex", skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// The new text [`Color`] of the [`Pair`].
+    #[serde(with = "option_hex", skip_serializing_if = "Option::is_none")]
+    pub text: Option<Color>,
+}
+
+impl PairOverride {
+    /// Returns true if the [`PairOverride`] does not change any color.
+    pub fn is_empty(&self) -> bool {
+        self.color.is_none() && self.text.is_none()
+    }
+
+    /// Returns the [`PairOverride`] needed to turn the `generated` [`Pair`]
+    /// into the `target` one.
+    ///
+    /// Both colors are overridden if any of them changed, so the text
+    /// color is not adjusted when applied.
+    pub fn between(generated: Pair, target: Pair) -> Self {
+        if generated == target {
+            return Self::default();
+        }
+
+        Self {
+            color: Some(target.color),
+            text: Some(target.text),
+        }
+    }
+
+    /// Applies the [`PairOverride`] to the given [`Pair`].
+    ///
+    /// If only the background color is overridden, the text color
+    /// will be adjusted to stay readable.
+    pub fn apply(&self, pair: &mut Pair) {
+        match (self.color, self.text) {
+            (Some(color), Some(text)) => {
+                *pair = Pair { color, text };
+            }
+            (Some(color), None) => {
+                *pair = Pair::new(color, pair.text);
+            }
+            (None, Some(text)) => {
+                pair.text = text;
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// An error produced when loading a [`Definition`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The TOML of the [`Definition`] is invalid.
+    #[cfg(feature = "theme-file")]
+    #[error("invalid TOML theme: {0}")]
+    Toml(Arc<toml::de::Error>),
+    /// The JSON of the [`Definition`] is invalid.
+    #[cfg(feature = "theme-file")]
+    #[error("invalid JSON theme: {0}")]
+    Json(Arc<serde_json::Error>),
+    /// The file of the [`Definition`] could not be read.
+    #[error("theme file could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+/// Returns the given value, if it differs from the default one.
+fn changed<T: PartialEq>(value: T, default: T) -> Option<T> {
+    (value != default).then_some(value)
+}
+
+fn serialize_color<S: Serializer>(
+    color: &Color,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let [r, g, b, a] = color.into_rgba8();
+
+    let hex = if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+
+    serializer.serialize_str(&hex)
+}
+
+fn deserialize_color<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Color, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+
+    Color::parse(&hex).ok_or_else(|| {
+        serde::de::Error::custom(format!("invalid hex color: {hex}"))
+    })
+}
+
+pub(crate) mod option_hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match color {
+            Some(color) => serialize_color(color, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color>, D::Error> {
+        deserialize_color(deserializer).map(Some)
+    }
+}
+
+mod palette_hex {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Hex(
+        #[serde(
+            serialize_with = "serialize_color",
+            deserialize_with = "deserialize_color"
+        )]
+        Color,
+    );
+
+    #[derive(Serialize, Deserialize)]
+    struct Colors {
+        background: Hex,
+        text: Hex,
+        primary: Hex,
+        success: Hex,
+        warning: Hex,
+        danger: Hex,
+    }
+
+    pub fn serialize<S: Serializer>(
+        palette: &Palette,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Colors {
+            background: Hex(palette.background),
+            text: Hex(palette.text),
+            primary: Hex(palette.primary),
+            success: Hex(palette.success),
+            warning: Hex(palette.warning),
+            danger: Hex(palette.danger),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Palette, D::Error> {
+        let colors = Colors::deserialize(deserializer)?;
+
+        Ok(Palette {
+            background: colors.background.0,
+            text: colors.text.0,
+            primary: colors.primary.0,
+            success: colors.success.0,
+            warning: colors.warning.0,
+            danger: colors.danger.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn definition_preserves_theme() {
+        for theme in Theme::ALL {
+            let imported = Definition::from_theme(theme).theme();
+
+            assert_eq!(imported.palette(), theme.palette());
+            assert_eq!(imported.extended_palette(), theme.extended_palette());
+            assert_eq!(imported.tokens(), theme.tokens());
+        }
+    }
+
+    #[test]
+    fn overrides_are_merged_into_generated_palette() {
+        let palette = Theme::Dark.palette();
+        let generated = palette::Extended::generate(palette);
+        let red = Color::from_rgb8(255, 0, 0);
+
+        let mut definition = Definition::new("Merged", palette);
+        definition.overrides.primary.strong.color = Some(red);
+        definition.overrides.danger.base.text = Some(Color::WHITE);
+
+        let theme = definition.theme();
+        let extended = theme.extended_palette();
+
+        assert_eq!(
+            extended.primary.strong,
+            Pair::new(red, generated.primary.strong.text)
+        );
+        assert_eq!(extended.danger.base.text, Color::WHITE);
+        assert_eq!(extended.danger.base.color, generated.danger.base.color);
+        assert_eq!(extended.background, generated.background);
+    }
+
+    #[test]
+    fn styles_are_carried_by_theme() {
+        let mut definition = Definition::new("Styled", Theme::Light.palette());
+        definition.styles.button.radius = Some(8.0);
+
+        let theme = definition.theme();
+
+        assert_eq!(
+            theme
+                .extension::<Styles>()
+                .and_then(|styles| styles.button.radius),
+            Some(8.0)
+        );
+        assert_eq!(Definition::from_theme(&theme), definition);
+    }
+
+    #[cfg(feature = "theme-file")]
+    const OCEAN: &str = r##"
+        name = "Ocean"
+
+        [palette]
+        background = "#0f1b2d"
+        text = "#e6edf3"
+        primary = "#3b82f6"
+        success = "#22c55e"
+        warning = "#eab308"
+        danger = "#ef4444cc"
+
+        [overrides.primary.strong]
+        color = "#1d4ed8"
+
+        [radius]
+        small = 4
+        medium = 8
+        large = 16
+
+        [styles.button]
+        radius = 8
+        border_width = 1
+        border_color = "#1d4ed8"
+
+        [styles.text_input]
+        background = "#0b1220"
+    "##;
+
+    #[test]
+    #[cfg(feature = "theme-file")]
+    fn toml_is_parsed() {
+        let definition = Definition::from_toml(OCEAN).expect("Parse theme");
+
+        assert_eq!(definition.name, "Ocean");
+        assert_eq!(
+            definition.palette.background,
+            Color::from_rgb8(0x0f, 0x1b, 0x2d)
+        );
+        assert_eq!(
+            definition.palette.danger,
+            Color::from_rgba8(0xef, 0x44, 0x44, f32::from(0xcc_u8) / 255.0)
+        );
+        assert_eq!(
+            definition.overrides.primary.strong.color,
+            Some(Color::from_rgb8(0x1d, 0x4e, 0xd8))
+        );
+        assert_eq!(definition.overrides.primary.strong.text, None);
+        assert_eq!(definition.radius.map(|radius| radius.medium), Some(8.0));
+        assert_eq!(definition.spacing, None);
+        assert_eq!(definition.styles.button.border_width, Some(1.0));
+        assert_eq!(
+            definition.styles.text_input.background,
+            Some(Color::from_rgb8(0x0b, 0x12, 0x20))
+        );
+        assert!(definition.styles.checkbox.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "theme-file")]
+    fn toml_and_json_round_trip() {
+        let definition = Definition::from_toml(OCEAN).expect("Parse theme");
+
+        assert_eq!(
+            Definition::from_toml(&definition.to_toml()).expect("Parse TOML"),
+            definition
+        );
+        assert_eq!(
+            Definition::from_json(&definition.to_json()).expect("Parse JSON"),
+            definition
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "theme-file")]
+    fn colors_are_serialized_as_hex() {
+        let definition = Definition::from_toml(OCEAN).expect("Parse theme");
+        let toml = definition.to_toml();
+
+        assert!(toml.contains(r##"background = "#0f1b2d""##));
+        assert!(toml.contains(r##"danger = "#ef4444cc""##));
+        assert!(toml.contains(r##"border_color = "#1d4ed8""##));
+    }
+
+    #[test]
+    #[cfg(feature = "theme-file")]
+    fn invalid_definitions_are_rejected() {
+        let invalid_color = OCEAN.replace("#3b82f6", "blue");
+        let missing_color = OCEAN.replace(r##"text = "#e6edf3""##, "");
+
+        assert!(matches!(
+            Definition::from_toml(&invalid_color),
+            Err(Error::Toml(error)) if error.to_string().contains("invalid hex color: blue")
+        ));
+        assert!(matches!(
+            Definition::from_toml(&missing_color),
+            Err(Error::Toml(_))
+        ));
+        assert!(matches!(
+            Definition::from_json("{ \"name\": \"Ocean\" }"),
+            Err(Error::Json(_))
+        ));
+    }
+}
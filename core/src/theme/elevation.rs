@@ -0,0 +1,103 @@
+//! Standardize the depth of surfaces with consistent shadows and tints.
+use crate::shadow::{Shadow, Shadows};
+use crate::theme::palette::{self, Pair};
+use crate::{Color, Vector};
+
+/// The elevation of a surface.
+///
+/// An [`Elevation`] maps to a consistent combination of [`Shadows`] and
+/// surface tint; so the depth of surfaces stays consistent across an
+/// application.
+///
+/// Levels above [`Elevation::MAX`] are treated as [`Elevation::MAX`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Elevation(pub u8);
+
+impl Elevation {
+    /// A flat surface, with no shadows.
+    pub const FLAT: Self = Self(0);
+
+    /// The highest [`Elevation`].
+    pub const MAX: Self = Self(5);
+
+    /// Returns the level of the [`Elevation`], clamped to [`Elevation::MAX`].
+    pub fn level(self) -> u8 {
+        self.0.min(Self::MAX.0)
+    }
+
+    /// Returns the next [`Elevation`] level.
+    pub fn raised(self) -> Self {
+        Self((self.level() + 1).min(Self::MAX.0))
+    }
+
+    /// Returns the previous [`Elevation`] level.
+    pub fn lowered(self) -> Self {
+        Self(self.level().saturating_sub(1))
+    }
+
+    /// Returns the [`Shadows`] cast by a surface with this [`Elevation`].
+    ///
+    /// The shadows are stronger for dark palettes; so they remain visible.
+    pub fn shadow(self, palette: &palette::Extended) -> Shadows {
+        let (offset, blur_radius) = match self.level() {
+            0 => return Shadows::new(),
+            1 => (1.0, 3.0),
+            2 => (2.0, 6.0),
+            3 => (4.0, 8.0),
+            4 => (6.0, 12.0),
+            _ => (8.0, 16.0),
+        };
+
+        let strength = if palette.is_dark { 2.0 } else { 1.0 };
+
+        Shadows::from(Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.15 * strength),
+            offset: Vector::new(0.0, offset),
+            blur_radius,
+            ..Shadow::default()
+        })
+        .with(Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.1 * strength),
+            offset: Vector::new(0.0, offset / 2.0),
+            blur_radius: blur_radius * 2.0,
+            ..Shadow::default()
+        })
+    }
+
+    /// Returns the surface [`Pair`] of a surface with this [`Elevation`].
+    ///
+    /// Higher surfaces are tinted with more of the primary color; which
+    /// is specially useful in dark palettes, where shadows are barely
+    /// noticeable.
+    pub fn surface(self, palette: &palette::Extended) -> Pair {
+        let factor = match self.level() {
+            0 => 0.0,
+            1 => 0.05,
+            2 => 0.08,
+            3 => 0.11,
+            4 => 0.12,
+            _ => 0.14,
+        };
+
+        let factor = if palette.is_dark {
+            factor
+        } else {
+            factor / 2.0
+        };
+
+        Pair::new(
+            palette::mix(
+                palette.background.base.color,
+                palette.primary.base.color,
+                factor,
+            ),
+            palette.background.base.text,
+        )
+    }
+}
+
+impl From<u8> for Elevation {
+    fn from(level: u8) -> Self {
+        Self(level)
+    }
+}
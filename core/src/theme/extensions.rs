@@ -0,0 +1,88 @@
+//! Carry the style data of third-party widgets with a theme.
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// A set of extensions of a [`Theme`], indexed by their type.
+///
+/// Extensions let custom widgets keep their own style data inside the
+/// [`Theme`] of an application; without the need of wrapping it.
+///
+/// ```
+/// # use iced_core::Theme;
+/// # use iced_core::Color;
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Knob {
+///     color: Color,
+/// }
+///
+/// let theme = Theme::Dark.with_extension(Knob {
+///     color: Color::from_rgb(1.0, 0.5, 0.0),
+/// });
+///
+/// assert_eq!(
+///     theme.extension::<Knob>().map(|knob| knob.color),
+///     Some(Color::from_rgb(1.0, 0.5, 0.0))
+/// );
+/// ```
+///
+/// [`Theme`]: crate::Theme
+#[derive(Clone, Default)]
+pub struct Extensions {
+    entries: BTreeMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+    /// Creates an empty set of [`Extensions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an extension, replacing any previous one of the same type.
+    pub fn insert<T>(&mut self, extension: T)
+    where
+        T: Any + Send + Sync,
+    {
+        let _ = self.entries.insert(TypeId::of::<T>(), Arc::new(extension));
+    }
+
+    /// Returns the extension of the given type, if present.
+    pub fn get<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        self.entries
+            .get(&TypeId::of::<T>())
+            .and_then(|extension| extension.downcast_ref())
+    }
+
+    /// Returns the amount of extensions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no extensions.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+impl PartialEq for Extensions {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries.len() == other.entries.len()
+            && self.entries.iter().zip(&other.entries).all(
+                |((type_a, a), (type_b, b))| {
+                    type_a == type_b && Arc::ptr_eq(a, b)
+                },
+            )
+    }
+}
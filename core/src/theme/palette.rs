@@ -42,6 +42,80 @@ impl Palette {
         danger: color!(0xc3423f),
     };
 
+    /// Generates a [`Palette`] from a single accent [`Color`] and
+    /// a background [`Color`].
+    ///
+    /// The accent is used as the primary color, adjusted to stand out
+    /// on top of the background. The text color and the success, warning,
+    /// and danger colors are derived to match and remain readable.
+    ///
+    /// The weak, strong, and hovered variants of each color are then
+    /// generated by [`Extended::generate`], as usual.
+    ///
+    /// ```
+    /// # use iced_core::Color;
+    /// # use iced_core::theme::Palette;
+    /// let palette = Palette::generate(
+    ///     Color::from_rgb8(0xE0, 0x6C, 0x75),
+    ///     Color::from_rgb8(0x1E, 0x1E, 0x2E),
+    /// );
+    ///
+    /// assert_eq!(palette.background, Color::from_rgb8(0x1E, 0x1E, 0x2E));
+    /// ```
+    pub fn generate(accent: Color, background: Color) -> Self {
+        let text = readable(
+            background,
+            if is_dark(background) {
+                Color::from_rgb(0.90, 0.90, 0.90)
+            } else {
+                Color::BLACK
+            },
+        );
+
+        let tint = |hue| {
+            let mut hsl = to_hsl(accent);
+
+            hsl.h = hue;
+            hsl.s = hsl.s.clamp(0.4, 0.8);
+            hsl.l = hsl.l.clamp(0.35, 0.65);
+
+            distinct(from_hsl(hsl), background)
+        };
+
+        Self {
+            background,
+            text,
+            primary: distinct(accent, background),
+            success: tint(145.0),
+            warning: tint(40.0),
+            danger: tint(0.0),
+        }
+    }
+
+    /// Returns a high contrast version of the [`Palette`].
+    ///
+    /// The background becomes pure black or white, the text becomes its
+    /// opposite, and the rest of colors are adjusted to have a contrast
+    /// ratio of at least 4.5:1 against the background.
+    pub fn high_contrast(self) -> Self {
+        let (background, text) = if is_dark(self.background) {
+            (Color::BLACK, Color::WHITE)
+        } else {
+            (Color::WHITE, Color::BLACK)
+        };
+
+        let contrasted = |color| with_contrast(color, background, 4.5);
+
+        Self {
+            background,
+            text,
+            primary: contrasted(self.primary),
+            success: contrasted(self.success),
+            warning: contrasted(self.warning),
+            danger: contrasted(self.danger),
+        }
+    }
+
     /// The built-in [Dracula] variant of a [`Palette`].
     ///
     /// [Dracula]: https://draculatheme.com
@@ -651,7 +725,7 @@ fn muted(color: Color) -> Color {
     from_hsl(hsl)
 }
 
-fn mix(a: Color, b: Color, factor: f32) -> Color {
+pub(crate) fn mix(a: Color, b: Color, factor: f32) -> Color {
     let b_amount = factor.clamp(0.0, 1.0);
     let a_amount = 1.0 - b_amount;
 
@@ -690,6 +764,25 @@ fn readable(background: Color, text: Color) -> Color {
     }
 }
 
+fn distinct(color: Color, background: Color) -> Color {
+    with_contrast(color, background, 3.0)
+}
+
+fn with_contrast(color: Color, background: Color, ratio: f32) -> Color {
+    let improve = if is_dark(background) { lighten } else { darken };
+    let mut candidate = color;
+
+    for _ in 0..20 {
+        if relative_contrast(background, candidate) >= ratio {
+            break;
+        }
+
+        candidate = improve(candidate, 0.05);
+    }
+
+    candidate
+}
+
 fn is_dark(color: Color) -> bool {
     to_hsl(color).l < 0.6
 }
@@ -773,3 +866,34 @@ fn from_hsl(hsl: Hsl) -> Color {
         a: hsl.a,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_palette_is_readable() {
+        let accents = [
+            Color::from_rgb8(0x58, 0x65, 0xF2),
+            Color::from_rgb8(0xFF, 0xEB, 0x3B),
+            Color::from_rgb8(0x10, 0x10, 0x10),
+        ];
+
+        for background in [Color::WHITE, Color::from_rgb8(0x1E, 0x1E, 0x2E)] {
+            for accent in accents {
+                let palette = Palette::generate(accent, background);
+
+                assert!(is_readable(palette.background, palette.text));
+
+                for color in [
+                    palette.primary,
+                    palette.success,
+                    palette.warning,
+                    palette.danger,
+                ] {
+                    assert!(relative_contrast(background, color) >= 3.0);
+                }
+            }
+        }
+    }
+}
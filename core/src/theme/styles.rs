@@ -0,0 +1,155 @@
+//! Override the styles of the built-in widgets.
+use crate::{Background, Border, Color};
+
+/// A set of overrides of the styles of the built-in widgets.
+///
+/// [`Styles`] are carried by a [`Theme`] as an extension; and the built-in
+/// widgets apply them on top of their default style, in any status.
+///
+/// They can be described in the `styles` table of a theme file:
+///
+/// ```toml
+/// [styles.button]
+/// radius = 8
+/// border_width = 1
+/// border_color = "#1d4ed8"
+///
+/// [styles.text_input]
+/// background = "#0b1220"
+/// ```
+///
+/// [`Theme`]: crate::Theme
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The overrides of the button style.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Style::is_empty")
+    )]
+    pub button: Style,
+    /// The overrides of the checkbox style.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Style::is_empty")
+    )]
+    pub checkbox: Style,
+    /// The overrides of the pick list style.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Style::is_empty")
+    )]
+    pub pick_list: Style,
+    /// The overrides of the text editor style.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Style::is_empty")
+    )]
+    pub text_editor: Style,
+    /// The overrides of the text input style.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Style::is_empty")
+    )]
+    pub text_input: Style,
+}
+
+impl Styles {
+    /// Returns true if the [`Styles`] do not override anything.
+    pub fn is_empty(&self) -> bool {
+        self.button.is_empty()
+            && self.checkbox.is_empty()
+            && self.pick_list.is_empty()
+            && self.text_editor.is_empty()
+            && self.text_input.is_empty()
+    }
+}
+
+/// The overrides of the style of a built-in widget.
+///
+/// Colors are written as hex strings in a theme file.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Style {
+    /// The background [`Color`] of the widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::theme::definition::option_hex",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub background: Option<Color>,
+    /// The text [`Color`] of the widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::theme::definition::option_hex",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub text_color: Option<Color>,
+    /// The [`Color`] of the border of the widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::theme::definition::option_hex",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub border_color: Option<Color>,
+    /// The width of the border of the widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub border_width: Option<f32>,
+    /// The radius of the corners of the widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub radius: Option<f32>,
+}
+
+impl Style {
+    /// Returns true if the [`Style`] does not override anything.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Applies the background override to the given [`Background`].
+    pub fn background(&self, background: Background) -> Background {
+        self.background.map(Background::from).unwrap_or(background)
+    }
+
+    /// Applies the text color override to the given [`Color`].
+    pub fn text_color(&self, text_color: Color) -> Color {
+        self.text_color.unwrap_or(text_color)
+    }
+
+    /// Applies the border overrides to the given [`Border`].
+    pub fn border(&self, mut border: Border) -> Border {
+        if let Some(width) = self.border_width {
+            border = border.width(width);
+        }
+
+        if let Some(color) = self.border_color {
+            border = border.color(color);
+        }
+
+        if let Some(radius) = self.radius {
+            border = border.rounded(radius);
+        }
+
+        border
+    }
+}
@@ -0,0 +1,173 @@
+//! Share spacing, sizes, and colors across the styles of an application.
+use crate::Color;
+use crate::theme::palette;
+
+/// A set of design tokens.
+///
+/// Design tokens are the named values that styles can use instead of
+/// hardcoded literals; so changing a single token updates every style
+/// that uses it.
+///
+/// The [`Tokens`] of a theme can be obtained with [`Theme::tokens`] and
+/// changed with [`Theme::with_tokens`].
+///
+/// [`Theme::tokens`]: crate::Theme::tokens
+/// [`Theme::with_tokens`]: crate::Theme::with_tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tokens {
+    /// The [`Spacing`] scale.
+    pub spacing: Spacing,
+    /// The [`Radii`] scale.
+    pub radius: Radii,
+    /// The [`Typography`] scale.
+    pub text: Typography,
+    /// The semantic [`Colors`].
+    pub colors: Colors,
+}
+
+impl Tokens {
+    /// Generates the default [`Tokens`] for the given [`palette::Extended`].
+    pub fn generate(palette: &palette::Extended) -> Self {
+        Self {
+            spacing: Spacing::DEFAULT,
+            radius: Radii::DEFAULT,
+            text: Typography::DEFAULT,
+            colors: Colors::generate(palette),
+        }
+    }
+}
+
+/// A scale of spacing values, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spacing {
+    /// The extra small spacing.
+    pub extra_small: f32,
+    /// The small spacing.
+    pub small: f32,
+    /// The medium spacing.
+    pub medium: f32,
+    /// The large spacing.
+    pub large: f32,
+    /// The extra large spacing.
+    pub extra_large: f32,
+}
+
+impl Spacing {
+    /// The default [`Spacing`] scale.
+    pub const DEFAULT: Self = Self {
+        extra_small: 2.0,
+        small: 5.0,
+        medium: 10.0,
+        large: 20.0,
+        extra_large: 40.0,
+    };
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A scale of border radii, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Radii {
+    /// The small radius.
+    ///
+    /// Used by most built-in widgets.
+    pub small: f32,
+    /// The medium radius.
+    pub medium: f32,
+    /// The large radius.
+    pub large: f32,
+}
+
+impl Radii {
+    /// The default [`Radii`] scale.
+    pub const DEFAULT: Self = Self {
+        small: 2.0,
+        medium: 5.0,
+        large: 10.0,
+    };
+}
+
+impl Default for Radii {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A scale of text sizes, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Typography {
+    /// The size of small text; like captions.
+    pub small: f32,
+    /// The size of body text.
+    pub body: f32,
+    /// The size of large text; like subtitles.
+    pub large: f32,
+    /// The size of headings.
+    pub heading: f32,
+    /// The size of titles.
+    pub title: f32,
+}
+
+impl Typography {
+    /// The default [`Typography`] scale.
+    pub const DEFAULT: Self = Self {
+        small: 12.0,
+        body: 16.0,
+        large: 20.0,
+        heading: 24.0,
+        title: 32.0,
+    };
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A set of colors named after their purpose.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Colors {
+    /// The color of surfaces; like the background of containers.
+    pub surface: Color,
+    /// The color of content on top of a surface.
+    pub on_surface: Color,
+    /// The color of content with less emphasis.
+    pub muted: Color,
+    /// The color of borders and dividers.
+    pub border: Color,
+    /// The color of interactive and highlighted elements.
+    pub accent: Color,
+    /// The color of content on top of the accent color.
+    pub on_accent: Color,
+    /// The color of positive outcomes.
+    pub success: Color,
+    /// The color of cautionary states.
+    pub warning: Color,
+    /// The color of destructive actions and errors.
+    pub danger: Color,
+}
+
+impl Colors {
+    /// Generates a set of semantic [`Colors`] from a [`palette::Extended`].
+    pub fn generate(palette: &palette::Extended) -> Self {
+        Self {
+            surface: palette.background.weak.color,
+            on_surface: palette.background.weak.text,
+            muted: palette.background.strongest.color,
+            border: palette.background.strong.color,
+            accent: palette.primary.base.color,
+            on_accent: palette.primary.base.text,
+            success: palette.success.base.color,
+            warning: palette.warning.base.color,
+            danger: palette.danger.base.color,
+        }
+    }
+}
@@ -10,6 +10,7 @@ pub use operation::Operation;
 pub use text::Text;
 pub use tree::Tree;
 
+use crate::accessibility;
 use crate::layout::{self, Layout};
 use crate::mouse;
 use crate::overlay;
@@ -100,14 +101,37 @@ where
         tree.children.clear();
     }
 
+    /// Describes the [`Widget`] to assistive technologies, if it is
+    /// accessible.
+    ///
+    /// By default, it returns `None`.
+    fn accessibility(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        None
+    }
+
     /// Applies an [`Operation`] to the [`Widget`].
+    ///
+    /// By default, it reports the [`accessibility`](Self::accessibility)
+    /// of the [`Widget`]; so widgets overriding it should do the same.
     fn operate(
         &self,
-        _state: &mut Tree,
-        _layout: Layout<'_>,
+        state: &mut Tree,
+        layout: Layout<'_>,
         _renderer: &Renderer,
-        _operation: &mut dyn Operation,
+        operation: &mut dyn Operation,
     ) {
+        if let Some(node) = self.accessibility(state, layout) {
+            operation.accessible(
+                None,
+                state.accessibility_id(),
+                layout.bounds(),
+                &node,
+            );
+        }
     }
 
     /// Processes a runtime [`Event`].
@@ -7,6 +7,7 @@ pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
+use crate::accessibility;
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
 
@@ -61,6 +62,20 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a widget that contains some text.
     fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
 
+    /// Operates on a widget that is accessible to assistive technologies.
+    ///
+    /// A widget with children should operate on them with
+    /// [`container`](Self::container) right after; so their nodes are nested
+    /// under its own.
+    fn accessible(
+        &mut self,
+        _id: Option<&Id>,
+        _node_id: accessibility::Id,
+        _bounds: Rectangle,
+        _node: &accessibility::Node,
+    ) {
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(
         &mut self,
@@ -128,6 +143,16 @@ where
         self.as_mut().text(id, bounds, text);
     }
 
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        node_id: accessibility::Id,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+    ) {
+        self.as_mut().accessible(id, node_id, bounds, node);
+    }
+
     fn custom(
         &mut self,
         id: Option<&Id>,
@@ -229,6 +254,16 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            node_id: accessibility::Id,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+        ) {
+            self.operation.accessible(id, node_id, bounds, node);
+        }
+
         fn custom(
             &mut self,
             id: Option<&Id>,
@@ -335,6 +370,16 @@ where
                     self.operation.text(id, bounds, text);
                 }
 
+                fn accessible(
+                    &mut self,
+                    id: Option<&Id>,
+                    node_id: accessibility::Id,
+                    bounds: Rectangle,
+                    node: &accessibility::Node,
+                ) {
+                    self.operation.accessible(id, node_id, bounds, node);
+                }
+
                 fn custom(
                     &mut self,
                     id: Option<&Id>,
@@ -389,6 +434,16 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            node_id: accessibility::Id,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+        ) {
+            self.operation.accessible(id, node_id, bounds, node);
+        }
+
         fn custom(
             &mut self,
             id: Option<&Id>,
@@ -494,6 +549,16 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            node_id: accessibility::Id,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+        ) {
+            self.operation.accessible(id, node_id, bounds, node);
+        }
+
         fn custom(
             &mut self,
             id: Option<&Id>,
@@ -566,3 +631,89 @@ pub fn scope<T: 'static>(
         operation: Box::new(operation),
     }
 }
+
+/// Produces an [`Operation`] that finds the bounds of the widget with the
+/// given [`Id`].
+pub fn bounds(target: Id) -> impl Operation<Rectangle> {
+    struct Bounds {
+        target: Id,
+        bounds: Option<Rectangle>,
+    }
+
+    impl Bounds {
+        fn check(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            if self.bounds.is_none() && id == Some(&self.target) {
+                self.bounds = Some(bounds);
+            }
+        }
+    }
+
+    impl Operation<Rectangle> for Bounds {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Rectangle>),
+        ) {
+            self.check(id, bounds);
+
+            if self.bounds.is_none() {
+                operate_on_children(self);
+            }
+        }
+
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _state: &mut dyn Focusable,
+        ) {
+            self.check(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _content_bounds: Rectangle,
+            _translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            self.check(id, bounds);
+        }
+
+        fn text_input(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _state: &mut dyn TextInput,
+        ) {
+            self.check(id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, _text: &str) {
+            self.check(id, bounds);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _state: &mut dyn Any,
+        ) {
+            self.check(id, bounds);
+        }
+
+        fn finish(&self) -> Outcome<Rectangle> {
+            match self.bounds {
+                Some(bounds) => Outcome::Some(bounds),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    Bounds {
+        target,
+        bounds: None,
+    }
+}
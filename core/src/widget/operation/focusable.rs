@@ -218,6 +218,89 @@ where
     operation::then(count(), |count| FocusNext { count, current: 0 })
 }
 
+/// Produces an [`Operation`] that searches for the current focused widget
+/// inside the container with the given [`Id`], and
+/// - if found, focuses the previous focusable widget inside of it.
+/// - if not found, focuses the last focusable widget inside of it.
+///
+/// Any focused widget outside of the container is unfocused.
+pub fn focus_previous_in<T>(container: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    within(container, focus_previous())
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget
+/// inside the container with the given [`Id`], and
+/// - if found, focuses the next focusable widget inside of it.
+/// - if not found, focuses the first focusable widget inside of it.
+///
+/// Any focused widget outside of the container is unfocused.
+pub fn focus_next_in<T>(container: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    within(container, focus_next())
+}
+
+/// Applies the given [`Operation`] to the children of the container with the
+/// given [`Id`]; while unfocusing any focusable widget outside of it.
+fn within<T>(
+    target: Id,
+    operation: impl Operation<T> + 'static,
+) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    struct Within<T> {
+        target: Id,
+        operation: Box<dyn Operation<T>>,
+    }
+
+    impl<T> Operation<T> for Within<T>
+    where
+        T: Send + 'static,
+    {
+        fn focusable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            state.unfocus();
+        }
+
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if id == Some(&self.target) {
+                operate_on_children(self.operation.as_mut());
+            } else {
+                operate_on_children(self);
+            }
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            match self.operation.finish() {
+                Outcome::Chain(next) => Outcome::Chain(Box::new(Within {
+                    target: self.target.clone(),
+                    operation: next,
+                })),
+                outcome => outcome,
+            }
+        }
+    }
+
+    Within {
+        target,
+        operation: Box::new(operation),
+    }
+}
+
 /// Produces an [`Operation`] that searches for the current focused widget
 /// and stores its ID. This ignores widgets that do not have an ID.
 pub fn find_focused() -> impl Operation<Id> {
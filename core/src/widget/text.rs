@@ -20,6 +20,7 @@
 //!         .into()
 //! }
 //! ```
+use crate::accessibility;
 use crate::alignment;
 use crate::layout;
 use crate::mouse;
@@ -244,13 +245,35 @@ where
         );
     }
 
+    fn accessibility(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        Some(
+            accessibility::Node::new(accessibility::Role::Label)
+                .name(self.fragment.as_ref()),
+        )
+    }
+
     fn operate(
         &self,
-        _state: &mut Tree,
+        state: &mut Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn super::Operation,
     ) {
+        if let Some(node) = Widget::<Message, Theme, Renderer>::accessibility(
+            self, state, layout,
+        ) {
+            operation.accessible(
+                None,
+                state.accessibility_id(),
+                layout.bounds(),
+                &node,
+            );
+        }
+
         operation.text(None, layout.bounds(), &self.fragment);
     }
 }
@@ -1,5 +1,6 @@
 //! Store internal widget state in a state tree to ensure continuity.
 use crate::Widget;
+use crate::accessibility;
 
 use std::any::{self, Any};
 use std::borrow::Borrow;
@@ -18,6 +19,8 @@ pub struct Tree {
 
     /// The children of the root widget of the [`Tree`].
     pub children: Vec<Tree>,
+
+    accessibility_id: accessibility::Id,
 }
 
 impl Tree {
@@ -27,6 +30,7 @@ impl Tree {
             tag: Tag::stateless(),
             state: State::None,
             children: Vec::new(),
+            accessibility_id: accessibility::Id::unique(),
         }
     }
 
@@ -43,9 +47,18 @@ impl Tree {
             tag: widget.tag(),
             state: widget.state(),
             children: widget.children(),
+            accessibility_id: accessibility::Id::unique(),
         }
     }
 
+    /// Returns the [`accessibility::Id`] of the widget of the [`Tree`].
+    ///
+    /// It is kept for as long as the [`Tree`] is reconciled with the same
+    /// kind of widget.
+    pub fn accessibility_id(&self) -> accessibility::Id {
+        self.accessibility_id
+    }
+
     /// Reconciles the current tree with the provided [`Widget`].
     ///
     /// If the tag of the [`Widget`] matches the tag of the [`Tree`], then the
@@ -4,22 +4,42 @@ pub mod screenshot;
 pub mod settings;
 
 mod direction;
+mod edge;
+mod edges;
+mod effect;
 mod event;
+mod frame_rate;
 mod id;
+mod layout;
 mod level;
 mod mode;
+mod monitor;
 mod position;
+mod present_mode;
+mod progress;
 mod redraw_request;
+mod transition;
 mod user_attention;
+mod video_mode;
 
 pub use direction::Direction;
+pub use edge::Edge;
+pub use edges::Edges;
+pub use effect::Effect;
 pub use event::Event;
+pub use frame_rate::FrameRate;
 pub use icon::Icon;
 pub use id::Id;
+pub use layout::Layout;
 pub use level::Level;
 pub use mode::Mode;
+pub use monitor::Monitor;
 pub use position::Position;
+pub use present_mode::PresentMode;
+pub use progress::Progress;
 pub use redraw_request::RedrawRequest;
 pub use screenshot::Screenshot;
 pub use settings::Settings;
+pub use transition::Transition;
 pub use user_attention::UserAttention;
+pub use video_mode::VideoMode;
@@ -0,0 +1,44 @@
+use crate::window::Layout;
+
+/// A half or a quarter of a screen that a window can be snapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// The left half of the screen.
+    Left,
+
+    /// The right half of the screen.
+    Right,
+
+    /// The top half of the screen.
+    Top,
+
+    /// The bottom half of the screen.
+    Bottom,
+
+    /// The top-left quarter of the screen.
+    TopLeft,
+
+    /// The top-right quarter of the screen.
+    TopRight,
+
+    /// The bottom-left quarter of the screen.
+    BottomLeft,
+
+    /// The bottom-right quarter of the screen.
+    BottomRight,
+}
+
+impl From<Edge> for Layout {
+    fn from(edge: Edge) -> Self {
+        match edge {
+            Edge::Left => Layout::grid(2, 1).cell(0, 0),
+            Edge::Right => Layout::grid(2, 1).cell(1, 0),
+            Edge::Top => Layout::grid(1, 2).cell(0, 0),
+            Edge::Bottom => Layout::grid(1, 2).cell(0, 1),
+            Edge::TopLeft => Layout::grid(2, 2).cell(0, 0),
+            Edge::TopRight => Layout::grid(2, 2).cell(1, 0),
+            Edge::BottomLeft => Layout::grid(2, 2).cell(0, 1),
+            Edge::BottomRight => Layout::grid(2, 2).cell(1, 1),
+        }
+    }
+}
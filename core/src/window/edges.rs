@@ -0,0 +1,31 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// A set of edges of a window; like the ones that can be dragged to
+    /// resize it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Edges: u8 {
+        /// The top edge.
+        const TOP = 0b0001;
+        /// The bottom edge.
+        const BOTTOM = 0b0010;
+        /// The left edge.
+        const LEFT = 0b0100;
+        /// The right edge.
+        const RIGHT = 0b1000;
+    }
+}
+
+impl Edges {
+    /// The left and right edges; which change the width of a window.
+    pub const HORIZONTAL: Self = Self::LEFT.union(Self::RIGHT);
+
+    /// The top and bottom edges; which change the height of a window.
+    pub const VERTICAL: Self = Self::TOP.union(Self::BOTTOM);
+}
+
+impl Default for Edges {
+    fn default() -> Self {
+        Self::all()
+    }
+}
@@ -0,0 +1,40 @@
+/// A translucent material drawn by the native system behind the contents of
+/// a window.
+///
+/// Effects are only visible through the transparent parts of a window; so
+/// the window must be created with `transparent` enabled and the background
+/// of the application must be translucent.
+///
+/// ## Platform-specific
+///
+/// - **Windows:** [`Mica`], [`MicaAlt`], and [`Acrylic`] require Windows 11.
+///   [`Blur`] is shown as [`Acrylic`].
+/// - **macOS:** Every effect is shown as a blur of the windows behind.
+/// - **X11 / Wayland:** Every effect is shown as a blur of the windows behind,
+///   if supported by the compositor (e.g. KDE).
+/// - **Web:** Unsupported.
+///
+/// Only Windows honors the kind of effect; every other platform treats
+/// any effect other than [`Effect::None`] as [`Blur`].
+///
+/// [`Mica`]: Effect::Mica
+/// [`MicaAlt`]: Effect::MicaAlt
+/// [`Acrylic`]: Effect::Acrylic
+/// [`Blur`]: Effect::Blur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Effect {
+    /// No effect.
+    #[default]
+    None,
+    /// A blur of the windows behind.
+    Blur,
+    /// A material tinted with the desktop wallpaper; meant for the main
+    /// window of an application.
+    Mica,
+    /// A variant of [`Effect::Mica`] with a stronger tint; meant for tabbed
+    /// windows.
+    MicaAlt,
+    /// A translucent, blurred material; meant for transient windows like
+    /// popups and flyouts.
+    Acrylic,
+}
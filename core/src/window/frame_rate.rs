@@ -0,0 +1,56 @@
+use crate::time::Duration;
+
+/// The maximum rate at which a window is redrawn.
+///
+/// Windows are only redrawn when something changes or is animating; a
+/// [`FrameRate`] limits how often that can happen. This can save a lot of
+/// power in applications that are always animating something, like
+/// dashboards.
+///
+/// By default, the frame rate is unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameRate {
+    /// The maximum frames per second while the window is focused.
+    pub focused: Option<u32>,
+
+    /// The maximum frames per second while the window is not focused.
+    pub unfocused: Option<u32>,
+}
+
+impl FrameRate {
+    /// A [`FrameRate`] without any limits.
+    pub const UNLIMITED: Self = Self {
+        focused: None,
+        unfocused: None,
+    };
+
+    /// Limits the frames per second of the [`FrameRate`] while the window
+    /// is focused.
+    pub fn focused(self, fps: u32) -> Self {
+        Self {
+            focused: Some(fps),
+            ..self
+        }
+    }
+
+    /// Limits the frames per second of the [`FrameRate`] while the window
+    /// is not focused.
+    pub fn unfocused(self, fps: u32) -> Self {
+        Self {
+            unfocused: Some(fps),
+            ..self
+        }
+    }
+
+    /// Returns the minimum [`Duration`] between two frames of a window, if
+    /// the [`FrameRate`] is limited.
+    pub fn interval(self, is_focused: bool) -> Option<Duration> {
+        let fps = if is_focused {
+            self.focused
+        } else {
+            self.unfocused
+        }?;
+
+        Some(Duration::from_secs_f64(1.0 / f64::from(fps.max(1))))
+    }
+}
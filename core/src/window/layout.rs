@@ -0,0 +1,103 @@
+use crate::Rectangle;
+
+/// A cell of a grid splitting a screen, that a window can be tiled into.
+///
+/// # Example
+/// ```
+/// use iced_core::window::Layout;
+///
+/// // The middle third of the screen
+/// let layout = Layout::grid(3, 1).cell(1, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    /// The amount of columns of the grid.
+    pub columns: u32,
+    /// The amount of rows of the grid.
+    pub rows: u32,
+    /// The column of the cell, starting at 0.
+    pub column: u32,
+    /// The row of the cell, starting at 0.
+    pub row: u32,
+}
+
+impl Layout {
+    /// Creates a [`Layout`] splitting the screen in the given amount of
+    /// columns and rows; placed in the first cell.
+    pub fn grid(columns: u32, rows: u32) -> Self {
+        Self {
+            columns: columns.max(1),
+            rows: rows.max(1),
+            column: 0,
+            row: 0,
+        }
+    }
+
+    /// Places the [`Layout`] in the given column and row of its grid.
+    pub fn cell(self, column: u32, row: u32) -> Self {
+        Self {
+            column: column.min(self.columns - 1),
+            row: row.min(self.rows - 1),
+            ..self
+        }
+    }
+
+    /// Returns the bounds of the [`Layout`] inside of the given area.
+    pub fn bounds(self, area: Rectangle) -> Rectangle {
+        let width = area.width / self.columns as f32;
+        let height = area.height / self.rows as f32;
+
+        Rectangle {
+            x: area.x + width * self.column as f32,
+            y: area.y + height * self.row as f32,
+            width,
+            height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::Edge;
+
+    #[test]
+    fn bounds() {
+        let area = Rectangle {
+            x: 0.0,
+            y: 40.0,
+            width: 1920.0,
+            height: 1040.0,
+        };
+
+        assert_eq!(
+            Layout::from(Edge::Right).bounds(area),
+            Rectangle {
+                x: 960.0,
+                y: 40.0,
+                width: 960.0,
+                height: 1040.0,
+            }
+        );
+
+        assert_eq!(
+            Layout::from(Edge::BottomLeft).bounds(area),
+            Rectangle {
+                x: 0.0,
+                y: 560.0,
+                width: 960.0,
+                height: 520.0,
+            }
+        );
+
+        assert_eq!(
+            Layout::grid(0, 3).cell(5, 1),
+            Layout {
+                columns: 1,
+                rows: 3,
+                column: 0,
+                row: 1,
+            }
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::window::VideoMode;
+
 /// The mode of a window-based application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
@@ -7,6 +9,17 @@ pub enum Mode {
     /// The application takes the whole screen of its current monitor.
     Fullscreen,
 
+    /// The application takes exclusive control of the screen of its current
+    /// monitor, switching it to the given [`VideoMode`].
+    ///
+    /// If the monitor does not support the [`VideoMode`], the closest
+    /// supported one is used.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / Web:** Unsupported; [`Mode::Fullscreen`] is used instead.
+    ExclusiveFullscreen(VideoMode),
+
     /// The application is hidden
     Hidden,
 }
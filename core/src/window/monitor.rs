@@ -0,0 +1,17 @@
+use crate::Rectangle;
+
+/// A display connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The human-readable name of the monitor, if available.
+    pub name: Option<String>,
+    /// The area covered by the monitor in the virtual desktop, in logical
+    /// pixels of the monitor.
+    pub bounds: Rectangle,
+    /// The scale factor of the monitor.
+    pub scale_factor: f32,
+    /// The refresh rate of the monitor in hertz, if available.
+    pub refresh_rate: Option<f32>,
+    /// Whether the monitor is the primary one of the system.
+    pub is_primary: bool,
+}
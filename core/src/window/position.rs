@@ -1,3 +1,4 @@
+use crate::window::Id;
 use crate::{Point, Size};
 
 /// The position of a window in a given screen.
@@ -21,6 +22,34 @@ pub enum Position {
     ///
     /// [`Specific`]: Self::Specific
     SpecificWith(fn(Size, Size) -> Point),
+    /// The window is centered on top of the window with the given [`Id`];
+    /// like a dialog.
+    ///
+    /// If the parent window does not exist, the window is [`Centered`]
+    /// on the screen instead.
+    ///
+    /// [`Centered`]: Self::Centered
+    CenteredOnParent(Id),
+    /// The top-left corner of the window is placed at the current position
+    /// of the mouse cursor; like a popup.
+    ///
+    /// The cursor position is only known while it is hovering some window
+    /// of the application. Otherwise, the [`Default`] position is used.
+    ///
+    /// [`Default`]: Self::Default
+    AtCursor,
+    /// The window is positioned with specific coordinates: `(X, Y)`,
+    /// relative to the top-left corner of the screen where the window
+    /// with the given [`Id`] is.
+    OnMonitor(Id, Point),
+    /// The top-left corner of the window is placed at the given coordinates,
+    /// relative to the top-left corner of the contents of the window with
+    /// the given [`Id`]; like where a tab was dropped.
+    ///
+    /// If the window does not exist, the [`Default`] position is used.
+    ///
+    /// [`Default`]: Self::Default
+    RelativeTo(Id, Point),
 }
 
 impl Default for Position {
@@ -0,0 +1,29 @@
+/// The strategy used to present the frames of a window.
+///
+/// Modes with less latency may produce tearing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PresentMode {
+    /// Frames are synchronized with the refresh rate of the display.
+    ///
+    /// There is no tearing, but a frame may wait for the next refresh before
+    /// being displayed. This mode is supported everywhere.
+    #[default]
+    Fifo,
+
+    /// Frames are synchronized with the refresh rate of the display, but any
+    /// outdated frame waiting to be displayed is replaced by newer ones.
+    ///
+    /// There is no tearing and latency is lower than [`PresentMode::Fifo`].
+    Mailbox,
+
+    /// Frames are displayed immediately.
+    ///
+    /// This has the lowest latency, but it may produce tearing.
+    Immediate,
+
+    /// The mode with the lowest latency supported by the window.
+    ///
+    /// [`PresentMode::Mailbox`] is preferred, since it does not tear; falling
+    /// back to [`PresentMode::Immediate`] and, finally, [`PresentMode::Fifo`].
+    LowLatency,
+}
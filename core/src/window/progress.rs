@@ -0,0 +1,21 @@
+/// The progress shown in the taskbar button or dock icon of a window.
+///
+/// ## Platform-specific
+///
+/// - **Windows:** Shown in the taskbar button.
+/// - **macOS:** Shown in the dock icon of the application; shared by all
+///   of its windows.
+/// - **Linux / Web:** Unsupported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Progress {
+    /// A known amount of progress, from `0.0` to `1.0`.
+    Determinate(f32),
+    /// An unknown amount of progress; shown as a busy indicator.
+    Indeterminate,
+}
+
+impl From<f32> for Progress {
+    fn from(value: f32) -> Self {
+        Self::Determinate(value)
+    }
+}
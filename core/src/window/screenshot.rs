@@ -82,6 +82,48 @@ impl Screenshot {
             scale_factor: self.scale_factor,
         })
     }
+
+    /// Crops a [`Screenshot`] to the provided `bounds` in logical pixels, like the
+    /// bounds of a widget. The `bounds` are clipped to the [`Screenshot`].
+    pub fn crop_bounds(&self, bounds: Rectangle) -> Result<Self, CropError> {
+        let scale_factor = self.scale_factor as f32;
+        let size = Size::new(self.size.width as f32, self.size.height as f32);
+
+        let region = (bounds * scale_factor)
+            .intersection(&Rectangle::with_size(size))
+            .ok_or(CropError::OutOfBounds)?;
+
+        let x = region.x.round() as u32;
+        let y = region.y.round() as u32;
+
+        self.crop(Rectangle {
+            x,
+            y,
+            width: ((region.x + region.width).round() as u32)
+                .min(self.size.width)
+                .saturating_sub(x),
+            height: ((region.y + region.height).round() as u32)
+                .min(self.size.height)
+                .saturating_sub(y),
+        })
+    }
+
+    /// Encodes the [`Screenshot`] as a PNG image.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut bytes = Vec::new();
+
+        let mut encoder =
+            png::Encoder::new(&mut bytes, self.size.width, self.size.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes)?;
+        writer.finish()?;
+
+        Ok(bytes)
+    }
 }
 
 impl AsRef<[u8]> for Screenshot {
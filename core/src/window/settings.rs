@@ -24,11 +24,16 @@ mod platform;
 #[path = "settings/other.rs"]
 mod platform;
 
-use crate::Size;
-use crate::window::{Icon, Level, Position};
+use crate::window::{Edges, FrameRate, Icon, Id, Level, Position, Transition};
+use crate::{Font, Size};
+
+use std::path::PathBuf;
 
 pub use platform::PlatformSpecific;
 
+#[cfg(target_os = "linux")]
+pub use platform::WindowType;
+
 /// The window settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings {
@@ -50,6 +55,14 @@ pub struct Settings {
     /// The maximum size of the window.
     pub max_size: Option<Size>,
 
+    /// The ratio between the width and the height of the window that must
+    /// be kept while resizing; like `16.0 / 9.0` for a video player.
+    pub aspect_ratio: Option<f32>,
+
+    /// The logical amount the size of the window must change in while
+    /// resizing; like the cell size in a terminal emulator.
+    pub resize_increments: Option<Size>,
+
     /// Whether the window should be visible or not.
     pub visible: bool,
 
@@ -62,9 +75,67 @@ pub struct Settings {
     /// Whether the window should be transparent.
     pub transparent: bool,
 
+    /// Whether the window should have a drop shadow drawn by the system when
+    /// it has no decorations.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS:** Supported.
+    /// - **Linux / Web:** Unsupported.
+    pub shadow: bool,
+
+    /// The logical width of the invisible border that can be dragged to
+    /// resize the window when it has no decorations.
+    ///
+    /// By default, it is `0.0`; meaning undecorated windows can only be
+    /// resized with `window::drag_resize`.
+    pub resize_border: f32,
+
+    /// The [`Edges`] of the window that can be dragged to resize it.
+    ///
+    /// By default, all of them. Disabling both edges of an axis fixes the
+    /// size of the window in that axis; like the height of a sidebar.
+    ///
+    /// ## Platform-specific
+    ///
+    /// Only the resize border of undecorated windows can disable a single
+    /// edge of an axis; the decorations of the system disable either both
+    /// or none.
+    pub resizable_edges: Edges,
+
+    /// The scale factor of the window; overriding the scale factor of the
+    /// program.
+    ///
+    /// By default, it is `None`; meaning the scale factor of the program
+    /// is used. The scale factor and theme of the program are already
+    /// queried per window; so they can change at runtime for each window.
+    pub scale_factor: Option<f64>,
+
+    /// The default [`Font`] of the window; overriding the default font of
+    /// the program.
+    ///
+    /// By default, it is `None`; meaning the default font of the program
+    /// is used.
+    pub default_font: Option<Font>,
+
     /// The window [`Level`].
     pub level: Level,
 
+    /// The [`Id`] of the window that owns the window; like the editor of a
+    /// floating inspector.
+    ///
+    /// An owned window stays above its owner, but not above other
+    /// applications; follows its owner when it moves; is minimized and
+    /// restored together with it; and is closed when it closes.
+    ///
+    /// By default, it is `None`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Linux:** Owned windows cannot be kept above only their owner.
+    /// - **Wayland / Web:** Owned windows do not follow their owner.
+    pub owner: Option<Id>,
+
     /// The icon of the window.
     pub icon: Option<Icon>,
 
@@ -80,6 +151,45 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// The [`Transition`] used to animate the contents of the window when it
+    /// opens or closes.
+    ///
+    /// By default, no [`Transition`] is used.
+    pub transition: Transition,
+
+    /// The [`FrameRate`] of the window.
+    ///
+    /// By default, it is `None`; meaning the [`FrameRate`] of the
+    /// application is used.
+    pub frame_rate: Option<FrameRate>,
+
+    /// Whether the overlays of the window, like menus, tooltips, and the
+    /// dropdowns of a pick list, can be drawn in small borderless popup
+    /// windows; so they can overflow the bounds of the window like native
+    /// menus.
+    ///
+    /// Overlays are drawn inside of the window when they fit in it, or when
+    /// popup windows are not supported by the platform.
+    ///
+    /// By default, it is `false`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / X11:** Supported.
+    /// - **Wayland / Web:** Unsupported.
+    pub popups: bool,
+
+    /// The path of a file used to remember the position, size, and
+    /// maximized state of the window across runs.
+    ///
+    /// If set, the state is saved when the window closes and restored when
+    /// it opens again; unless it would place the window outside of the
+    /// connected monitors. A maximized window is saved with the bounds it
+    /// had before being maximized.
+    ///
+    /// By default, it is `None`.
+    pub persistence: Option<PathBuf>,
 }
 
 impl Default for Settings {
@@ -91,13 +201,25 @@ impl Default for Settings {
             position: Position::default(),
             min_size: None,
             max_size: None,
+            aspect_ratio: None,
+            resize_increments: None,
             visible: true,
             resizable: true,
             decorations: true,
             transparent: false,
+            shadow: false,
+            resize_border: 0.0,
+            resizable_edges: Edges::default(),
+            scale_factor: None,
+            default_font: None,
             level: Level::default(),
+            owner: None,
             icon: None,
             exit_on_close_request: true,
+            transition: Transition::None,
+            frame_rate: None,
+            popups: false,
+            persistence: None,
             platform_specific: PlatformSpecific::default(),
         }
     }
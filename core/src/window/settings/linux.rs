@@ -14,4 +14,53 @@ pub struct PlatformSpecific {
     /// This flag is particularly useful for creating UI elements that need precise
     /// positioning and immediate display without window manager interference.
     pub override_redirect: bool,
+
+    /// The types of the window for X11 window managers, in order of
+    /// preference; like [`WindowType::Dock`] for bars or
+    /// [`WindowType::Notification`] for notification daemons.
+    ///
+    /// By default, it is empty; meaning a normal window.
+    pub window_type: Vec<WindowType>,
+
+    /// The instance name in the `WM_CLASS` of X11 windows.
+    ///
+    /// The class name is always the [`application_id`]. By default, it is
+    /// `None`; meaning the [`application_id`] is used as well.
+    ///
+    /// [`application_id`]: Self::application_id
+    pub wm_instance: Option<String>,
+}
+
+/// The type of an X11 window; used by window managers to decide how to
+/// place and decorate it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowType {
+    /// A desktop feature; like the desktop icons.
+    Desktop,
+    /// A dock or panel; normally kept on top of other windows.
+    Dock,
+    /// A toolbar torn off from the main window.
+    Toolbar,
+    /// A menu torn off from the main window.
+    Menu,
+    /// A small persistent utility window; like a palette or toolbox.
+    Utility,
+    /// A splash screen shown while the application starts.
+    Splash,
+    /// A dialog window.
+    Dialog,
+    /// A menu opened from a menu bar.
+    DropdownMenu,
+    /// A menu opened with a right click.
+    PopupMenu,
+    /// A tooltip.
+    Tooltip,
+    /// A notification; like a bubble with a new message.
+    Notification,
+    /// A dropdown of a combo box.
+    Combo,
+    /// A window being dragged.
+    Dnd,
+    /// A normal window.
+    Normal,
 }
@@ -1,7 +1,7 @@
 //! Platform specific settings for macOS.
 
 /// The platform specific window settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct PlatformSpecific {
     /// Hides the window title.
     pub title_hidden: bool,
@@ -9,4 +9,10 @@ pub struct PlatformSpecific {
     pub titlebar_transparent: bool,
     /// Makes the window content appear behind the titlebar.
     pub fullsize_content_view: bool,
+    /// The identifier of the group of native tabs the window joins.
+    ///
+    /// Windows with the same identifier, like the document windows of an
+    /// application, can be grouped into native tabs by the system; and
+    /// merged with the "Merge All Windows" item of the Window menu.
+    pub tabbing_identifier: Option<String>,
 }
@@ -0,0 +1,35 @@
+use crate::time::Duration;
+
+/// The effect used to animate the contents of a window when it opens
+/// or closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transition {
+    /// The contents appear and disappear immediately.
+    #[default]
+    None,
+
+    /// The contents fade in and out.
+    Fade,
+
+    /// The contents fade in and out while scaling up and down slightly.
+    Scale,
+}
+
+impl Transition {
+    /// The [`Duration`] of a [`Transition`].
+    pub const DURATION: Duration = Duration::from_millis(200);
+
+    /// Returns the opacity and scale of the contents of a window for the
+    /// given progress of the [`Transition`], from `0.0` (hidden) to `1.0`
+    /// (shown).
+    pub fn apply(self, progress: f32) -> (f32, f32) {
+        let t = progress.clamp(0.0, 1.0);
+        let t = t * t * (3.0 - 2.0 * t);
+
+        match self {
+            Self::None => (1.0, 1.0),
+            Self::Fade => (t, 1.0),
+            Self::Scale => (t, 0.95 + 0.05 * t),
+        }
+    }
+}
@@ -3,10 +3,12 @@
 /// ## Platform-specific
 ///
 /// - **X11:** Sets the WM's `XUrgencyHint`. No distinction between [`Critical`] and [`Informational`].
+/// - **Wayland:** Requires the `xdg_activation_v1` protocol.
+/// - **Web:** Unsupported.
 ///
 /// [`Critical`]: Self::Critical
 /// [`Informational`]: Self::Informational
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UserAttention {
     /// ## Platform-specific
     ///
@@ -0,0 +1,20 @@
+use crate::Size;
+
+/// A video mode supported by a monitor; used to take the whole screen in
+/// exclusive fullscreen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// The resolution of the video mode, in physical pixels.
+    pub size: Size<u32>,
+    /// The bit depth of the video mode.
+    pub bit_depth: u16,
+    /// The refresh rate of the video mode, in millihertz.
+    pub refresh_rate_millihertz: u32,
+}
+
+impl VideoMode {
+    /// Returns the refresh rate of the [`VideoMode`], in hertz.
+    pub fn refresh_rate(&self) -> f32 {
+        self.refresh_rate_millihertz as f32 / 1000.0
+    }
+}
@@ -21,6 +21,7 @@ enum Message {
     RadiusTopRightChanged(f32),
     RadiusBottomRightChanged(f32),
     RadiusBottomLeftChanged(f32),
+    SmoothingChanged(f32),
     BorderWidthChanged(f32),
     ShadowXOffsetChanged(f32),
     ShadowYOffsetChanged(f32),
@@ -37,6 +38,7 @@ impl Example {
                 color: Color::from_rgba(0.0, 0.0, 0.0, 0.8),
                 offset: Vector::new(0.0, 8.0),
                 blur_radius: 16.0,
+                ..Shadow::default()
             },
             snap: false,
         }
@@ -56,6 +58,9 @@ impl Example {
             Message::RadiusBottomLeftChanged(radius) => {
                 self.radius = self.radius.bottom_left(radius);
             }
+            Message::SmoothingChanged(smoothing) => {
+                self.radius = self.radius.smooth(smoothing);
+            }
             Message::BorderWidthChanged(width) => {
                 self.border_width = width;
             }
@@ -80,6 +85,7 @@ impl Example {
             top_right,
             bottom_right,
             bottom_left,
+            smoothing,
         } = self.radius;
 
         let Shadow {
@@ -103,6 +109,8 @@ impl Example {
                 .step(0.01),
             slider(1.0..=100.0, bottom_left, Message::RadiusBottomLeftChanged)
                 .step(0.01),
+            text!("Smoothing: {smoothing:.2}"),
+            slider(0.0..=1.0, smoothing, Message::SmoothingChanged).step(0.01),
             slider(1.0..=10.0, self.border_width, Message::BorderWidthChanged)
                 .step(0.01),
             text!("Shadow: {sx:.2}x{sy:.2}, {sr:.2}"),
@@ -200,8 +208,9 @@ mod quad {
                         radius: self.radius,
                         width: self.border_width,
                         color: Color::from_rgb(1.0, 0.0, 0.0),
+                        ..Border::default()
                     },
-                    shadow: self.shadow,
+                    shadow: self.shadow.into(),
                     snap: self.snap,
                 },
                 Color::BLACK,
@@ -8,13 +8,12 @@ use iced_wgpu::graphics::Viewport;
 use iced_wgpu::{Engine, Renderer, wgpu};
 use iced_winit::Clipboard;
 use iced_winit::conversion;
-use iced_winit::core::mouse;
 use iced_winit::core::renderer;
 use iced_winit::core::time::Instant;
-use iced_winit::core::window;
-use iced_winit::core::{Event, Font, Pixels, Size, Theme};
+use iced_winit::core::{Font, Pixels, Size, Theme};
 use iced_winit::futures;
-use iced_winit::runtime::user_interface::{self, UserInterface};
+use iced_winit::runtime::Integration;
+use iced_winit::runtime::user_interface;
 use iced_winit::winit;
 
 use winit::{
@@ -43,9 +42,7 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
             renderer: Renderer,
             scene: Scene,
             controls: Controls,
-            events: Vec<Event>,
-            cursor: mouse::Cursor,
-            cache: user_interface::Cache,
+            integration: Integration,
             clipboard: Clipboard,
             viewport: Viewport,
             modifiers: ModifiersState,
@@ -129,7 +126,8 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                 surface.configure(
                     &device,
                     &wgpu::SurfaceConfiguration {
-                        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                            | wgpu::TextureUsages::COPY_SRC,
                         format,
                         width: physical_size.width,
                         height: physical_size.height,
@@ -170,10 +168,8 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                     format,
                     scene,
                     controls,
-                    events: Vec::new(),
-                    cursor: mouse::Cursor::Unavailable,
+                    integration: Integration::new(viewport.logical_size()),
                     modifiers: ModifiersState::default(),
-                    cache: user_interface::Cache::new(),
                     clipboard,
                     viewport,
                     resized: false,
@@ -196,12 +192,10 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                 renderer,
                 scene,
                 controls,
-                events,
+                integration,
                 viewport,
-                cursor,
                 modifiers,
                 clipboard,
-                cache,
                 resized,
             } = self
             else {
@@ -222,7 +216,8 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                             device,
                             &wgpu::SurfaceConfiguration {
                                 format: *format,
-                                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                                    | wgpu::TextureUsages::COPY_SRC,
                                 width: size.width,
                                 height: size.height,
                                 present_mode: wgpu::PresentMode::AutoVsync,
@@ -232,6 +227,8 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                             },
                         );
 
+                        integration.resize(viewport.logical_size());
+
                         *resized = false;
                     }
 
@@ -257,27 +254,14 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                                 scene.draw(&mut render_pass);
                             }
 
-                            // Submit the scene
-                            queue.submit([encoder.finish()]);
-
                             // Draw iced on top
-                            let mut interface = UserInterface::build(
+                            let state = integration.draw(
                                 controls.view(),
-                                viewport.logical_size(),
-                                std::mem::take(cache),
-                                renderer,
-                            );
-
-                            let (state, _) = interface.update(
-                                &[Event::Window(
-                                    window::Event::RedrawRequested(
-                                        Instant::now(),
-                                    ),
-                                )],
-                                *cursor,
                                 renderer,
                                 clipboard,
-                                &mut Vec::new(),
+                                &Theme::Dark,
+                                &renderer::Style::default(),
+                                Instant::now(),
                             );
 
                             // Update the mouse cursor
@@ -293,22 +277,17 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                                 );
                             }
 
-                            // Draw the interface
-                            interface.draw(
-                                renderer,
-                                &Theme::Dark,
-                                &renderer::Style::default(),
-                                *cursor,
-                            );
-                            *cache = interface.into_cache();
-
-                            renderer.present(
+                            renderer.render_into(
+                                &mut encoder,
                                 None,
-                                frame.texture.format(),
-                                &view,
+                                &frame.texture,
                                 viewport,
                             );
 
+                            // Submit the scene and the interface together
+                            let _ = queue.submit([encoder.finish()]);
+                            renderer.recall();
+
                             // Present the frame
                             frame.present();
                         }
@@ -326,13 +305,6 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                         },
                     }
                 }
-                WindowEvent::CursorMoved { position, .. } => {
-                    *cursor =
-                        mouse::Cursor::Available(conversion::cursor_position(
-                            position,
-                            viewport.scale_factor(),
-                        ));
-                }
                 WindowEvent::ModifiersChanged(new_modifiers) => {
                     *modifiers = new_modifiers.state();
                 }
@@ -351,32 +323,21 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                 window.scale_factor(),
                 *modifiers,
             ) {
-                events.push(event);
+                integration.queue_event(event);
             }
 
             // If there are events pending
-            if !events.is_empty() {
+            if integration.has_pending_events() {
                 // We process them
-                let mut interface = UserInterface::build(
-                    controls.view(),
-                    viewport.logical_size(),
-                    std::mem::take(cache),
-                    renderer,
-                );
-
                 let mut messages = Vec::new();
 
-                let _ = interface.update(
-                    events,
-                    *cursor,
+                let _ = integration.update(
+                    controls.view(),
                     renderer,
                     clipboard,
                     &mut messages,
                 );
 
-                events.clear();
-                *cache = interface.into_cache();
-
                 // update our UI with any messages
                 for message in messages {
                     controls.update(message);
@@ -79,6 +79,7 @@ impl Example {
 
                         let (_id, open) = window::open(window::Settings {
                             position,
+                            transition: window::Transition::Scale,
                             ..window::Settings::default()
                         });
 
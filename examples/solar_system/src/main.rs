@@ -179,6 +179,7 @@ impl<Message> canvas::Program<Message> for State {
                     line_dash: canvas::LineDash {
                         offset: 0,
                         segments: &[3.0, 6.0],
+                        phase: 0.0,
                     },
                     ..Stroke::default()
                 },
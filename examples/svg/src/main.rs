@@ -40,6 +40,7 @@ impl Tiger {
                     } else {
                         None
                     },
+                    ..svg::Style::default()
                 });
 
         let apply_color_filter =
@@ -0,0 +1,175 @@
+//! This example showcases how themes can be exported, previewed, and
+//! imported back by other applications.
+use iced::clipboard;
+use iced::widget::{
+    button, center, column, container, row, scrollable, text, themer,
+};
+use iced::{Center, Element, Fill, Font, Task, Theme};
+
+pub fn main() -> iced::Result {
+    iced::application(Gallery::default, Gallery::update, Gallery::view)
+        .theme(Gallery::theme)
+        .run()
+}
+
+struct Gallery {
+    themes: Vec<Theme>,
+    selected: usize,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(usize),
+    Export,
+    Import,
+    Pasted(Option<String>),
+}
+
+impl Gallery {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Selected(index) => {
+                self.selected = index;
+                self.error = None;
+
+                Task::none()
+            }
+            Message::Export => clipboard::write(self.theme().to_toml()),
+            Message::Import => clipboard::read().map(Message::Pasted),
+            Message::Pasted(contents) => {
+                let Some(contents) = contents else {
+                    self.error = Some("The clipboard is empty".to_owned());
+                    return Task::none();
+                };
+
+                match Theme::from_toml(&contents)
+                    .or_else(|_| Theme::from_json(&contents))
+                {
+                    Ok(theme) => {
+                        self.themes.push(theme);
+                        self.selected = self.themes.len() - 1;
+                        self.error = None;
+                    }
+                    Err(error) => {
+                        self.error = Some(error.to_string());
+                    }
+                }
+
+                Task::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let previews = self.themes.iter().enumerate().map(|(index, theme)| {
+            preview(theme, index == self.selected, index)
+        });
+
+        let gallery = scrollable(row(previews).spacing(10).wrap())
+            .width(Fill)
+            .height(Fill);
+
+        let definition = scrollable(
+            text(self.theme().to_toml()).font(Font::MONOSPACE).size(14),
+        )
+        .width(Fill)
+        .height(Fill);
+
+        let controls = row![
+            button("Copy theme").on_press(Message::Export),
+            button("Paste theme").on_press(Message::Import),
+        ]
+        .spacing(10);
+
+        let error = self
+            .error
+            .as_deref()
+            .map(|error| text(error).style(text::danger));
+
+        column![
+            gallery,
+            row![
+                column![controls, definition].spacing(10).width(Fill),
+                column![].push_maybe(error).width(Fill),
+            ]
+            .spacing(20)
+            .height(Fill),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+
+    fn theme(&self) -> Theme {
+        self.themes[self.selected].clone()
+    }
+}
+
+impl Default for Gallery {
+    fn default() -> Self {
+        Self {
+            themes: Theme::ALL.to_vec(),
+            selected: 0,
+            error: None,
+        }
+    }
+}
+
+fn preview(
+    theme: &Theme,
+    is_selected: bool,
+    index: usize,
+) -> Element<'static, Message> {
+    let palette = theme.palette();
+
+    let swatch = |color| {
+        container("").width(16).height(16).style(move |_theme| {
+            container::Style {
+                background: Some(color.into()),
+                border: iced::border::rounded(4),
+                ..container::Style::default()
+            }
+        })
+    };
+
+    let card = container(
+        column![
+            text(theme.to_string()).size(14),
+            row![
+                swatch(palette.primary),
+                swatch(palette.success),
+                swatch(palette.warning),
+                swatch(palette.danger),
+            ]
+            .spacing(5),
+        ]
+        .spacing(10)
+        .align_x(Center),
+    )
+    .padding(10)
+    .width(150)
+    .style(move |theme: &Theme| {
+        let palette = theme.extended_palette();
+
+        container::Style {
+            border: iced::border::rounded(theme.tokens().radius.medium)
+                .width(if is_selected { 2 } else { 1 })
+                .color(if is_selected {
+                    palette.primary.strong.color
+                } else {
+                    palette.background.strong.color
+                }),
+            ..container::rounded_box(theme)
+        }
+    });
+
+    themer(
+        theme.clone(),
+        button(center(card).height(100))
+            .padding(0)
+            .style(button::text)
+            .on_press(Message::Selected(index)),
+    )
+    .into()
+}
@@ -8,3 +8,6 @@ pub mod native;
 pub mod wasm;
 
 pub mod default;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod timer;
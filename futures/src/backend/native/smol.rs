@@ -32,6 +32,15 @@ pub mod time {
         subscription::from_recipe(Every(duration))
     }
 
+    /// Waits until the given `duration` has elapsed.
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl Future<Output = ()> + crate::MaybeSend + 'static {
+        use futures::FutureExt;
+
+        smol::Timer::after(duration).map(|_| ())
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
@@ -19,4 +19,17 @@ impl crate::Executor for Executor {
 
 pub mod time {
     //! Listen and react to time.
+    use crate::MaybeSend;
+    use crate::backend::timer;
+    use crate::core::time::Duration;
+
+    /// Waits until the given `duration` has elapsed.
+    ///
+    /// The thread pool has no timer of its own; so a shared timer thread
+    /// waits instead.
+    pub fn sleep(
+        duration: Duration,
+    ) -> impl Future<Output = ()> + MaybeSend + 'static {
+        timer::sleep(duration)
+    }
 }
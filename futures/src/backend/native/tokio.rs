@@ -56,6 +56,13 @@ pub mod time {
         })
     }
 
+    /// Waits until the given `duration` has elapsed.
+    pub fn sleep(
+        duration: Duration,
+    ) -> impl Future<Output = ()> + MaybeSend + 'static {
+        tokio::time::sleep(duration)
+    }
+
     /// Returns a [`Subscription`] that runs the given async function at a
     /// set interval; producing the result of the function as output.
     pub fn repeat<F, T>(f: fn() -> F, interval: Duration) -> Subscription<T>
@@ -20,4 +20,25 @@ impl crate::Executor for Executor {
 
 pub mod time {
     //! Listen and react to time.
+    use crate::MaybeSend;
+    use crate::core::time::Duration;
+
+    /// Waits until the given `duration` has elapsed.
+    ///
+    /// There is no executor to drive a timer; so a shared timer thread
+    /// waits instead.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn sleep(
+        duration: Duration,
+    ) -> impl Future<Output = ()> + MaybeSend + 'static {
+        crate::backend::timer::sleep(duration)
+    }
+
+    /// Waits until the given `duration` has elapsed.
+    #[cfg(target_arch = "wasm32")]
+    pub fn sleep(
+        duration: Duration,
+    ) -> impl Future<Output = ()> + MaybeSend + 'static {
+        wasmtimer::tokio::sleep(duration)
+    }
 }
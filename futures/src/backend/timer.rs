@@ -0,0 +1,117 @@
+//! A timer shared by the backends without one of their own.
+use crate::core::time::{Duration, Instant};
+
+use futures::channel::oneshot;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::{Condvar, LazyLock, Mutex};
+
+/// Waits until the given `duration` has elapsed.
+///
+/// A single thread waits on every pending deadline; instead of one thread
+/// per call.
+pub fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+    static TIMER: LazyLock<&'static Timer> = LazyLock::new(Timer::spawn);
+
+    let (sender, receiver) = oneshot::channel();
+
+    TIMER.push(Deadline {
+        instant: Instant::now() + duration,
+        sender,
+    });
+
+    async move {
+        let _ = receiver.await;
+    }
+}
+
+struct Timer {
+    deadlines: Mutex<BinaryHeap<Deadline>>,
+    condvar: Condvar,
+}
+
+impl Timer {
+    fn spawn() -> &'static Self {
+        let timer: &'static Self = Box::leak(Box::new(Self {
+            deadlines: Mutex::new(BinaryHeap::new()),
+            condvar: Condvar::new(),
+        }));
+
+        let _ = std::thread::Builder::new()
+            .name("iced_futures::timer".to_owned())
+            .spawn(move || timer.run())
+            .expect("Spawn timer thread");
+
+        timer
+    }
+
+    fn push(&self, deadline: Deadline) {
+        self.deadlines
+            .lock()
+            .expect("Lock timer deadlines")
+            .push(deadline);
+
+        self.condvar.notify_one();
+    }
+
+    fn run(&self) {
+        let mut deadlines =
+            self.deadlines.lock().expect("Lock timer deadlines");
+
+        loop {
+            let now = Instant::now();
+
+            while deadlines
+                .peek()
+                .is_some_and(|deadline| deadline.instant <= now)
+            {
+                if let Some(deadline) = deadlines.pop() {
+                    let _ = deadline.sender.send(());
+                }
+            }
+
+            deadlines = match deadlines.peek() {
+                Some(deadline) => {
+                    let timeout = deadline.instant - now;
+
+                    self.condvar
+                        .wait_timeout(deadlines, timeout)
+                        .expect("Wait for timer deadlines")
+                        .0
+                }
+                None => self
+                    .condvar
+                    .wait(deadlines)
+                    .expect("Wait for timer deadlines"),
+            };
+        }
+    }
+}
+
+/// A pending deadline; ordered so the earliest one is at the top of the
+/// heap.
+struct Deadline {
+    instant: Instant,
+    sender: oneshot::Sender<()>,
+}
+
+impl PartialEq for Deadline {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant == other.instant
+    }
+}
+
+impl Eq for Deadline {}
+
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Deadline {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.instant.cmp(&self.instant)
+    }
+}
@@ -20,6 +20,13 @@ pub mod time {
 
     use wasmtimer::std::Instant;
 
+    /// Waits until the given `duration` has elapsed.
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl Future<Output = ()> + 'static {
+        wasmtimer::tokio::sleep(duration)
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
@@ -0,0 +1,171 @@
+//! Run blocking work outside of the async executor.
+use crate::MaybeSend;
+
+/// Runs the given closure outside of the async executor and returns a
+/// [`Future`] that resolves with its result.
+///
+/// CPU-heavy work—like encoding images or parsing large files—should run
+/// here instead of inside a future; otherwise, it can starve the executor
+/// and delay other tasks and subscriptions.
+///
+/// The blocking facility of the default executor is used, if it has one:
+///
+/// - `tokio` runs the closure with [`spawn_blocking`].
+/// - `smol` runs the closure with [`unblock`].
+/// - Otherwise, the closure runs on a dedicated thread.
+///
+/// The closure is only started once the [`Future`] is polled. It resolves
+/// with `None` if the closure panics.
+///
+/// On Wasm, the closure runs on the current thread when polled.
+///
+/// [`spawn_blocking`]: https://docs.rs/tokio/latest/tokio/task/fn.spawn_blocking.html
+/// [`unblock`]: https://docs.rs/smol/latest/smol/fn.unblock.html
+pub fn spawn<T>(
+    f: impl FnOnce() -> T + MaybeSend + 'static,
+) -> impl Future<Output = Option<T>> + MaybeSend + 'static
+where
+    T: MaybeSend + 'static,
+{
+    platform::spawn(f)
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+mod platform {
+    pub async fn spawn<T>(f: impl FnOnce() -> T + Send + 'static) -> Option<T>
+    where
+        T: Send + 'static,
+    {
+        match tokio::task::spawn_blocking(f).await {
+            Ok(output) => Some(output),
+            Err(error) => {
+                log::error!("Blocking task failed: {error}");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    feature = "smol",
+    not(feature = "tokio")
+))]
+mod platform {
+    use std::panic::{self, AssertUnwindSafe};
+
+    pub async fn spawn<T>(f: impl FnOnce() -> T + Send + 'static) -> Option<T>
+    where
+        T: Send + 'static,
+    {
+        smol::unblock(move || {
+            panic::catch_unwind(AssertUnwindSafe(f))
+                .inspect_err(|_| log::error!("Blocking task panicked"))
+                .ok()
+        })
+        .await
+    }
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(any(feature = "tokio", feature = "smol"))
+))]
+mod platform {
+    use futures::channel::oneshot;
+    use std::thread;
+
+    pub async fn spawn<T>(f: impl FnOnce() -> T + Send + 'static) -> Option<T>
+    where
+        T: Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+
+        let thread = thread::Builder::new()
+            .name("iced-blocking".to_owned())
+            .spawn(move || {
+                let _ = sender.send(f());
+            });
+
+        if let Err(error) = thread {
+            log::error!("Blocking thread could not be spawned: {error}");
+            return None;
+        }
+
+        // The sender is dropped without a value if the closure panics
+        receiver
+            .await
+            .inspect_err(|_| log::error!("Blocking task panicked"))
+            .ok()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    pub async fn spawn<T>(f: impl FnOnce() -> T + 'static) -> Option<T>
+    where
+        T: 'static,
+    {
+        Some(f())
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+    use std::sync::atomic::{self, AtomicBool};
+    use std::time::Duration;
+
+    fn block_on<T>(future: impl Future<Output = T>) -> T {
+        // `spawn_blocking` needs to run inside of a `tokio` runtime
+        #[cfg(feature = "tokio")]
+        {
+            tokio::runtime::Runtime::new()
+                .expect("Create runtime")
+                .block_on(future)
+        }
+
+        #[cfg(not(feature = "tokio"))]
+        {
+            futures::executor::block_on(future)
+        }
+    }
+
+    #[test]
+    fn blocking_tasks_complete() {
+        let output = block_on(spawn(|| {
+            std::thread::sleep(Duration::from_millis(10));
+            (1..=10).sum::<u32>()
+        }));
+
+        assert_eq!(output, Some(55));
+    }
+
+    #[test]
+    fn panicking_blocking_tasks_produce_nothing() {
+        let output = block_on(spawn(|| -> u32 {
+            panic!("blocking task panicked on purpose")
+        }));
+
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn blocking_tasks_start_when_polled() {
+        let is_started = Arc::new(AtomicBool::new(false));
+
+        let task = spawn({
+            let is_started = is_started.clone();
+
+            move || is_started.store(true, atomic::Ordering::SeqCst)
+        });
+
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(!is_started.load(atomic::Ordering::SeqCst));
+
+        assert_eq!(block_on(task), Some(()));
+        assert!(is_started.load(atomic::Ordering::SeqCst));
+    }
+}
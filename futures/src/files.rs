@@ -0,0 +1,126 @@
+//! Watch the file system for changes.
+use crate::backend::default::time;
+use crate::core::time::Duration;
+use crate::futures::channel::mpsc;
+use crate::futures::future::{self, Either};
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::subscription::Subscription;
+
+use notify::Watcher;
+use std::path::{Path, PathBuf};
+
+/// How long the file system must stay quiet before changes are reported.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// A change in the file system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// A file or directory was created.
+    Created(PathBuf),
+    /// A file or directory was modified.
+    Modified(PathBuf),
+    /// A file or directory was removed.
+    Removed(PathBuf),
+}
+
+impl Event {
+    /// Returns the path of the file or directory that changed.
+    pub fn path(&self) -> &Path {
+        match self {
+            Event::Created(path)
+            | Event::Modified(path)
+            | Event::Removed(path) => path,
+        }
+    }
+}
+
+/// Returns a [`Subscription`] that watches the file or directory at the
+/// given path for changes; recursively, in the case of a directory.
+///
+/// Changes are debounced; so bursts of writes—like an editor saving a
+/// file—produce a single [`Event`] per path.
+pub fn watch(path: impl Into<PathBuf>) -> Subscription<Event> {
+    Subscription::run_with(path.into(), |path| {
+        let path = path.clone();
+
+        stream::channel(100, async move |mut output| {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            let watcher = notify::recommended_watcher(
+                move |result: notify::Result<notify::Event>| match result {
+                    Ok(event) => {
+                        let _ = sender.unbounded_send(event);
+                    }
+                    Err(error) => {
+                        log::warn!("File system watcher failed: {error}");
+                    }
+                },
+            );
+
+            let mut watcher = match watcher {
+                Ok(watcher) => watcher,
+                Err(error) => {
+                    log::error!(
+                        "Failed to create file system watcher: {error}"
+                    );
+                    return;
+                }
+            };
+
+            if let Err(error) =
+                watcher.watch(&path, notify::RecursiveMode::Recursive)
+            {
+                log::error!("Failed to watch {}: {error}", path.display());
+                return;
+            }
+
+            while let Some(event) = receiver.next().await {
+                let mut changes = Vec::new();
+                merge(&mut changes, event);
+
+                // Wait until the file system stays quiet
+                loop {
+                    let quiet = Box::pin(time::sleep(DEBOUNCE));
+
+                    match future::select(receiver.next(), quiet).await {
+                        Either::Left((Some(event), _)) => {
+                            merge(&mut changes, event);
+                        }
+                        Either::Left((None, _)) | Either::Right(_) => break,
+                    }
+                }
+
+                for change in changes {
+                    if output.send(change).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        })
+    })
+}
+
+fn merge(changes: &mut Vec<Event>, event: notify::Event) {
+    let change: fn(PathBuf) -> Event = match event.kind {
+        notify::EventKind::Create(_) => Event::Created,
+        notify::EventKind::Modify(_) => Event::Modified,
+        notify::EventKind::Remove(_) => Event::Removed,
+        _ => return,
+    };
+
+    for path in event.paths {
+        let change = change(path);
+
+        match changes
+            .iter_mut()
+            .find(|existing| existing.path() == change.path())
+        {
+            // Modifying a file that was just created is still a creation
+            Some(Event::Created(_)) if matches!(change, Event::Modified(_)) => {
+            }
+            Some(existing) => *existing = change,
+            None => changes.push(change),
+        }
+    }
+}
@@ -12,16 +12,21 @@ mod maybe;
 mod runtime;
 
 pub mod backend;
+pub mod blocking;
 pub mod event;
 pub mod executor;
+#[cfg(all(feature = "files", not(target_arch = "wasm32")))]
+pub mod files;
 pub mod keyboard;
 pub mod stream;
 pub mod subscription;
+#[cfg(all(feature = "websocket", not(target_arch = "wasm32")))]
+pub mod websocket;
 
 pub use executor::Executor;
 pub use maybe::{MaybeSend, MaybeSync};
 pub use platform::*;
-pub use runtime::Runtime;
+pub use runtime::{Priority, Runtime};
 pub use subscription::Subscription;
 
 #[cfg(not(target_arch = "wasm32"))]
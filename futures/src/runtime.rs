@@ -2,8 +2,26 @@
 use crate::subscription;
 use crate::{BoxStream, Executor, MaybeSend};
 
+use futures::task::{self, ArcWake};
 use futures::{Sink, channel::mpsc};
 use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// The scheduling priority of a stream run in a [`Runtime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Bulk work that can wait.
+    ///
+    /// A background stream is not polled while any interactive stream of
+    /// the [`Runtime`] is ready to make progress.
+    Background,
+    /// Work that the user is waiting for, like reacting to input.
+    #[default]
+    Interactive,
+}
 
 /// A batteries-included runtime of commands and subscriptions.
 ///
@@ -16,6 +34,7 @@ pub struct Runtime<Executor, Sender, Message> {
     executor: Executor,
     sender: Sender,
     subscriptions: subscription::Tracker,
+    scheduler: Arc<Mutex<Scheduler>>,
     _message: PhantomData<Message>,
 }
 
@@ -39,6 +58,7 @@ where
             executor,
             sender,
             subscriptions: subscription::Tracker::new(),
+            scheduler: Arc::new(Mutex::new(Scheduler::default())),
             _message: PhantomData,
         }
     }
@@ -63,6 +83,21 @@ where
     ///
     /// [`Stream`]: BoxStream
     pub fn run(&mut self, stream: BoxStream<Message>) {
+        self.run_with_priority(stream, Priority::Interactive);
+    }
+
+    /// Runs a [`Stream`] in the [`Runtime`] until completion with the given
+    /// [`Priority`].
+    ///
+    /// The resulting `Message`s will be forwarded to the `Sender` of the
+    /// [`Runtime`].
+    ///
+    /// [`Stream`]: BoxStream
+    pub fn run_with_priority(
+        &mut self,
+        stream: BoxStream<Message>,
+        priority: Priority,
+    ) {
         use futures::{FutureExt, StreamExt};
 
         let sender = self.sender.clone();
@@ -76,7 +111,20 @@ where
                 }
             });
 
-        self.executor.spawn(future);
+        match priority {
+            Priority::Interactive => {
+                self.executor.spawn(Interactive::new(
+                    Box::pin(future),
+                    self.scheduler.clone(),
+                ));
+            }
+            Priority::Background => {
+                self.executor.spawn(Background {
+                    future: Box::pin(future),
+                    scheduler: self.scheduler.clone(),
+                });
+            }
+        }
     }
 
     /// Tracks a [`Subscription`] in the [`Runtime`].
@@ -118,3 +166,266 @@ where
         self.subscriptions.broadcast(event);
     }
 }
+
+/// Keeps track of the interactive futures of a [`Runtime`] that are ready to
+/// make progress; and of the background futures waiting for them.
+#[derive(Debug, Default)]
+struct Scheduler {
+    ready: usize,
+    deferred: Vec<Waker>,
+}
+
+impl Scheduler {
+    fn ready(&mut self) {
+        self.ready += 1;
+    }
+
+    /// Returns the wakers of the deferred background futures, once no
+    /// interactive futures are ready.
+    fn polled(&mut self) -> Vec<Waker> {
+        self.ready -= 1;
+
+        if self.ready == 0 {
+            std::mem::take(&mut self.deferred)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct Interactive<F> {
+    future: Pin<Box<F>>,
+    readiness: Arc<Readiness>,
+}
+
+impl<F> Interactive<F> {
+    fn new(future: Pin<Box<F>>, scheduler: Arc<Mutex<Scheduler>>) -> Self {
+        // Interactive futures are ready until they are first polled
+        scheduler.lock().expect("Lock scheduler").ready();
+
+        Self {
+            future,
+            readiness: Arc::new(Readiness {
+                scheduler,
+                is_ready: AtomicBool::new(true),
+                is_finished: AtomicBool::new(false),
+                waker: Mutex::new(None),
+            }),
+        }
+    }
+}
+
+impl<F: Future<Output = ()>> Future for Interactive<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.readiness.unready();
+
+        *self.readiness.waker.lock().expect("Lock waker") =
+            Some(cx.waker().clone());
+
+        let waker = task::waker(self.readiness.clone());
+
+        let poll = self.future.as_mut().poll(&mut Context::from_waker(&waker));
+
+        if poll.is_ready() {
+            self.readiness.finish();
+        }
+
+        poll
+    }
+}
+
+impl<F> Drop for Interactive<F> {
+    fn drop(&mut self) {
+        self.readiness.finish();
+    }
+}
+
+/// The readiness of an [`Interactive`] future.
+///
+/// Its state only changes while the [`Scheduler`] is locked; so a waker
+/// firing concurrently with the end of its future cannot leave the future
+/// marked as ready forever.
+struct Readiness {
+    scheduler: Arc<Mutex<Scheduler>>,
+    is_ready: AtomicBool,
+    is_finished: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Readiness {
+    fn unready(&self) {
+        let deferred = {
+            let mut scheduler = self.scheduler.lock().expect("Lock scheduler");
+
+            if self.is_ready.swap(false, atomic::Ordering::AcqRel) {
+                scheduler.polled()
+            } else {
+                Vec::new()
+            }
+        };
+
+        for waker in deferred {
+            waker.wake();
+        }
+    }
+
+    /// Marks the future as finished, ignoring any later wakes.
+    fn finish(&self) {
+        {
+            let _scheduler = self.scheduler.lock().expect("Lock scheduler");
+            self.is_finished.store(true, atomic::Ordering::Release);
+        }
+
+        self.unready();
+    }
+}
+
+impl ArcWake for Readiness {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        {
+            let mut scheduler =
+                arc_self.scheduler.lock().expect("Lock scheduler");
+
+            if arc_self.is_finished.load(atomic::Ordering::Acquire) {
+                return;
+            }
+
+            if !arc_self.is_ready.swap(true, atomic::Ordering::AcqRel) {
+                scheduler.ready();
+            }
+        }
+
+        if let Some(waker) = arc_self.waker.lock().expect("Lock waker").as_ref()
+        {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+struct Background<F> {
+    future: Pin<Box<F>>,
+    scheduler: Arc<Mutex<Scheduler>>,
+}
+
+impl<F: Future<Output = ()>> Future for Background<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        {
+            let mut scheduler = self.scheduler.lock().expect("Lock scheduler");
+
+            if scheduler.ready > 0 {
+                scheduler.deferred.push(cx.waker().clone());
+
+                return Poll::Pending;
+            }
+        }
+
+        self.future.as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::future;
+    use futures::task::noop_waker;
+
+    /// Returns an interactive future that keeps its waker in the returned
+    /// slot; so it can be woken after it is gone.
+    fn interactive(
+        scheduler: &Arc<Mutex<Scheduler>>,
+        is_done: bool,
+    ) -> (
+        Interactive<impl Future<Output = ()>>,
+        Arc<Mutex<Option<Waker>>>,
+    ) {
+        let slot = Arc::new(Mutex::new(None));
+
+        let future = {
+            let slot = slot.clone();
+
+            future::poll_fn(move |cx| {
+                *slot.lock().expect("Lock slot") = Some(cx.waker().clone());
+
+                if is_done {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+        };
+
+        (Interactive::new(Box::pin(future), scheduler.clone()), slot)
+    }
+
+    fn background(
+        scheduler: &Arc<Mutex<Scheduler>>,
+    ) -> Background<impl Future<Output = ()>> {
+        Background {
+            future: Box::pin(future::ready(())),
+            scheduler: scheduler.clone(),
+        }
+    }
+
+    fn wake(slot: &Mutex<Option<Waker>>) {
+        slot.lock()
+            .expect("Lock slot")
+            .take()
+            .expect("Waker of future")
+            .wake();
+    }
+
+    #[test]
+    fn background_runs_after_interactive_is_dropped() {
+        let scheduler = Arc::new(Mutex::new(Scheduler::default()));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let (mut interactive, slot) = interactive(&scheduler, false);
+        let mut background = background(&scheduler);
+
+        assert!(Pin::new(&mut background).poll(&mut cx).is_pending());
+        assert!(Pin::new(&mut interactive).poll(&mut cx).is_pending());
+
+        drop(interactive);
+        wake(&slot);
+
+        assert!(Pin::new(&mut background).poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn background_runs_after_interactive_finishes() {
+        let scheduler = Arc::new(Mutex::new(Scheduler::default()));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let (mut interactive, slot) = interactive(&scheduler, true);
+        let mut background = background(&scheduler);
+
+        assert!(Pin::new(&mut interactive).poll(&mut cx).is_ready());
+        wake(&slot);
+
+        assert!(Pin::new(&mut background).poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn background_waits_for_woken_interactive() {
+        let scheduler = Arc::new(Mutex::new(Scheduler::default()));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let (mut interactive, slot) = interactive(&scheduler, false);
+        let mut background = background(&scheduler);
+
+        assert!(Pin::new(&mut interactive).poll(&mut cx).is_pending());
+        wake(&slot);
+
+        assert!(Pin::new(&mut background).poll(&mut cx).is_pending());
+        assert!(Pin::new(&mut interactive).poll(&mut cx).is_pending());
+        assert!(Pin::new(&mut background).poll(&mut cx).is_ready());
+    }
+}
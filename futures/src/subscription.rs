@@ -4,9 +4,10 @@ mod tracker;
 pub use tracker::Tracker;
 
 use crate::core::event;
+use crate::core::time::Duration;
 use crate::core::window;
 use crate::futures::Stream;
-use crate::{BoxStream, MaybeSend};
+use crate::{BoxFuture, BoxStream, MaybeSend};
 
 use std::any::TypeId;
 use std::hash::Hash;
@@ -286,6 +287,29 @@ impl<T> Subscription<T> {
         }
     }
 
+    /// Limits the [`Subscription`] to produce at most one output every
+    /// `duration`.
+    ///
+    /// The first output is produced immediately. Any outputs produced in the
+    /// following `duration` are discarded, except for the last one; which is
+    /// produced once the `duration` has elapsed. This is useful to react to
+    /// bursts of events—like window resizes—without missing the final state.
+    pub fn throttle(mut self, duration: Duration) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Throttle { recipe, duration })
+                        as Box<dyn Recipe<Output = T>>
+                })
+                .collect(),
+        }
+    }
+
     /// Returns the amount of recipe units in this [`Subscription`].
     pub fn units(&self) -> usize {
         self.recipes.len()
@@ -422,6 +446,94 @@ where
     }
 }
 
+struct Throttle<T> {
+    recipe: Box<dyn Recipe<Output = T>>,
+    duration: Duration,
+}
+
+impl<T> Recipe for Throttle<T>
+where
+    T: MaybeSend + 'static,
+{
+    type Output = T;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        use crate::backend::default::time;
+        use futures::future::{self, Either};
+        use futures::stream::{self, StreamExt};
+
+        struct State<T> {
+            stream: Option<BoxStream<T>>,
+            pending: Option<T>,
+            window: Option<BoxFuture<()>>,
+        }
+
+        let duration = self.duration;
+
+        let state = State {
+            stream: Some(self.recipe.stream(input)),
+            pending: None,
+            window: None,
+        };
+
+        crate::boxed_stream(stream::unfold(
+            state,
+            move |mut state| async move {
+                loop {
+                    let Some(window) = state.window.as_mut() else {
+                        // Outside of a window, outputs are produced immediately
+                        let output = state.stream.as_mut()?.next().await?;
+                        state.window = Some(Box::pin(time::sleep(duration)));
+
+                        return Some((output, state));
+                    };
+
+                    let Some(stream) = state.stream.as_mut() else {
+                        window.await;
+                        state.window = None;
+
+                        return state
+                            .pending
+                            .take()
+                            .map(|output| (output, state));
+                    };
+
+                    let next = match future::select(stream.next(), window).await
+                    {
+                        Either::Left((output, _)) => Some(output),
+                        Either::Right(((), _)) => None,
+                    };
+
+                    match next {
+                        Some(Some(output)) => {
+                            state.pending = Some(output);
+                        }
+                        Some(None) => {
+                            state.stream = None;
+                        }
+                        None => {
+                            state.window = None;
+
+                            if let Some(output) = state.pending.take() {
+                                state.window =
+                                    Some(Box::pin(time::sleep(duration)));
+
+                                return Some((output, state));
+                            }
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
 pub(crate) fn filter_map<I, F, T>(id: I, f: F) -> Subscription<T>
 where
     I: Hash + 'static,
@@ -0,0 +1,185 @@
+//! Connect to WebSocket servers.
+use crate::backend::default::time;
+use crate::core::time::{Duration, Instant};
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::stream;
+use crate::subscription::Subscription;
+
+use async_net::TcpStream;
+use async_tungstenite::async_tls::{ClientStream, client_async_tls};
+use async_tungstenite::tungstenite;
+use async_tungstenite::tungstenite::client::IntoClientRequest;
+use async_tungstenite::tungstenite::error::UrlError;
+
+/// The delay before the first reconnection attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The maximum delay between reconnection attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// The time a connection must stay up before the backoff is reset.
+const STABLE_CONNECTION: Duration = Duration::from_secs(10);
+
+/// An event of a WebSocket connection.
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// The connection was established.
+    ///
+    /// The [`Connection`] can be used to send messages to the server.
+    Connected(Connection),
+    /// A message was received from the server.
+    Message(Message),
+    /// The connection was lost, or could not be established.
+    ///
+    /// The [`Subscription`] will keep trying to reconnect.
+    Disconnected,
+}
+
+/// A message sent to or received from a WebSocket server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// A text message.
+    Text(String),
+    /// A binary message.
+    Binary(Vec<u8>),
+}
+
+/// An open WebSocket connection.
+#[derive(Debug, Clone)]
+pub struct Connection(mpsc::Sender<Message>);
+
+impl Connection {
+    /// Sends a [`Message`] to the server.
+    ///
+    /// Messages are discarded if the connection is lost or too many of them
+    /// are queued.
+    pub fn send(&mut self, message: Message) {
+        if let Err(error) = self.0.try_send(message) {
+            log::warn!("WebSocket message could not be sent: {error}");
+        }
+    }
+}
+
+/// Returns a [`Subscription`] that connects to the WebSocket server at the
+/// given URL.
+///
+/// The [`Subscription`] reconnects automatically with exponential backoff
+/// whenever the connection is lost.
+///
+/// The connection does not depend on the executor of the runtime; so it
+/// works with any of the available backends.
+pub fn connect(url: impl Into<String>) -> Subscription<Event> {
+    Subscription::run_with(url.into(), |url| {
+        let url = url.clone();
+
+        stream::channel(100, async move |mut output| {
+            let mut backoff = INITIAL_BACKOFF;
+
+            loop {
+                let websocket = match open(&url).await {
+                    Ok(websocket) => websocket,
+                    Err(error) => {
+                        log::warn!("Failed to connect to {url}: {error}");
+
+                        if output.send(Event::Disconnected).await.is_err() {
+                            return;
+                        }
+
+                        time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(MAX_BACKOFF);
+
+                        continue;
+                    }
+                };
+
+                let connected_at = Instant::now();
+                let (sender, mut input) = mpsc::channel(100);
+
+                if output
+                    .send(Event::Connected(Connection(sender)))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+
+                let mut websocket = websocket.fuse();
+
+                loop {
+                    crate::futures::select! {
+                        received = websocket.next() => {
+                            let message = match received {
+                                Some(Ok(tungstenite::Message::Text(text))) => {
+                                    Message::Text(text)
+                                }
+                                Some(Ok(tungstenite::Message::Binary(bytes))) => {
+                                    Message::Binary(bytes)
+                                }
+                                Some(Ok(tungstenite::Message::Close(_)))
+                                | Some(Err(_))
+                                | None => break,
+                                Some(Ok(_)) => continue,
+                            };
+
+                            if output.send(Event::Message(message)).await.is_err() {
+                                return;
+                            }
+                        }
+                        message = input.select_next_some() => {
+                            let message = match message {
+                                Message::Text(text) => {
+                                    tungstenite::Message::Text(text)
+                                }
+                                Message::Binary(bytes) => {
+                                    tungstenite::Message::Binary(bytes)
+                                }
+                            };
+
+                            if websocket.send(message).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                if output.send(Event::Disconnected).await.is_err() {
+                    return;
+                }
+
+                if connected_at.elapsed() >= STABLE_CONNECTION {
+                    backoff = INITIAL_BACKOFF;
+                }
+
+                time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        })
+    })
+}
+
+/// Opens a WebSocket connection to the given URL.
+async fn open(
+    url: &str,
+) -> Result<
+    async_tungstenite::WebSocketStream<ClientStream<TcpStream>>,
+    tungstenite::Error,
+> {
+    let request = url.into_client_request()?;
+    let uri = request.uri();
+
+    let host = uri.host().ok_or(UrlError::NoHostName)?.to_owned();
+
+    let port = match (uri.port_u16(), uri.scheme_str()) {
+        (Some(port), _) => port,
+        (None, Some("wss")) => 443,
+        (None, Some("ws")) => 80,
+        _ => return Err(UrlError::UnsupportedUrlScheme.into()),
+    };
+
+    let stream = TcpStream::connect((host.as_str(), port)).await?;
+
+    let (websocket, _) = client_async_tls(request, stream).await?;
+
+    Ok(websocket)
+}
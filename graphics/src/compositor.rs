@@ -1,8 +1,10 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
-use crate::core::Color;
+use crate::core::image;
+use crate::core::window;
+use crate::core::{Color, Font};
 use crate::futures::{MaybeSend, MaybeSync};
-use crate::{Error, Settings, Viewport};
+use crate::{Antialiasing, Error, Settings, Viewport};
 
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
 use thiserror::Error;
@@ -58,6 +60,22 @@ pub trait Compositor: Sized {
         height: u32,
     );
 
+    /// Configures a [`Surface`] with the given dimensions and
+    /// [`window::PresentMode`].
+    ///
+    /// By default, the [`window::PresentMode`] is ignored.
+    ///
+    /// [`Surface`]: Self::Surface
+    fn configure_surface_with_present_mode(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+        _present_mode: window::PresentMode,
+    ) {
+        self.configure_surface(surface, width, height);
+    }
+
     /// Returns [`Information`] used by this [`Compositor`].
     fn fetch_information(&self) -> Information;
 
@@ -92,6 +110,64 @@ pub trait Compositor: Sized {
         viewport: &Viewport,
         background_color: Color,
     ) -> Vec<u8>;
+
+    /// Changes the [`Antialiasing`] strategy of the given [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn set_antialiasing(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _antialiasing: Option<Antialiasing>,
+    ) {
+    }
+
+    /// Changes the default [`Font`] of the given [`Renderer`].
+    ///
+    /// `None` restores the default [`Font`] of the [`Compositor`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn set_default_font(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _font: Option<Font>,
+    ) {
+    }
+
+    /// Evicts the images that were not drawn in the last frame from the
+    /// caches of the given [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn evict_images(&mut self, _renderer: &mut Self::Renderer) {}
+
+    /// Evicts the image of the given [`image::Handle`] from the caches of the
+    /// given [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn evict_image(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _handle: &image::Handle,
+    ) {
+    }
+
+    /// Loads the image of the given [`image::Handle`] into the caches of the
+    /// given [`Renderer`] before it is drawn.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn prefetch_image(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _handle: &image::Handle,
+    ) {
+    }
+
+    /// Returns the memory [`Usage`](image::Usage) of the images cached by the
+    /// given [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn image_usage(&self, _renderer: &Self::Renderer) -> image::Usage {
+        image::Usage::default()
+    }
 }
 
 /// A window that can be used in a [`Compositor`].
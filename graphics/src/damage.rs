@@ -1,4 +1,10 @@
 //! Compute the damage between frames.
+//!
+//! Damage tracking is only partially implemented: damage is computed by
+//! diffing the layers of consecutive frames, not tracked from the widget tree;
+//! and it is only leveraged by the `tiny-skia` renderer, which redraws and
+//! presents just the damaged regions of its surfaces. The `wgpu` renderer
+//! always redraws and presents whole frames.
 use crate::core::{Point, Rectangle};
 
 /// Diffs the damage regions given some previous and current primitives.
@@ -1,18 +1,32 @@
 use crate::cache::{self, Cached};
-use crate::core::Size;
+use crate::core::{Point, Rectangle, Size, Vector};
 use crate::geometry::{self, Frame};
 
 pub use cache::Group;
 
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+
 /// A simple cache that stores generated geometry to avoid recomputation.
 ///
 /// A [`Cache`] will not redraw its geometry unless the dimensions of its layer
 /// change or it is explicitly cleared.
+///
+/// Besides its main geometry, a [`Cache`] can store named layers—see
+/// [`Cache::draw_layer`]—and tiles—see [`Cache::draw_tiled`]—which can be
+/// invalidated independently.
 pub struct Cache<Renderer>
 where
     Renderer: geometry::Renderer,
 {
     raw: crate::Cache<Data<<Renderer::Geometry as Cached>::Cache>>,
+    layers: RefCell<
+        FxHashMap<
+            String,
+            crate::Cache<Data<<Renderer::Geometry as Cached>::Cache>>,
+        >,
+    >,
+    tiles: RefCell<Tiles<<Renderer::Geometry as Cached>::Cache>>,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +35,13 @@ struct Data<T> {
     geometry: T,
 }
 
+struct Tiles<T> {
+    bounds: Size,
+    size: Size,
+    entries: Vec<(Rectangle, crate::Cache<Data<T>>)>,
+    damage: Vec<Rectangle>,
+}
+
 impl<Renderer> Cache<Renderer>
 where
     Renderer: geometry::Renderer,
@@ -29,6 +50,13 @@ where
     pub fn new() -> Self {
         Cache {
             raw: cache::Cache::new(),
+            layers: RefCell::new(FxHashMap::default()),
+            tiles: RefCell::new(Tiles {
+                bounds: Size::ZERO,
+                size: Size::ZERO,
+                entries: Vec::new(),
+                damage: Vec::new(),
+            }),
         }
     }
 
@@ -41,12 +69,38 @@ where
     pub fn with_group(group: Group) -> Self {
         Cache {
             raw: crate::Cache::with_group(group),
+            ..Self::new()
         }
     }
 
     /// Clears the [`Cache`], forcing a redraw the next time it is used.
+    ///
+    /// This clears all of its layers and tiles as well.
     pub fn clear(&self) {
         self.raw.clear();
+
+        for layer in self.layers.borrow().values() {
+            layer.clear();
+        }
+
+        for (_, tile) in &self.tiles.borrow().entries {
+            tile.clear();
+        }
+    }
+
+    /// Clears the named layer of the [`Cache`], forcing only its geometry to
+    /// be redrawn the next time [`Cache::draw_layer`] is called for it.
+    pub fn clear_layer(&self, layer: &str) {
+        if let Some(layer) = self.layers.borrow().get(layer) {
+            layer.clear();
+        }
+    }
+
+    /// Clears the given region of the [`Cache`], forcing only the tiles that
+    /// intersect it to be redrawn the next time [`Cache::draw_tiled`] is
+    /// called.
+    pub fn clear_region(&self, region: Rectangle) {
+        self.tiles.borrow_mut().damage.push(region);
     }
 
     /// Draws geometry using the provided closure and stores it in the
@@ -65,35 +119,138 @@ where
         bounds: Size,
         draw_fn: impl FnOnce(&mut Frame<Renderer>),
     ) -> Renderer::Geometry {
-        use std::ops::Deref;
+        draw(&self.raw, renderer, bounds, draw_fn)
+    }
 
-        let state = self.raw.state();
+    /// Draws the geometry of the named layer of the [`Cache`] using the
+    /// provided closure.
+    ///
+    /// Each layer is cached independently and can be invalidated with
+    /// [`Cache::clear_layer`]; so, for instance, a cursor can be redrawn
+    /// on top of a huge plot without redrawing the plot.
+    pub fn draw_layer(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        layer: &str,
+        draw_fn: impl FnOnce(&mut Frame<Renderer>),
+    ) -> Renderer::Geometry {
+        let mut layers = self.layers.borrow_mut();
 
-        let previous = match state.borrow().deref() {
-            cache::State::Empty { previous } => {
-                previous.as_ref().map(|data| data.geometry.clone())
+        let cache = layers.entry(layer.to_owned()).or_insert_with(|| {
+            if self.raw.group().is_singleton() {
+                crate::Cache::new()
+            } else {
+                crate::Cache::with_group(self.raw.group())
             }
-            cache::State::Filled { current } => {
-                if current.bounds == bounds {
-                    return Cached::load(&current.geometry);
+        });
+
+        draw(cache, renderer, bounds, draw_fn)
+    }
+
+    /// Draws geometry in tiles of the given size using the provided closure.
+    ///
+    /// The closure is called once for every tile that needs to be redrawn;
+    /// with a [`Frame`] that clips its drawing operations to the bounds of the
+    /// tile, which are provided too. The coordinates of the [`Frame`] are
+    /// still relative to the whole `bounds`.
+    ///
+    /// Tiles are only redrawn if the bounds change, the [`Cache`] is cleared,
+    /// or they intersect a region cleared with [`Cache::clear_region`].
+    pub fn draw_tiled(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        tile_size: Size,
+        draw_fn: impl Fn(&mut Frame<Renderer>, Rectangle),
+    ) -> Vec<Renderer::Geometry> {
+        let mut tiles = self.tiles.borrow_mut();
+
+        if tiles.bounds != bounds || tiles.size != tile_size {
+            let columns = (bounds.width / tile_size.width.max(1.0)).ceil();
+            let rows = (bounds.height / tile_size.height.max(1.0)).ceil();
+
+            tiles.bounds = bounds;
+            tiles.size = tile_size;
+            tiles.damage.clear();
+            tiles.entries = (0..rows as usize)
+                .flat_map(|row| {
+                    (0..columns as usize).map(move |column| {
+                        Rectangle::new(
+                            Point::new(
+                                column as f32 * tile_size.width,
+                                row as f32 * tile_size.height,
+                            ),
+                            tile_size,
+                        )
+                    })
+                })
+                .filter_map(|tile| {
+                    tile.intersection(&Rectangle::with_size(bounds))
+                })
+                .map(|tile| (tile, crate::Cache::new()))
+                .collect();
+        }
+
+        let damage = std::mem::take(&mut tiles.damage);
+
+        tiles
+            .entries
+            .iter()
+            .map(|(tile, cache)| {
+                if damage.iter().any(|region| region.intersects(tile)) {
+                    cache.clear();
                 }
 
-                Some(current.geometry.clone())
+                draw(cache, renderer, bounds, |frame| {
+                    frame.with_clip(*tile, |frame| {
+                        frame.translate(Vector::new(-tile.x, -tile.y));
+
+                        draw_fn(frame, *tile);
+                    });
+                })
+            })
+            .collect()
+    }
+}
+
+fn draw<Renderer>(
+    raw: &crate::Cache<Data<<Renderer::Geometry as Cached>::Cache>>,
+    renderer: &Renderer,
+    bounds: Size,
+    draw_fn: impl FnOnce(&mut Frame<Renderer>),
+) -> Renderer::Geometry
+where
+    Renderer: geometry::Renderer,
+{
+    use std::ops::Deref;
+
+    let state = raw.state();
+
+    let previous = match state.borrow().deref() {
+        cache::State::Empty { previous } => {
+            previous.as_ref().map(|data| data.geometry.clone())
+        }
+        cache::State::Filled { current } => {
+            if current.bounds == bounds {
+                return Cached::load(&current.geometry);
             }
-        };
 
-        let mut frame = Frame::new(renderer, bounds);
-        draw_fn(&mut frame);
+            Some(current.geometry.clone())
+        }
+    };
 
-        let geometry = frame.into_geometry().cache(self.raw.group(), previous);
-        let result = Cached::load(&geometry);
+    let mut frame = Frame::new(renderer, bounds);
+    draw_fn(&mut frame);
 
-        *state.borrow_mut() = cache::State::Filled {
-            current: Data { bounds, geometry },
-        };
+    let geometry = frame.into_geometry().cache(raw.group(), previous);
+    let result = Cached::load(&geometry);
 
-        result
-    }
+    *state.borrow_mut() = cache::State::Filled {
+        current: Data { bounds, geometry },
+    };
+
+    result
 }
 
 impl<Renderer> std::fmt::Debug for Cache<Renderer>
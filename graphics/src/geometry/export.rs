@@ -0,0 +1,387 @@
+//! Export the contents of a [`Frame`] as vector or raster images.
+//!
+//! [`Frame`]: super::Frame
+use crate::core::{Color, Point, Radians, Rectangle, Size, Vector};
+use crate::geometry::fill::{self, Fill};
+use crate::geometry::stroke::{LineCap, LineJoin, Stroke};
+use crate::geometry::{Gradient, Path, Style, Text};
+
+use std::fmt::Write;
+
+/// An error produced when exporting a [`Frame`].
+///
+/// [`Frame`]: super::Frame
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The [`Frame`] was not created with [`Frame::recording`].
+    ///
+    /// [`Frame`]: super::Frame
+    /// [`Frame::recording`]: super::Frame::recording
+    #[error("the frame is not recording its drawing operations")]
+    NotRecording,
+    /// The contents of the [`Frame`] could not be rasterized.
+    ///
+    /// [`Frame`]: super::Frame
+    #[cfg(feature = "png")]
+    #[error("the frame could not be rasterized: {0}")]
+    RasterizationFailed(String),
+}
+
+/// The drawing operations of a [`Frame`], recorded as SVG elements.
+///
+/// [`Frame`]: super::Frame
+#[derive(Debug, Clone)]
+pub(crate) struct Recording {
+    size: Size,
+    clip: Option<Rectangle>,
+    transform: Transform,
+    stack: Vec<Transform>,
+    definitions: String,
+    elements: String,
+    next_id: usize,
+}
+
+impl Recording {
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            clip: None,
+            transform: Transform::IDENTITY,
+            stack: Vec::new(),
+            definitions: String::new(),
+            elements: String::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn draft(&self, clip_bounds: Rectangle) -> Self {
+        Self {
+            size: clip_bounds.size(),
+            clip: Some(clip_bounds),
+            transform: Transform::IDENTITY
+                .translate(Vector::new(clip_bounds.x, clip_bounds.y)),
+            stack: Vec::new(),
+            definitions: String::new(),
+            elements: String::new(),
+            next_id: self.next_id,
+        }
+    }
+
+    pub fn paste(&mut self, recording: Self) {
+        self.next_id = recording.next_id;
+        self.definitions.push_str(&recording.definitions);
+
+        let Some(clip) = recording.clip else {
+            self.elements.push_str(&recording.elements);
+            return;
+        };
+
+        let id = self.id("clip");
+
+        let _ = write!(
+            self.definitions,
+            r#"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+            clip.x, clip.y, clip.width, clip.height
+        );
+
+        let _ = write!(
+            self.elements,
+            r#"<g clip-path="url(#{id})">{}</g>"#,
+            recording.elements
+        );
+    }
+
+    pub fn push_transform(&mut self) {
+        self.stack.push(self.transform);
+    }
+
+    pub fn pop_transform(&mut self) {
+        self.transform = self.stack.pop().expect("Pop transform");
+    }
+
+    pub fn translate(&mut self, translation: Vector) {
+        self.transform = self.transform.translate(translation);
+    }
+
+    pub fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.transform = self.transform.rotate(angle.into());
+    }
+
+    pub fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        self.transform = self.transform.scale(scale.into());
+    }
+
+    pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+        let paint = self.paint(fill.style);
+
+        let rule = match fill.rule {
+            fill::Rule::NonZero => "nonzero",
+            fill::Rule::EvenOdd => "evenodd",
+        };
+
+        let _ = write!(
+            self.elements,
+            r#"<path d="{}" fill="{paint}" fill-rule="{rule}"{}{}/>"#,
+            data(path),
+            opacity("fill-opacity", fill.style),
+            self.transform,
+        );
+    }
+
+    pub fn fill_rectangle(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        fill: impl Into<Fill>,
+    ) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    pub fn fill_text(&mut self, text: impl Into<Text>) {
+        text.into().draw_with(|path, color| self.fill(&path, color));
+    }
+
+    pub fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+        let paint = self.paint(stroke.style);
+
+        let cap = match stroke.line_cap {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        };
+
+        let join = match stroke.line_join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        };
+
+        let _ = write!(
+            self.elements,
+            r#"<path d="{}" fill="none" stroke="{paint}" stroke-width="{}" stroke-linecap="{cap}" stroke-linejoin="{join}"{}"#,
+            data(path),
+            stroke.width,
+            opacity("stroke-opacity", stroke.style),
+        );
+
+        if !stroke.line_dash.segments.is_empty() {
+            let segments: Vec<String> = stroke
+                .line_dash
+                .segments
+                .iter()
+                .map(f32::to_string)
+                .collect();
+
+            let _ = write!(
+                self.elements,
+                r#" stroke-dasharray="{}" stroke-dashoffset="{}""#,
+                segments.join(" "),
+                stroke.line_dash.start(),
+            );
+        }
+
+        let _ = write!(self.elements, "{}/>", self.transform);
+    }
+
+    pub fn stroke_rectangle<'a>(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        stroke: impl Into<Stroke<'a>>,
+    ) {
+        self.stroke(&Path::rectangle(top_left, size), stroke);
+    }
+
+    /// Serializes the [`Recording`] as an SVG document.
+    pub fn to_svg(&self) -> String {
+        let Size { width, height } = self.size;
+
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}"><defs>{}</defs>{}</svg>"#,
+            self.definitions, self.elements
+        )
+    }
+
+    /// Rasterizes the [`Recording`] and encodes it as a PNG image.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, scale: f32) -> Result<Vec<u8>, Error> {
+        use resvg::{tiny_skia, usvg};
+
+        let failed = |error: &dyn std::fmt::Display| {
+            Error::RasterizationFailed(error.to_string())
+        };
+
+        let tree =
+            usvg::Tree::from_str(&self.to_svg(), &usvg::Options::default())
+                .map_err(|error| failed(&error))?;
+
+        let mut pixmap = tiny_skia::Pixmap::new(
+            (self.size.width * scale).ceil() as u32,
+            (self.size.height * scale).ceil() as u32,
+        )
+        .ok_or_else(|| failed(&"the frame is empty"))?;
+
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+
+        pixmap.encode_png().map_err(|error| failed(&error))
+    }
+
+    fn paint(&mut self, style: Style) -> String {
+        match style {
+            Style::Solid(color) => hex(color),
+            Style::Gradient(Gradient::Linear(linear)) => {
+                let id = self.id("gradient");
+
+                let _ = write!(
+                    self.definitions,
+                    r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+                    linear.start.x, linear.start.y, linear.end.x, linear.end.y,
+                );
+
+                for stop in linear.stops.iter().flatten() {
+                    let _ = write!(
+                        self.definitions,
+                        r#"<stop offset="{}" stop-color="{}" stop-opacity="{}"/>"#,
+                        stop.offset,
+                        hex(stop.color),
+                        stop.color.a,
+                    );
+                }
+
+                self.definitions.push_str("</linearGradient>");
+
+                format!("url(#{id})")
+            }
+        }
+    }
+
+    fn id(&mut self, prefix: &str) -> String {
+        let id = format!("{prefix}-{}", self.next_id);
+        self.next_id += 1;
+
+        id
+    }
+}
+
+/// A 2D affine transformation, laid out like an SVG `matrix`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Transform([f32; 6]);
+
+impl Transform {
+    const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    fn translate(self, translation: Vector) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+        let Vector { x, y } = translation;
+
+        Self([a, b, c, d, a * x + c * y + e, b * x + d * y + f])
+    }
+
+    fn rotate(self, angle: Radians) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+        let (sin, cos) = angle.0.sin_cos();
+
+        Self([
+            a * cos + c * sin,
+            b * cos + d * sin,
+            c * cos - a * sin,
+            d * cos - b * sin,
+            e,
+            f,
+        ])
+    }
+
+    fn scale(self, scale: Vector) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+
+        Self([a * scale.x, b * scale.x, c * scale.y, d * scale.y, e, f])
+    }
+}
+
+impl std::fmt::Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if *self == Self::IDENTITY {
+            return Ok(());
+        }
+
+        let [a, b, c, d, e, g] = self.0;
+
+        write!(f, r#" transform="matrix({a} {b} {c} {d} {e} {g})""#)
+    }
+}
+
+fn data(path: &Path) -> String {
+    let mut data = String::new();
+
+    for event in path.raw() {
+        let _ = match event {
+            lyon_path::Event::Begin { at } => {
+                write!(data, "M{} {}", at.x, at.y)
+            }
+            lyon_path::Event::Line { to, .. } => {
+                write!(data, "L{} {}", to.x, to.y)
+            }
+            lyon_path::Event::Quadratic { ctrl, to, .. } => {
+                write!(data, "Q{} {} {} {}", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            lyon_path::Event::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(
+                data,
+                "C{} {} {} {} {} {}",
+                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+            ),
+            lyon_path::Event::End { close: true, .. } => write!(data, "Z"),
+            lyon_path::Event::End { .. } => Ok(()),
+        };
+    }
+
+    data
+}
+
+fn hex(color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn opacity(attribute: &str, style: Style) -> String {
+    match style {
+        Style::Solid(color) if color.a < 1.0 => {
+            format!(r#" {attribute}="{}""#, color.a)
+        }
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_transformed_fills() {
+        let mut recording = Recording::new(Size::new(100.0, 50.0));
+
+        recording.translate(Vector::new(10.0, 20.0));
+        recording.fill_rectangle(
+            Point::ORIGIN,
+            Size::new(5.0, 5.0),
+            Color::from_rgba(1.0, 0.0, 0.0, 0.5),
+        );
+
+        let svg = recording.to_svg();
+
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50""#
+        ));
+        assert!(svg.contains(r##"fill="#ff0000""##));
+        assert!(svg.contains(r#"fill-opacity="0.5""#));
+        assert!(svg.contains(r#"transform="matrix(1 0 0 1 10 20)""#));
+    }
+}
@@ -1,6 +1,9 @@
 //! Draw and generate geometry.
-use crate::core::{Point, Radians, Rectangle, Size, Vector};
-use crate::geometry::{self, Fill, Image, Path, Stroke, Svg, Text};
+use crate::core::gradient::ColorStop;
+use crate::core::{Color, Point, Radians, Rectangle, Size, Vector};
+use crate::geometry::export::{self, Recording};
+use crate::geometry::path::polygon;
+use crate::geometry::{self, Fill, Image, LineDash, Path, Stroke, Svg, Text};
 
 /// The region of a surface that can be used to draw geometry.
 #[allow(missing_debug_implementations)]
@@ -9,6 +12,7 @@ where
     Renderer: geometry::Renderer,
 {
     raw: Renderer::Frame,
+    recording: Option<Recording>,
 }
 
 impl<Renderer> Frame<Renderer>
@@ -19,6 +23,20 @@ where
     pub fn new(renderer: &Renderer, size: Size) -> Self {
         Self {
             raw: renderer.new_frame(size),
+            recording: None,
+        }
+    }
+
+    /// Creates a new [`Frame`] with the given dimensions that records its
+    /// drawing operations, so they can be exported with [`to_svg`] and
+    /// [`to_png`].
+    ///
+    /// [`to_svg`]: Self::to_svg
+    /// [`to_png`]: Self::to_png
+    pub fn recording(renderer: &Renderer, size: Size) -> Self {
+        Self {
+            raw: renderer.new_frame(size),
+            recording: Some(Recording::new(size)),
         }
     }
 
@@ -45,6 +63,12 @@ where
     /// Draws the given [`Path`] on the [`Frame`] by filling it with the
     /// provided style.
     pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.fill(path, fill);
+        }
+
         self.raw.fill(path, fill);
     }
 
@@ -56,15 +80,99 @@ where
         size: Size,
         fill: impl Into<Fill>,
     ) {
+        let fill = fill.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.fill_rectangle(top_left, size, fill);
+        }
+
         self.raw.fill_rectangle(top_left, size, fill);
     }
 
     /// Draws the stroke of the given [`Path`] on the [`Frame`] with the
     /// provided style.
     pub fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.stroke(path, stroke);
+        }
+
         self.raw.stroke(path, stroke);
     }
 
+    /// Draws the stroke of the given [`Path`] with a gradient that follows
+    /// its direction, interpolating the given [`ColorStop`]s from the start
+    /// (`0.0`) to the end (`1.0`) of the [`Path`].
+    ///
+    /// The style of the [`Stroke`] is ignored; while its dash pattern
+    /// continues seamlessly along the [`Path`].
+    pub fn stroke_along<'a>(
+        &mut self,
+        path: &Path,
+        stroke: impl Into<Stroke<'a>>,
+        stops: &[ColorStop],
+    ) {
+        let stroke = stroke.into();
+
+        let edges = polygon::edges(path.raw(), false);
+        let length = polygon::length(&edges);
+
+        if length <= 0.0 {
+            return;
+        }
+
+        // Edges are grouped in pieces, so joins are kept within each of them
+        let piece_length = stroke.width.max(1.0);
+
+        let mut edges = edges.into_iter().peekable();
+        let mut distance = 0.0;
+
+        while let Some((start, end)) = edges.next() {
+            let piece_start = distance;
+            let mut points = vec![start, end];
+
+            distance += start.distance(end);
+
+            while distance - piece_start < piece_length {
+                let Some(&(next_start, next_end)) = edges.peek() else {
+                    break;
+                };
+
+                if next_start != *points.last().expect("Last point") {
+                    break;
+                }
+
+                let _ = edges.next();
+
+                points.push(next_end);
+                distance += next_start.distance(next_end);
+            }
+
+            let piece = Path::new(|builder| {
+                builder.move_to(points[0]);
+
+                for point in &points[1..] {
+                    builder.line_to(*point);
+                }
+            });
+
+            let color =
+                gradient_color(stops, (piece_start + distance) / 2.0 / length);
+
+            self.stroke(
+                &piece,
+                Stroke {
+                    line_dash: LineDash {
+                        phase: stroke.line_dash.phase + piece_start,
+                        ..stroke.line_dash
+                    },
+                    ..stroke.with_color(color)
+                },
+            );
+        }
+    }
+
     /// Draws the stroke of an axis-aligned rectangle with the provided style
     /// given its top-left corner coordinate and its `Size` on the [`Frame`] .
     pub fn stroke_rectangle<'a>(
@@ -73,6 +181,12 @@ where
         size: Size,
         stroke: impl Into<Stroke<'a>>,
     ) {
+        let stroke = stroke.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.stroke_rectangle(top_left, size, stroke);
+        }
+
         self.raw.stroke_rectangle(top_left, size, stroke);
     }
 
@@ -83,9 +197,29 @@ where
     /// a `Canvas`. Therefore, it is currently only meant to be used for
     /// overlays, which is the most common use case.
     pub fn fill_text(&mut self, text: impl Into<Text>) {
+        let text = text.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.fill_text(text.clone());
+        }
+
         self.raw.fill_text(text);
     }
 
+    /// Draws the characters of the given [`Text`] along the given [`Path`],
+    /// filling them with the given color.
+    ///
+    /// Unlike [`fill_text`], the glyphs are drawn as geometry; so they
+    /// follow the transforms of the [`Frame`] and are layered like any
+    /// other [`Path`]. See [`Text::draw_along`] for details on how the
+    /// [`Text`] is placed.
+    ///
+    /// [`fill_text`]: Self::fill_text
+    pub fn fill_text_along(&mut self, path: &Path, text: impl Into<Text>) {
+        text.into()
+            .draw_along(path, |glyph, color| self.fill(&glyph, color));
+    }
+
     /// Draws the given [`Image`] on the [`Frame`] inside the given bounds.
     #[cfg(feature = "image")]
     pub fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
@@ -116,11 +250,19 @@ where
 
     /// Pushes the current transform in the transform stack.
     pub fn push_transform(&mut self) {
+        if let Some(recording) = &mut self.recording {
+            recording.push_transform();
+        }
+
         self.raw.push_transform();
     }
 
     /// Pops a transform from the transform stack and sets it as the current transform.
     pub fn pop_transform(&mut self) {
+        if let Some(recording) = &mut self.recording {
+            recording.pop_transform();
+        }
+
         self.raw.pop_transform();
     }
 
@@ -152,40 +294,125 @@ where
     fn draft(&mut self, clip_bounds: Rectangle) -> Self {
         Self {
             raw: self.raw.draft(clip_bounds),
+            recording: self
+                .recording
+                .as_ref()
+                .map(|recording| recording.draft(clip_bounds)),
         }
     }
 
     /// Draws the contents of the given [`Frame`] with origin at the given [`Point`].
     fn paste(&mut self, frame: Self) {
+        if let (Some(recording), Some(draft)) =
+            (&mut self.recording, frame.recording)
+        {
+            recording.paste(draft);
+        }
+
         self.raw.paste(frame.raw);
     }
 
     /// Applies a translation to the current transform of the [`Frame`].
     pub fn translate(&mut self, translation: Vector) {
+        if let Some(recording) = &mut self.recording {
+            recording.translate(translation);
+        }
+
         self.raw.translate(translation);
     }
 
     /// Applies a rotation in radians to the current transform of the [`Frame`].
     pub fn rotate(&mut self, angle: impl Into<Radians>) {
+        let angle = angle.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.rotate(angle);
+        }
+
         self.raw.rotate(angle);
     }
 
     /// Applies a uniform scaling to the current transform of the [`Frame`].
     pub fn scale(&mut self, scale: impl Into<f32>) {
+        let scale = scale.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.scale_nonuniform(Vector::new(scale, scale));
+        }
+
         self.raw.scale(scale);
     }
 
     /// Applies a non-uniform scaling to the current transform of the [`Frame`].
     pub fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+
+        if let Some(recording) = &mut self.recording {
+            recording.scale_nonuniform(scale);
+        }
+
         self.raw.scale_nonuniform(scale);
     }
 
+    /// Exports the contents of the [`Frame`] as an SVG document.
+    ///
+    /// The [`Frame`] must have been created with [`Frame::recording`].
+    /// Images and SVGs drawn on the [`Frame`] are not exported.
+    pub fn to_svg(&self) -> Result<String, export::Error> {
+        self.recording
+            .as_ref()
+            .map(Recording::to_svg)
+            .ok_or(export::Error::NotRecording)
+    }
+
+    /// Rasterizes the contents of the [`Frame`] with the given scale factor
+    /// and encodes them as a PNG image.
+    ///
+    /// The [`Frame`] must have been created with [`Frame::recording`].
+    /// Images and SVGs drawn on the [`Frame`] are not exported.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, scale: f32) -> Result<Vec<u8>, export::Error> {
+        self.recording
+            .as_ref()
+            .ok_or(export::Error::NotRecording)?
+            .to_png(scale)
+    }
+
     /// Turns the [`Frame`] into its underlying geometry.
     pub fn into_geometry(self) -> Renderer::Geometry {
         self.raw.into_geometry()
     }
 }
 
+fn gradient_color(stops: &[ColorStop], offset: f32) -> Color {
+    let mut stops = stops.to_vec();
+    stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+    let Some(first) = stops.first() else {
+        return Color::BLACK;
+    };
+
+    let mut previous = first;
+
+    for stop in &stops {
+        if offset <= stop.offset {
+            let range = stop.offset - previous.offset;
+
+            return if range > 0.0 {
+                previous
+                    .color
+                    .mix(stop.color, (offset - previous.offset) / range)
+            } else {
+                stop.color
+            };
+        }
+
+        previous = stop;
+    }
+
+    previous.color
+}
+
 /// The internal implementation of a [`Frame`].
 ///
 /// Analogous to [`Frame`]. See [`Frame`] for the documentation
@@ -3,6 +3,8 @@ pub mod arc;
 
 mod builder;
 
+pub(crate) mod polygon;
+
 #[doc(no_inline)]
 pub use arc::Arc;
 pub use builder::Builder;
@@ -10,7 +12,8 @@ pub use builder::Builder;
 pub use lyon_path;
 
 use crate::core::border;
-use crate::core::{Point, Size};
+use crate::core::{Point, Radians, Size};
+use crate::geometry::fill;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -64,6 +67,105 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Returns true if the given [`Point`] is inside the [`Path`] when filled
+    /// with the [`fill::Rule::NonZero`] rule.
+    ///
+    /// Curves are flattened with a small tolerance before testing.
+    pub fn contains(&self, point: Point) -> bool {
+        self.contains_with_rule(point, fill::Rule::NonZero)
+    }
+
+    /// Returns true if the given [`Point`] is inside the [`Path`] when filled
+    /// with the given [`fill::Rule`].
+    pub fn contains_with_rule(&self, point: Point, rule: fill::Rule) -> bool {
+        let winding = polygon::winding(&polygon::edges(&self.raw, true), point);
+
+        match rule {
+            fill::Rule::NonZero => winding != 0,
+            fill::Rule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns true if the given [`Point`] is on the stroke of the [`Path`]
+    /// with the given `width`.
+    ///
+    /// Line caps and joins are ignored.
+    pub fn stroke_contains(&self, point: Point, width: f32) -> bool {
+        polygon::edges(&self.raw, false)
+            .iter()
+            .any(|edge| polygon::distance(edge, point) <= width / 2.0)
+    }
+
+    /// Returns the total length of the [`Path`].
+    ///
+    /// Curves are flattened with a small tolerance before measuring.
+    pub fn length(&self) -> f32 {
+        polygon::length(&polygon::edges(&self.raw, false))
+    }
+
+    /// Returns the [`Point`] at the given distance along the [`Path`],
+    /// together with the direction of the [`Path`] at that point.
+    ///
+    /// Returns `None` if the distance is out of the bounds of the [`Path`].
+    pub fn sample(&self, distance: f32) -> Option<(Point, Radians)> {
+        polygon::sample(&polygon::edges(&self.raw, false), distance).map(
+            |(point, direction)| {
+                (point, Radians(direction.y.atan2(direction.x)))
+            },
+        )
+    }
+
+    /// Returns the union of the [`Path`] and the given one; that is, the area
+    /// covered by any of them.
+    ///
+    /// Both paths are treated as filled with the [`fill::Rule::NonZero`]
+    /// rule, and curves are flattened into line segments.
+    pub fn union(&self, other: &Path) -> Path {
+        self.combine(other, polygon::Operation::Union)
+    }
+
+    /// Returns the intersection of the [`Path`] and the given one; that is,
+    /// the area covered by both of them.
+    ///
+    /// Both paths are treated as filled with the [`fill::Rule::NonZero`]
+    /// rule, and curves are flattened into line segments.
+    pub fn intersection(&self, other: &Path) -> Path {
+        self.combine(other, polygon::Operation::Intersection)
+    }
+
+    /// Returns the difference of the [`Path`] and the given one; that is, the
+    /// area of the [`Path`] not covered by the other one.
+    ///
+    /// Both paths are treated as filled with the [`fill::Rule::NonZero`]
+    /// rule, and curves are flattened into line segments.
+    pub fn difference(&self, other: &Path) -> Path {
+        self.combine(other, polygon::Operation::Difference)
+    }
+
+    fn combine(&self, other: &Path, operation: polygon::Operation) -> Path {
+        let rings = polygon::combine(
+            &polygon::edges(&self.raw, true),
+            &polygon::edges(&other.raw, true),
+            operation,
+        );
+
+        Self::new(|builder| {
+            for ring in rings {
+                let mut points = ring.into_iter();
+
+                if let Some(first) = points.next() {
+                    builder.move_to(first);
+
+                    for point in points {
+                        builder.line_to(point);
+                    }
+
+                    builder.close();
+                }
+            }
+        })
+    }
+
     /// Returns the internal [`lyon_path::Path`].
     #[inline]
     pub fn raw(&self) -> &lyon_path::Path {
@@ -0,0 +1,311 @@
+//! Flatten paths into polygons and combine them.
+use crate::core::{Point, Vector};
+
+use lyon_path::iterator::PathIterator;
+
+/// The tolerance used to flatten the curves of a path.
+const TOLERANCE: f32 = 0.05;
+
+/// The distance under which two points are considered the same.
+const EPSILON: f32 = 1e-3;
+
+/// A segment of a polygon.
+pub type Edge = (Point, Point);
+
+/// A boolean operation between two polygons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl Operation {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Operation::Union => a || b,
+            Operation::Intersection => a && b,
+            Operation::Difference => a && !b,
+        }
+    }
+}
+
+/// Flattens the given path into a list of edges.
+///
+/// Sub-paths are implicitly closed if `close_all` is true; like when they are
+/// filled.
+pub fn edges(path: &lyon_path::Path, close_all: bool) -> Vec<Edge> {
+    let mut edges = Vec::new();
+
+    for event in path.iter().flattened(TOLERANCE) {
+        match event {
+            lyon_path::Event::Line { from, to } => {
+                edges.push((point(from), point(to)));
+            }
+            lyon_path::Event::End { last, first, close }
+                if (close || close_all) && last != first =>
+            {
+                edges.push((point(last), point(first)));
+            }
+            _ => {}
+        }
+    }
+
+    edges
+}
+
+/// Computes the winding number of the given edges around a point.
+pub fn winding(edges: &[Edge], point: Point) -> i32 {
+    edges.iter().fold(0, |winding, &(a, b)| {
+        let side = cross(b - a, point - a);
+
+        if a.y <= point.y {
+            if b.y > point.y && side > 0.0 {
+                return winding + 1;
+            }
+        } else if b.y <= point.y && side < 0.0 {
+            return winding - 1;
+        }
+
+        winding
+    })
+}
+
+/// Returns the distance between a point and an edge.
+pub fn distance(&(a, b): &Edge, point: Point) -> f32 {
+    let ab = b - a;
+    let length = ab.x * ab.x + ab.y * ab.y;
+
+    let t = if length > 0.0 {
+        (dot(point - a, ab) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    point.distance(a + ab * t)
+}
+
+/// Returns the total length of the given edges.
+pub fn length(edges: &[Edge]) -> f32 {
+    edges.iter().map(|(a, b)| a.distance(*b)).sum()
+}
+
+/// Walks the given edges in order and returns the point at the given
+/// distance, together with the unit direction of the edge at that point.
+pub fn sample(edges: &[Edge], distance: f32) -> Option<(Point, Vector)> {
+    if distance < 0.0 {
+        return None;
+    }
+
+    let mut remaining = distance;
+
+    for &(a, b) in edges {
+        let length = a.distance(b);
+
+        if remaining <= length && length > 0.0 {
+            let direction = (b - a) * (1.0 / length);
+
+            return Some((a + direction * remaining, direction));
+        }
+
+        remaining -= length;
+    }
+
+    None
+}
+
+/// Combines two sets of closed edges with the given [`Operation`], using the
+/// non-zero fill rule; returning the rings of the result.
+pub fn combine(
+    a: &[Edge],
+    b: &[Edge],
+    operation: Operation,
+) -> Vec<Vec<Point>> {
+    let mut splits_a = vec![Vec::new(); a.len()];
+    let mut splits_b = vec![Vec::new(); b.len()];
+
+    for (i, edge_a) in a.iter().enumerate() {
+        for (j, edge_b) in b.iter().enumerate() {
+            if let Some((t, u, point)) = intersection(edge_a, edge_b) {
+                splits_a[i].push((t, point));
+                splits_b[j].push((u, point));
+            }
+        }
+    }
+
+    let is_inside = |point: Point| {
+        operation.apply(winding(a, point) != 0, winding(b, point) != 0)
+    };
+
+    let mut pieces: Vec<Edge> = Vec::new();
+
+    for (edges, splits, shared) in [(a, splits_a, None), (b, splits_b, Some(a))]
+    {
+        for (edge, mut splits) in edges.iter().zip(splits) {
+            splits.sort_by(|(t, _), (u, _)| t.total_cmp(u));
+
+            let points = std::iter::once(edge.0)
+                .chain(splits.into_iter().map(|(_, point)| point))
+                .chain(std::iter::once(edge.1));
+
+            let mut previous = None;
+
+            for point in points {
+                let Some(start) = previous.replace(point) else {
+                    continue;
+                };
+
+                if start.distance(point) < EPSILON {
+                    continue;
+                }
+
+                // Only the pieces on the boundary of the result are kept
+                let middle = start + (point - start) * 0.5;
+                let direction = point - start;
+                let normal = Vector::new(-direction.y, direction.x)
+                    * (EPSILON / (direction.x.hypot(direction.y)));
+
+                let left = is_inside(middle + normal);
+                let right = is_inside(middle - normal);
+
+                if left == right {
+                    continue;
+                }
+
+                // Pieces shared by both inputs are only kept once
+                if shared.is_some_and(|shared| edges_contain(shared, middle)) {
+                    continue;
+                }
+
+                // The result is always kept on the left of its edges
+                pieces.push(if left { (start, point) } else { (point, start) });
+            }
+        }
+    }
+
+    chain(pieces)
+}
+
+fn edges_contain(edges: &[Edge], point: Point) -> bool {
+    edges.iter().any(|edge| distance(edge, point) < EPSILON)
+}
+
+fn chain(mut pieces: Vec<Edge>) -> Vec<Vec<Point>> {
+    let mut rings = Vec::new();
+
+    while let Some((start, mut end)) = pieces.pop() {
+        let mut ring = vec![start];
+
+        while end.distance(start) >= EPSILON {
+            ring.push(end);
+
+            let Some(next) = pieces
+                .iter()
+                .position(|(from, _)| from.distance(end) < EPSILON)
+            else {
+                break;
+            };
+
+            end = pieces.swap_remove(next).1;
+        }
+
+        if ring.len() > 2 {
+            rings.push(ring);
+        }
+    }
+
+    rings
+}
+
+fn intersection(&(a, b): &Edge, &(c, d): &Edge) -> Option<(f32, f32, Point)> {
+    let ab = b - a;
+    let cd = d - c;
+    let denominator = cross(ab, cd);
+
+    if denominator.abs() <= f32::EPSILON {
+        return None;
+    }
+
+    let ac = c - a;
+    let t = cross(ac, cd) / denominator;
+    let u = cross(ac, ab) / denominator;
+
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u))
+        .then(|| (t, u, a + ab * t))
+}
+
+fn cross(a: Vector, b: Vector) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Vector, b: Vector) -> f32 {
+    a.x * b.x + a.y * b.y
+}
+
+fn point(point: lyon_path::math::Point) -> Point {
+    Point::new(point.x, point.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> Vec<Edge> {
+        let corners = [
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x, y + size),
+        ];
+
+        (0..4).map(|i| (corners[i], corners[(i + 1) % 4])).collect()
+    }
+
+    fn area(rings: &[Vec<Point>]) -> f32 {
+        rings
+            .iter()
+            .flat_map(|ring| {
+                ring.iter()
+                    .zip(ring.iter().cycle().skip(1))
+                    .map(|(a, b)| a.x * b.y - b.x * a.y)
+            })
+            .sum::<f32>()
+            .abs()
+            / 2.0
+    }
+
+    #[test]
+    fn computes_winding() {
+        let square = square(0.0, 0.0, 2.0);
+
+        assert_ne!(winding(&square, Point::new(1.0, 1.0)), 0);
+        assert_eq!(winding(&square, Point::new(3.0, 1.0)), 0);
+    }
+
+    #[test]
+    fn samples_along_edges() {
+        let square = square(0.0, 0.0, 2.0);
+
+        assert_eq!(length(&square), 8.0);
+        assert_eq!(
+            sample(&square, 3.0),
+            Some((Point::new(2.0, 1.0), Vector::new(0.0, 1.0)))
+        );
+        assert_eq!(sample(&square, 9.0), None);
+    }
+
+    #[test]
+    fn combines_overlapping_squares() {
+        let a = square(0.0, 0.0, 2.0);
+        let b = square(1.0, 1.0, 2.0);
+
+        let union = combine(&a, &b, Operation::Union);
+        let intersection = combine(&a, &b, Operation::Intersection);
+        let difference = combine(&a, &b, Operation::Difference);
+
+        assert_eq!(union.len(), 1);
+        assert!((area(&union) - 7.0).abs() < 1e-3);
+        assert!((area(&intersection) - 1.0).abs() < 1e-3);
+        assert!((area(&difference) - 3.0).abs() < 1e-3);
+    }
+}
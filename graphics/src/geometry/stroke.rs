@@ -95,4 +95,18 @@ pub struct LineDash<'a> {
 
     /// The offset of [`LineDash::segments`] to start the pattern.
     pub offset: usize,
+
+    /// The distance the pattern is shifted along the line, on top of the
+    /// [`LineDash::offset`].
+    ///
+    /// Animating it moves the dashes along the line; like marching ants.
+    pub phase: f32,
+}
+
+impl LineDash<'_> {
+    /// Returns the distance into the pattern at which the line starts;
+    /// combining the [`LineDash::offset`] and the [`LineDash::phase`].
+    pub fn start(&self) -> f32 {
+        self.segments.iter().take(self.offset).sum::<f32>() + self.phase
+    }
 }
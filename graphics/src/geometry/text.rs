@@ -3,6 +3,7 @@ use crate::core::alignment;
 use crate::core::text::{Alignment, LineHeight, Paragraph, Shaping, Wrapping};
 use crate::core::{Color, Font, Pixels, Point, Size, Vector};
 use crate::geometry::Path;
+use crate::geometry::path::polygon;
 use crate::text;
 
 /// A bunch of text that can be drawn to a canvas
@@ -45,18 +46,8 @@ pub struct Text {
 impl Text {
     /// Computes the [`Path`]s of the [`Text`] and draws them using
     /// the given closure.
-    pub fn draw_with(&self, mut f: impl FnMut(Path, Color)) {
-        let paragraph = text::Paragraph::with_text(core::text::Text {
-            content: &self.content,
-            bounds: Size::new(self.max_width, f32::INFINITY),
-            size: self.size,
-            line_height: self.line_height,
-            font: self.font,
-            align_x: self.align_x,
-            align_y: self.align_y,
-            shaping: self.shaping,
-            wrapping: Wrapping::default(),
-        });
+    pub fn draw_with(&self, f: impl FnMut(Path, Color)) {
+        let paragraph = self.paragraph();
 
         let translation_x = match self.align_x {
             Alignment::Default | Alignment::Left | Alignment::Justified => {
@@ -66,18 +57,87 @@ impl Text {
             Alignment::Right => self.position.x - paragraph.min_width(),
         };
 
-        let translation_y = {
-            match self.align_y {
-                alignment::Vertical::Top => self.position.y,
-                alignment::Vertical::Center => {
-                    self.position.y - paragraph.min_height() / 2.0
-                }
-                alignment::Vertical::Bottom => {
-                    self.position.y - paragraph.min_height()
+        let translation_y = self.position.y - self.vertical_offset(&paragraph);
+
+        self.draw_glyphs(&paragraph, f, |x, _width, y| {
+            Some((Point::new(translation_x + x, translation_y + y), 0.0))
+        });
+    }
+
+    /// Computes the [`Path`]s of the [`Text`] laid out along the given
+    /// [`Path`] and draws them using the given closure.
+    ///
+    /// Each glyph is rotated to follow the direction of the [`Path`] at its
+    /// center. The horizontal alignment of the [`Text`] anchors it to the
+    /// start, the middle, or the end of the [`Path`]; while its vertical
+    /// alignment places it below, across, or above the [`Path`].
+    ///
+    /// The `position` of the [`Text`] is used as an offset along (`x`) and
+    /// across (`y`) the [`Path`]. Glyphs that fall outside of the [`Path`]
+    /// are not drawn.
+    pub fn draw_along(&self, path: &Path, f: impl FnMut(Path, Color)) {
+        let paragraph = self.paragraph();
+
+        let edges = polygon::edges(path.raw(), false);
+        let length = polygon::length(&edges);
+
+        let start = self.position.x
+            + match self.align_x {
+                Alignment::Default | Alignment::Left | Alignment::Justified => {
+                    0.0
                 }
-            }
-        };
+                Alignment::Center => (length - paragraph.min_width()) / 2.0,
+                Alignment::Right => length - paragraph.min_width(),
+            };
+
+        let offset_y = self.position.y - self.vertical_offset(&paragraph);
 
+        self.draw_glyphs(&paragraph, f, |x, width, y| {
+            let (center, direction) =
+                polygon::sample(&edges, start + x + width / 2.0)?;
+
+            let normal = Vector::new(-direction.y, direction.x);
+            let origin =
+                center - direction * (width / 2.0) + normal * (offset_y + y);
+
+            Some((origin, direction.y.atan2(direction.x)))
+        });
+    }
+
+    fn paragraph(&self) -> text::Paragraph {
+        text::Paragraph::with_text(core::text::Text {
+            content: &self.content,
+            bounds: Size::new(self.max_width, f32::INFINITY),
+            size: self.size,
+            line_height: self.line_height,
+            font: self.font,
+            align_x: self.align_x,
+            align_y: self.align_y,
+            shaping: self.shaping,
+            wrapping: Wrapping::default(),
+        })
+    }
+
+    fn vertical_offset(&self, paragraph: &text::Paragraph) -> f32 {
+        match self.align_y {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => paragraph.min_height() / 2.0,
+            alignment::Vertical::Bottom => paragraph.min_height(),
+        }
+    }
+
+    /// Draws the glyphs of the given paragraph, placing each of them with the
+    /// `place` closure.
+    ///
+    /// The closure receives the horizontal position, the width, and the
+    /// baseline of a glyph; and returns the origin and the rotation of the
+    /// glyph, if it should be drawn.
+    fn draw_glyphs(
+        &self,
+        paragraph: &text::Paragraph,
+        mut f: impl FnMut(Path, Color),
+        mut place: impl FnMut(f32, f32, f32) -> Option<(Point, f32)>,
+    ) {
         let buffer = paragraph.buffer();
         let mut swash_cache = cosmic_text::SwashCache::new();
 
@@ -88,9 +148,22 @@ impl Text {
             for glyph in run.glyphs.iter() {
                 let physical_glyph = glyph.physical((0.0, 0.0), 1.0);
 
-                let start_x = translation_x + glyph.x + glyph.x_offset;
-                let start_y = translation_y + glyph.y_offset + run.line_y;
-                let offset = Vector::new(start_x, start_y);
+                let Some((origin, rotation)) = place(
+                    glyph.x + glyph.x_offset,
+                    glyph.w,
+                    glyph.y_offset + run.line_y,
+                ) else {
+                    continue;
+                };
+
+                let (sin, cos) = rotation.sin_cos();
+
+                let transform = |x: f32, y: f32| {
+                    Point::new(
+                        origin.x + x * cos - y * sin,
+                        origin.y + x * sin + y * cos,
+                    )
+                };
 
                 if let Some(commands) = swash_cache.get_outline_commands(
                     font_system.raw(),
@@ -102,29 +175,22 @@ impl Text {
                         for command in commands {
                             match command {
                                 Command::MoveTo(p) => {
-                                    path.move_to(
-                                        Point::new(p.x, -p.y) + offset,
-                                    );
+                                    path.move_to(transform(p.x, -p.y));
                                 }
                                 Command::LineTo(p) => {
-                                    path.line_to(
-                                        Point::new(p.x, -p.y) + offset,
-                                    );
+                                    path.line_to(transform(p.x, -p.y));
                                 }
                                 Command::CurveTo(control_a, control_b, to) => {
                                     path.bezier_curve_to(
-                                        Point::new(control_a.x, -control_a.y)
-                                            + offset,
-                                        Point::new(control_b.x, -control_b.y)
-                                            + offset,
-                                        Point::new(to.x, -to.y) + offset,
+                                        transform(control_a.x, -control_a.y),
+                                        transform(control_b.x, -control_b.y),
+                                        transform(to.x, -to.y),
                                     );
                                 }
                                 Command::QuadTo(control, to) => {
                                     path.quadratic_curve_to(
-                                        Point::new(control.x, -control.y)
-                                            + offset,
-                                        Point::new(to.x, -to.y) + offset,
+                                        transform(control.x, -control.y),
+                                        transform(to.x, -to.y),
                                     );
                                 }
                                 Command::Close => {
@@ -144,11 +210,18 @@ impl Text {
                         physical_glyph.cache_key,
                         cosmic_text::Color::rgba(r, g, b, a),
                         |x, y, color| {
+                            let (x, y) = (x as f32, y as f32);
+
+                            let pixel = Path::new(|path| {
+                                path.move_to(transform(x, y));
+                                path.line_to(transform(x + 1.0, y));
+                                path.line_to(transform(x + 1.0, y + 1.0));
+                                path.line_to(transform(x, y + 1.0));
+                                path.close();
+                            });
+
                             f(
-                                Path::rectangle(
-                                    Point::new(x as f32, y as f32) + offset,
-                                    Size::new(1.0, 1.0),
-                                ),
+                                pixel,
                                 Color::from_rgba8(
                                     color.r(),
                                     color.g(),
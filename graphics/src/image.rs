@@ -89,6 +89,7 @@ pub fn load(
 
             let operation = std::fs::File::open(path)
                 .ok()
+                .filter(|_| handle.honors_exif_orientation())
                 .map(std::io::BufReader::new)
                 .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
                 .unwrap_or_else(Operation::empty);
@@ -103,10 +104,11 @@ pub fn load(
         }
         image::Handle::Bytes(_, bytes) => {
             let image = ::image::load_from_memory(bytes)?;
-            let operation =
-                Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                    .ok()
-                    .unwrap_or_else(Operation::empty);
+            let operation = handle
+                .honors_exif_orientation()
+                .then(|| Operation::from_exif(&mut std::io::Cursor::new(bytes)))
+                .and_then(Result::ok)
+                .unwrap_or_else(Operation::empty);
 
             let rgba = operation.perform(image).into_rgba8();
 
@@ -122,6 +124,11 @@ pub fn load(
             pixels,
             ..
         } => (*width, *height, pixels.clone()),
+        image::Handle::External { width, height, .. } => (
+            *width,
+            *height,
+            image::Bytes::from(vec![0; *width as usize * *height as usize * 4]),
+        ),
     };
 
     if let Some(image) = ::image::ImageBuffer::from_raw(width, height, pixels) {
@@ -134,3 +141,80 @@ pub fn load(
         ))
     }
 }
+
+#[cfg(feature = "animated-image")]
+/// Tries to decode all the frames of an animated image; alongside the
+/// delay of each frame.
+///
+/// GIF, APNG, and WebP images are supported. Any other image is decoded
+/// as a single frame.
+pub fn load_frames(
+    bytes: &[u8],
+) -> ::image::ImageResult<
+    Vec<(
+        ::image::ImageBuffer<::image::Rgba<u8>, image::Bytes>,
+        std::time::Duration,
+    )>,
+> {
+    use ::image::codecs::{gif, png, webp};
+    use ::image::{AnimationDecoder, ImageFormat};
+    use std::io::Cursor;
+
+    let frames = match ::image::guess_format(bytes)? {
+        ImageFormat::Gif => {
+            gif::GifDecoder::new(Cursor::new(bytes))?.into_frames()
+        }
+        ImageFormat::Png => {
+            let decoder = png::PngDecoder::new(Cursor::new(bytes))?;
+
+            if !decoder.is_apng()? {
+                return single_frame(bytes);
+            }
+
+            decoder.apng()?.into_frames()
+        }
+        ImageFormat::WebP => {
+            let decoder = webp::WebPDecoder::new(Cursor::new(bytes))?;
+
+            if !decoder.has_animation() {
+                return single_frame(bytes);
+            }
+
+            decoder.into_frames()
+        }
+        _ => return single_frame(bytes),
+    };
+
+    frames
+        .map(|frame| {
+            let frame = frame?;
+            let delay = std::time::Duration::from(frame.delay());
+            let buffer = frame.into_buffer();
+
+            let (width, height) = buffer.dimensions();
+            let pixels = image::Bytes::from(buffer.into_raw());
+
+            Ok((
+                ::image::ImageBuffer::from_raw(width, height, pixels)
+                    .expect("Valid frame buffer"),
+                delay,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(feature = "animated-image")]
+fn single_frame(
+    bytes: &[u8],
+) -> ::image::ImageResult<
+    Vec<(
+        ::image::ImageBuffer<::image::Rgba<u8>, image::Bytes>,
+        std::time::Duration,
+    )>,
+> {
+    let image = load(&image::Handle::from_bytes(
+        image::Bytes::copy_from_slice(bytes),
+    ))?;
+
+    Ok(vec![(image, std::time::Duration::ZERO)])
+}
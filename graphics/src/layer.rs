@@ -18,6 +18,13 @@ pub trait Layer: Default {
     /// Resizes the [`Layer`] to the given bounds.
     fn resize(&mut self, bounds: Rectangle);
 
+    /// Sets the opacity of the [`Layer`].
+    ///
+    /// The primitives of the [`Layer`] must be blended together first, and
+    /// then composited with this opacity; so they do not show through each
+    /// other.
+    fn set_opacity(&mut self, opacity: f32);
+
     /// Clears all the layers contents and resets its bounds.
     fn reset(&mut self);
 }
@@ -27,6 +34,8 @@ pub trait Layer: Default {
 pub struct Stack<T: Layer> {
     layers: Vec<T>,
     transformations: Vec<Transformation>,
+    opacities: Vec<f32>,
+    clips: Vec<Rectangle>,
     previous: Vec<usize>,
     current: usize,
     active_count: usize,
@@ -38,6 +47,8 @@ impl<T: Layer> Stack<T> {
         Self {
             layers: vec![T::default()],
             transformations: vec![Transformation::IDENTITY],
+            opacities: vec![1.0],
+            clips: vec![],
             previous: vec![],
             current: 0,
             active_count: 1,
@@ -62,27 +73,14 @@ impl<T: Layer> Stack<T> {
     /// Pushes a new clipping region in the [`Stack`]; creating a new layer in the
     /// process.
     pub fn push_clip(&mut self, bounds: Rectangle) {
-        self.previous.push(self.current);
-
-        self.current = self.active_count;
-        self.active_count += 1;
-
-        let bounds = bounds * self.transformation();
-
-        if self.current == self.layers.len() {
-            self.layers.push(T::with_bounds(bounds));
-        } else {
-            self.layers[self.current].resize(bounds);
-        }
+        self.push_layer(bounds * self.transformation());
     }
 
     /// Pops the current clipping region from the [`Stack`] and restores the previous one.
     ///
     /// The current layer will be recorded for drawing.
     pub fn pop_clip(&mut self) {
-        self.flush();
-
-        self.current = self.previous.pop().unwrap();
+        self.pop_layer();
     }
 
     /// Pushes a new [`Transformation`] in the [`Stack`].
@@ -101,6 +99,44 @@ impl<T: Layer> Stack<T> {
         let _ = self.transformations.pop();
     }
 
+    /// Returns the current opacity of the [`Stack`].
+    #[inline]
+    pub fn opacity(&self) -> f32 {
+        self.opacities.last().copied().unwrap()
+    }
+
+    /// Pushes a new opacity in the [`Stack`]; creating a new layer in the
+    /// process, unless the opacity does not change.
+    ///
+    /// Future drawing operations will be grouped and composited with this
+    /// opacity until it is popped using [`pop_opacity`].
+    ///
+    /// [`pop_opacity`]: Self::pop_opacity
+    pub fn push_opacity(&mut self, opacity: f32) {
+        let current = self.opacity();
+        let opacity = current * opacity.clamp(0.0, 1.0);
+
+        self.opacities.push(opacity);
+
+        if opacity != current {
+            let bounds =
+                self.clips.last().copied().unwrap_or(Rectangle::INFINITE);
+
+            self.push_layer(bounds);
+        }
+    }
+
+    /// Pops the current opacity in the [`Stack`].
+    ///
+    /// The current layer will be recorded for drawing.
+    pub fn pop_opacity(&mut self) {
+        let opacity = self.opacities.pop().unwrap();
+
+        if opacity != self.opacity() {
+            self.pop_layer();
+        }
+    }
+
     /// Returns an iterator over mutable references to the layers in the [`Stack`].
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
         self.flush();
@@ -133,8 +169,34 @@ impl<T: Layer> Stack<T> {
 
         self.current = 0;
         self.active_count = 1;
+        self.opacities.truncate(1);
+        self.clips.clear();
         self.previous.clear();
     }
+
+    fn push_layer(&mut self, bounds: Rectangle) {
+        self.previous.push(self.current);
+        self.clips.push(bounds);
+
+        self.current = self.active_count;
+        self.active_count += 1;
+
+        if self.current == self.layers.len() {
+            self.layers.push(T::with_bounds(bounds));
+        } else {
+            self.layers[self.current].resize(bounds);
+        }
+
+        let opacity = self.opacity();
+        self.layers[self.current].set_opacity(opacity);
+    }
+
+    fn pop_layer(&mut self) {
+        self.flush();
+
+        let _ = self.clips.pop();
+        self.current = self.previous.pop().unwrap();
+    }
 }
 
 impl<T: Layer> Default for Stack<T> {
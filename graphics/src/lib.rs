@@ -8,7 +8,6 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
-mod antialiasing;
 mod settings;
 mod viewport;
 
@@ -26,7 +25,10 @@ pub mod text;
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
-pub use antialiasing::Antialiasing;
+#[cfg(feature = "svg")]
+pub mod svg;
+
+pub use crate::core::Antialiasing;
 pub use cache::Cache;
 pub use compositor::Compositor;
 pub use error::Error;
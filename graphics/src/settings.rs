@@ -1,5 +1,5 @@
 use crate::Antialiasing;
-use crate::core::{self, Font, Pixels};
+use crate::core::{self, Font, Pixels, image};
 
 /// The settings of a renderer.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +16,9 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The memory [`Budget`](image::Budget) of the cached images.
+    pub image_budget: image::Budget,
 }
 
 impl Default for Settings {
@@ -24,6 +27,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_budget: image::Budget::default(),
         }
     }
 }
@@ -42,6 +46,7 @@ impl From<core::Settings> for Settings {
             },
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing.then_some(Antialiasing::MSAAx4),
+            image_budget: settings.image_budget,
         }
     }
 }
@@ -0,0 +1,459 @@
+//! Play the animations of vector graphics.
+use crate::core::svg::{Data, Handle};
+use crate::core::time::Duration;
+
+use rustc_hash::FxHashMap;
+use std::ops::Range;
+
+/// Loads the source of the SVG of the given [`Handle`] with its style sheet
+/// and at its time, if the [`Handle`] has any.
+///
+/// Returns `None` if the [`Handle`] has neither; so its data can be parsed
+/// as is.
+///
+/// See [`frame`] for the supported animations and [`with_style_sheet`] for
+/// how style sheets are applied.
+pub fn load(handle: &Handle) -> Option<String> {
+    let time = handle.time();
+    let style_sheet = handle.style_sheet();
+
+    if time.is_none() && style_sheet.is_none() {
+        return None;
+    }
+
+    let source = match handle.data() {
+        Data::Path(path) => std::fs::read_to_string(path).ok()?,
+        Data::Bytes(bytes) => std::str::from_utf8(bytes).ok()?.to_owned(),
+    };
+
+    let source = time.and_then(|time| frame(&source, time)).unwrap_or(source);
+
+    Some(match style_sheet {
+        Some(style_sheet) => with_style_sheet(&source, style_sheet),
+        None => source,
+    })
+}
+
+/// Appends the given CSS style sheet to the given SVG source, as a `<style>`
+/// element at the end of its root.
+///
+/// Since it comes last, its rules take precedence over the ones of the
+/// source with the same specificity.
+pub fn with_style_sheet(source: &str, style_sheet: &str) -> String {
+    let Some(end) = source.rfind("</svg>") else {
+        return source.to_owned();
+    };
+
+    let style_sheet = style_sheet.replace("]]>", "]]]]><![CDATA[>");
+
+    format!(
+        "{}<style type=\"text/css\"><![CDATA[{style_sheet}]]></style>{}",
+        &source[..end],
+        &source[end..]
+    )
+}
+
+/// Evaluates the SMIL animations of the given SVG source at the given `time`,
+/// returning the source of the resulting frame.
+///
+/// The `<animate>`, `<set>`, and `<animateTransform>` elements are supported
+/// as long as they target their parent element and begin at a fixed offset.
+/// Numbers, lists of numbers, and hexadecimal colors are interpolated; any
+/// other values change discretely.
+///
+/// Returns `None` if the source cannot be parsed or it has no animations.
+pub fn frame(source: &str, time: Duration) -> Option<String> {
+    let document = roxmltree::Document::parse(source).ok()?;
+
+    let mut removals = Vec::new();
+    let mut values: FxHashMap<(roxmltree::NodeId, &str), String> =
+        FxHashMap::default();
+    let mut order = Vec::new();
+
+    for node in document.descendants().filter(roxmltree::Node::is_element) {
+        let kind = match node.tag_name().name() {
+            "animate" => Kind::Animate,
+            "set" => Kind::Set,
+            "animateTransform" => {
+                Kind::Transform(node.attribute("type").unwrap_or("translate"))
+            }
+            _ => continue,
+        };
+
+        removals.push(node.range());
+
+        let Some(target) = node.parent_element() else {
+            continue;
+        };
+
+        if node.attribute("href").is_some()
+            || node
+                .attribute(("http://www.w3.org/1999/xlink", "href"))
+                .is_some()
+        {
+            continue;
+        }
+
+        let Some(attribute) = (match kind {
+            Kind::Transform(_) => {
+                Some(node.attribute("attributeName").unwrap_or("transform"))
+            }
+            _ => node.attribute("attributeName"),
+        }) else {
+            continue;
+        };
+
+        let key = (target.id(), attribute);
+
+        let base = values
+            .get(&key)
+            .cloned()
+            .or_else(|| target.attribute(attribute).map(str::to_owned));
+
+        let Some(value) = evaluate(node, kind, base.as_deref(), time) else {
+            continue;
+        };
+
+        let value = match (kind, node.attribute("additive"), base) {
+            (Kind::Transform(_), Some("sum"), Some(base)) => {
+                format!("{base} {value}")
+            }
+            _ => value,
+        };
+
+        if values.insert(key, value).is_none() {
+            order.push(key);
+        }
+    }
+
+    if removals.is_empty() {
+        return None;
+    }
+
+    let mut edits: Vec<(Range<usize>, String)> = removals
+        .into_iter()
+        .map(|range| (range, String::new()))
+        .collect();
+
+    for key @ (id, attribute) in order {
+        let target = document.get_node(id)?;
+        let value = escape(&values[&key]);
+
+        match target.attributes().find(|candidate| {
+            candidate.name() == attribute && candidate.namespace().is_none()
+        }) {
+            Some(existing) => {
+                edits.push((
+                    existing.range(),
+                    format!("{attribute}=\"{value}\""),
+                ));
+            }
+            None => {
+                let start = target.range().start + 1;
+                let name = source[start..]
+                    .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                    .unwrap_or(0);
+
+                edits.push((
+                    start + name..start + name,
+                    format!(" {attribute}=\"{value}\""),
+                ));
+            }
+        }
+    }
+
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+
+    let mut frame = source.to_owned();
+
+    for (range, replacement) in edits {
+        frame.replace_range(range, &replacement);
+    }
+
+    Some(frame)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind<'a> {
+    Animate,
+    Set,
+    Transform(&'a str),
+}
+
+fn evaluate(
+    node: roxmltree::Node<'_, '_>,
+    kind: Kind<'_>,
+    base: Option<&str>,
+    time: Duration,
+) -> Option<String> {
+    let begin = node.attribute("begin").map_or(Some(0.0), clock)?;
+    let duration = node.attribute("dur").and_then(clock);
+    let time = time.as_secs_f32() - begin;
+
+    if time < 0.0 {
+        return None;
+    }
+
+    if let Kind::Set = kind {
+        return match duration {
+            Some(duration) if time >= duration && !is_frozen(node) => None,
+            _ => node.attribute("to").map(str::to_owned),
+        };
+    }
+
+    let duration = duration.filter(|duration| *duration > 0.0)?;
+
+    let repeat =
+        match (node.attribute("repeatCount"), node.attribute("repeatDur")) {
+            (Some("indefinite"), _) | (_, Some("indefinite")) => None,
+            (Some(count), _) => Some(count.trim().parse::<f32>().ok()?),
+            (None, Some(repeat)) => Some(clock(repeat)? / duration),
+            (None, None) => Some(1.0),
+        };
+
+    let progress = match repeat {
+        Some(count) if time >= duration * count => {
+            if !is_frozen(node) {
+                return None;
+            }
+
+            let remainder = count.fract();
+
+            if remainder > 0.0 { remainder } else { 1.0 }
+        }
+        _ => time % duration / duration,
+    };
+
+    let values: Vec<&str> = match node.attribute("values") {
+        Some(values) => values
+            .split(';')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .collect(),
+        None => {
+            let to = node.attribute("to")?;
+
+            match node.attribute("from") {
+                Some(from) => vec![from, to],
+                None => match (kind, base) {
+                    (Kind::Animate, Some(base)) => vec![base, to],
+                    _ => vec![to],
+                },
+            }
+        }
+    };
+
+    let value = if node.attribute("calcMode") == Some("discrete") {
+        let index = ((progress * values.len() as f32) as usize)
+            .min(values.len().saturating_sub(1));
+
+        (*values.get(index)?).to_owned()
+    } else {
+        interpolate(&values, key_times(node, values.len()), progress)?
+    };
+
+    Some(match kind {
+        Kind::Transform(kind) => format!("{kind}({value})"),
+        _ => value,
+    })
+}
+
+fn is_frozen(node: roxmltree::Node<'_, '_>) -> bool {
+    node.attribute("fill") == Some("freeze")
+}
+
+fn key_times(node: roxmltree::Node<'_, '_>, count: usize) -> Vec<f32> {
+    node.attribute("keyTimes")
+        .and_then(|key_times| {
+            key_times
+                .split(';')
+                .map(|time| time.trim().parse::<f32>().ok())
+                .collect::<Option<Vec<_>>>()
+        })
+        .filter(|key_times| key_times.len() == count)
+        .unwrap_or_else(|| {
+            (0..count)
+                .map(|i| i as f32 / (count.max(2) - 1) as f32)
+                .collect()
+        })
+}
+
+fn interpolate(
+    values: &[&str],
+    key_times: Vec<f32>,
+    progress: f32,
+) -> Option<String> {
+    let (first, rest) = values.split_first()?;
+
+    if rest.is_empty() {
+        return Some((*first).to_owned());
+    }
+
+    let segment = key_times
+        .windows(2)
+        .position(|window| progress < window[1])
+        .unwrap_or(values.len() - 2);
+
+    let start = key_times[segment];
+    let end = key_times[segment + 1];
+
+    let t = if end > start {
+        ((progress - start) / (end - start)).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    let from = values[segment];
+    let to = values[segment + 1];
+
+    Some(
+        lerp_numbers(from, to, t)
+            .or_else(|| lerp_colors(from, to, t))
+            .unwrap_or_else(|| if t < 1.0 { from } else { to }.to_owned()),
+    )
+}
+
+fn lerp_numbers(from: &str, to: &str, t: f32) -> Option<String> {
+    let from = numbers(from)?;
+    let to = numbers(to)?;
+
+    if from.is_empty() || from.len() != to.len() {
+        return None;
+    }
+
+    from.iter()
+        .zip(&to)
+        .map(|((a, unit), (b, other))| {
+            (unit == other).then(|| format!("{}{unit}", a + (b - a) * t))
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|numbers| numbers.join(" "))
+}
+
+/// Parses the numbers of the given list, with their units.
+fn numbers(value: &str) -> Option<Vec<(f32, &str)>> {
+    value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let split = token
+                .find(|c: char| {
+                    !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e'))
+                })
+                .unwrap_or(token.len());
+
+            let (number, unit) = token.split_at(split);
+
+            Some((number.parse::<f32>().ok()?, unit))
+        })
+        .collect()
+}
+
+fn lerp_colors(from: &str, to: &str, t: f32) -> Option<String> {
+    let rgb = |value: &str| {
+        let hex = value.strip_prefix('#')?;
+
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+
+        match hex.len() {
+            3 => Some([
+                channel(&hex[0..1])? * 17,
+                channel(&hex[1..2])? * 17,
+                channel(&hex[2..3])? * 17,
+            ]),
+            6 => Some([
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            ]),
+            _ => None,
+        }
+    };
+
+    let from = rgb(from)?;
+    let to = rgb(to)?;
+
+    let [r, g, b] = [0, 1, 2].map(|i| {
+        (f32::from(from[i]) + (f32::from(to[i]) - f32::from(from[i])) * t)
+            .round() as u8
+    });
+
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+fn clock(value: &str) -> Option<f32> {
+    let value = value.trim();
+
+    if value.contains(':') {
+        return value.split(':').try_fold(0.0, |total, part| {
+            Some(total * 60.0 + part.parse::<f32>().ok()?)
+        });
+    }
+
+    let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix("min") {
+        (number, 60.0)
+    } else if let Some(number) = value.strip_suffix('h') {
+        (number, 3600.0)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else {
+        (value, 1.0)
+    };
+
+    Some(number.trim().parse::<f32>().ok()? * scale)
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animates_parent_attribute() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg"><rect width="5"><animate attributeName="width" from="0" to="20" dur="2s"/></rect></svg>"#;
+
+        let frame = frame(source, Duration::from_secs(1)).unwrap();
+
+        assert_eq!(
+            frame,
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><rect width="10"></rect></svg>"#
+        );
+    }
+
+    #[test]
+    fn animates_transform() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg"><g><animateTransform attributeName="transform" type="rotate" from="0 5 5" to="360 5 5" dur="1s" repeatCount="indefinite"/></g></svg>"#;
+
+        let frame = frame(source, Duration::from_millis(1250)).unwrap();
+
+        assert_eq!(
+            frame,
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><g transform="rotate(90 5 5)"></g></svg>"#
+        );
+    }
+
+    #[test]
+    fn appends_style_sheet() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg"><rect/></svg>"#;
+
+        assert_eq!(
+            with_style_sheet(source, "rect { fill: red; }"),
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><rect/><style type="text/css"><![CDATA[rect { fill: red; }]]></style></svg>"#
+        );
+    }
+
+    #[test]
+    fn ignores_static_svgs() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg"><rect width="10"/></svg>"#;
+
+        assert_eq!(frame(source, Duration::from_secs(1)), None);
+    }
+}
@@ -25,7 +25,7 @@ pub trait Program: Sized {
     type Message: Message + 'static;
 
     /// The theme of the program.
-    type Theme: Default + theme::Base;
+    type Theme: Default + theme::Base + 'static;
 
     /// The renderer of the program.
     type Renderer: Renderer;
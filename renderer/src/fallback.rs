@@ -2,6 +2,7 @@
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
+use crate::core::window;
 use crate::core::{
     self, Background, Color, Font, Image, Pixels, Point, Rectangle, Size, Svg,
     Transformation,
@@ -69,6 +70,22 @@ where
     fn end_transformation(&mut self) {
         delegate!(self, renderer, renderer.end_transformation());
     }
+
+    fn start_opacity(&mut self, opacity: f32) {
+        delegate!(self, renderer, renderer.start_opacity(opacity));
+    }
+
+    fn end_opacity(&mut self) {
+        delegate!(self, renderer, renderer.end_opacity());
+    }
+
+    fn hint(&mut self, scale_factor: f32) {
+        delegate!(self, renderer, renderer.hint(scale_factor));
+    }
+
+    fn scale_factor(&self) -> Option<f32> {
+        delegate!(self, renderer, renderer.scale_factor())
+    }
 }
 
 impl<A, B> core::text::Renderer for Renderer<A, B>
@@ -309,6 +326,34 @@ where
         }
     }
 
+    fn configure_surface_with_present_mode(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+        present_mode: window::PresentMode,
+    ) {
+        match (self, surface) {
+            (Self::Primary(compositor), Surface::Primary(surface)) => {
+                compositor.configure_surface_with_present_mode(
+                    surface,
+                    width,
+                    height,
+                    present_mode,
+                );
+            }
+            (Self::Secondary(compositor), Surface::Secondary(surface)) => {
+                compositor.configure_surface_with_present_mode(
+                    surface,
+                    width,
+                    height,
+                    present_mode,
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn load_font(&mut self, font: Cow<'static, [u8]>) {
         delegate!(self, compositor, compositor.load_font(font));
     }
@@ -368,6 +413,94 @@ where
             _ => unreachable!(),
         }
     }
+
+    fn set_antialiasing(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        antialiasing: Option<graphics::Antialiasing>,
+    ) {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.set_antialiasing(renderer, antialiasing);
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.set_antialiasing(renderer, antialiasing);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn set_default_font(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        font: Option<Font>,
+    ) {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.set_default_font(renderer, font);
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.set_default_font(renderer, font);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn evict_images(&mut self, renderer: &mut Self::Renderer) {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.evict_images(renderer);
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.evict_images(renderer);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn evict_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &image::Handle,
+    ) {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.evict_image(renderer, handle);
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.evict_image(renderer, handle);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn prefetch_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &image::Handle,
+    ) {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.prefetch_image(renderer, handle);
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.prefetch_image(renderer, handle);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn image_usage(&self, renderer: &Self::Renderer) -> image::Usage {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.image_usage(renderer)
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.image_usage(renderer)
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 #[cfg(feature = "wgpu")]
@@ -4,6 +4,7 @@
 pub use iced_wgpu as wgpu;
 
 pub mod fallback;
+pub mod offscreen;
 
 pub use iced_graphics as graphics;
 pub use iced_graphics::core;
@@ -11,6 +12,9 @@ pub use iced_graphics::core;
 #[cfg(feature = "geometry")]
 pub use iced_graphics::geometry;
 
+#[cfg(any(feature = "wgpu", feature = "tiny-skia"))]
+pub use offscreen::render_to_image;
+
 /// The default graphics renderer for [`iced`].
 ///
 /// [`iced`]: https://github.com/iced-rs/iced
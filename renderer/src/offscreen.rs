@@ -0,0 +1,90 @@
+//! Render elements to images without a window.
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer::{self, Headless};
+use crate::core::theme;
+use crate::core::widget::Tree;
+use crate::core::window::Screenshot;
+use crate::core::{Element, Rectangle, Size};
+
+/// Lays out and draws the given [`Element`] offscreen with a new headless
+/// [`Renderer`], returning its RGBA pixels as a [`Screenshot`].
+///
+/// The `size` is given in logical pixels, and the [`Element`] is rasterized
+/// with a scale factor of `1.0`. Use [`render_with`] to reuse an existing
+/// renderer or to choose a different scale factor.
+///
+/// Returns `None` if no headless backend is available.
+///
+/// [`Renderer`]: crate::Renderer
+#[cfg(any(feature = "wgpu", feature = "tiny-skia"))]
+pub async fn render_to_image<'a, Message, Theme>(
+    element: impl Into<Element<'a, Message, Theme, crate::Renderer>>,
+    size: impl Into<Size>,
+    theme: &Theme,
+) -> Option<Screenshot>
+where
+    Theme: theme::Base,
+{
+    use crate::core::{Font, Pixels};
+
+    let mut renderer =
+        <crate::Renderer as Headless>::new(Font::DEFAULT, Pixels(16.0), None)
+            .await?;
+
+    Some(render_with(&mut renderer, element, size, 1.0, theme))
+}
+
+/// Lays out and draws the given [`Element`] offscreen with the given
+/// headless renderer and scale factor, returning its RGBA pixels as a
+/// [`Screenshot`].
+///
+/// Overlays are not drawn.
+pub fn render_with<'a, Message, Theme, Renderer>(
+    renderer: &mut Renderer,
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    size: impl Into<Size>,
+    scale_factor: f32,
+    theme: &Theme,
+) -> Screenshot
+where
+    Theme: theme::Base,
+    Renderer: renderer::Renderer + Headless,
+{
+    let element = element.into();
+    let size = size.into();
+
+    let mut tree = Tree::new(&element);
+
+    let node = element.as_widget().layout(
+        &mut tree,
+        renderer,
+        &layout::Limits::new(Size::ZERO, size),
+    );
+
+    let base = theme.base();
+
+    renderer.clear();
+
+    element.as_widget().draw(
+        &tree,
+        renderer,
+        theme,
+        &renderer::Style {
+            text_color: base.text_color,
+        },
+        Layout::new(&node),
+        mouse::Cursor::Unavailable,
+        &Rectangle::with_size(size),
+    );
+
+    let physical_size = Size::new(
+        (size.width * scale_factor).round() as u32,
+        (size.height * scale_factor).round() as u32,
+    );
+
+    let rgba =
+        renderer.screenshot(physical_size, scale_factor, base.background_color);
+
+    Screenshot::new(rgba, physical_size, f64::from(scale_factor))
+}
@@ -0,0 +1,38 @@
+//! Make applications usable with assistive technologies.
+use crate::task::{self, Task};
+
+/// The priority of an announcement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    /// The announcement waits until the screen reader is idle; like a status
+    /// change.
+    #[default]
+    Polite,
+
+    /// The announcement interrupts the screen reader; like an error.
+    Assertive,
+}
+
+/// An accessibility action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Announce the given text to screen readers with the given
+    /// [`Priority`].
+    Announce(String, Priority),
+}
+
+/// Announces the given text to screen readers with the given [`Priority`];
+/// like "File saved" or "3 results found".
+///
+/// The announcement is made through the focused window, without changing
+/// the focus.
+///
+/// ## Platform-specific
+///
+/// Requires the `a11y` feature; otherwise, it does nothing.
+pub fn announce<T>(text: impl Into<String>, priority: Priority) -> Task<T> {
+    task::effect(crate::Action::Accessibility(Action::Announce(
+        text.into(),
+        priority,
+    )))
+}
@@ -1,8 +1,11 @@
 //! Access the clipboard.
 use crate::core::clipboard::Kind;
+use crate::core::image;
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
+use std::path::PathBuf;
+
 /// A clipboard action to be performed by some [`Task`].
 ///
 /// [`Task`]: crate::Task
@@ -23,6 +26,63 @@ pub enum Action {
         /// The contents to be written.
         contents: String,
     },
+
+    /// Read the clipboard as an [`Image`].
+    ReadImage(oneshot::Sender<Option<Image>>),
+
+    /// Write the given [`Image`] to the clipboard.
+    WriteImage(Image),
+
+    /// Read the clipboard as HTML.
+    ReadHtml(oneshot::Sender<Option<String>>),
+
+    /// Write the given HTML to the clipboard.
+    WriteHtml {
+        /// The HTML to be written.
+        html: String,
+        /// The plain text alternative for applications that do not
+        /// support HTML.
+        alt_text: String,
+    },
+
+    /// Read the clipboard as a list of file paths.
+    ReadFiles(oneshot::Sender<Option<Vec<PathBuf>>>),
+
+    /// Write the given list of file paths to the clipboard.
+    WriteFiles(Vec<PathBuf>),
+
+    /// Read the clipboard data with the given MIME type.
+    ReadData {
+        /// The MIME type of the data.
+        mime: String,
+        /// The channel to send the read data.
+        channel: oneshot::Sender<Option<Vec<u8>>>,
+    },
+
+    /// Write the given data with the given MIME type to the clipboard.
+    WriteData {
+        /// The MIME type of the data.
+        mime: String,
+        /// The data to be written.
+        bytes: Vec<u8>,
+    },
+}
+
+/// An image stored in the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    /// The RGBA pixels of the image.
+    pub pixels: image::Bytes,
+}
+
+impl From<Image> for image::Handle {
+    fn from(image: Image) -> Self {
+        image::Handle::from_rgba(image.width, image.height, image.pixels)
+    }
 }
 
 /// Read the current contents of the clipboard.
@@ -60,3 +120,80 @@ pub fn write_primary<Message>(contents: String) -> Task<Message> {
         contents,
     }))
 }
+
+/// Read the current contents of the clipboard as an [`Image`].
+pub fn read_image() -> Task<Option<Image>> {
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadImage(channel))
+    })
+}
+
+/// Write the given RGBA pixels to the clipboard as an [`Image`].
+pub fn write_image<T>(
+    width: u32,
+    height: u32,
+    pixels: impl Into<image::Bytes>,
+) -> Task<T> {
+    task::effect(crate::Action::Clipboard(Action::WriteImage(Image {
+        width,
+        height,
+        pixels: pixels.into(),
+    })))
+}
+
+/// Read the current contents of the clipboard as HTML.
+pub fn read_html() -> Task<Option<String>> {
+    task::oneshot(|channel| crate::Action::Clipboard(Action::ReadHtml(channel)))
+}
+
+/// Write the given HTML to the clipboard, together with a plain text
+/// alternative for applications that do not support HTML.
+///
+/// HTML is the rich text format shared by every platform; most editors and
+/// word processors keep its formatting when pasting. Other rich formats, like
+/// RTF, can still be written as a custom format with [`write_data`].
+pub fn write_html<T>(html: String, alt_text: String) -> Task<T> {
+    task::effect(crate::Action::Clipboard(Action::WriteHtml {
+        html,
+        alt_text,
+    }))
+}
+
+/// Read the current contents of the clipboard as a list of file paths.
+///
+/// This is what file managers normally produce when copying files.
+pub fn read_files() -> Task<Option<Vec<PathBuf>>> {
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadFiles(channel))
+    })
+}
+
+/// Write the given list of file paths to the clipboard, so they can be
+/// pasted in a file manager.
+pub fn write_files<T>(paths: Vec<PathBuf>) -> Task<T> {
+    task::effect(crate::Action::Clipboard(Action::WriteFiles(paths)))
+}
+
+/// Read the current contents of the clipboard with the given MIME type.
+///
+/// Any MIME type other than `text/plain` and `text/html` is read as a custom
+/// format of the platform; a pasteboard type on macOS, a registered clipboard
+/// format on Windows, and a selection target on X11 and Wayland.
+pub fn read_data(mime: impl Into<String>) -> Task<Option<Vec<u8>>> {
+    let mime = mime.into();
+
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadData { mime, channel })
+    })
+}
+
+/// Write the given data with the given MIME type to the clipboard.
+///
+/// This can be used to exchange custom formats (e.g. `application/x-nodes`)
+/// through the clipboard.
+pub fn write_data<T>(mime: impl Into<String>, bytes: Vec<u8>) -> Task<T> {
+    task::effect(crate::Action::Clipboard(Action::WriteData {
+        mime: mime.into(),
+        bytes,
+    }))
+}
@@ -0,0 +1,122 @@
+//! Drag data out of the application.
+use crate::core::Point;
+use crate::core::image;
+use crate::core::window::Screenshot;
+use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
+
+use std::path::PathBuf;
+
+/// A drag and drop action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Start dragging the given [`Payload`] out of the focused window.
+    StartDrag {
+        /// The [`Payload`] being dragged.
+        payload: Payload,
+        /// The [`Preview`] shown under the cursor while dragging, if any.
+        preview: Option<Preview>,
+        /// The channel to send the [`Outcome`] of the drag.
+        channel: oneshot::Sender<Outcome>,
+    },
+}
+
+/// The data dragged out of the application.
+///
+/// Other kinds of data can be dragged by writing them to a file first;
+/// like an exported image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Payload {
+    /// A list of files.
+    Files(Vec<PathBuf>),
+}
+
+/// The image shown under the cursor while dragging.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preview {
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    /// The RGBA pixels of the image.
+    pub pixels: image::Bytes,
+    /// The position of the cursor in the image, in pixels.
+    pub hotspot: Point,
+    /// The opacity of the image, from `0.0` to `1.0`.
+    pub opacity: f32,
+}
+
+impl Preview {
+    /// Creates a new [`Preview`] from the given RGBA pixels; fully opaque
+    /// and with the cursor at its top-left corner.
+    pub fn from_rgba(
+        width: u32,
+        height: u32,
+        pixels: impl Into<image::Bytes>,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            pixels: pixels.into(),
+            hotspot: Point::ORIGIN,
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the position of the cursor in the [`Preview`], in pixels.
+    pub fn hotspot(mut self, hotspot: impl Into<Point>) -> Self {
+        self.hotspot = hotspot.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Preview`].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl From<Screenshot> for Preview {
+    fn from(screenshot: Screenshot) -> Self {
+        Self::from_rgba(
+            screenshot.size.width,
+            screenshot.size.height,
+            screenshot.bytes,
+        )
+    }
+}
+
+/// The outcome of a drag started with [`start_drag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The [`Payload`] was dropped into some target.
+    Dropped,
+    /// The drag was canceled by the user, or it could not be started.
+    Canceled,
+    /// Dragging out of the application is not supported by the platform.
+    Unsupported,
+}
+
+/// Starts dragging the given [`Payload`] out of the focused window, so it
+/// can be dropped into other applications.
+///
+/// This should be called while a mouse button is pressed; normally, after
+/// the cursor has moved a bit away from the press position.
+///
+/// A rendered widget can be used as the [`Preview`] by cropping a window
+/// [`Screenshot`] to its bounds.
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** Requires the `dnd` feature.
+/// - **Linux / Web:** Unsupported; the drag always produces
+///   [`Outcome::Unsupported`].
+pub fn start_drag(payload: Payload, preview: Option<Preview>) -> Task<Outcome> {
+    task::oneshot(|channel| {
+        crate::Action::Dnd(Action::StartDrag {
+            payload,
+            preview,
+            channel,
+        })
+    })
+}
@@ -0,0 +1,51 @@
+//! Manage the images cached by the renderer.
+use crate::core::image::{Handle, Usage};
+use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
+
+/// An operation to be performed on the image caches of the renderer.
+#[derive(Debug)]
+pub enum Action {
+    /// Evict the images that were not drawn in the last frame.
+    EvictUnused,
+
+    /// Evict the image of the given [`Handle`].
+    Evict(Handle),
+
+    /// Load the image of the given [`Handle`] before it is drawn.
+    Prefetch(Handle),
+
+    /// Query the memory [`Usage`] of the cached images.
+    QueryUsage(oneshot::Sender<Usage>),
+}
+
+/// Evicts the images that were not drawn in the last frame from the caches
+/// of every window, releasing any unused GPU memory.
+///
+/// This can be useful after navigating away from an image-heavy view.
+pub fn evict_unused<T>() -> Task<T> {
+    task::effect(crate::Action::Image(Action::EvictUnused))
+}
+
+/// Evicts the image of the given [`Handle`] from the caches of every window.
+///
+/// The image will be loaded again the next time it is drawn.
+pub fn evict<T>(handle: impl Into<Handle>) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Evict(handle.into())))
+}
+
+/// Decodes and uploads the image of the given [`Handle`] to the caches of
+/// every window, so it can be drawn immediately once it becomes visible.
+///
+/// Unless the image cache has a [`Budget`] with a maximum size, prefetched
+/// images are evicted if they are not drawn in the next frame.
+///
+/// [`Budget`]: crate::core::image::Budget
+pub fn prefetch<T>(handle: impl Into<Handle>) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Prefetch(handle.into())))
+}
+
+/// Queries the memory [`Usage`] of the images cached for every window.
+pub fn usage() -> Task<Usage> {
+    task::oneshot(|channel| crate::Action::Image(Action::QueryUsage(channel)))
+}
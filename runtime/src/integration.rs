@@ -0,0 +1,164 @@
+//! Embed a user interface in an application that owns its event loop.
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::window;
+use crate::core::{Clipboard, Element, Event, Size};
+use crate::user_interface::{self, UserInterface};
+
+/// The state of a user interface embedded in a host application; like a
+/// game engine or any other program that owns its event loop and renderer.
+///
+/// The host forwards its events with [`Integration::queue_event`], processes
+/// them with [`Integration::update`], and draws the interface each frame with
+/// [`Integration::draw`].
+#[derive(Debug)]
+pub struct Integration {
+    cache: user_interface::Cache,
+    events: Vec<Event>,
+    cursor: mouse::Cursor,
+    size: Size,
+}
+
+impl Integration {
+    /// Creates a new [`Integration`] with the given logical size.
+    pub fn new(size: Size) -> Self {
+        Self {
+            cache: user_interface::Cache::new(),
+            events: Vec::new(),
+            cursor: mouse::Cursor::Unavailable,
+            size,
+        }
+    }
+
+    /// Returns the logical size of the [`Integration`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the current [`mouse::Cursor`] of the [`Integration`].
+    pub fn cursor(&self) -> mouse::Cursor {
+        self.cursor
+    }
+
+    /// Resizes the [`Integration`] to the given logical size.
+    pub fn resize(&mut self, size: Size) {
+        if self.size != size {
+            self.size = size;
+            self.events
+                .push(Event::Window(window::Event::Resized(size)));
+        }
+    }
+
+    /// Queues an [`Event`] to be processed in the next
+    /// [`Integration::update`].
+    ///
+    /// The [`mouse::Cursor`] of the [`Integration`] is kept in sync with the
+    /// mouse events.
+    pub fn queue_event(&mut self, event: Event) {
+        match &event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                self.cursor = mouse::Cursor::Available(*position);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor = mouse::Cursor::Unavailable;
+            }
+            _ => {}
+        }
+
+        self.events.push(event);
+    }
+
+    /// Returns true if there are queued events waiting to be processed.
+    pub fn has_pending_events(&self) -> bool {
+        !self.events.is_empty()
+    }
+
+    /// Processes the queued events with the given [`Element`], producing
+    /// the messages of the interface.
+    ///
+    /// Returns the resulting [`user_interface::State`] and whether each
+    /// event was captured, so that the host can ignore the captured ones.
+    pub fn update<'a, Message, Theme, Renderer>(
+        &mut self,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+        renderer: &mut Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> (user_interface::State, Vec<(Event, bool)>)
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: crate::core::Renderer + 'a,
+    {
+        let mut interface = UserInterface::build(
+            element,
+            self.size,
+            std::mem::take(&mut self.cache),
+            renderer,
+        );
+
+        let events = std::mem::take(&mut self.events);
+
+        let (state, statuses) = interface.update(
+            &events,
+            self.cursor,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        self.cache = interface.into_cache();
+
+        let events = events
+            .into_iter()
+            .zip(statuses)
+            .map(|(event, status)| {
+                (event, status == crate::core::event::Status::Captured)
+            })
+            .collect();
+
+        (state, events)
+    }
+
+    /// Draws the given [`Element`] with the [`Renderer`] of the host.
+    ///
+    /// Animations are advanced to the given instant before drawing.
+    ///
+    /// [`Renderer`]: crate::core::Renderer
+    pub fn draw<'a, Message, Theme, Renderer>(
+        &mut self,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+        renderer: &mut Renderer,
+        clipboard: &mut dyn Clipboard,
+        theme: &Theme,
+        style: &renderer::Style,
+        now: Instant,
+    ) -> user_interface::State
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: crate::core::Renderer + 'a,
+    {
+        let mut interface = UserInterface::build(
+            element,
+            self.size,
+            std::mem::take(&mut self.cache),
+            renderer,
+        );
+
+        let (state, _) = interface.update(
+            &[Event::Window(window::Event::RedrawRequested(now))],
+            self.cursor,
+            renderer,
+            clipboard,
+            &mut Vec::new(),
+        );
+
+        interface.draw(renderer, theme, style, self.cursor);
+
+        self.cache = interface.into_cache();
+
+        state
+    }
+}
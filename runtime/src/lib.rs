@@ -9,12 +9,17 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod accessibility;
 pub mod clipboard;
+pub mod dnd;
 pub mod font;
+pub mod image;
+pub mod integration;
 pub mod keyboard;
 pub mod overlay;
 pub mod system;
 pub mod task;
+pub mod tray;
 pub mod user_interface;
 pub mod window;
 
@@ -22,6 +27,7 @@ pub use iced_core as core;
 pub use iced_debug as debug;
 pub use iced_futures as futures;
 
+pub use integration::Integration;
 pub use task::Task;
 pub use user_interface::UserInterface;
 
@@ -56,6 +62,21 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action),
 
+    /// Run an image action.
+    Image(image::Action),
+
+    /// Run a drag and drop action.
+    Dnd(dnd::Action),
+
+    /// Run a tray action.
+    Tray(tray::Action),
+
+    /// Run an accessibility action.
+    Accessibility(accessibility::Action),
+
+    /// Run a debounce action.
+    Debounce(task::Debounce),
+
     /// Exits the runtime.
     ///
     /// This will normally close any application windows and
@@ -79,6 +100,11 @@ impl<T> Action<T> {
             Action::Clipboard(action) => Err(Action::Clipboard(action)),
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
+            Action::Image(action) => Err(Action::Image(action)),
+            Action::Dnd(action) => Err(Action::Dnd(action)),
+            Action::Tray(action) => Err(Action::Tray(action)),
+            Action::Accessibility(action) => Err(Action::Accessibility(action)),
+            Action::Debounce(action) => Err(Action::Debounce(action)),
             Action::Exit => Err(Action::Exit),
         }
     }
@@ -102,6 +128,15 @@ where
             }
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
+            Action::Image(action) => write!(f, "Action::Image({action:?})"),
+            Action::Dnd(action) => write!(f, "Action::Dnd({action:?})"),
+            Action::Tray(action) => write!(f, "Action::Tray({action:?})"),
+            Action::Accessibility(action) => {
+                write!(f, "Action::Accessibility({action:?})")
+            }
+            Action::Debounce(action) => {
+                write!(f, "Action::Debounce({action:?})")
+            }
             Action::Exit => write!(f, "Action::Exit"),
         }
     }
@@ -1,11 +1,210 @@
 //! Access the native system.
+use crate::core::event;
+use crate::core::time::Duration;
+use crate::futures::Subscription;
+use crate::futures::backend::default::time;
+use crate::futures::blocking;
 use crate::futures::futures::channel::oneshot;
+use crate::futures::futures::stream;
+use crate::task::{self, Task};
+
+mod network;
+mod power;
+
+pub use crate::core::system::{Appearance, Event, Mode, Network};
+pub use power::{Battery, Power};
+
+/// How often [`network_status_changes`] checks the network connectivity.
+const NETWORK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often [`power_changes`] checks the power state.
+const POWER_POLL_INTERVAL: Duration = Duration::from_secs(30);
 
 /// An operation to be performed on the system.
 #[derive(Debug)]
 pub enum Action {
     /// Query system information and produce `T` with the result.
     QueryInformation(oneshot::Sender<Information>),
+
+    /// Query the current [`Appearance`] of the system.
+    QueryAppearance(oneshot::Sender<Appearance>),
+}
+
+/// Queries the current [`Appearance`] of the system.
+///
+/// The [`Mode`] will be [`Mode::Unspecified`] if the system preference
+/// cannot be detected.
+pub fn appearance() -> Task<Appearance> {
+    task::oneshot(|channel| {
+        crate::Action::System(Action::QueryAppearance(channel))
+    })
+}
+
+/// Subscribes to changes of the [`Appearance`] of the system.
+///
+/// This can be combined with [`appearance`] to make the theme of an
+/// application follow the preference of the user.
+pub fn appearance_changes() -> Subscription<Appearance> {
+    crate::futures::event::listen_with(|event, _status, _window| {
+        if let event::Event::System(Event::AppearanceChanged(appearance)) =
+            event
+        {
+            Some(appearance)
+        } else {
+            None
+        }
+    })
+}
+
+/// Subscribes to changes of the color [`Mode`] or the accent color of the
+/// system.
+///
+/// Unlike [`appearance_changes`], this is not triggered when only the
+/// accessibility preferences change; so the theme of an application can
+/// react to it without being rebuilt needlessly.
+pub fn theme_changes() -> Subscription<Appearance> {
+    crate::futures::event::listen_with(|event, _status, _window| {
+        if let event::Event::System(Event::ThemeChanged(appearance)) = event {
+            Some(appearance)
+        } else {
+            None
+        }
+    })
+}
+
+/// Queries the current [`Network`] connectivity of the system.
+///
+/// ## Platform-specific
+///
+/// - **Windows:** The connectivity and cost are read from the profile of
+///   the connection used to reach the internet.
+/// - **Linux:** Being online means having a route to the internet, which
+///   does not guarantee it is reachable; for instance, behind a captive
+///   portal. Metered connections are detected through NetworkManager.
+/// - **macOS:** Being online means having a route to the internet. Metered
+///   connections cannot be detected.
+/// - **Web:** Being online is reported by the browser. Metered connections
+///   cannot be detected.
+pub fn network_status() -> Task<Network> {
+    Task::perform_blocking(network::current, std::convert::identity)
+}
+
+/// Subscribes to changes of the [`Network`] connectivity of the system.
+///
+/// The current [`Network`] status is produced immediately; so this can be
+/// used to pause sync work or show an offline banner reactively.
+pub fn network_status_changes() -> Subscription<Network> {
+    Subscription::run(|| {
+        stream::unfold(None, |last| async move {
+            loop {
+                if last.is_some() {
+                    time::sleep(NETWORK_POLL_INTERVAL).await;
+                }
+
+                let current = blocking::spawn(network::current).await?;
+
+                if Some(current) != last {
+                    return Some((current, Some(current)));
+                }
+            }
+        })
+    })
+}
+
+/// Queries the current [`Power`] state of the system.
+pub fn power_status() -> Task<Power> {
+    Task::perform_blocking(Power::current, std::convert::identity)
+}
+
+/// Subscribes to changes of the [`Power`] state of the system.
+///
+/// The current [`Power`] state is produced immediately; so this can be
+/// used to reduce animations or polling while running on battery.
+pub fn power_changes() -> Subscription<Power> {
+    Subscription::run(|| {
+        stream::unfold(None, |last| async move {
+            loop {
+                if last.is_some() {
+                    time::sleep(POWER_POLL_INTERVAL).await;
+                }
+
+                let current = blocking::spawn(Power::current).await?;
+
+                if Some(current) != last {
+                    return Some((current, Some(current)));
+                }
+            }
+        })
+    })
+}
+
+/// Subscribes to the shutdown requests of the operating system; like
+/// `SIGINT`, `SIGTERM`, or `SIGHUP` on Unix, and console close events on
+/// Windows.
+///
+/// While subscribed, these requests do not terminate the application.
+/// Instead, it can save its state and then [`exit`] gracefully—for instance,
+/// by chaining its final save [`Task`] with [`exit`].
+///
+/// A second request terminates the application immediately; so users can
+/// still force a stuck application to quit.
+///
+/// [`exit`]: crate::exit
+#[cfg(all(feature = "shutdown", not(target_arch = "wasm32")))]
+pub fn shutdown_requests() -> Subscription<()> {
+    Subscription::run(|| {
+        use crate::futures::futures::channel::mpsc;
+
+        let (sender, receiver) = mpsc::unbounded();
+        shutdown::listen(sender);
+
+        receiver
+    })
+}
+
+#[cfg(all(feature = "shutdown", not(target_arch = "wasm32")))]
+mod shutdown {
+    use crate::futures::futures::channel::mpsc;
+
+    use std::sync::atomic::{self, AtomicBool};
+    use std::sync::{Mutex, Once};
+
+    /// The exit code of an application terminated by a signal, by convention.
+    const EXIT_CODE: i32 = 130;
+
+    static LISTENERS: Mutex<Vec<mpsc::UnboundedSender<()>>> =
+        Mutex::new(Vec::new());
+
+    static IS_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+    pub fn listen(listener: mpsc::UnboundedSender<()>) {
+        static HANDLER: Once = Once::new();
+
+        HANDLER.call_once(|| {
+            if let Err(error) = ctrlc::set_handler(request) {
+                log::error!("Failed to handle shutdown requests: {error}");
+            }
+        });
+
+        LISTENERS
+            .lock()
+            .expect("Lock shutdown listeners")
+            .push(listener);
+    }
+
+    fn request() {
+        if IS_REQUESTED.swap(true, atomic::Ordering::SeqCst) {
+            std::process::exit(EXIT_CODE);
+        }
+
+        let mut listeners = LISTENERS.lock().expect("Lock shutdown listeners");
+
+        listeners.retain(|listener| listener.unbounded_send(()).is_ok());
+
+        if listeners.is_empty() {
+            std::process::exit(EXIT_CODE);
+        }
+    }
 }
 
 /// Contains information about the system (e.g. system name, processor, memory, graphics adapter).
@@ -36,4 +235,6 @@ pub struct Information {
     pub graphics_backend: String,
     /// Model information for the active graphics adapter
     pub graphics_adapter: String,
+    /// The [`Power`] state of the system
+    pub power: Power,
 }
@@ -0,0 +1,168 @@
+//! Detect the network connectivity of the native system.
+use crate::core::system::Network;
+
+/// Returns the current [`Network`] connectivity of the system.
+///
+/// This queries the system synchronously; consider using
+/// [`network_status`] instead in your application.
+///
+/// [`network_status`]: super::network_status
+#[cfg(target_os = "linux")]
+pub fn current() -> Network {
+    Network {
+        is_online: has_route(),
+        is_metered: network_manager::is_metered(),
+    }
+}
+
+/// Returns the current [`Network`] connectivity of the system.
+///
+/// This queries the system synchronously; consider using
+/// [`network_status`] instead in your application.
+///
+/// [`network_status`]: super::network_status
+#[cfg(target_os = "windows")]
+pub fn current() -> Network {
+    use windows::Networking::Connectivity::{
+        NetworkConnectivityLevel, NetworkCostType, NetworkInformation,
+    };
+
+    // The profile of the connection used to reach the internet, if any
+    let Ok(profile) = NetworkInformation::GetInternetConnectionProfile() else {
+        return Network {
+            is_online: false,
+            is_metered: None,
+        };
+    };
+
+    let is_online = profile.GetNetworkConnectivityLevel().map_or_else(
+        |_| has_route(),
+        |level| level == NetworkConnectivityLevel::InternetAccess,
+    );
+
+    let is_metered = profile.GetConnectionCost().ok().and_then(|cost| {
+        if cost.Roaming().unwrap_or(false)
+            || cost.OverDataLimit().unwrap_or(false)
+        {
+            return Some(true);
+        }
+
+        match cost.NetworkCostType().ok()? {
+            NetworkCostType::Unrestricted => Some(false),
+            NetworkCostType::Fixed | NetworkCostType::Variable => Some(true),
+            _ => None,
+        }
+    });
+
+    Network {
+        is_online,
+        is_metered,
+    }
+}
+
+/// Returns the current [`Network`] connectivity of the system.
+///
+/// Metered connections cannot be detected on this platform yet.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "windows",
+    target_arch = "wasm32"
+)))]
+pub fn current() -> Network {
+    Network {
+        is_online: has_route(),
+        is_metered: None,
+    }
+}
+
+/// Returns the current [`Network`] connectivity of the system.
+///
+/// Metered connections cannot be detected on this platform yet.
+#[cfg(target_arch = "wasm32")]
+pub fn current() -> Network {
+    let is_online = web_sys::window()
+        .map(|window| window.navigator().on_line())
+        .unwrap_or(true);
+
+    Network {
+        is_online,
+        is_metered: None,
+    }
+}
+
+/// Returns true if the system has a route to the internet.
+///
+/// Connecting a UDP socket sends no packets; it only checks the routing
+/// table of the system. So a route does not guarantee the internet is
+/// reachable; for instance, behind a captive portal.
+#[cfg(not(target_arch = "wasm32"))]
+fn has_route() -> bool {
+    use std::net::UdpSocket;
+
+    [
+        ("0.0.0.0:0", "1.1.1.1:53"),
+        ("[::]:0", "[2606:4700:4700::1111]:53"),
+    ]
+    .into_iter()
+    .any(|(local, remote)| {
+        UdpSocket::bind(local)
+            .and_then(|socket| socket.connect(remote))
+            .is_ok()
+    })
+}
+
+/// The metered state is the `Metered` property of NetworkManager, if
+/// available; queried through `busctl` to avoid a D-Bus dependency.
+#[cfg(target_os = "linux")]
+mod network_manager {
+    use std::process::Command;
+
+    pub fn is_metered() -> Option<bool> {
+        let output = Command::new("busctl")
+            .args([
+                "get-property",
+                "org.freedesktop.NetworkManager",
+                "/org/freedesktop/NetworkManager",
+                "org.freedesktop.NetworkManager",
+                "Metered",
+            ])
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        parse(&output.stdout)
+    }
+
+    /// Parses an `NMMetered` value; printed by `busctl` as `u <value>`.
+    fn parse(output: &[u8]) -> Option<bool> {
+        let value = std::str::from_utf8(output).ok()?;
+        let value: u32 = value.trim().strip_prefix("u ")?.parse().ok()?;
+
+        match value {
+            // Yes, or guessed yes
+            1 | 3 => Some(true),
+            // No, or guessed no
+            2 | 4 => Some(false),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn metered_states_are_parsed() {
+            assert_eq!(parse(b"u 1\n"), Some(true));
+            assert_eq!(parse(b"u 3\n"), Some(true));
+            assert_eq!(parse(b"u 2\n"), Some(false));
+            assert_eq!(parse(b"u 4\n"), Some(false));
+            assert_eq!(parse(b"u 0\n"), None);
+            assert_eq!(parse(b"s \"yes\"\n"), None);
+            assert_eq!(parse(b""), None);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Detect the power state of the native system.
+
+/// The power state of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Power {
+    /// The main [`Battery`] of the system, if any.
+    pub battery: Option<Battery>,
+    /// Whether the power saver mode of the system is enabled.
+    pub is_power_saving: bool,
+}
+
+/// The state of a battery.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Battery {
+    /// The charge level of the battery, from `0.0` to `1.0`.
+    pub level: f32,
+    /// Whether the system is plugged in and charging the battery.
+    pub is_charging: bool,
+}
+
+impl Power {
+    /// Returns the current [`Power`] state of the system.
+    ///
+    /// This reads the power state synchronously; consider using
+    /// [`power_status`] instead in your application.
+    ///
+    /// [`power_status`]: super::power_status
+    #[cfg(target_os = "linux")]
+    pub fn current() -> Self {
+        use std::fs;
+        use std::path::Path;
+
+        fn read(path: impl AsRef<Path>) -> Option<String> {
+            fs::read_to_string(path)
+                .ok()
+                .map(|contents| contents.trim().to_owned())
+        }
+
+        let battery = fs::read_dir("/sys/class/power_supply")
+            .ok()
+            .into_iter()
+            .flatten()
+            .flatten()
+            .find_map(|entry| {
+                let path = entry.path();
+
+                if read(path.join("type"))? != "Battery" {
+                    return None;
+                }
+
+                let capacity: f32 =
+                    read(path.join("capacity"))?.parse().ok()?;
+                let status = read(path.join("status")).unwrap_or_default();
+
+                Some(Battery {
+                    level: (capacity / 100.0).clamp(0.0, 1.0),
+                    is_charging: matches!(status.as_str(), "Charging" | "Full"),
+                })
+            });
+
+        let is_power_saving = read("/sys/firmware/acpi/platform_profile")
+            .is_some_and(|profile| profile == "low-power");
+
+        Self {
+            battery,
+            is_power_saving,
+        }
+    }
+
+    /// Returns the current [`Power`] state of the system.
+    ///
+    /// This reads the power state synchronously; consider using
+    /// [`power_status`] instead in your application.
+    ///
+    /// [`power_status`]: super::power_status
+    #[cfg(target_os = "windows")]
+    pub fn current() -> Self {
+        use windows_sys::Win32::System::Power::{
+            GetSystemPowerStatus, SYSTEM_POWER_STATUS,
+        };
+
+        const AC_ONLINE: u8 = 1;
+        const NO_BATTERY: u8 = 128;
+        const UNKNOWN: u8 = 255;
+        const BATTERY_SAVER_ON: u8 = 1;
+
+        let mut status = SYSTEM_POWER_STATUS {
+            ACLineStatus: 0,
+            BatteryFlag: 0,
+            BatteryLifePercent: 0,
+            SystemStatusFlag: 0,
+            BatteryLifeTime: 0,
+            BatteryFullLifeTime: 0,
+        };
+
+        // SAFETY: The pointer refers to a `SYSTEM_POWER_STATUS` that lives
+        // for the whole call.
+        #[allow(unsafe_code)]
+        let result = unsafe { GetSystemPowerStatus(&raw mut status) };
+
+        if result == 0 {
+            return Self::default();
+        }
+
+        let has_battery = status.BatteryFlag & NO_BATTERY == 0
+            && status.BatteryFlag != UNKNOWN
+            && status.BatteryLifePercent != UNKNOWN;
+
+        Self {
+            battery: has_battery.then(|| Battery {
+                level: f32::from(status.BatteryLifePercent.min(100)) / 100.0,
+                is_charging: status.ACLineStatus == AC_ONLINE,
+            }),
+            is_power_saving: status.SystemStatusFlag == BATTERY_SAVER_ON,
+        }
+    }
+
+    /// Returns the current [`Power`] state of the system.
+    ///
+    /// Detection is not supported on this platform yet.
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    pub fn current() -> Self {
+        Self::default()
+    }
+}
@@ -1,14 +1,23 @@
 //! Create runtime tasks.
 use crate::Action;
+use crate::core::time::Duration;
 use crate::core::widget;
+use crate::futures::backend::default::time;
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::future::{self, FutureExt};
 use crate::futures::futures::stream::{self, Stream, StreamExt};
+use crate::futures::subscription::Hasher;
 use crate::futures::{BoxStream, MaybeSend, boxed_stream};
 
+use std::any::Any;
+use std::collections::HashMap;
 use std::convert::Infallible;
+use std::hash::{Hash, Hasher as _};
 use std::sync::Arc;
+use std::sync::atomic::{self, AtomicBool, AtomicU64};
+
+pub use crate::futures::Priority;
 
 #[cfg(feature = "sipper")]
 #[doc(no_inline)]
@@ -21,6 +30,7 @@ pub use sipper::{Never, Sender, Sipper, Straw, sipper, stream};
 pub struct Task<T> {
     stream: Option<BoxStream<Action<T>>>,
     units: usize,
+    priority: Priority,
 }
 
 impl<T> Task<T> {
@@ -29,6 +39,7 @@ impl<T> Task<T> {
         Self {
             stream: None,
             units: 0,
+            priority: Priority::Interactive,
         }
     }
 
@@ -53,6 +64,33 @@ impl<T> Task<T> {
         Self::future(future.map(f))
     }
 
+    /// Creates a [`Task`] that runs the given blocking closure outside of the
+    /// async executor and maps its output with the given closure.
+    ///
+    /// See [`blocking::spawn`] for details on where the closure runs.
+    ///
+    /// [`blocking::spawn`]: crate::futures::blocking::spawn
+    ///
+    /// Use this for CPU-heavy work—like encoding images or parsing large
+    /// files—that would otherwise starve the executor. If the blocking
+    /// closure panics, the [`Task`] produces no output.
+    pub fn perform_blocking<A>(
+        blocking: impl FnOnce() -> A + MaybeSend + 'static,
+        f: impl FnOnce(A) -> T + MaybeSend + 'static,
+    ) -> Self
+    where
+        T: MaybeSend + 'static,
+        A: MaybeSend + 'static,
+    {
+        Self::stream(
+            stream::once(
+                crate::futures::blocking::spawn(blocking)
+                    .map(|output| output.map(f)),
+            )
+            .filter_map(future::ready),
+        )
+    }
+
     /// Creates a [`Task`] that runs the given [`Stream`] to completion and maps each
     /// item with the given closure.
     pub fn run<A>(
@@ -82,18 +120,85 @@ impl<T> Task<T> {
         })))
     }
 
+    /// Creates a [`Task`] that runs the [`Future`] returned by the given
+    /// closure, which can report intermediate [`Progress`] while it runs.
+    ///
+    /// Progress is mapped with the first closure and the output with the
+    /// second one. The output is always produced after every progress
+    /// report.
+    ///
+    /// ```
+    /// # use iced_runtime::Task;
+    /// enum Message {
+    ///     Downloading(f32),
+    ///     Downloaded(Vec<u8>),
+    /// }
+    ///
+    /// let task = Task::stream_with_progress(
+    ///     |progress| async move {
+    ///         let mut bytes = Vec::new();
+    ///
+    ///         for chunk in 0..10 {
+    ///             bytes.push(chunk);
+    ///             progress.send(chunk as f32 / 10.0);
+    ///         }
+    ///
+    ///         bytes
+    ///     },
+    ///     Message::Downloading,
+    ///     Message::Downloaded,
+    /// );
+    /// ```
+    pub fn stream_with_progress<P, O, F>(
+        job: impl FnOnce(Progress<P>) -> F,
+        on_progress: impl FnMut(P) -> T + MaybeSend + 'static,
+        on_output: impl FnOnce(O) -> T + MaybeSend + 'static,
+    ) -> Self
+    where
+        F: Future<Output = O> + MaybeSend + 'static,
+        P: MaybeSend + 'static,
+        O: MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        let (output_sender, output_receiver) = oneshot::channel();
+        let job = job(Progress { sender });
+
+        let runner = stream::once(async move {
+            let _ = output_sender.send(job.await);
+        })
+        .filter_map(|()| future::ready(None));
+
+        let progress = stream::select(receiver.map(on_progress), runner);
+
+        let output =
+            stream::once(
+                async move { output_receiver.await.ok().map(on_output) },
+            )
+            .filter_map(future::ready);
+
+        Self::stream(progress.chain(output))
+    }
+
     /// Combines the given tasks and produces a single [`Task`] that will run all of them
     /// in parallel.
+    ///
+    /// The [`Priority`] of the batch is the highest one among the tasks that
+    /// perform any work; so a single interactive task makes the whole batch
+    /// interactive. A batch without any work is interactive, like
+    /// [`Task::none`].
     pub fn batch(tasks: impl IntoIterator<Item = Self>) -> Self
     where
         T: 'static,
     {
         let mut select_all = stream::SelectAll::new();
         let mut units = 0;
+        let mut priority = None;
 
         for task in tasks.into_iter() {
             if let Some(stream) = task.stream {
                 select_all.push(stream);
+                priority = priority.max(Some(task.priority));
             }
 
             units += task.units;
@@ -102,6 +207,7 @@ impl<T> Task<T> {
         Self {
             stream: Some(boxed_stream(select_all)),
             units,
+            priority: priority.unwrap_or_default(),
         }
     }
 
@@ -149,6 +255,7 @@ impl<T> Task<T> {
                 }
             },
             units: self.units,
+            priority: self.priority,
         }
     }
 
@@ -163,15 +270,185 @@ impl<T> Task<T> {
                 None => Self {
                     stream: Some(first),
                     units: self.units,
+                    priority: self.priority,
                 },
                 Some(second) => Self {
                     stream: Some(boxed_stream(first.chain(second))),
                     units: self.units + task.units,
+                    priority: self.priority.max(task.priority),
                 },
             },
         }
     }
 
+    /// Creates a [`Task`] that runs the [`Future`] returned by the given
+    /// closure until it succeeds, waiting between attempts according to the
+    /// given [`Retry`] policy.
+    ///
+    /// Every failed [`Attempt`] that will be retried is mapped with the first
+    /// closure; and the final result with the second one.
+    ///
+    /// ```
+    /// # use iced_runtime::Task;
+    /// # use iced_runtime::task::{Attempt, Retry};
+    /// # async fn fetch() -> Result<String, String> { Ok(String::new()) }
+    /// enum Message {
+    ///     Retrying(Attempt<String>),
+    ///     Fetched(Result<String, String>),
+    /// }
+    ///
+    /// let task = Task::retry(
+    ///     Retry::default().max_attempts(3),
+    ///     fetch,
+    ///     Message::Retrying,
+    ///     Message::Fetched,
+    /// );
+    /// ```
+    pub fn retry<O, E, F>(
+        policy: Retry,
+        mut job: impl FnMut() -> F + MaybeSend + 'static,
+        on_attempt: impl FnMut(Attempt<E>) -> T + MaybeSend + 'static,
+        on_output: impl FnOnce(Result<O, E>) -> T + MaybeSend + 'static,
+    ) -> Self
+    where
+        F: Future<Output = Result<O, E>> + MaybeSend + 'static,
+        O: MaybeSend + 'static,
+        E: MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        Self::stream_with_progress(
+            move |progress| async move {
+                let mut number = 1;
+
+                loop {
+                    match job().await {
+                        Err(error) if number < policy.max_attempts => {
+                            let delay = policy.delay(number);
+
+                            progress.send(Attempt {
+                                number,
+                                error,
+                                delay,
+                            });
+
+                            time::sleep(delay).await;
+                            number += 1;
+                        }
+                        result => return result,
+                    }
+                }
+            },
+            on_attempt,
+            on_output,
+        )
+    }
+
+    /// Stops the [`Task`] if it has not finished after the given `duration`,
+    /// producing the `fallback` instead.
+    ///
+    /// Any output produced before the timeout is kept.
+    pub fn timeout(self, duration: Duration, fallback: T) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        let Some(stream) = self.stream else {
+            return self;
+        };
+
+        let timed_out = Arc::new(AtomicBool::new(false));
+
+        let timer = {
+            let timed_out = timed_out.clone();
+
+            async move {
+                time::sleep(duration).await;
+                timed_out.store(true, atomic::Ordering::Relaxed);
+            }
+        };
+
+        let fallback = stream::once(async move {
+            timed_out
+                .load(atomic::Ordering::Relaxed)
+                .then_some(Action::Output(fallback))
+        })
+        .filter_map(future::ready);
+
+        Self {
+            stream: Some(boxed_stream(
+                stream.take_until(timer).chain(fallback),
+            )),
+            units: self.units,
+            priority: self.priority,
+        }
+    }
+
+    /// Delays the [`Task`] by the given `duration`; discarding it if another
+    /// [`Task`] is debounced with the same `key` in the meantime.
+    ///
+    /// This is useful to react to bursts of events—like typing in a search
+    /// box—by only running the work for the last one.
+    ///
+    /// The `key` identifies the burst; any [`Hash`] value works, and its type
+    /// is part of its identity. Keys are tracked by the runtime that runs the
+    /// [`Task`]; so different runtimes never discard each other's tasks.
+    ///
+    /// ```
+    /// # use iced_runtime::Task;
+    /// # use iced_runtime::core::time::Duration;
+    /// # async fn search(_query: String) -> Vec<String> { Vec::new() }
+    /// enum Message {
+    ///     QueryChanged(String),
+    ///     ResultsFound(Vec<String>),
+    /// }
+    ///
+    /// fn update(message: Message) -> Task<Message> {
+    ///     match message {
+    ///         Message::QueryChanged(query) => {
+    ///             Task::perform(search(query), Message::ResultsFound)
+    ///                 .debounce("search", Duration::from_millis(300))
+    ///         }
+    ///         Message::ResultsFound(_results) => Task::none(),
+    ///     }
+    /// }
+    /// ```
+    pub fn debounce(self, key: impl Hash + 'static, duration: Duration) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+        let key = {
+            let mut hasher = Hasher::default();
+            key.type_id().hash(&mut hasher);
+            key.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        let generation = GENERATION.fetch_add(1, atomic::Ordering::Relaxed);
+
+        let delay = Task::future(async move { time::sleep(duration).await });
+
+        let is_latest = delay.then(move |()| {
+            oneshot(move |channel| {
+                Action::Debounce(Debounce::Finish {
+                    key,
+                    generation,
+                    channel,
+                })
+            })
+        });
+
+        let mut task = Some(self);
+
+        effect(Action::Debounce(Debounce::Start { key, generation }))
+            .chain(is_latest)
+            .then(move |is_latest| match task.take() {
+                Some(task) if is_latest => task,
+                _ => Task::none(),
+            })
+    }
+
     /// Creates a new [`Task`] that collects all the output of the current one into a [`Vec`].
     pub fn collect(self) -> Task<Vec<T>>
     where
@@ -209,6 +486,7 @@ impl<T> Task<T> {
                     .filter_map(future::ready),
                 )),
                 units: self.units,
+                priority: self.priority,
             },
         }
     }
@@ -245,6 +523,7 @@ impl<T> Task<T> {
             Self {
                 stream,
                 units: self.units,
+                priority: self.priority,
             },
             Handle { internal: handle },
         )
@@ -268,9 +547,19 @@ impl<T> Task<T> {
         Self {
             stream: Some(boxed_stream(stream.map(Action::Output))),
             units: 1,
+            priority: Priority::Interactive,
         }
     }
 
+    /// Sets the [`Priority`] of the [`Task`].
+    ///
+    /// Background tasks are only polled while no interactive tasks are
+    /// ready to make progress; so bulk work does not delay the response
+    /// to user input.
+    pub fn priority(self, priority: Priority) -> Self {
+        Self { priority, ..self }
+    }
+
     /// Returns the amount of work "units" of the [`Task`].
     pub fn units(&self) -> usize {
         self.units
@@ -281,6 +570,7 @@ impl<T> std::fmt::Debug for Task<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(&format!("Task<{}>", std::any::type_name::<T>()))
             .field("units", &self.units)
+            .field("priority", &self.priority)
             .finish()
     }
 }
@@ -350,6 +640,130 @@ impl Drop for Handle {
     }
 }
 
+/// A reporter of the progress of a [`Task`] created with
+/// [`Task::stream_with_progress`].
+#[derive(Debug)]
+pub struct Progress<P> {
+    sender: mpsc::UnboundedSender<P>,
+}
+
+impl<P> Progress<P> {
+    /// Reports the given progress.
+    ///
+    /// Reports sent after the [`Task`] is dropped are discarded.
+    pub fn send(&self, progress: P) {
+        let _ = self.sender.unbounded_send(progress);
+    }
+}
+
+impl<P> Clone for Progress<P> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// A policy to retry a failed [`Task`] with exponential backoff.
+///
+/// See [`Task::retry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Retry {
+    /// The maximum amount of attempts, including the first one.
+    pub max_attempts: usize,
+    /// The delay before the first retry.
+    pub initial_delay: Duration,
+    /// The maximum delay between attempts.
+    pub max_delay: Duration,
+    /// The factor the delay is multiplied by after every retry.
+    pub multiplier: f32,
+    /// The fraction of the delay that is randomized, between `0.0` and `1.0`;
+    /// so many clients failing at once do not retry in lockstep.
+    pub jitter: f32,
+}
+
+impl Retry {
+    /// Sets the maximum amount of attempts of the [`Retry`] policy.
+    pub fn max_attempts(self, max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            ..self
+        }
+    }
+
+    /// Sets the delay before the first retry of the [`Retry`] policy.
+    pub fn initial_delay(self, initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            ..self
+        }
+    }
+
+    /// Sets the maximum delay between attempts of the [`Retry`] policy.
+    pub fn max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+
+    /// Sets the delay multiplier of the [`Retry`] policy.
+    pub fn multiplier(self, multiplier: f32) -> Self {
+        Self { multiplier, ..self }
+    }
+
+    /// Sets the jitter of the [`Retry`] policy.
+    pub fn jitter(self, jitter: f32) -> Self {
+        Self {
+            jitter: jitter.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Returns the delay before retrying the given failed attempt, starting
+    /// at `1`.
+    ///
+    /// The delay is randomly shortened by up to the [`jitter`] fraction.
+    ///
+    /// [`jitter`]: Self::jitter
+    pub fn delay(&self, attempt: usize) -> Duration {
+        self.delay_with(attempt, fastrand::f32())
+    }
+
+    fn delay_with(&self, attempt: usize, random: f32) -> Duration {
+        let exponent =
+            i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+
+        let delay = (self.initial_delay.as_secs_f32()
+            * self.multiplier.powi(exponent))
+        .min(self.max_delay.as_secs_f32());
+
+        let delay = delay * (1.0 - self.jitter * random);
+
+        Duration::try_from_secs_f32(delay).unwrap_or(self.max_delay)
+    }
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+        }
+    }
+}
+
+/// A failed attempt of a [`Task::retry`] that will be retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt<E> {
+    /// The number of the attempt, starting at `1`.
+    pub number: usize,
+    /// The error of the attempt.
+    pub error: E,
+    /// The delay until the next attempt.
+    pub delay: Duration,
+}
+
 impl<T> Task<Option<T>> {
     /// Executes a new [`Task`] after this one, only when it produces `Some` value.
     ///
@@ -422,6 +836,7 @@ where
             }),
         ))),
         units: 1,
+        priority: Priority::Interactive,
     }
 }
 
@@ -441,6 +856,7 @@ where
                 .chain(receiver.map(|result| Action::Output(result))),
         )),
         units: 1,
+        priority: Priority::Interactive,
     }
 }
 
@@ -453,6 +869,69 @@ pub fn effect<T>(action: impl Into<Action<Infallible>>) -> Task<T> {
             action.output().expect_err("no output")
         }))),
         units: 1,
+        priority: Priority::Interactive,
+    }
+}
+
+/// An action to debounce a [`Task`]; see [`Task::debounce`].
+#[derive(Debug)]
+pub enum Debounce {
+    /// Starts a debounced [`Task`] with the given key and generation.
+    Start {
+        /// The hashed key of the [`Task`].
+        key: u64,
+        /// The unique generation of the [`Task`].
+        generation: u64,
+    },
+    /// Finishes the delay of a debounced [`Task`]; producing whether it is
+    /// still the latest of its key.
+    Finish {
+        /// The hashed key of the [`Task`].
+        key: u64,
+        /// The unique generation of the [`Task`].
+        generation: u64,
+        /// The channel to send back whether the [`Task`] must run.
+        channel: oneshot::Sender<bool>,
+    },
+}
+
+/// The debounced tasks of a runtime.
+///
+/// Each runtime keeps its own [`Debouncer`] and runs every [`Debounce`]
+/// action with it.
+#[derive(Debug, Default)]
+pub struct Debouncer {
+    latest: HashMap<u64, u64>,
+}
+
+impl Debouncer {
+    /// Creates a new [`Debouncer`] without any debounced tasks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the given [`Debounce`] action.
+    pub fn run(&mut self, action: Debounce) {
+        match action {
+            Debounce::Start { key, generation } => {
+                // Actions of different tasks may arrive in any order
+                let latest = self.latest.entry(key).or_insert(generation);
+                *latest = (*latest).max(generation);
+            }
+            Debounce::Finish {
+                key,
+                generation,
+                channel,
+            } => {
+                let is_latest = self.latest.get(&key) == Some(&generation);
+
+                if is_latest {
+                    let _ = self.latest.remove(&key);
+                }
+
+                let _ = channel.send(is_latest);
+            }
+        }
     }
 }
 
@@ -460,3 +939,291 @@ pub fn effect<T>(action: impl Into<Action<Infallible>>) -> Task<T> {
 pub fn into_stream<T>(task: Task<T>) -> Option<BoxStream<Action<T>>> {
     task.stream
 }
+
+/// Returns the underlying [`Stream`] of the [`Task`], together with its
+/// [`Priority`].
+pub fn into_stream_with_priority<T>(
+    task: Task<T>,
+) -> Option<(BoxStream<Action<T>>, Priority)> {
+    Some((task.stream?, task.priority))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::futures::Executor;
+    use crate::futures::backend::default;
+
+    /// Runs the given [`Task`] to completion; returning all of its output.
+    fn run<T>(task: Task<T>) -> Vec<T>
+    where
+        T: MaybeSend + 'static,
+    {
+        run_all([task])
+    }
+
+    /// Runs the given tasks concurrently in the same runtime; returning
+    /// all of their output.
+    fn run_all<T>(tasks: impl IntoIterator<Item = Task<T>>) -> Vec<T>
+    where
+        T: MaybeSend + 'static,
+    {
+        let stream =
+            stream::select_all(tasks.into_iter().filter_map(into_stream));
+
+        let executor = default::Executor::new().expect("Create executor");
+        let mut debouncer = Debouncer::new();
+
+        // The timers of the executor may need its context to be entered
+        Executor::enter(&executor, || {
+            futures::executor::block_on(
+                stream
+                    .filter_map(|action| {
+                        future::ready(match action {
+                            Action::Output(output) => Some(output),
+                            Action::Debounce(action) => {
+                                debouncer.run(action);
+                                None
+                            }
+                            _ => None,
+                        })
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Progressed(u32),
+        Finished(&'static str),
+    }
+
+    #[test]
+    fn stream_with_progress_produces_output_after_progress() {
+        let task = Task::stream_with_progress(
+            |progress| async move {
+                for step in 0..3 {
+                    progress.send(step);
+                }
+
+                "done"
+            },
+            Message::Progressed,
+            Message::Finished,
+        );
+
+        assert_eq!(
+            run(task),
+            vec![
+                Message::Progressed(0),
+                Message::Progressed(1),
+                Message::Progressed(2),
+                Message::Finished("done"),
+            ]
+        );
+    }
+
+    #[test]
+    fn timeout_produces_fallback_when_expired() {
+        let task = Task::future(async {
+            time::sleep(Duration::from_secs(5)).await;
+            1
+        })
+        .timeout(Duration::from_millis(10), 0);
+
+        assert_eq!(run(task), vec![0]);
+    }
+
+    #[test]
+    fn timeout_keeps_output_produced_in_time() {
+        let task = Task::done(1)
+            .chain(Task::future(async {
+                time::sleep(Duration::from_secs(5)).await;
+                2
+            }))
+            .timeout(Duration::from_millis(10), 0);
+
+        assert_eq!(run(task), vec![1, 0]);
+        assert_eq!(
+            run(Task::done(1).timeout(Duration::from_secs(5), 0)),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn perform_blocking_produces_output() {
+        let task =
+            Task::perform_blocking(|| (1..=10).sum::<u32>(), |sum| sum * 2);
+
+        assert_eq!(run(task), vec![110]);
+    }
+
+    #[test]
+    fn perform_blocking_produces_nothing_on_panic() {
+        let task = Task::perform_blocking(
+            || -> u32 { panic!("blocking task panicked on purpose") },
+            |value| value,
+        );
+
+        assert_eq!(run(task), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn debounce_runs_only_the_latest_task_of_a_key() {
+        let debounced = |value| {
+            Task::done(value).debounce("key", Duration::from_millis(10))
+        };
+
+        assert_eq!(
+            run_all([debounced(1), debounced(2), debounced(3)]),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn debounce_keeps_the_latest_task_of_every_key() {
+        let delay = Duration::from_millis(10);
+
+        let mut output = run_all([
+            Task::done(1).debounce("a", delay),
+            Task::done(2).debounce("b", delay),
+            Task::done(3).debounce(0_u8, delay),
+            Task::done(4).debounce(0_u16, delay),
+            Task::done(5).debounce("a", delay),
+        ]);
+
+        output.sort_unstable();
+
+        assert_eq!(output, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn debounce_cancels_replaced_tasks_before_they_start() {
+        let has_started = Arc::new(AtomicBool::new(false));
+
+        let replaced = {
+            let has_started = has_started.clone();
+
+            Task::future(async move {
+                has_started.store(true, atomic::Ordering::Relaxed);
+                1
+            })
+        };
+
+        let delay = Duration::from_millis(10);
+
+        assert_eq!(
+            run_all([
+                replaced.debounce("key", delay),
+                Task::done(2).debounce("key", delay),
+            ]),
+            vec![2]
+        );
+        assert!(!has_started.load(atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    fn debouncer_keeps_the_latest_generation() {
+        let mut debouncer = Debouncer::new();
+
+        let finish = |debouncer: &mut Debouncer, generation| {
+            let (channel, mut receiver) = oneshot::channel();
+
+            debouncer.run(Debounce::Finish {
+                key: 0,
+                generation,
+                channel,
+            });
+
+            receiver.try_recv().ok().flatten()
+        };
+
+        // Tasks may start out of order
+        debouncer.run(Debounce::Start {
+            key: 0,
+            generation: 2,
+        });
+        debouncer.run(Debounce::Start {
+            key: 0,
+            generation: 1,
+        });
+
+        assert_eq!(finish(&mut debouncer, 1), Some(false));
+        assert_eq!(finish(&mut debouncer, 2), Some(true));
+        assert_eq!(finish(&mut debouncer, 2), Some(false));
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially() {
+        let policy = Retry::default()
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .multiplier(2.0)
+            .jitter(0.0);
+
+        let delays: Vec<_> = (1..=6)
+            .map(|attempt| policy.delay(attempt).as_millis())
+            .collect();
+
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(policy.delay(usize::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn retry_delay_stays_within_jitter_bounds() {
+        let policy = Retry::default()
+            .initial_delay(Duration::from_secs(1))
+            .multiplier(1.0)
+            .jitter(0.25);
+
+        assert_eq!(policy.delay_with(1, 0.0), Duration::from_secs(1));
+        assert_eq!(policy.delay_with(1, 1.0), Duration::from_millis(750));
+
+        for _ in 0..1_000 {
+            let delay = policy.delay(1);
+
+            assert!(delay >= Duration::from_millis(750));
+            assert!(delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn retry_delay_is_randomized() {
+        let policy = Retry::default().jitter(1.0);
+
+        let delays: std::collections::HashSet<_> =
+            (0..100).map(|_| policy.delay(1)).collect();
+
+        assert!(delays.len() > 1);
+    }
+
+    #[test]
+    fn batch_priority_is_the_highest_of_its_tasks() {
+        let background =
+            || Task::<()>::future(async {}).priority(Priority::Background);
+
+        let interactive = || Task::<()>::future(async {});
+
+        assert_eq!(
+            Task::<()>::batch(std::iter::empty()).priority,
+            Priority::Interactive
+        );
+        assert_eq!(
+            Task::batch([Task::<()>::none(), Task::none()]).priority,
+            Priority::Interactive
+        );
+        assert_eq!(
+            Task::batch([background(), background()]).priority,
+            Priority::Background
+        );
+        assert_eq!(
+            Task::batch([background(), Task::none()]).priority,
+            Priority::Background
+        );
+        assert_eq!(
+            Task::batch([background(), interactive()]).priority,
+            Priority::Interactive
+        );
+    }
+}
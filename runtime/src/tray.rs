@@ -0,0 +1,299 @@
+//! Show an icon with a menu in the system tray.
+//!
+//! A tray icon is commonly used to keep an application running in the
+//! background after its windows are closed. To do so:
+//!
+//! 1. Open a tray icon with [`open`].
+//! 2. Disable `exit_on_close_request` in the window settings, listen to
+//!    `window::close_requests`, and hide the window with `window::set_mode`.
+//! 3. Listen to the [`events`] of the tray icon and [`restore`] the window
+//!    when the icon is clicked.
+//!
+//! ## Platform-specific
+//!
+//! Tray icons are only supported on Windows and macOS. On other platforms,
+//! opening a tray icon does nothing and no [`events`] are produced; so the
+//! windows of an application should stay reachable without it.
+use crate::core::window::{self, Icon, Mode};
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::stream::{Stream, StreamExt};
+use crate::futures::{MaybeSend, Subscription};
+use crate::task::{self, Task};
+
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::sync::atomic::{self, AtomicU64};
+use std::task::{Context, Poll};
+
+/// The identifier of a tray icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+static COUNT: AtomicU64 = AtomicU64::new(1);
+
+impl Id {
+    /// Creates a new unique tray icon [`Id`].
+    pub fn unique() -> Id {
+        Id(COUNT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::str::FromStr for Id {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Id)
+    }
+}
+
+/// The settings of a tray icon.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The [`Icon`] shown in the tray.
+    pub icon: Icon,
+    /// The text shown when hovering the icon, if any.
+    pub tooltip: Option<String>,
+    /// The items of the menu shown when the icon is right-clicked.
+    pub menu: Vec<Item>,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] for a tray icon with the given [`Icon`].
+    pub fn new(icon: Icon) -> Self {
+        Self {
+            icon,
+            tooltip: None,
+            menu: Vec::new(),
+        }
+    }
+
+    /// Sets the tooltip of the tray icon.
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Adds an [`Item`] to the menu of the tray icon.
+    pub fn item(mut self, item: Item) -> Self {
+        self.menu.push(item);
+        self
+    }
+}
+
+/// An item in the menu of a tray icon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    /// An entry that produces an [`Event::MenuSelected`] when selected.
+    Entry {
+        /// The identifier of the entry, produced in [`Event::MenuSelected`].
+        id: String,
+        /// The label of the entry.
+        label: String,
+        /// Whether the entry can be selected.
+        is_enabled: bool,
+    },
+    /// A line separating groups of entries.
+    Separator,
+}
+
+impl Item {
+    /// Creates a new enabled [`Item::Entry`].
+    pub fn entry(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::Entry {
+            id: id.into(),
+            label: label.into(),
+            is_enabled: true,
+        }
+    }
+
+    /// Creates a new [`Item::Separator`].
+    pub fn separator() -> Self {
+        Self::Separator
+    }
+}
+
+/// An interaction with a tray icon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The tray icon was clicked.
+    Clicked,
+    /// The tray icon was double clicked.
+    DoubleClicked,
+    /// The menu entry with the given identifier was selected.
+    MenuSelected(String),
+}
+
+/// A tray action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Open a tray icon with the given [`Settings`].
+    Open(Id, Settings),
+
+    /// Close the tray icon.
+    Close(Id),
+
+    /// Change the [`Icon`] of the tray icon.
+    SetIcon(Id, Icon),
+
+    /// Change the tooltip of the tray icon.
+    SetTooltip(Id, Option<String>),
+}
+
+/// Opens a new tray icon with the given [`Settings`].
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** Supported.
+/// - **Linux / Web:** Unsupported.
+pub fn open<T>(settings: Settings) -> (Id, Task<T>) {
+    let id = Id::unique();
+
+    (
+        id,
+        task::effect(crate::Action::Tray(Action::Open(id, settings))),
+    )
+}
+
+/// Closes the tray icon with the given [`Id`].
+pub fn close<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Tray(Action::Close(id)))
+}
+
+/// Changes the [`Icon`] of the tray icon; for instance, to indicate some
+/// status.
+pub fn set_icon<T>(id: Id, icon: Icon) -> Task<T> {
+    task::effect(crate::Action::Tray(Action::SetIcon(id, icon)))
+}
+
+/// Changes the tooltip of the tray icon.
+pub fn set_tooltip<T>(id: Id, tooltip: Option<String>) -> Task<T> {
+    task::effect(crate::Action::Tray(Action::SetTooltip(id, tooltip)))
+}
+
+/// Shows, unminimizes, and focuses the window with the given [`window::Id`];
+/// normally, after a tray icon is clicked.
+///
+/// Nothing happens if the window does not exist anymore.
+pub fn restore<T>(window: window::Id) -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    crate::window::get_mode(window).then(move |mode| {
+        let show = if mode == Mode::Hidden {
+            crate::window::set_mode(window, Mode::Windowed)
+        } else {
+            Task::none()
+        };
+
+        show.chain(crate::window::minimize(window, false))
+            .chain(crate::window::gain_focus(window))
+    })
+}
+
+/// Subscribes to the [`Event`] of every tray icon.
+pub fn events() -> Subscription<(Id, Event)> {
+    Subscription::run(Listener::new)
+}
+
+static LISTENERS: Mutex<Vec<(u64, mpsc::UnboundedSender<(Id, Event)>)>> =
+    Mutex::new(Vec::new());
+
+/// A stream of tray events that stops listening when dropped.
+struct Listener {
+    id: u64,
+    receiver: mpsc::UnboundedReceiver<(Id, Event)>,
+}
+
+impl Listener {
+    fn new() -> Self {
+        static COUNT: AtomicU64 = AtomicU64::new(0);
+
+        let id = COUNT.fetch_add(1, atomic::Ordering::Relaxed);
+        let (sender, receiver) = mpsc::unbounded();
+
+        LISTENERS
+            .lock()
+            .expect("Lock tray listeners")
+            .push((id, sender));
+
+        Self { id, receiver }
+    }
+}
+
+impl Stream for Listener {
+    type Item = (Id, Event);
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        LISTENERS
+            .lock()
+            .expect("Lock tray listeners")
+            .retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Notifies the [`events`] listeners of an [`Event`] of the tray icon with
+/// the given [`Id`].
+///
+/// This method is meant to be used by runtimes implementing tray icons.
+pub fn notify(id: Id, event: Event) {
+    let mut listeners = LISTENERS.lock().expect("Lock tray listeners");
+
+    listeners.retain(|(_, listener)| {
+        listener.unbounded_send((id, event.clone())).is_ok()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+
+    fn is_listening(id: u64) -> bool {
+        LISTENERS
+            .lock()
+            .expect("Lock tray listeners")
+            .iter()
+            .any(|(listener, _)| *listener == id)
+    }
+
+    #[test]
+    fn listeners_receive_notified_events() {
+        let mut listener = Listener::new();
+        let id = Id::unique();
+
+        notify(id, Event::Clicked);
+
+        assert_eq!(block_on(listener.next()), Some((id, Event::Clicked)));
+    }
+
+    #[test]
+    fn listeners_stop_listening_when_dropped() {
+        let listener = Listener::new();
+        let other = Listener::new();
+        let id = listener.id;
+
+        assert!(is_listening(id));
+
+        drop(listener);
+
+        assert!(!is_listening(id));
+        assert!(is_listening(other.id));
+    }
+}
@@ -3,7 +3,7 @@ use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget;
+use crate::core::widget::{self, Operation as _};
 use crate::core::window;
 use crate::core::{
     Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector,
@@ -29,6 +29,8 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<Overlay>,
     bounds: Size,
+    overlay_bounds: Size,
+    focus: Focus,
 }
 
 struct Overlay {
@@ -99,7 +101,7 @@ where
     ) -> Self {
         let root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache { mut state, focus } = cache;
         state.diff(root.as_widget());
 
         let base = root.as_widget().layout(
@@ -114,6 +116,8 @@ where
             state,
             overlay: None,
             bounds,
+            overlay_bounds: bounds,
+            focus,
         }
     }
 
@@ -195,6 +199,8 @@ where
         let mut input_method = InputMethod::Disabled;
         let viewport = Rectangle::with_size(self.bounds);
 
+        self.restore_focus(renderer);
+
         let mut maybe_overlay = self
             .root
             .as_widget_mut()
@@ -209,7 +215,7 @@ where
 
         let (base_cursor, overlay_statuses, overlay_interaction) =
             if maybe_overlay.is_some() {
-                let bounds = self.bounds;
+                let bounds = self.overlay_bounds;
 
                 let mut overlay = maybe_overlay.as_mut().unwrap();
                 let mut layout = overlay.layout(renderer, bounds);
@@ -353,7 +359,8 @@ where
                         )
                         .map(overlay::Nested::new)
                     {
-                        let layout = overlay.layout(renderer, self.bounds);
+                        let layout =
+                            overlay.layout(renderer, self.overlay_bounds);
                         let interaction = overlay.mouse_interaction(
                             Layout::new(&layout),
                             cursor,
@@ -481,6 +488,24 @@ where
         theme: &Theme,
         style: &renderer::Style,
         cursor: mouse::Cursor,
+    ) {
+        self.draw_base(renderer, theme, style, cursor);
+        self.draw_overlay(renderer, theme, style, cursor);
+    }
+
+    /// Draws the [`UserInterface`] with the provided [`Renderer`]; without
+    /// its overlay.
+    ///
+    /// This is useful to draw the overlay somewhere else with
+    /// [`draw_overlay`](Self::draw_overlay); like in a popup window.
+    ///
+    /// [`Renderer`]: crate::core::Renderer
+    pub fn draw_base(
+        &mut self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        cursor: mouse::Cursor,
     ) {
         // TODO: Move to shell level (?)
         renderer.clear();
@@ -505,6 +530,20 @@ where
             base_cursor,
             &viewport,
         );
+    }
+
+    /// Draws the overlay of the [`UserInterface`], if any, with the provided
+    /// [`Renderer`].
+    ///
+    /// [`Renderer`]: crate::core::Renderer
+    pub fn draw_overlay(
+        &mut self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        cursor: mouse::Cursor,
+    ) {
+        let viewport = Rectangle::with_size(self.bounds);
 
         let Self {
             overlay,
@@ -562,7 +601,7 @@ where
         {
             if self.overlay.is_none() {
                 self.overlay = Some(Overlay {
-                    layout: overlay.layout(renderer, self.bounds),
+                    layout: overlay.layout(renderer, self.overlay_bounds),
                     interaction: mouse::Interaction::None,
                 });
             }
@@ -578,20 +617,138 @@ where
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                focus: self.focus,
+            },
+            renderer,
+        )
+    }
+
+    /// Sets the bounds available to the overlays of the [`UserInterface`].
+    ///
+    /// By default, overlays are laid out within the bounds of the
+    /// [`UserInterface`]. Bigger bounds let them overflow it; for instance,
+    /// when they are drawn in popup windows with
+    /// [`draw_overlay`](Self::draw_overlay).
+    pub fn set_overlay_bounds(&mut self, bounds: Size) {
+        if self.overlay_bounds != bounds {
+            self.overlay_bounds = bounds;
+            self.overlay = None;
+        }
+    }
+
+    /// Returns the bounds covered by the overlay of the [`UserInterface`],
+    /// if any.
+    pub fn overlay_extent(&self) -> Option<Rectangle> {
+        fn extent(layout: Layout<'_>, bounds: Size) -> Option<Rectangle> {
+            // Groups of overlays fill the whole bounds
+            if layout.bounds() == Rectangle::with_size(bounds) {
+                return layout
+                    .children()
+                    .filter_map(|child| extent(child, bounds))
+                    .reduce(|a, b| a.union(&b));
+            }
+
+            Some(layout.bounds())
+        }
+
+        fn nested(layout: Layout<'_>, bounds: Size) -> Option<Rectangle> {
+            let mut children = layout.children();
+
+            let overlay = extent(children.next()?, bounds);
+            let nested =
+                children.next().and_then(|child| nested(child, bounds));
+
+            match (overlay, nested) {
+                (Some(a), Some(b)) => Some(a.union(&b)),
+                (a, b) => a.or(b),
+            }
+        }
+
+        let Overlay { layout, .. } = self.overlay.as_ref()?;
+
+        nested(Layout::new(layout), self.overlay_bounds)
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            focus: self.focus,
+        }
     }
+
+    /// Refocuses the widget that was focused before an overlay opened, once
+    /// it closes; unless some other widget got focused in the meantime.
+    ///
+    /// Only widgets with an [`Id`](widget::Id) can be refocused.
+    fn restore_focus(&mut self, renderer: &Renderer) {
+        let is_overlay_open = self
+            .root
+            .as_widget_mut()
+            .overlay(
+                &mut self.state,
+                Layout::new(&self.base),
+                renderer,
+                &Rectangle::with_size(self.bounds),
+                Vector::ZERO,
+            )
+            .is_some();
+
+        let was_overlay_open =
+            std::mem::replace(&mut self.focus.is_overlay_open, is_overlay_open);
+
+        if is_overlay_open {
+            return;
+        }
+
+        let mut find_focused = widget::operation::focusable::find_focused();
+
+        self.root.as_widget().operate(
+            &mut self.state,
+            Layout::new(&self.base),
+            renderer,
+            &mut widget::operation::black_box(&mut find_focused),
+        );
+
+        let focused = match find_focused.finish() {
+            widget::operation::Outcome::Some(id) => Some(id),
+            _ => None,
+        };
+
+        match (focused, self.focus.last.clone()) {
+            (None, Some(last)) if was_overlay_open => {
+                self.root.as_widget().operate(
+                    &mut self.state,
+                    Layout::new(&self.base),
+                    renderer,
+                    &mut widget::operation::focusable::focus(last),
+                );
+            }
+            (focused, _) => {
+                self.focus.last = focused;
+            }
+        }
+    }
+}
+
+/// The focus of a [`UserInterface`] around its overlays.
+#[derive(Debug, Default)]
+struct Focus {
+    last: Option<widget::Id>,
+    is_overlay_open: bool,
 }
 
 /// Reusable data of a specific [`UserInterface`].
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    focus: Focus,
 }
 
 impl Cache {
@@ -602,6 +759,7 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            focus: Focus::default(),
         }
     }
 }
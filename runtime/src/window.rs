@@ -1,10 +1,14 @@
 //! Build window-based GUI applications.
 use crate::core::time::Instant;
+use crate::core::widget;
+use crate::core::widget::operation;
+use crate::core::window::screenshot::CropError;
 use crate::core::window::{
-    Direction, Event, Icon, Id, Level, Mode, Screenshot, Settings,
-    UserAttention,
+    Direction, Edge, Edges, Effect, Event, FrameRate, Icon, Id, Layout, Level,
+    Mode, Monitor, PresentMode, Progress, Screenshot, Settings, UserAttention,
+    VideoMode,
 };
-use crate::core::{Point, Size};
+use crate::core::{Antialiasing, Font, Point, Rectangle, Size};
 use crate::futures::Subscription;
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
@@ -14,15 +18,39 @@ pub use raw_window_handle;
 
 use raw_window_handle::WindowHandle;
 
+use std::any::Any;
+
 /// An operation to be performed on some window.
 #[allow(missing_debug_implementations)]
 pub enum Action {
     /// Opens a new window with some [`Settings`].
     Open(Id, Settings, oneshot::Sender<Id>),
 
+    /// Opens a new window with some [`Settings`] as a modal of the parent
+    /// window; producing its result once closed.
+    OpenModal(
+        Id,
+        Id,
+        Settings,
+        oneshot::Sender<Option<Box<dyn Any + Send>>>,
+    ),
+
+    /// Close a modal window with the given result.
+    CloseModal(Id, Box<dyn Any + Send>),
+
     /// Close the window and exits the application.
     Close(Id),
 
+    /// Hold the close requests of the window until the close is confirmed
+    /// or canceled.
+    HoldClose(Id),
+
+    /// Close the window if its close is being held.
+    ConfirmClose(Id),
+
+    /// Stop holding the close requests of the window.
+    CancelClose(Id),
+
     /// Gets the [`Id`] of the oldest window.
     GetOldest(oneshot::Sender<Option<Id>>),
 
@@ -67,9 +95,16 @@ pub enum Action {
     /// Get the current logical coordinates of the window.
     GetPosition(Id, oneshot::Sender<Option<Point>>),
 
+    /// Get the other window under the given logical coordinates, relative
+    /// to the window; together with the coordinates relative to it.
+    GetWindowAt(Id, Point, oneshot::Sender<Option<(Id, Point)>>),
+
     /// Get the current scale factor (DPI) of the window.
     GetScaleFactor(Id, oneshot::Sender<f32>),
 
+    /// Override the default [`Font`] of the program in the window.
+    SetDefaultFont(Id, Option<Font>),
+
     /// Move the window to the given logical coordinates.
     ///
     /// Unsupported on Wayland.
@@ -106,6 +141,17 @@ pub enum Action {
     /// - **Wayland:** Requires `xdg_activation_v1` protocol, `None` has no effect.
     RequestUserAttention(Id, Option<UserAttention>),
 
+    /// Show the given [`Progress`] in the taskbar button or dock icon of
+    /// the window; or hide it if `None`.
+    SetProgress(Id, Option<Progress>),
+
+    /// Show the given count as a badge in the taskbar button or dock icon
+    /// of the window; or hide it if `None`.
+    SetBadge(Id, Option<u32>),
+
+    /// Set the background [`Effect`] of the window.
+    SetBackgroundEffect(Id, Effect),
+
     /// Bring the window to the front and sets input focus. Has no effect if the window is
     /// already in focus, minimized, or not visible.
     ///
@@ -121,6 +167,23 @@ pub enum Action {
     /// Change the window [`Level`].
     SetLevel(Id, Level),
 
+    /// Get the current window [`Level`].
+    GetLevel(Id, oneshot::Sender<Level>),
+
+    /// Get every [`VideoMode`] supported by the current monitor of the
+    /// window.
+    GetVideoModes(Id, oneshot::Sender<Vec<VideoMode>>),
+
+    /// Get every [`Monitor`] connected to the system.
+    GetMonitors(oneshot::Sender<Vec<Monitor>>),
+
+    /// Move the window to the center of the given [`Monitor`].
+    MoveToMonitor(Id, Monitor),
+
+    /// Move and resize the window into the given [`Layout`] of the work
+    /// area of its current monitor.
+    Tile(Id, Layout),
+
     /// Show the system menu at cursor position.
     ///
     /// ## Platform-specific
@@ -149,8 +212,9 @@ pub enum Action {
     /// Runs the closure with the native window handle of the window with the given [`Id`].
     RunWithHandle(Id, Box<dyn FnOnce(WindowHandle<'_>) + Send>),
 
-    /// Screenshot the viewport of the window.
-    Screenshot(Id, oneshot::Sender<Screenshot>),
+    /// Screenshot the viewport of the window, optionally at a specific
+    /// scale factor.
+    Screenshot(Id, Option<f32>, oneshot::Sender<Screenshot>),
 
     /// Enables mouse passthrough for the given window.
     ///
@@ -175,6 +239,50 @@ pub enum Action {
 
     /// Set the window size increment.
     SetResizeIncrements(Id, Option<Size>),
+
+    /// Turn the window into a compact picture-in-picture frame of the given
+    /// logical size.
+    EnterPip(Id, Size),
+
+    /// Restore the window from picture-in-picture.
+    ExitPip(Id),
+
+    /// Set whether the window has a system drop shadow when undecorated.
+    SetShadow(Id, bool),
+
+    /// Set the logical width of the resize border of the window when
+    /// undecorated.
+    SetResizeBorder(Id, f32),
+
+    /// Set the [`Edges`] of the window that can be dragged to resize it.
+    SetResizableEdges(Id, Edges),
+
+    /// Set the ratio between the width and the height of the window.
+    SetAspectRatio(Id, Option<f32>),
+
+    /// Set the [`Antialiasing`] strategy of the window.
+    SetAntialiasing(Id, Option<Antialiasing>),
+
+    /// Set the [`PresentMode`] of the window.
+    SetPresentMode(Id, PresentMode),
+
+    /// Set the [`FrameRate`] of the window.
+    SetFrameRate(Id, FrameRate),
+
+    /// Set the identifier of the group of native tabs the window joins.
+    SetTabbingIdentifier(Id, String),
+
+    /// Select the next native tab of the group of the window.
+    SelectNextTab(Id),
+
+    /// Select the previous native tab of the group of the window.
+    SelectPreviousTab(Id),
+
+    /// Select the native tab at the given index of the group of the window.
+    SelectTab(Id, usize),
+
+    /// Get the amount of native tabs in the group of the window.
+    GetTabCount(Id, oneshot::Sender<usize>),
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -260,11 +368,100 @@ pub fn open(settings: Settings) -> (Id, Task<Id>) {
     )
 }
 
+/// Opens a new window with the given [`Settings`] as a modal of the `parent`
+/// window; producing the result given to [`close_modal`] once the modal is
+/// closed, or `None` if it is closed in any other way.
+///
+/// While the modal is open, the parent window ignores any user input and is
+/// dimmed. Clicking it focuses the modal instead. The modal is centered on
+/// the parent window, unless its [`Settings`] have a specific [`Position`].
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** The modal is always kept above the parent window.
+/// - **Linux / Web:** The modal may be placed behind the parent window.
+///
+/// [`Position`]: crate::core::window::Position
+pub fn open_modal<R>(parent: Id, settings: Settings) -> (Id, Task<Option<R>>)
+where
+    R: Send + 'static,
+{
+    use crate::core::window::Position;
+
+    let id = Id::unique();
+
+    let settings = Settings {
+        position: if settings.position == Position::Default {
+            Position::CenteredOnParent(parent)
+        } else {
+            settings.position
+        },
+        ..settings
+    };
+
+    (
+        id,
+        task::oneshot(|channel| {
+            crate::Action::Window(Action::OpenModal(
+                id, parent, settings, channel,
+            ))
+        })
+        .map(|result| {
+            result
+                .and_then(|result| result.downcast::<R>().ok())
+                .map(|result| *result)
+        }),
+    )
+}
+
+/// Closes the modal window with `id`; producing the given result in the
+/// [`Task`] returned by [`open_modal`].
+///
+/// The result must have the type expected by [`open_modal`]; otherwise, the
+/// modal produces `None`.
+pub fn close_modal<T, R>(id: Id, result: R) -> Task<T>
+where
+    R: Send + 'static,
+{
+    task::effect(crate::Action::Window(Action::CloseModal(
+        id,
+        Box::new(result),
+    )))
+}
+
 /// Closes the window with `id`.
 pub fn close<T>(id: Id) -> Task<T> {
     task::effect(crate::Action::Window(Action::Close(id)))
 }
 
+/// Holds the close of the window with `id`; normally, after receiving one of
+/// its [`close_requests`] and before asking the user to confirm it.
+///
+/// While held, new close requests of the window are ignored; so pressing
+/// the close button again does not produce duplicate confirmation dialogs.
+/// The close must be finished with [`confirm_close`] or [`cancel_close`].
+///
+/// Close requests are only produced if `exit_on_close_request` is disabled
+/// in the [`Settings`] of the window.
+pub fn hold_close<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::HoldClose(id)))
+}
+
+/// Closes the window with `id` if its close is being held with
+/// [`hold_close`].
+///
+/// Nothing happens if the close was canceled in the meantime; so a late
+/// confirmation never closes a window the user decided to keep.
+pub fn confirm_close<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ConfirmClose(id)))
+}
+
+/// Cancels the close of the window with `id` held with [`hold_close`]; so
+/// new close requests are produced again.
+pub fn cancel_close<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::CancelClose(id)))
+}
+
 /// Gets the window [`Id`] of the oldest window.
 pub fn get_oldest() -> Task<Option<Id>> {
     task::oneshot(|channel| crate::Action::Window(Action::GetOldest(channel)))
@@ -295,6 +492,101 @@ pub fn set_resizable<T>(id: Id, resizable: bool) -> Task<T> {
     task::effect(crate::Action::Window(Action::SetResizable(id, resizable)))
 }
 
+/// Sets the [`Antialiasing`] strategy used to render the window.
+///
+/// `None` disables antialiasing. Renderers that do not support multisampling
+/// ignore this setting.
+pub fn set_antialiasing<T>(
+    id: Id,
+    antialiasing: Option<Antialiasing>,
+) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetAntialiasing(
+        id,
+        antialiasing,
+    )))
+}
+
+/// Sets the [`PresentMode`] of the window.
+///
+/// Latency-sensitive applications can use [`PresentMode::LowLatency`] to
+/// trade tearing for responsiveness. Unsupported modes fall back to vsync.
+pub fn set_present_mode<T>(id: Id, present_mode: PresentMode) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetPresentMode(
+        id,
+        present_mode,
+    )))
+}
+
+/// Sets the [`FrameRate`] of the window; overriding the [`FrameRate`] of the
+/// application.
+pub fn set_frame_rate<T>(id: Id, frame_rate: FrameRate) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetFrameRate(id, frame_rate)))
+}
+
+/// Sets the identifier of the group of native tabs the window with the given
+/// [`Id`] joins; so the document windows of an application can be grouped
+/// into tabs by the system.
+///
+/// Tabs can be merged by the user with the "Merge All Windows" item of the
+/// Window menu.
+///
+/// ## Platform-specific
+///
+/// - **macOS:** Supported.
+/// - **Windows / Linux / Web:** Unsupported.
+pub fn set_tabbing_identifier<T>(
+    id: Id,
+    identifier: impl Into<String>,
+) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetTabbingIdentifier(
+        id,
+        identifier.into(),
+    )))
+}
+
+/// Selects the next native tab of the group of the window with the given
+/// [`Id`].
+///
+/// ## Platform-specific
+///
+/// - **macOS:** Supported.
+/// - **Windows / Linux / Web:** Unsupported.
+pub fn select_next_tab<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SelectNextTab(id)))
+}
+
+/// Selects the previous native tab of the group of the window with the given
+/// [`Id`].
+///
+/// ## Platform-specific
+///
+/// - **macOS:** Supported.
+/// - **Windows / Linux / Web:** Unsupported.
+pub fn select_previous_tab<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SelectPreviousTab(id)))
+}
+
+/// Selects the native tab at the given index of the group of the window with
+/// the given [`Id`].
+///
+/// ## Platform-specific
+///
+/// - **macOS:** Supported.
+/// - **Windows / Linux / Web:** Unsupported.
+pub fn select_tab<T>(id: Id, index: usize) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SelectTab(id, index)))
+}
+
+/// Gets the amount of native tabs in the group of the window with the given
+/// [`Id`].
+///
+/// Windows without native tabs count as a single tab.
+pub fn get_tab_count(id: Id) -> Task<usize> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetTabCount(id, channel))
+    })
+}
+
 /// Set the inner maximum size of the window.
 pub fn set_max_size<T>(id: Id, size: Option<Size>) -> Task<T> {
     task::effect(crate::Action::Window(Action::SetMaxSize(id, size)))
@@ -314,6 +606,61 @@ pub fn set_resize_increments<T>(id: Id, increments: Option<Size>) -> Task<T> {
     )))
 }
 
+/// Turns the window into a compact picture-in-picture frame of the given
+/// logical size; frameless, always on top, and placed at the bottom-right
+/// corner of its monitor.
+///
+/// The previous state of the window is restored with [`exit_pip`].
+///
+/// Since the window has no decorations while in picture-in-picture, its
+/// contents should provide their own controls; for instance, shown only
+/// when hovered with a `mouse_area` and moved with [`drag`].
+pub fn enter_pip<T>(id: Id, size: impl Into<Size>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::EnterPip(id, size.into())))
+}
+
+/// Restores the window to the state it had before [`enter_pip`].
+pub fn exit_pip<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ExitPip(id)))
+}
+
+/// Sets whether the window has a drop shadow drawn by the system when it has
+/// no decorations; so windows with custom title bars do not look flat.
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS:** Supported.
+/// - **Linux / Web:** Unsupported.
+pub fn set_shadow<T>(id: Id, shadow: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetShadow(id, shadow)))
+}
+
+/// Sets the logical width of the invisible border that can be dragged to
+/// resize the window when it has no decorations.
+///
+/// A width of `0.0` disables the border.
+pub fn set_resize_border<T>(id: Id, width: f32) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetResizeBorder(id, width)))
+}
+
+/// Sets the [`Edges`] of the window that can be dragged to resize it; like
+/// only the left and right edges of a sidebar.
+///
+/// Disabling both edges of an axis fixes the size of the window in that
+/// axis. Only the resize border of undecorated windows can disable a single
+/// edge of an axis.
+pub fn set_resizable_edges<T>(id: Id, edges: Edges) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetResizableEdges(id, edges)))
+}
+
+/// Set the ratio between the width and the height of the window that must be
+/// kept while resizing; or `None` to allow any size.
+///
+/// This is usually used by apps such as video players.
+pub fn set_aspect_ratio<T>(id: Id, ratio: Option<f32>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetAspectRatio(id, ratio)))
+}
+
 /// Get the window's size in logical dimensions.
 pub fn get_size(id: Id) -> Task<Size> {
     task::oneshot(move |channel| {
@@ -352,6 +699,20 @@ pub fn get_position(id: Id) -> Task<Option<Point>> {
     })
 }
 
+/// Gets the window of the application, other than the one with the given
+/// [`Id`], that is under the given logical coordinates relative to it;
+/// together with the coordinates relative to the found window.
+///
+/// This is useful to find where a tab torn out of a window was dropped; for
+/// instance, to merge it back into another window.
+///
+/// The coordinates may lay outside of the window with the given [`Id`].
+pub fn get_window_at(id: Id, position: Point) -> Task<Option<(Id, Point)>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetWindowAt(id, position, channel))
+    })
+}
+
 /// Gets the scale factor of the window with the given [`Id`].
 pub fn get_scale_factor(id: Id) -> Task<f32> {
     task::oneshot(move |channel| {
@@ -359,6 +720,14 @@ pub fn get_scale_factor(id: Id) -> Task<f32> {
     })
 }
 
+/// Overrides the default [`Font`] of the program in the window with the
+/// given [`Id`].
+///
+/// `None` restores the default [`Font`] of the program.
+pub fn set_default_font<T>(id: Id, font: Option<Font>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetDefaultFont(id, font)))
+}
+
 /// Moves the window to the given logical coordinates.
 pub fn move_to<T>(id: Id, position: Point) -> Task<T> {
     task::effect(crate::Action::Window(Action::Move(id, position)))
@@ -402,6 +771,76 @@ pub fn request_user_attention<T>(
     )))
 }
 
+/// Requests the attention of the user to the window; like flashing its
+/// taskbar button or bouncing the dock icon of the application.
+///
+/// This can be useful to notify the user when a long task finishes or a
+/// message arrives while the window is in the background. The request is
+/// cleared once the window gains focus.
+///
+/// See [`UserAttention`] for the supported platforms.
+pub fn request_attention<T>(id: Id, user_attention: UserAttention) -> Task<T> {
+    request_user_attention(id, Some(user_attention))
+}
+
+/// Cancels any attention request of the window made with
+/// [`request_attention`].
+pub fn cancel_attention<T>(id: Id) -> Task<T> {
+    request_user_attention(id, None)
+}
+
+/// Shows the given progress, from `0.0` to `1.0`, in the taskbar button or
+/// dock icon of the window.
+///
+/// Providing `None` hides the progress. This can be useful to display the
+/// progress of downloads or exports even when the window is minimized.
+///
+/// See [`Progress`] for the supported platforms.
+pub fn set_progress<T>(id: Id, progress: Option<f32>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetProgress(
+        id,
+        progress.map(Progress::Determinate),
+    )))
+}
+
+/// Shows an indeterminate progress in the taskbar button or dock icon of the
+/// window; for operations that do not know how much work is left.
+///
+/// Use [`set_progress`] with `None` to hide it.
+pub fn set_progress_indeterminate<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetProgress(
+        id,
+        Some(Progress::Indeterminate),
+    )))
+}
+
+/// Shows the given count as a badge in the taskbar button or dock icon of
+/// the window; like the amount of unread messages.
+///
+/// `None` or `0` hides the badge.
+///
+/// ## Platform-specific
+///
+/// - **Windows**: Shown as an overlay icon of the taskbar button; counts
+///   above 99 are shown as 99.
+/// - **macOS**: Shown in the dock icon of the application; shared by all
+///   of its windows.
+/// - **Linux / Web**: Unsupported.
+pub fn set_badge<T>(id: Id, count: Option<u32>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetBadge(id, count)))
+}
+
+/// Sets the background [`Effect`] of the window; like Mica or Acrylic on
+/// Windows, or a blur of the windows behind on macOS and KDE.
+///
+/// The window must be `transparent` and the application background must be
+/// translucent for the effect to be visible.
+pub fn set_background_effect<T>(id: Id, effect: Effect) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetBackgroundEffect(
+        id, effect,
+    )))
+}
+
 /// Brings the window to the front and sets input focus. Has no effect if the window is
 /// already in focus, minimized, or not visible.
 ///
@@ -413,10 +852,65 @@ pub fn gain_focus<T>(id: Id) -> Task<T> {
 }
 
 /// Changes the window [`Level`].
+///
+/// This can be used to keep mini-players or overlay utilities above other
+/// windows; and toggled at any time after the window is created.
 pub fn set_level<T>(id: Id, level: Level) -> Task<T> {
     task::effect(crate::Action::Window(Action::SetLevel(id, level)))
 }
 
+/// Gets the current window [`Level`].
+pub fn level(id: Id) -> Task<Level> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetLevel(id, channel))
+    })
+}
+
+/// Gets every [`VideoMode`] supported by the current monitor of the window;
+/// to be used with [`Mode::ExclusiveFullscreen`].
+pub fn video_modes(id: Id) -> Task<Vec<VideoMode>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetVideoModes(id, channel))
+    })
+}
+
+/// Gets every [`Monitor`] connected to the system.
+///
+/// Monitors can only be retrieved while at least one window is open.
+pub fn monitors() -> Task<Vec<Monitor>> {
+    task::oneshot(|channel| crate::Action::Window(Action::GetMonitors(channel)))
+}
+
+/// Moves the window to the center of the given [`Monitor`].
+///
+/// If the window is in fullscreen, it will take the whole screen of the new
+/// [`Monitor`] instead.
+pub fn move_to_monitor<T>(id: Id, monitor: Monitor) -> Task<T> {
+    task::effect(crate::Action::Window(Action::MoveToMonitor(id, monitor)))
+}
+
+/// Snaps the window to the given [`Edge`] of its current monitor; like
+/// the left half or the top-right quarter.
+///
+/// The taskbars and docks of the system are respected when possible.
+pub fn snap<T>(id: Id, edge: Edge) -> Task<T> {
+    tile(id, Layout::from(edge))
+}
+
+/// Moves and resizes the window into the given [`Layout`] of its current
+/// monitor.
+///
+/// The taskbars and docks of the system are respected when possible.
+///
+/// ## Platform-specific
+///
+/// - **Windows:** The work area of the monitor is used.
+/// - **Others:** The whole monitor is used.
+/// - **Wayland / Web:** Unsupported.
+pub fn tile<T>(id: Id, layout: Layout) -> Task<T> {
+    task::effect(crate::Action::Window(Action::Tile(id, layout)))
+}
+
 /// Show the [system menu] at cursor position.
 ///
 /// [system menu]: https://en.wikipedia.org/wiki/Common_menus_in_Microsoft_Windows#System_menu
@@ -460,10 +954,48 @@ where
 /// Captures a [`Screenshot`] from the window.
 pub fn screenshot(id: Id) -> Task<Screenshot> {
     task::oneshot(move |channel| {
-        crate::Action::Window(Action::Screenshot(id, channel))
+        crate::Action::Window(Action::Screenshot(id, None, channel))
+    })
+}
+
+/// Captures a [`Screenshot`] from the window at the given scale factor,
+/// instead of the scale factor of the window.
+pub fn screenshot_scaled(id: Id, scale_factor: f32) -> Task<Screenshot> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::Screenshot(
+            id,
+            Some(scale_factor),
+            channel,
+        ))
     })
 }
 
+/// Captures a [`Screenshot`] of the given `region` of the window at the
+/// given scale factor.
+///
+/// The `region` is given in logical pixels.
+pub fn screenshot_region(
+    id: Id,
+    region: Rectangle,
+    scale_factor: f32,
+) -> Task<Result<Screenshot, CropError>> {
+    screenshot_scaled(id, scale_factor)
+        .map(move |screenshot| screenshot.crop_bounds(region))
+}
+
+/// Captures a [`Screenshot`] of the bounds of the widget with the given
+/// [`widget::Id`] in the window at the given scale factor.
+///
+/// No [`Screenshot`] will be produced if the widget cannot be found.
+pub fn screenshot_widget(
+    id: Id,
+    widget: impl Into<widget::Id>,
+    scale_factor: f32,
+) -> Task<Result<Screenshot, CropError>> {
+    task::widget(operation::bounds(widget.into()))
+        .then(move |bounds| screenshot_region(id, bounds, scale_factor))
+}
+
 /// Enables mouse passthrough for the given window.
 ///
 /// This disables mouse events for the window and passes mouse events
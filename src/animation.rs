@@ -0,0 +1,43 @@
+//! Animate your applications.
+pub use crate::core::animation::*;
+
+use crate::time::Instant;
+use crate::{Subscription, window};
+
+/// Returns a [`Subscription`] that produces the [`Instant`] of every frame
+/// while `is_animating` is true; and nothing otherwise.
+///
+/// This can be used to keep redrawing the window only while some
+/// [`Animated`] values are in progress:
+///
+/// ```no_run
+/// use iced::Subscription;
+/// use iced::animation::{self, Animated};
+/// use iced::time::Instant;
+///
+/// struct State {
+///     opacity: Animated<f32>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Tick(Instant),
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Tick(now) => state.opacity.tick(now),
+///     }
+/// }
+///
+/// fn subscription(state: &State) -> Subscription<Message> {
+///     animation::frames(state.opacity.is_animating()).map(Message::Tick)
+/// }
+/// ```
+pub fn frames(is_animating: bool) -> Subscription<Instant> {
+    if is_animating {
+        window::frames()
+    } else {
+        Subscription::none()
+    }
+}
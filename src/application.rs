@@ -206,6 +206,28 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`Settings::image_budget`] of the [`Application`].
+    pub fn image_budget(self, image_budget: crate::image::Budget) -> Self {
+        Self {
+            settings: Settings {
+                image_budget,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::frame_rate`] of the [`Application`].
+    pub fn frame_rate(self, frame_rate: crate::window::FrameRate) -> Self {
+        Self {
+            settings: Settings {
+                frame_rate,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Application`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -252,6 +274,17 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::transition`] of the [`Application`].
+    pub fn window_transition(self, transition: window::Transition) -> Self {
+        Self {
+            window: window::Settings {
+                transition,
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::size`] of the [`Application`].
     pub fn window_size(self, size: impl Into<Size>) -> Self {
         Self {
@@ -150,6 +150,28 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the [`Settings::image_budget`] of the [`Daemon`].
+    pub fn image_budget(self, image_budget: crate::image::Budget) -> Self {
+        Self {
+            settings: Settings {
+                image_budget,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::frame_rate`] of the [`Daemon`].
+    pub fn frame_rate(self, frame_rate: crate::window::FrameRate) -> Self {
+        Self {
+            settings: Settings {
+                frame_rate,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Daemon`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -485,6 +485,12 @@ use iced_winit::runtime;
 pub use iced_futures::futures;
 pub use iced_futures::stream;
 
+#[cfg(all(feature = "files", not(target_arch = "wasm32")))]
+pub use iced_futures::files;
+
+#[cfg(all(feature = "websocket", not(target_arch = "wasm32")))]
+pub use iced_futures::websocket;
+
 #[cfg(not(any(
     target_arch = "wasm32",
     feature = "thread-pool",
@@ -505,8 +511,11 @@ pub use iced_renderer::wgpu::wgpu;
 
 mod error;
 
+pub mod animation;
 pub mod application;
 pub mod daemon;
+pub mod mouse;
+pub mod theme;
 pub mod time;
 pub mod window;
 
@@ -514,16 +523,17 @@ pub mod window;
 pub mod advanced;
 
 pub use crate::core::alignment;
-pub use crate::core::animation;
+pub use crate::core::background;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
 pub use crate::core::padding;
-pub use crate::core::theme;
+pub use crate::core::shadow;
 pub use crate::core::{
-    Alignment, Animation, Background, Border, Color, ContentFit, Degrees,
-    Function, Gradient, Length, Padding, Pixels, Point, Radians, Rectangle,
-    Rotation, Settings, Shadow, Size, Theme, Transformation, Vector, never,
+    Alignment, Animation, Antialiasing, Background, Border, Color, ContentFit,
+    Degrees, Function, Gradient, Length, Padding, Pixels, Point, Radians,
+    Rectangle, Rotation, Settings, Shadow, Size, Theme, Transformation, Vector,
+    never,
 };
 pub use crate::runtime::exit;
 pub use iced_futures::Subscription;
@@ -540,19 +550,33 @@ pub mod debug {
 
 pub mod task {
     //! Create runtime tasks.
-    pub use crate::runtime::task::{Handle, Task};
+    pub use crate::runtime::task::{
+        Attempt, Handle, Priority, Progress, Retry, Task,
+    };
 
     #[cfg(feature = "sipper")]
     pub use crate::runtime::task::{Never, Sipper, Straw, sipper, stream};
 }
 
+pub mod accessibility {
+    //! Make your application usable with assistive technologies.
+    pub use crate::runtime::accessibility::{Priority, announce};
+}
+
 pub mod clipboard {
     //! Access the clipboard.
     pub use crate::runtime::clipboard::{
-        read, read_primary, write, write_primary,
+        Image, read, read_data, read_files, read_html, read_image,
+        read_primary, write, write_data, write_files, write_html, write_image,
+        write_primary,
     };
 }
 
+pub mod dnd {
+    //! Drag data out of the application.
+    pub use crate::runtime::dnd::{Outcome, Payload, Preview, start_drag};
+}
+
 pub mod executor {
     //! Choose your preferred executor to power your application.
     pub use iced_futures::Executor;
@@ -573,6 +597,19 @@ pub mod event {
     };
 }
 
+pub mod image {
+    //! Manage the images cached by the renderer.
+    pub use crate::core::image::{Budget, Growth, Usage};
+    pub use crate::runtime::image::{evict, evict_unused, prefetch, usage};
+
+    #[cfg(all(
+        feature = "wgpu",
+        feature = "image",
+        not(target_arch = "wasm32")
+    ))]
+    pub use iced_renderer::wgpu::external;
+}
+
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
@@ -580,20 +617,32 @@ pub mod keyboard {
     pub use iced_futures::keyboard::{on_key_press, on_key_release};
 }
 
-pub mod mouse {
-    //! Listen and react to mouse events.
-    pub use crate::core::mouse::{
-        Button, Cursor, Event, Interaction, ScrollDelta,
+pub mod system {
+    //! Retrieve system information and preferences.
+    pub use crate::runtime::system::{
+        Appearance, Battery, Mode, Network, Power, appearance,
+        appearance_changes, network_status, network_status_changes,
+        power_changes, power_status, theme_changes,
     };
-}
 
-#[cfg(feature = "system")]
-pub mod system {
-    //! Retrieve system information.
+    #[cfg(all(feature = "shutdown", not(target_arch = "wasm32")))]
+    pub use crate::runtime::system::shutdown_requests;
+
+    #[cfg(feature = "system")]
     pub use crate::runtime::system::Information;
+    #[cfg(feature = "system")]
     pub use crate::shell::system::*;
 }
 
+#[cfg(feature = "tray")]
+pub mod tray {
+    //! Show an icon with a menu in the system tray.
+    pub use crate::runtime::tray::{
+        Event, Id, Item, Settings, close, events, open, restore, set_icon,
+        set_tooltip,
+    };
+}
+
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
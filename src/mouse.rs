@@ -0,0 +1,37 @@
+//! Listen and react to mouse events.
+pub use crate::core::mouse::{
+    Button, Cursor, Custom, Event, Interaction, ScrollDelta, custom,
+};
+
+#[cfg(feature = "svg")]
+use crate::core::Point;
+#[cfg(feature = "svg")]
+use crate::window::icon;
+
+/// Creates a [`Custom`] cursor by rasterizing the given SVG data into a
+/// square of the given `size`, in pixels.
+///
+/// The `hotspot` is the point of the cursor that is aimed at, in pixels.
+#[cfg(feature = "svg")]
+pub fn custom_from_svg(
+    data: &[u8],
+    size: u32,
+    hotspot: Point<u32>,
+) -> Result<Custom, Error> {
+    let (rgba, size) = icon::from_svg(data, size)?.into_raw();
+
+    Ok(Custom::from_rgba(rgba, size.width, size.height, hotspot)?)
+}
+
+/// An error produced when creating a [`Custom`] cursor from SVG data.
+#[cfg(feature = "svg")]
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The SVG data could not be rasterized.
+    #[error("Unable to rasterize the cursor: {0}")]
+    Svg(#[from] icon::Error),
+
+    /// The rasterized cursor is not valid.
+    #[error("The cursor is invalid: {0}")]
+    Invalid(#[from] custom::Error),
+}
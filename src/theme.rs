@@ -0,0 +1,82 @@
+//! Use the built-in theme and styles.
+pub use crate::core::theme::*;
+
+#[cfg(all(feature = "theme-file", not(target_arch = "wasm32")))]
+pub use file::{load, save, watch};
+
+#[cfg(all(feature = "theme-file", not(target_arch = "wasm32")))]
+mod file {
+    use crate::Subscription;
+    use crate::core::theme::Theme;
+    use crate::core::theme::definition::{Definition, Error};
+    use crate::files;
+    use crate::futures::future;
+    use crate::futures::stream;
+
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// Returns a [`Subscription`] that loads the [`Theme`] defined in the
+    /// file at the given path, and reloads it every time the file changes.
+    ///
+    /// Files with a `.json` extension are parsed as JSON, while any other
+    /// file is parsed as TOML. See [`Definition`] for the format.
+    ///
+    /// This is useful to iterate on the design of a [`Theme`] without
+    /// having to recompile your application.
+    pub fn watch(
+        path: impl Into<PathBuf>,
+    ) -> Subscription<Result<Theme, Error>> {
+        let path = path.into();
+
+        let initial = Subscription::run_with(path.clone(), |path| {
+            stream::once(future::ready(load(path)))
+        });
+
+        let changes = files::watch(path).map(|event| load(event.path()));
+
+        Subscription::batch([initial, changes])
+    }
+
+    /// Loads the [`Theme`] defined in the file at the given path.
+    ///
+    /// Files with a `.json` extension are parsed as JSON, while any other
+    /// file is parsed as TOML. See [`Definition`] for the format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Theme, Error> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)?;
+
+        let definition = if is_json(path) {
+            Definition::from_json(&contents)?
+        } else {
+            Definition::from_toml(&contents)?
+        };
+
+        Ok(definition.theme())
+    }
+
+    /// Exports the given [`Theme`] to the file at the given path; so it
+    /// can be shared and [`load`]ed by other applications.
+    ///
+    /// Files with a `.json` extension are written as JSON, while any
+    /// other file is written as TOML.
+    pub fn save(theme: &Theme, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let definition = Definition::from_theme(theme);
+
+        let contents = if is_json(path) {
+            definition.to_json()
+        } else {
+            definition.to_toml()
+        };
+
+        fs::write(path, contents)?;
+
+        Ok(())
+    }
+
+    fn is_json(path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+    }
+}
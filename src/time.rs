@@ -11,3 +11,147 @@ pub use crate::core::time::*;
     )))
 )]
 pub use iced_futures::backend::default::time::*;
+
+use crate::futures::channel::oneshot;
+use crate::futures::future::{self, Either};
+use crate::futures::stream;
+use crate::{Subscription, Task};
+
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{Arc, Mutex};
+
+/// Returns a [`Task`] that produces the current [`Instant`] once the given
+/// `duration` has elapsed.
+pub fn after(duration: Duration) -> Task<Instant> {
+    Task::future(async move {
+        iced_futures::backend::default::time::sleep(duration).await;
+
+        Instant::now()
+    })
+}
+
+/// Creates a new [`Controller`] of a ticking [`Subscription`] that produces
+/// messages at the given interval.
+///
+/// Unlike [`every`], the [`Subscription`] of a [`Controller`] can be paused,
+/// resumed, and have its interval changed at runtime without being
+/// restarted. Store the [`Controller`] in your application state and use
+/// [`Controller::subscription`] to listen to its ticks.
+pub fn every_controlled(interval: Duration) -> Controller {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    Controller {
+        id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+        state: Arc::new(Mutex::new(State {
+            interval,
+            is_paused: false,
+            listeners: Vec::new(),
+        })),
+    }
+}
+
+/// A handle to control a ticking [`Subscription`].
+///
+/// Clones of a [`Controller`] control the same [`Subscription`].
+#[derive(Debug, Clone)]
+pub struct Controller {
+    id: u64,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    interval: Duration,
+    is_paused: bool,
+    listeners: Vec<oneshot::Sender<()>>,
+}
+
+impl Controller {
+    /// Returns the [`Subscription`] that ticks according to the
+    /// [`Controller`].
+    ///
+    /// The first tick is produced after a full interval, and then continues
+    /// to produce more ticks every interval after that.
+    pub fn subscription(&self) -> Subscription<Instant> {
+        Subscription::run_with(self.clone(), |controller| {
+            let controller = controller.clone();
+
+            stream::unfold(controller, |controller| async move {
+                loop {
+                    let (interval, is_paused, changed) = controller.listen();
+
+                    if is_paused {
+                        let _ = changed.await;
+                        continue;
+                    }
+
+                    let sleep = Box::pin(
+                        iced_futures::backend::default::time::sleep(interval),
+                    );
+
+                    if let Either::Left(_) =
+                        future::select(sleep, changed).await
+                    {
+                        return Some((Instant::now(), controller));
+                    }
+                }
+            })
+        })
+    }
+
+    /// Pauses the ticks of the [`Controller`].
+    pub fn pause(&self) {
+        self.update(|state| state.is_paused = true);
+    }
+
+    /// Resumes the ticks of the [`Controller`].
+    ///
+    /// The next tick is produced after a full interval.
+    pub fn resume(&self) {
+        self.update(|state| state.is_paused = false);
+    }
+
+    /// Returns true if the [`Controller`] is paused.
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().expect("Lock timer state").is_paused
+    }
+
+    /// Changes the interval of the ticks of the [`Controller`].
+    ///
+    /// The next tick is produced after a full new interval.
+    pub fn set_interval(&self, interval: Duration) {
+        self.update(|state| state.interval = interval);
+    }
+
+    /// Returns the current interval of the ticks of the [`Controller`].
+    pub fn interval(&self) -> Duration {
+        self.state.lock().expect("Lock timer state").interval
+    }
+
+    fn update(&self, f: impl FnOnce(&mut State)) {
+        let mut state = self.state.lock().expect("Lock timer state");
+
+        f(&mut state);
+
+        for listener in state.listeners.drain(..) {
+            let _ = listener.send(());
+        }
+    }
+
+    fn listen(&self) -> (Duration, bool, oneshot::Receiver<()>) {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.state.lock().expect("Lock timer state");
+
+        state.listeners.retain(|listener| !listener.is_canceled());
+        state.listeners.push(sender);
+
+        (state.interval, state.is_paused, receiver)
+    }
+}
+
+impl Hash for Controller {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
@@ -1,8 +1,13 @@
 //! Configure the window of your application in native platforms.
 
 pub mod icon;
+pub mod scoped;
 
 pub use icon::Icon;
+pub use scoped::{ForWindow, Scoped, Windows};
 
 pub use crate::core::window::*;
 pub use crate::runtime::window::*;
+
+pub use crate::widget::caption;
+pub use crate::widget::{DragArea, drag_area};
@@ -46,6 +46,46 @@ pub fn from_file_data(
     )?)
 }
 
+/// Creates an icon by rasterizing the given SVG data into a square of the
+/// given `size`, in pixels.
+///
+/// Together with `window::set_icon`, this can be used to update the icon of a
+/// window at runtime; for instance, to show an unread count or a recording
+/// indicator.
+#[cfg(feature = "svg")]
+pub fn from_svg(data: &[u8], size: u32) -> Result<Icon, Error> {
+    use resvg::tiny_skia;
+    use resvg::usvg;
+
+    let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size, size).ok_or(Error::InvalidSize(size))?;
+
+    let tree_size = tree.size();
+    let scale = size as f32 / tree_size.width().max(tree_size.height());
+
+    let transform = tiny_skia::Transform::from_scale(scale, scale)
+        .post_translate(
+            (size as f32 - tree_size.width() * scale) / 2.0,
+            (size as f32 - tree_size.height() * scale) / 2.0,
+        );
+
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    let rgba = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.demultiply();
+
+            [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+        })
+        .collect();
+
+    Ok(icon::from_rgba(rgba, size, size)?)
+}
+
 /// An error produced when creating an [`Icon`].
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -61,4 +101,14 @@ pub enum Error {
     #[cfg(feature = "image")]
     #[error("Unable to create icon from a file: {0}")]
     ImageError(#[from] image::error::ImageError),
+
+    /// The SVG data could not be parsed.
+    #[cfg(feature = "svg")]
+    #[error("Unable to create icon from SVG data: {0}")]
+    SvgError(#[from] resvg::usvg::Error),
+
+    /// The requested size of the [`Icon`] is not valid.
+    #[cfg(feature = "svg")]
+    #[error("The icon size is invalid: {0}")]
+    InvalidSize(u32),
 }
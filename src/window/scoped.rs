@@ -0,0 +1,220 @@
+//! Route the messages of a multi-window application to its windows.
+use crate::core::Element;
+use crate::window::{self, Id, Settings};
+use crate::{Subscription, Task};
+
+use iced_futures::MaybeSend;
+
+use std::collections::BTreeMap;
+
+/// A message scoped to the window with some [`Id`].
+///
+/// You can create one with [`ForWindow::for_window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scoped<Message> {
+    /// The [`Id`] of the window.
+    pub window: Id,
+    /// The message of the window.
+    pub message: Message,
+}
+
+impl<Message> Scoped<Message> {
+    /// Creates a new [`Scoped`] message for the window with the given [`Id`].
+    pub fn new(window: Id, message: Message) -> Self {
+        Self { window, message }
+    }
+}
+
+impl<Message> From<(Id, Message)> for Scoped<Message> {
+    fn from((window, message): (Id, Message)) -> Self {
+        Self::new(window, message)
+    }
+}
+
+/// Scopes any message to a window.
+pub trait ForWindow: Sized {
+    /// Scopes the message to the window with the given [`Id`].
+    fn for_window(self, window: Id) -> Scoped<Self> {
+        Scoped::new(window, self)
+    }
+}
+
+impl<T> ForWindow for T {}
+
+/// The states of the windows of an application; each one with its own
+/// view, update, and subscription logic.
+///
+/// [`Windows`] routes [`Scoped`] messages to the state of their window, so
+/// an application does not need to match on window ids everywhere.
+///
+/// # Example
+/// ```no_run
+/// use iced::window::{self, Scoped, Windows};
+/// use iced::widget::{button, text};
+/// use iced::{Element, Task};
+///
+/// struct Editor {
+///     count: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Increment,
+/// }
+///
+/// fn update(
+///     windows: &mut Windows<Editor>,
+///     message: Scoped<Message>,
+/// ) -> Task<Scoped<Message>> {
+///     windows.update(message, |editor, message| {
+///         match message {
+///             Message::Increment => editor.count += 1,
+///         }
+///
+///         Task::none()
+///     })
+/// }
+///
+/// fn view(
+///     windows: &Windows<Editor>,
+///     window: window::Id,
+/// ) -> Element<'_, Scoped<Message>> {
+///     windows
+///         .view(window, |editor| {
+///             button(text(editor.count))
+///                 .on_press(Message::Increment)
+///                 .into()
+///         })
+///         .unwrap_or_else(|| text("Closing...").into())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Windows<State> {
+    states: BTreeMap<Id, State>,
+}
+
+impl<State> Windows<State> {
+    /// Creates an empty set of [`Windows`].
+    pub fn new() -> Self {
+        Self {
+            states: BTreeMap::new(),
+        }
+    }
+
+    /// Opens a new window with the given [`Settings`] and state; producing
+    /// its [`Id`] once opened.
+    pub fn open(&mut self, settings: Settings, state: State) -> (Id, Task<Id>) {
+        let (id, task) = window::open(settings);
+
+        let _ = self.states.insert(id, state);
+
+        (id, task)
+    }
+
+    /// Inserts the state of the window with the given [`Id`]; returning its
+    /// previous state, if any.
+    pub fn insert(&mut self, window: Id, state: State) -> Option<State> {
+        self.states.insert(window, state)
+    }
+
+    /// Removes the state of the window with the given [`Id`]; normally, once
+    /// it is closed.
+    pub fn remove(&mut self, window: Id) -> Option<State> {
+        self.states.remove(&window)
+    }
+
+    /// Returns the state of the window with the given [`Id`], if any.
+    pub fn get(&self, window: Id) -> Option<&State> {
+        self.states.get(&window)
+    }
+
+    /// Returns the mutable state of the window with the given [`Id`], if any.
+    pub fn get_mut(&mut self, window: Id) -> Option<&mut State> {
+        self.states.get_mut(&window)
+    }
+
+    /// Returns an iterator over the states of all the windows.
+    pub fn iter(&self) -> impl Iterator<Item = (Id, &State)> {
+        self.states.iter().map(|(id, state)| (*id, state))
+    }
+
+    /// Returns a mutable iterator over the states of all the windows.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Id, &mut State)> {
+        self.states.iter_mut().map(|(id, state)| (*id, state))
+    }
+
+    /// Returns the amount of windows.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns true if there are no windows.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Runs the update logic of the window that the [`Scoped`] message is
+    /// for; scoping the messages of the resulting [`Task`] to the same
+    /// window.
+    ///
+    /// Messages for windows without a state are ignored.
+    pub fn update<Message>(
+        &mut self,
+        scoped: Scoped<Message>,
+        update: impl FnOnce(&mut State, Message) -> Task<Message>,
+    ) -> Task<Scoped<Message>>
+    where
+        Message: MaybeSend + 'static,
+    {
+        let Some(state) = self.states.get_mut(&scoped.window) else {
+            return Task::none();
+        };
+
+        let window = scoped.window;
+
+        update(state, scoped.message)
+            .map(move |message| Scoped::new(window, message))
+    }
+
+    /// Produces the view of the window with the given [`Id`]; scoping its
+    /// messages to the same window.
+    ///
+    /// Returns `None` if the window has no state; for instance, while it is
+    /// being closed.
+    pub fn view<'a, Message, Theme, Renderer>(
+        &'a self,
+        window: Id,
+        view: impl FnOnce(&'a State) -> Element<'a, Message, Theme, Renderer>,
+    ) -> Option<Element<'a, Scoped<Message>, Theme, Renderer>>
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: crate::core::Renderer + 'a,
+    {
+        let state = self.states.get(&window)?;
+
+        Some(view(state).map(move |message| Scoped::new(window, message)))
+    }
+
+    /// Batches the subscriptions of all the windows; scoping their messages
+    /// to their window.
+    pub fn subscription<Message>(
+        &self,
+        subscription: impl Fn(&State) -> Subscription<Message>,
+    ) -> Subscription<Scoped<Message>>
+    where
+        Message: 'static,
+    {
+        Subscription::batch(
+            self.states.iter().map(|(id, state)| {
+                subscription(state).with(*id).map(Scoped::from)
+            }),
+        )
+    }
+}
+
+impl<State> Default for Windows<State> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,7 +1,9 @@
 use crate::Primitive;
+use crate::core::background::Blur;
 use crate::core::renderer::Quad;
 use crate::core::{
-    Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
+    Background, Color, Gradient, Point, Rectangle, Shadow, Size,
+    Transformation, Vector,
 };
 use crate::graphics::{Image, Text};
 use crate::text;
@@ -56,12 +58,15 @@ impl Engine {
 
         let transform = into_transform(transformation);
 
-        // Make sure the border radius is not larger than the bounds
-        let border_width = quad
-            .border
-            .width
-            .min(quad.bounds.width / 2.0)
-            .min(quad.bounds.height / 2.0);
+        // Make sure the border widths are not larger than the bounds
+        let border_widths = quad.border.widths().map(|width| {
+            width
+                .min(quad.bounds.width / 2.0)
+                .min(quad.bounds.height / 2.0)
+        });
+
+        let [border_width, ..] = border_widths;
+        let [border_color, ..] = quad.border.colors();
 
         let mut fill_border_radius = <[f32; 4]>::from(quad.border.radius);
 
@@ -71,86 +76,32 @@ impl Engine {
                 .min(quad.bounds.height / 2.0);
         }
 
-        let path = rounded_rectangle(quad.bounds, fill_border_radius);
-
-        let shadow = quad.shadow;
-
-        if shadow.color.a > 0.0 {
-            let shadow_bounds = Rectangle {
-                x: quad.bounds.x + shadow.offset.x - shadow.blur_radius,
-                y: quad.bounds.y + shadow.offset.y - shadow.blur_radius,
-                width: quad.bounds.width + shadow.blur_radius * 2.0,
-                height: quad.bounds.height + shadow.blur_radius * 2.0,
-            } * transformation;
-
-            let radii = fill_border_radius
-                .into_iter()
-                .map(|radius| radius * transformation.scale_factor())
-                .collect::<Vec<_>>();
-            let (x, y, width, height) = (
-                shadow_bounds.x as u32,
-                shadow_bounds.y as u32,
-                shadow_bounds.width as u32,
-                shadow_bounds.height as u32,
+        let smoothing = quad.border.radius.smoothing;
+        let path =
+            rounded_rectangle(quad.bounds, fill_border_radius, smoothing);
+
+        // Shadows are stacked from bottom to top
+        for shadow in quad.shadow.iter().rev().filter(|shadow| !shadow.inset) {
+            draw_shadow(
+                shadow,
+                quad.bounds,
+                fill_border_radius,
+                smoothing,
+                transformation,
+                pixels,
+                None,
+            );
+        }
+
+        if let Background::Blur(blur) = background {
+            draw_backdrop_blur(
+                blur,
+                &path,
+                physical_bounds,
+                transformation,
+                pixels,
+                clip_mask,
             );
-            let half_width = physical_bounds.width / 2.0;
-            let half_height = physical_bounds.height / 2.0;
-
-            let colors = (y..y + height)
-                .flat_map(|y| (x..x + width).map(move |x| (x as f32, y as f32)))
-                .filter_map(|(x, y)| {
-                    tiny_skia::Size::from_wh(half_width, half_height).map(
-                        |size| {
-                            let shadow_distance = rounded_box_sdf(
-                                Vector::new(
-                                    x - physical_bounds.position().x
-                                        - (shadow.offset.x
-                                            * transformation.scale_factor())
-                                        - half_width,
-                                    y - physical_bounds.position().y
-                                        - (shadow.offset.y
-                                            * transformation.scale_factor())
-                                        - half_height,
-                                ),
-                                size,
-                                &radii,
-                            )
-                            .max(0.0);
-                            let shadow_alpha = 1.0
-                                - smoothstep(
-                                    -shadow.blur_radius
-                                        * transformation.scale_factor(),
-                                    shadow.blur_radius
-                                        * transformation.scale_factor(),
-                                    shadow_distance,
-                                );
-
-                            let mut color = into_color(shadow.color);
-                            color.apply_opacity(shadow_alpha);
-
-                            color.to_color_u8().premultiply()
-                        },
-                    )
-                })
-                .collect();
-
-            if let Some(pixmap) = tiny_skia::IntSize::from_wh(width, height)
-                .and_then(|size| {
-                    tiny_skia::Pixmap::from_vec(
-                        bytemuck::cast_vec(colors),
-                        size,
-                    )
-                })
-            {
-                pixels.draw_pixmap(
-                    x as i32,
-                    y as i32,
-                    pixmap.as_ref(),
-                    &tiny_skia::PixmapPaint::default(),
-                    tiny_skia::Transform::default(),
-                    None,
-                );
-            }
         }
 
         pixels.fill_path(
@@ -160,6 +111,9 @@ impl Engine {
                     Background::Color(color) => {
                         tiny_skia::Shader::SolidColor(into_color(*color))
                     }
+                    Background::Blur(blur) => {
+                        tiny_skia::Shader::SolidColor(into_color(blur.tint))
+                    }
                     Background::Gradient(Gradient::Linear(linear)) => {
                         let (start, end) =
                             linear.angle.to_distance(&quad.bounds);
@@ -210,7 +164,30 @@ impl Engine {
             clip_mask,
         );
 
-        if border_width > 0.0 {
+        for shadow in quad.shadow.iter().rev().filter(|shadow| shadow.inset) {
+            draw_shadow(
+                shadow,
+                quad.bounds,
+                fill_border_radius,
+                smoothing,
+                transformation,
+                pixels,
+                clip_mask,
+            );
+        }
+
+        if !quad.border.is_uniform() {
+            draw_border_sides(
+                quad.bounds,
+                fill_border_radius,
+                smoothing,
+                border_widths,
+                quad.border.colors(),
+                transform,
+                pixels,
+                clip_mask,
+            );
+        } else if border_width > 0.0 {
             // Border path is offset by half the border width
             let border_bounds = Rectangle {
                 x: quad.bounds.x + border_width / 2.0,
@@ -240,13 +217,13 @@ impl Engine {
             // Stroking a path works well in this case
             if is_simple_border {
                 let border_path =
-                    rounded_rectangle(border_bounds, border_radius);
+                    rounded_rectangle(border_bounds, border_radius, smoothing);
 
                 pixels.stroke_path(
                     &border_path,
                     &tiny_skia::Paint {
                         shader: tiny_skia::Shader::SolidColor(into_color(
-                            quad.border.color,
+                            border_color,
                         )),
                         anti_alias: true,
                         ..tiny_skia::Paint::default()
@@ -279,7 +256,11 @@ impl Engine {
                     width: quad.bounds.width,
                     height: quad.bounds.height,
                 };
-                let path = rounded_rectangle(zero_bounds, fill_border_radius);
+                let path = rounded_rectangle(
+                    zero_bounds,
+                    fill_border_radius,
+                    smoothing,
+                );
 
                 quad_mask.fill_path(
                     &path,
@@ -295,13 +276,13 @@ impl Engine {
                 };
 
                 let border_radius_path =
-                    rounded_rectangle(path_bounds, border_radius);
+                    rounded_rectangle(path_bounds, border_radius, smoothing);
 
                 temp_pixmap.stroke_path(
                     &border_radius_path,
                     &tiny_skia::Paint {
                         shader: tiny_skia::Shader::SolidColor(into_color(
-                            quad.border.color,
+                            border_color,
                         )),
                         anti_alias: true,
                         ..tiny_skia::Paint::default()
@@ -574,6 +555,7 @@ impl Engine {
                     raster.filter_method,
                     *bounds,
                     raster.opacity,
+                    raster.filter,
                     _pixels,
                     transform,
                     clip_mask,
@@ -656,6 +638,7 @@ fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
 fn rounded_rectangle(
     bounds: Rectangle,
     border_radius: [f32; 4],
+    smoothing: f32,
 ) -> tiny_skia::Path {
     let [top_left, top_right, bottom_right, bottom_left] = border_radius;
 
@@ -675,7 +658,8 @@ fn rounded_rectangle(
         );
     }
 
-    if top_left == top_right
+    if smoothing <= 0.0
+        && top_left == top_right
         && top_left == bottom_right
         && top_left == bottom_left
         && top_left == bounds.width / 2.0
@@ -695,13 +679,13 @@ fn rounded_rectangle(
     builder.line_to(bounds.x + bounds.width - top_right, bounds.y);
 
     if top_right > 0.0 {
-        arc_to(
+        corner_to(
             &mut builder,
-            bounds.x + bounds.width - top_right,
-            bounds.y,
-            bounds.x + bounds.width,
-            bounds.y + top_right,
+            Point::new(bounds.x + bounds.width - top_right, bounds.y),
+            Point::new(bounds.x + bounds.width, bounds.y),
+            Point::new(bounds.x + bounds.width, bounds.y + top_right),
             top_right,
+            smoothing,
         );
     }
 
@@ -712,13 +696,19 @@ fn rounded_rectangle(
     );
 
     if bottom_right > 0.0 {
-        arc_to(
+        corner_to(
             &mut builder,
-            bounds.x + bounds.width,
-            bounds.y + bounds.height - bottom_right,
-            bounds.x + bounds.width - bottom_right,
-            bounds.y + bounds.height,
+            Point::new(
+                bounds.x + bounds.width,
+                bounds.y + bounds.height - bottom_right,
+            ),
+            Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+            Point::new(
+                bounds.x + bounds.width - bottom_right,
+                bounds.y + bounds.height,
+            ),
             bottom_right,
+            smoothing,
         );
     }
 
@@ -729,38 +719,332 @@ fn rounded_rectangle(
     );
 
     if bottom_left > 0.0 {
-        arc_to(
+        corner_to(
             &mut builder,
-            bounds.x + bottom_left,
-            bounds.y + bounds.height,
-            bounds.x,
-            bounds.y + bounds.height - bottom_left,
+            Point::new(bounds.x + bottom_left, bounds.y + bounds.height),
+            Point::new(bounds.x, bounds.y + bounds.height),
+            Point::new(bounds.x, bounds.y + bounds.height - bottom_left),
             bottom_left,
+            smoothing,
         );
     }
 
     maybe_line_to(&mut builder, bounds.x, bounds.y + top_left);
 
     if top_left > 0.0 {
-        arc_to(
+        corner_to(
             &mut builder,
-            bounds.x,
-            bounds.y + top_left,
-            bounds.x + top_left,
-            bounds.y,
+            Point::new(bounds.x, bounds.y + top_left),
+            Point::new(bounds.x, bounds.y),
+            Point::new(bounds.x + top_left, bounds.y),
             top_left,
+            smoothing,
         );
     }
 
     builder.finish().expect("Build rounded rectangle path")
 }
 
+fn draw_shadow(
+    shadow: &Shadow,
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+    smoothing: f32,
+    transformation: Transformation,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    if !shadow.is_visible() {
+        return;
+    }
+
+    let scale_factor = transformation.scale_factor();
+    let physical_bounds = bounds * transformation;
+
+    // Outer shadows spread around the quad, while inset shadows
+    // never leave its bounds
+    let shadow_bounds = if shadow.inset {
+        physical_bounds
+    } else {
+        Rectangle {
+            x: bounds.x + shadow.offset.x - shadow.blur_radius,
+            y: bounds.y + shadow.offset.y - shadow.blur_radius,
+            width: bounds.width + shadow.blur_radius * 2.0,
+            height: bounds.height + shadow.blur_radius * 2.0,
+        } * transformation
+    };
+
+    let radii = border_radius
+        .into_iter()
+        .map(|radius| radius * scale_factor)
+        .collect::<Vec<_>>();
+    let (x, y, width, height) = (
+        shadow_bounds.x as u32,
+        shadow_bounds.y as u32,
+        shadow_bounds.width as u32,
+        shadow_bounds.height as u32,
+    );
+    let half_width = physical_bounds.width / 2.0;
+    let half_height = physical_bounds.height / 2.0;
+    let blur_radius = shadow.blur_radius * scale_factor;
+
+    let colors = (y..y + height)
+        .flat_map(|y| (x..x + width).map(move |x| (x as f32, y as f32)))
+        .filter_map(|(x, y)| {
+            tiny_skia::Size::from_wh(half_width, half_height).map(|size| {
+                let to_center = Vector::new(
+                    x - physical_bounds.position().x - half_width,
+                    y - physical_bounds.position().y - half_height,
+                );
+
+                let shadow_distance = rounded_box_sdf(
+                    to_center - shadow.offset * scale_factor,
+                    size,
+                    &radii,
+                    smoothing,
+                );
+
+                let shadow_alpha = if shadow.inset {
+                    let quad_distance =
+                        rounded_box_sdf(to_center, size, &radii, smoothing);
+
+                    smoothstep(-blur_radius, blur_radius, shadow_distance)
+                        * (0.5 - quad_distance).clamp(0.0, 1.0)
+                } else {
+                    1.0 - smoothstep(
+                        -blur_radius,
+                        blur_radius,
+                        shadow_distance.max(0.0),
+                    )
+                };
+
+                let mut color = into_color(shadow.color);
+                color.apply_opacity(shadow_alpha);
+
+                color.to_color_u8().premultiply()
+            })
+        })
+        .collect();
+
+    if let Some(pixmap) =
+        tiny_skia::IntSize::from_wh(width, height).and_then(|size| {
+            tiny_skia::Pixmap::from_vec(bytemuck::cast_vec(colors), size)
+        })
+    {
+        pixels.draw_pixmap(
+            x as i32,
+            y as i32,
+            pixmap.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::default(),
+            clip_mask,
+        );
+    }
+}
+
+fn draw_border_sides(
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+    smoothing: f32,
+    border_widths: [f32; 4],
+    border_colors: [Color; 4],
+    transform: tiny_skia::Transform,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let [top, right, bottom, left] = border_widths;
+
+    if top <= 0.0 && right <= 0.0 && bottom <= 0.0 && left <= 0.0 {
+        return;
+    }
+
+    let (width, height) =
+        (bounds.width.ceil() as u32, bounds.height.ceil() as u32);
+
+    let Some(mut temp_pixmap) = tiny_skia::Pixmap::new(width, height) else {
+        return;
+    };
+
+    let outer_bounds = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: bounds.width,
+        height: bounds.height,
+    };
+
+    let inner_bounds = Rectangle {
+        x: left,
+        y: top,
+        width: (bounds.width - left - right).max(0.0),
+        height: (bounds.height - top - bottom).max(0.0),
+    };
+
+    let [top_left, top_right, bottom_right, bottom_left] = border_radius;
+
+    let inner_radius = [
+        (top_left - left.max(top)).max(0.0),
+        (top_right - top.max(right)).max(0.0),
+        (bottom_right - right.max(bottom)).max(0.0),
+        (bottom_left - bottom.max(left)).max(0.0),
+    ];
+
+    let mut builder = tiny_skia::PathBuilder::new();
+    builder.push_path(&rounded_rectangle(
+        outer_bounds,
+        border_radius,
+        smoothing,
+    ));
+
+    if inner_bounds.width > 0.0 && inner_bounds.height > 0.0 {
+        builder.push_path(&rounded_rectangle(
+            inner_bounds,
+            inner_radius,
+            smoothing,
+        ));
+    }
+
+    let Some(ring) = builder.finish() else {
+        return;
+    };
+
+    // Each side owns the trapezoid between its outer and inner edges
+    let (outer_right, outer_bottom) = (bounds.width, bounds.height);
+    let (inner_left, inner_top) = (inner_bounds.x, inner_bounds.y);
+    let (inner_right, inner_bottom) = (
+        inner_bounds.x + inner_bounds.width,
+        inner_bounds.y + inner_bounds.height,
+    );
+
+    let sides = [
+        (
+            top,
+            [
+                (0.0, 0.0),
+                (outer_right, 0.0),
+                (inner_right, inner_top),
+                (inner_left, inner_top),
+            ],
+        ),
+        (
+            right,
+            [
+                (outer_right, 0.0),
+                (outer_right, outer_bottom),
+                (inner_right, inner_bottom),
+                (inner_right, inner_top),
+            ],
+        ),
+        (
+            bottom,
+            [
+                (outer_right, outer_bottom),
+                (0.0, outer_bottom),
+                (inner_left, inner_bottom),
+                (inner_right, inner_bottom),
+            ],
+        ),
+        (
+            left,
+            [
+                (0.0, outer_bottom),
+                (0.0, 0.0),
+                (inner_left, inner_top),
+                (inner_left, inner_bottom),
+            ],
+        ),
+    ];
+
+    for ((width, corners), color) in sides.into_iter().zip(border_colors) {
+        if width <= 0.0 || color.a <= 0.0 {
+            continue;
+        }
+
+        let mut trapezoid = tiny_skia::PathBuilder::new();
+        trapezoid.move_to(corners[0].0, corners[0].1);
+
+        for (x, y) in &corners[1..] {
+            trapezoid.line_to(*x, *y);
+        }
+
+        trapezoid.close();
+
+        let Some(trapezoid) = trapezoid.finish() else {
+            continue;
+        };
+
+        let Some(mut side_mask) =
+            tiny_skia::Mask::new(temp_pixmap.width(), temp_pixmap.height())
+        else {
+            continue;
+        };
+
+        side_mask.fill_path(
+            &trapezoid,
+            tiny_skia::FillRule::Winding,
+            true,
+            tiny_skia::Transform::identity(),
+        );
+
+        temp_pixmap.fill_path(
+            &ring,
+            &tiny_skia::Paint {
+                shader: tiny_skia::Shader::SolidColor(into_color(color)),
+                anti_alias: true,
+                ..tiny_skia::Paint::default()
+            },
+            tiny_skia::FillRule::EvenOdd,
+            tiny_skia::Transform::identity(),
+            Some(&side_mask),
+        );
+    }
+
+    pixels.draw_pixmap(
+        bounds.x as i32,
+        bounds.y as i32,
+        temp_pixmap.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        transform,
+        clip_mask,
+    );
+}
+
 fn maybe_line_to(path: &mut tiny_skia::PathBuilder, x: f32, y: f32) {
     if path.last_point() != Some(tiny_skia::Point { x, y }) {
         path.line_to(x, y);
     }
 }
 
+fn corner_to(
+    path: &mut tiny_skia::PathBuilder,
+    from: Point,
+    corner: Point,
+    to: Point,
+    radius: f32,
+    smoothing: f32,
+) {
+    if smoothing <= 0.0 {
+        arc_to(path, from.x, from.y, to.x, to.y, radius);
+        return;
+    }
+
+    // The control points are pulled towards the corner until the midpoint
+    // of the curve lies on the superellipse
+    let n = superellipse_exponent(smoothing);
+    let k = (8.0 * 2.0f32.powf(-1.0 / n) - 4.0) / 3.0;
+
+    let control_a = from + (corner - from) * k;
+    let control_b = to + (corner - to) * k;
+
+    path.cubic_to(
+        control_a.x,
+        control_a.y,
+        control_b.x,
+        control_b.y,
+        to.x,
+        to.y,
+    );
+}
+
 fn arc_to(
     path: &mut tiny_skia::PathBuilder,
     x_from: f32,
@@ -797,6 +1081,132 @@ fn arc_to(
     }
 }
 
+fn draw_backdrop_blur(
+    blur: &Blur,
+    path: &tiny_skia::Path,
+    physical_bounds: Rectangle,
+    transformation: Transformation,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let radius = blur.radius * transformation.scale_factor();
+
+    if radius < 1.0 {
+        return;
+    }
+
+    // The blurred region is expanded by the radius, so the
+    // edges of the backdrop can be sampled properly
+    let Some(region) = tiny_skia::IntRect::from_ltrb(
+        (physical_bounds.x - radius).floor().max(0.0) as i32,
+        (physical_bounds.y - radius).floor().max(0.0) as i32,
+        (physical_bounds.x + physical_bounds.width + radius)
+            .ceil()
+            .min(pixels.width() as f32) as i32,
+        (physical_bounds.y + physical_bounds.height + radius)
+            .ceil()
+            .min(pixels.height() as f32) as i32,
+    ) else {
+        return;
+    };
+
+    let Some(mut backdrop) = pixels.as_ref().clone_rect(region) else {
+        return;
+    };
+
+    box_blur(&mut backdrop, radius);
+
+    let Some(mut mask) = tiny_skia::Mask::new(pixels.width(), pixels.height())
+    else {
+        return;
+    };
+
+    mask.fill_path(
+        path,
+        tiny_skia::FillRule::EvenOdd,
+        true,
+        into_transform(transformation),
+    );
+
+    if let Some(clip_mask) = clip_mask {
+        for (coverage, clip) in mask.data_mut().iter_mut().zip(clip_mask.data())
+        {
+            *coverage = (u16::from(*coverage) * u16::from(*clip) / 255) as u8;
+        }
+    }
+
+    pixels.draw_pixmap(
+        region.x(),
+        region.y(),
+        backdrop.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        Some(&mask),
+    );
+}
+
+fn box_blur(pixmap: &mut tiny_skia::Pixmap, radius: f32) {
+    // Three successive box blurs approximate a gaussian blur
+    let box_radius = ((radius / 2.0).round() as usize).max(1);
+
+    let width = pixmap.width() as usize;
+    let height = pixmap.height() as usize;
+
+    let pixels = pixmap.data_mut();
+    let mut buffer = vec![0; pixels.len()];
+
+    for _ in 0..3 {
+        box_blur_pass(pixels, &mut buffer, width, height, box_radius, true);
+        box_blur_pass(&buffer, pixels, width, height, box_radius, false);
+    }
+}
+
+fn box_blur_pass(
+    source: &[u8],
+    target: &mut [u8],
+    width: usize,
+    height: usize,
+    radius: usize,
+    horizontal: bool,
+) {
+    let (lines, length) = if horizontal {
+        (height, width)
+    } else {
+        (width, height)
+    };
+
+    let index = |line: usize, i: usize| {
+        if horizontal {
+            (line * width + i) * 4
+        } else {
+            (i * width + line) * 4
+        }
+    };
+
+    let window = (radius * 2 + 1) as u32;
+    let radius = radius as isize;
+    let last = length as isize - 1;
+
+    for line in 0..lines {
+        for channel in 0..4 {
+            let sample = |i: isize| {
+                u32::from(
+                    source[index(line, i.clamp(0, last) as usize) + channel],
+                )
+            };
+
+            let mut sum: u32 = (-radius..=radius).map(sample).sum();
+
+            for i in 0..length {
+                target[index(line, i) + channel] = (sum / window) as u8;
+
+                sum += sample(i as isize + radius + 1);
+                sum -= sample(i as isize - radius);
+            }
+        }
+    }
+}
+
 fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
     let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
 
@@ -807,6 +1217,7 @@ fn rounded_box_sdf(
     to_center: Vector,
     size: tiny_skia::Size,
     radii: &[f32],
+    smoothing: f32,
 ) -> f32 {
     let radius = match (to_center.x > 0.0, to_center.y > 0.0) {
         (true, true) => radii[2],
@@ -815,10 +1226,20 @@ fn rounded_box_sdf(
         (false, false) => radii[0],
     };
 
-    let x = (to_center.x.abs() - size.width() + radius).max(0.0);
-    let y = (to_center.y.abs() - size.height() + radius).max(0.0);
+    let x = to_center.x.abs() - size.width() + radius;
+    let y = to_center.y.abs() - size.height() + radius;
+
+    // Smoothed corners use the norm of a superellipse
+    let n = superellipse_exponent(smoothing);
+
+    x.max(y).min(0.0) + (x.max(0.0).powf(n) + y.max(0.0).powf(n)).powf(1.0 / n)
+        - radius
+}
 
-    (x.powf(2.0) + y.powf(2.0)).sqrt() - radius
+/// Returns the exponent of the superellipse of a corner with the given
+/// smoothing; matching the SDF of the `wgpu` renderer.
+fn superellipse_exponent(smoothing: f32) -> f32 {
+    2.0 + smoothing.clamp(0.0, 1.0) * 3.0
 }
 
 pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
@@ -846,3 +1267,50 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Border;
+
+    #[test]
+    fn draw_quad_with_mixed_border_widths() {
+        const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+        const BLUE: Color = Color::from_rgb(0.0, 0.0, 1.0);
+        const GREEN: Color = Color::from_rgb(0.0, 1.0, 0.0);
+
+        let bounds = Rectangle::with_size(Size::new(20.0, 20.0));
+
+        let mut engine = Engine::new();
+        let mut pixmap = tiny_skia::Pixmap::new(20, 20).unwrap();
+        let mut clip_mask = tiny_skia::Mask::new(20, 20).unwrap();
+
+        engine.draw_quad(
+            &Quad {
+                bounds,
+                border: Border::default().top(2, RED).bottom(4, BLUE),
+                ..Quad::default()
+            },
+            &Background::Color(GREEN),
+            Transformation::IDENTITY,
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            bounds,
+        );
+
+        let color = |x, y| {
+            let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+
+            [pixel.red(), pixel.green(), pixel.blue()]
+        };
+
+        assert_eq!(color(10, 0), [255, 0, 0]);
+        assert_eq!(color(10, 1), [255, 0, 0]);
+        assert_eq!(color(10, 2), [0, 255, 0]);
+        assert_eq!(color(10, 15), [0, 255, 0]);
+        assert_eq!(color(10, 16), [0, 0, 255]);
+        assert_eq!(color(10, 19), [0, 0, 255]);
+        assert_eq!(color(0, 10), [0, 255, 0]);
+        assert_eq!(color(19, 10), [0, 255, 0]);
+    }
+}
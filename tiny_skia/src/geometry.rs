@@ -504,7 +504,7 @@ pub fn into_stroke(stroke: &Stroke<'_>) -> tiny_skia::Stroke {
         } else {
             tiny_skia::StrokeDash::new(
                 stroke.line_dash.segments.into(),
-                stroke.line_dash.offset as f32,
+                stroke.line_dash.start(),
             )
         },
         ..Default::default()
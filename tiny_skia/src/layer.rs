@@ -15,6 +15,7 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug, Clone)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub opacity: f32,
     pub quads: Vec<(Quad, Background)>,
     pub primitives: Vec<Item<Primitive>>,
     pub text: Vec<Item<Text>>,
@@ -132,7 +133,16 @@ impl Layer {
         bounds: Rectangle,
         transformation: Transformation,
     ) {
-        let image = Image::Raster(image, bounds * transformation);
+        let image = Image::Raster(
+            core::Image {
+                filter: core::image::Filter {
+                    blur: image.filter.blur * transformation.scale_factor(),
+                    ..image.filter
+                },
+                ..image
+            },
+            bounds * transformation,
+        );
 
         self.images.push(image);
     }
@@ -175,7 +185,9 @@ impl Layer {
     }
 
     pub fn damage(previous: &Self, current: &Self) -> Vec<Rectangle> {
-        if previous.bounds != current.bounds {
+        if previous.bounds != current.bounds
+            || previous.opacity != current.opacity
+        {
             return vec![previous.bounds, current.bounds];
         }
 
@@ -268,6 +280,7 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            opacity: 1.0,
             quads: Vec::new(),
             primitives: Vec::new(),
             text: Vec::new(),
@@ -290,8 +303,13 @@ impl graphics::Layer for Layer {
         self.bounds = bounds;
     }
 
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.opacity = 1.0;
 
         self.quads.clear();
         self.primitives.clear();
@@ -46,6 +46,7 @@ pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
     layers: layer::Stack,
+    scale_factor: Option<f32>,
     engine: Engine, // TODO: Shared engine
 }
 
@@ -55,10 +56,16 @@ impl Renderer {
             default_font,
             default_text_size,
             layers: layer::Stack::new(),
+            scale_factor: None,
             engine: Engine::new(),
         }
     }
 
+    /// Sets the default [`Font`] of the [`Renderer`].
+    pub fn set_default_font(&mut self, font: Font) {
+        self.default_font = font;
+    }
+
     pub fn layers(&mut self) -> &[Layer] {
         self.layers.flush();
         self.layers.as_slice()
@@ -113,84 +120,46 @@ impl Renderer {
 
                 engine::adjust_clip_mask(clip_mask, clip_bounds);
 
-                if !layer.quads.is_empty() {
-                    let render_span = debug::render(debug::Primitive::Quad);
-                    for (quad, background) in &layer.quads {
-                        self.engine.draw_quad(
-                            quad,
-                            background,
-                            Transformation::scale(scale_factor),
-                            pixels,
-                            clip_mask,
-                            clip_bounds,
-                        );
-                    }
-                    render_span.finish();
-                }
-
-                if !layer.primitives.is_empty() {
-                    let render_span = debug::render(debug::Primitive::Triangle);
-
-                    for group in &layer.primitives {
-                        let Some(new_clip_bounds) = (group.clip_bounds()
-                            * scale_factor)
-                            .intersection(&clip_bounds)
-                        else {
-                            continue;
-                        };
-
-                        engine::adjust_clip_mask(clip_mask, new_clip_bounds);
-
-                        for primitive in group.as_slice() {
-                            self.engine.draw_primitive(
-                                primitive,
-                                group.transformation()
-                                    * Transformation::scale(scale_factor),
-                                pixels,
-                                clip_mask,
-                                clip_bounds,
-                            );
-                        }
-
-                        engine::adjust_clip_mask(clip_mask, clip_bounds);
-                    }
-
-                    render_span.finish();
-                }
-
-                if !layer.images.is_empty() {
-                    let render_span = debug::render(debug::Primitive::Image);
-
-                    for image in &layer.images {
-                        self.engine.draw_image(
-                            image,
-                            Transformation::scale(scale_factor),
-                            pixels,
-                            clip_mask,
-                            clip_bounds,
-                        );
-                    }
-
-                    render_span.finish();
-                }
-
-                if !layer.text.is_empty() {
-                    let render_span = debug::render(debug::Primitive::Image);
-
-                    for group in &layer.text {
-                        for text in group.as_slice() {
-                            self.engine.draw_text(
-                                text,
-                                group.transformation()
-                                    * Transformation::scale(scale_factor),
-                                pixels,
-                                clip_mask,
-                                clip_bounds,
-                            );
-                        }
-                    }
-
-                    render_span.finish();
+                // Translucent layers are drawn in their own pixmap first;
+                // so their primitives do not show through each other
+                if layer.opacity < 1.0 {
+                    let Some(mut group) =
+                        tiny_skia::Pixmap::new(pixels.width(), pixels.height())
+                    else {
+                        continue;
+                    };
+
+                    draw_layer(
+                        &mut self.engine,
+                        layer,
+                        &mut group.as_mut(),
+                        clip_mask,
+                        clip_bounds,
+                        scale_factor,
+                    );
+
+                    engine::adjust_clip_mask(clip_mask, clip_bounds);
+
+                    pixels.draw_pixmap(
+                        0,
+                        0,
+                        group.as_ref(),
+                        &tiny_skia::PixmapPaint {
+                            opacity: layer.opacity,
+                            ..tiny_skia::PixmapPaint::default()
+                        },
+                        tiny_skia::Transform::identity(),
+                        Some(clip_mask),
+                    );
+                } else {
+                    draw_layer(
+                        &mut self.engine,
+                        layer,
+                        pixels,
+                        clip_mask,
+                        clip_bounds,
+                        scale_factor,
+                    );
                 }
             }
         }
@@ -216,6 +185,14 @@ impl core::Renderer for Renderer {
         self.layers.pop_transformation();
     }
 
+    fn start_opacity(&mut self, opacity: f32) {
+        self.layers.push_opacity(opacity);
+    }
+
+    fn end_opacity(&mut self) {
+        self.layers.pop_opacity();
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -228,6 +205,14 @@ impl core::Renderer for Renderer {
     fn clear(&mut self) {
         self.layers.clear();
     }
+
+    fn hint(&mut self, scale_factor: f32) {
+        self.scale_factor = Some(scale_factor);
+    }
+
+    fn scale_factor(&self) -> Option<f32> {
+        self.scale_factor
+    }
 }
 
 impl core::text::Renderer for Renderer {
@@ -411,3 +396,91 @@ impl renderer::Headless for Renderer {
         window::compositor::screenshot(self, &viewport, background_color)
     }
 }
+
+fn draw_layer(
+    engine: &mut engine::Engine,
+    layer: &Layer,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &mut tiny_skia::Mask,
+    clip_bounds: Rectangle,
+    scale_factor: f32,
+) {
+    if !layer.quads.is_empty() {
+        let render_span = debug::render(debug::Primitive::Quad);
+        for (quad, background) in &layer.quads {
+            engine.draw_quad(
+                quad,
+                background,
+                Transformation::scale(scale_factor),
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        }
+        render_span.finish();
+    }
+
+    if !layer.primitives.is_empty() {
+        let render_span = debug::render(debug::Primitive::Triangle);
+
+        for group in &layer.primitives {
+            let Some(new_clip_bounds) =
+                (group.clip_bounds() * scale_factor).intersection(&clip_bounds)
+            else {
+                continue;
+            };
+
+            engine::adjust_clip_mask(clip_mask, new_clip_bounds);
+
+            for primitive in group.as_slice() {
+                engine.draw_primitive(
+                    primitive,
+                    group.transformation()
+                        * Transformation::scale(scale_factor),
+                    pixels,
+                    clip_mask,
+                    clip_bounds,
+                );
+            }
+
+            engine::adjust_clip_mask(clip_mask, clip_bounds);
+        }
+
+        render_span.finish();
+    }
+
+    if !layer.images.is_empty() {
+        let render_span = debug::render(debug::Primitive::Image);
+
+        for image in &layer.images {
+            engine.draw_image(
+                image,
+                Transformation::scale(scale_factor),
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        }
+
+        render_span.finish();
+    }
+
+    if !layer.text.is_empty() {
+        let render_span = debug::render(debug::Primitive::Image);
+
+        for group in &layer.text {
+            for text in group.as_slice() {
+                engine.draw_text(
+                    text,
+                    group.transformation()
+                        * Transformation::scale(scale_factor),
+                    pixels,
+                    clip_mask,
+                    clip_bounds,
+                );
+            }
+        }
+
+        render_span.finish();
+    }
+}
@@ -32,11 +32,20 @@ impl Pipeline {
         filter_method: raster::FilterMethod,
         bounds: Rectangle,
         opacity: f32,
+        filter: raster::Filter,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         transform: tiny_skia::Transform,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
-        if let Some(image) = self.cache.borrow_mut().allocate(handle) {
+        let mut cache = self.cache.borrow_mut();
+
+        let image = if filter.is_none() {
+            cache.allocate(handle)
+        } else {
+            cache.allocate_filtered(handle, filter, bounds)
+        };
+
+        if let Some(image) = image {
             let width_scale = bounds.width / image.width() as f32;
             let height_scale = bounds.height / image.height() as f32;
 
@@ -75,8 +84,12 @@ impl Pipeline {
 struct Cache {
     entries: FxHashMap<raster::Id, Option<Entry>>,
     hits: FxHashSet<raster::Id>,
+    filtered: FxHashMap<(raster::Id, FilterKey), Entry>,
+    filtered_hits: FxHashSet<(raster::Id, FilterKey)>,
 }
 
+type FilterKey = [u32; 7];
+
 impl Cache {
     pub fn allocate(
         &mut self,
@@ -116,9 +129,69 @@ impl Cache {
         })
     }
 
+    pub fn allocate_filtered(
+        &mut self,
+        handle: &raster::Handle,
+        filter: raster::Filter,
+        bounds: Rectangle,
+    ) -> Option<tiny_skia::PixmapRef<'_>> {
+        let (width, height) = {
+            let image = self.allocate(handle)?;
+            (image.width(), image.height())
+        };
+
+        let id = handle.id();
+
+        // The blur radius in pixels of the image
+        let blur = (filter.blur * width as f32 / bounds.width.max(1.0)).round();
+        let [r, g, b, a] = filter.tint.unwrap_or_default().into_rgba8();
+
+        let key = (
+            id,
+            [
+                blur.to_bits(),
+                filter.grayscale.to_bits(),
+                filter.brightness.to_bits(),
+                u32::from(r),
+                u32::from(g),
+                u32::from(b),
+                u32::from(a),
+            ],
+        );
+
+        if !self.filtered.contains_key(&key) {
+            let mut pixels = self.entries.get(&id)?.as_ref()?.pixels.clone();
+
+            apply(&mut pixels, width, height, filter, blur);
+
+            let _ = self.filtered.insert(
+                key,
+                Entry {
+                    width,
+                    height,
+                    pixels,
+                },
+            );
+        }
+
+        let _ = self.filtered_hits.insert(key);
+        self.filtered.get(&key).map(|entry| {
+            tiny_skia::PixmapRef::from_bytes(
+                bytemuck::cast_slice(&entry.pixels),
+                entry.width,
+                entry.height,
+            )
+            .expect("Build pixmap from image bytes")
+        })
+    }
+
     fn trim(&mut self) {
         self.entries.retain(|key, _| self.hits.contains(key));
         self.hits.clear();
+
+        self.filtered
+            .retain(|key, _| self.filtered_hits.contains(key));
+        self.filtered_hits.clear();
     }
 }
 
@@ -128,3 +201,118 @@ struct Entry {
     height: u32,
     pixels: Vec<u32>,
 }
+
+/// Applies the [`raster::Filter`] to some premultiplied BGRA pixels.
+fn apply(
+    pixels: &mut [u32],
+    width: u32,
+    height: u32,
+    filter: raster::Filter,
+    blur: f32,
+) {
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(pixels);
+
+    if blur > 0.0 && width > 0 && height > 0 {
+        // Three box blurs approximate a gaussian blur
+        let radius = (blur / 2.0).ceil() as usize;
+
+        for _ in 0..3 {
+            box_blur(bytes, width as usize, height as usize, radius, false);
+            box_blur(bytes, width as usize, height as usize, radius, true);
+        }
+    }
+
+    let tint = filter.tint.map(|tint| {
+        let [r, g, b, a] = tint.into_rgba8();
+
+        (
+            [b, g, r].map(|channel| f32::from(channel) / 255.0),
+            f32::from(a) / 255.0,
+        )
+    });
+
+    for pixel in bytes.chunks_exact_mut(4) {
+        let alpha = f32::from(pixel[3]) / 255.0;
+        let mut color = [0, 1, 2].map(|i| f32::from(pixel[i]) / 255.0);
+
+        let luminance =
+            0.0722 * color[0] + 0.7152 * color[1] + 0.2126 * color[2];
+
+        for channel in &mut color {
+            *channel += (luminance - *channel) * filter.grayscale;
+        }
+
+        if let Some((tint, strength)) = tint {
+            for (channel, tint) in color.iter_mut().zip(tint) {
+                *channel += (tint * alpha - *channel) * strength;
+            }
+        }
+
+        for (i, channel) in color.into_iter().enumerate() {
+            pixel[i] = ((channel * filter.brightness).clamp(0.0, alpha) * 255.0)
+                .round() as u8;
+        }
+    }
+}
+
+/// Blurs the rows—or the columns, if `vertical`—of some pixels with a box
+/// of the given `radius`.
+fn box_blur(
+    bytes: &mut [u8],
+    width: usize,
+    height: usize,
+    radius: usize,
+    vertical: bool,
+) {
+    let (length, lines) = if vertical {
+        (height, width)
+    } else {
+        (width, height)
+    };
+
+    let index = |line: usize, i: usize| {
+        if vertical {
+            (i * width + line) * 4
+        } else {
+            (line * width + i) * 4
+        }
+    };
+
+    let mut pixels = vec![[0u8; 4]; length];
+    let window = (2 * radius + 1) as u32;
+
+    for line in 0..lines {
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            let start = index(line, i);
+            pixel.copy_from_slice(&bytes[start..start + 4]);
+        }
+
+        let mut sum =
+            pixels[0].map(|channel| u32::from(channel) * (radius as u32 + 1));
+
+        for i in 1..=radius {
+            for (total, channel) in
+                sum.iter_mut().zip(pixels[i.min(length - 1)])
+            {
+                *total += u32::from(channel);
+            }
+        }
+
+        for i in 0..length {
+            let start = index(line, i);
+
+            for (target, total) in bytes[start..start + 4].iter_mut().zip(sum) {
+                *target = (total / window) as u8;
+            }
+
+            let next = pixels[(i + radius + 1).min(length - 1)];
+            let previous = pixels[i.saturating_sub(radius)];
+
+            for ((total, next), previous) in
+                sum.iter_mut().zip(next).zip(previous)
+            {
+                *total = *total + u32::from(next) - u32::from(previous);
+            }
+        }
+    }
+}
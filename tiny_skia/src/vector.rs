@@ -101,13 +101,18 @@ impl Cache {
         };
 
         if let hash_map::Entry::Vacant(entry) = self.trees.entry(id) {
-            let svg = match handle.data() {
-                Data::Path(path) => {
+            let source = crate::graphics::svg::load(handle);
+
+            let svg = match (source, handle.data()) {
+                (Some(source), _) => {
+                    usvg::Tree::from_str(&source, &options).ok()
+                }
+                (None, Data::Path(path)) => {
                     fs::read_to_string(path).ok().and_then(|contents| {
                         usvg::Tree::from_str(&contents, &options).ok()
                     })
                 }
-                Data::Bytes(bytes) => {
+                (None, Data::Bytes(bytes)) => {
                     usvg::Tree::from_data(bytes, &options).ok()
                 }
             };
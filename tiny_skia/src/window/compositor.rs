@@ -1,4 +1,4 @@
-use crate::core::{Color, Rectangle, Size};
+use crate::core::{self, Color, Rectangle, Size};
 use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
 use crate::graphics::error::{self, Error};
@@ -132,6 +132,14 @@ impl crate::graphics::Compositor for Compositor {
     ) -> Vec<u8> {
         screenshot(renderer, viewport, background_color)
     }
+
+    fn set_default_font(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        font: Option<core::Font>,
+    ) {
+        renderer.set_default_font(font.unwrap_or(self.settings.default_font));
+    }
 }
 
 pub fn new<W: compositor::Window>(
@@ -145,6 +153,12 @@ pub fn new<W: compositor::Window>(
     Compositor { context, settings }
 }
 
+/// Draws and presents only the regions of the surface that changed since its
+/// buffer was last presented.
+///
+/// The damage is computed by diffing the layers of the renderer; the widget
+/// tree does not report any damage, so every frame is still laid out and
+/// drawn into layers in full.
 pub fn present(
     renderer: &mut Renderer,
     surface: &mut Surface,
@@ -211,7 +225,52 @@ pub fn present(
     );
 
     on_pre_present();
-    buffer.present().map_err(|_| compositor::SurfaceError::Lost)
+
+    // Only the damaged regions of the buffer need to be presented
+    let regions = regions(
+        &damage,
+        viewport.scale_factor() as f32,
+        physical_size.width,
+        physical_size.height,
+    );
+
+    buffer
+        .present_with_damage(&regions)
+        .map_err(|_| compositor::SurfaceError::Lost)
+}
+
+/// Converts the logical damage of a frame into the physical regions of a
+/// buffer of the given size; dropping any region outside of it.
+fn regions(
+    damage: &[Rectangle],
+    scale_factor: f32,
+    width: u32,
+    height: u32,
+) -> Vec<softbuffer::Rect> {
+    damage
+        .iter()
+        .filter_map(|region| {
+            let region = *region * scale_factor;
+
+            let x = region.x.floor().max(0.0) as u32;
+            let y = region.y.floor().max(0.0) as u32;
+
+            let region_width = ((region.x + region.width).ceil() as u32)
+                .min(width)
+                .checked_sub(x)?;
+
+            let region_height = ((region.y + region.height).ceil() as u32)
+                .min(height)
+                .checked_sub(y)?;
+
+            Some(softbuffer::Rect {
+                x,
+                y,
+                width: NonZeroU32::new(region_width)?,
+                height: NonZeroU32::new(region_height)?,
+            })
+        })
+        .collect()
 }
 
 pub fn screenshot(
@@ -261,3 +320,40 @@ pub fn screenshot(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Point;
+
+    #[test]
+    fn regions_are_scaled_and_rounded_outwards() {
+        let regions = regions(
+            &[Rectangle::new(Point::new(10.2, 5.5), Size::new(20.5, 10.0))],
+            2.0,
+            200,
+            200,
+        );
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!((regions[0].x, regions[0].y), (20, 11));
+        assert_eq!((regions[0].width.get(), regions[0].height.get()), (42, 20));
+    }
+
+    #[test]
+    fn regions_are_clipped_to_the_buffer() {
+        let regions = regions(
+            &[
+                Rectangle::new(Point::new(90.0, 90.0), Size::new(50.0, 50.0)),
+                Rectangle::new(Point::new(150.0, 0.0), Size::new(10.0, 10.0)),
+            ],
+            1.0,
+            100,
+            100,
+        );
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!((regions[0].x, regions[0].y), (90, 90));
+        assert_eq!((regions[0].width.get(), regions[0].height.get()), (10, 10));
+    }
+}
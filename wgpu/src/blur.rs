@@ -0,0 +1,523 @@
+use crate::Buffer;
+use crate::core::{Rectangle, Transformation};
+
+use bytemuck::{Pod, Zeroable};
+
+use std::mem;
+
+const INITIAL_INSTANCES: usize = 10;
+
+/// A region of the backdrop to be blurred.
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct Blur {
+    /// The position of the [`Blur`].
+    pub position: [f32; 2],
+
+    /// The size of the [`Blur`].
+    pub size: [f32; 2],
+
+    /// The border radii of the [`Blur`].
+    pub border_radius: [f32; 4],
+
+    /// The smoothing of the corners of the [`Blur`].
+    pub border_smoothing: f32,
+
+    /// The blur radius of the [`Blur`].
+    pub radius: f32,
+}
+
+/// A group of [`Blur`] regions rendered together.
+pub type Batch = Vec<Blur>;
+
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    horizontal: wgpu::RenderPipeline,
+    vertical: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    constant_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    format: wgpu::TextureFormat,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu.blur.uniforms_layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>()
+                                    as wgpu::BufferAddress,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering,
+                        ),
+                        count: None,
+                    },
+                ],
+            });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu.blur.texture_layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: true,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu.blur.pipeline_layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu.blur.shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    concat!(
+                        include_str!("shader/quad.wgsl"),
+                        "\n",
+                        include_str!("shader/vertex.wgsl"),
+                        "\n",
+                        include_str!("shader/blur.wgsl"),
+                    ),
+                )),
+            });
+
+        let pipeline = |label, vertex, fragment, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some(vertex),
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: mem::size_of::<Blur>() as u64,
+                        step_mode: wgpu::VertexStepMode::Instance,
+                        attributes: &wgpu::vertex_attr_array!(
+                            // Position
+                            0 => Float32x2,
+                            // Size
+                            1 => Float32x2,
+                            // Border radius
+                            2 => Float32x4,
+                            // Border smoothing
+                            3 => Float32,
+                            // Blur radius
+                            4 => Float32,
+                        ),
+                    }],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(fragment),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        };
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("iced_wgpu.blur.sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..wgpu::SamplerDescriptor::default()
+        });
+
+        Self {
+            horizontal: pipeline(
+                "iced_wgpu.blur.horizontal.pipeline",
+                "blur_vs_expanded",
+                "blur_fs_horizontal",
+                None,
+            ),
+            vertical: pipeline(
+                "iced_wgpu.blur.vertical.pipeline",
+                "blur_vs_main",
+                "blur_fs_vertical",
+                None,
+            ),
+            composite: pipeline(
+                "iced_wgpu.blur.composite.pipeline",
+                "blur_vs_main",
+                "blur_fs_composite",
+                Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            ),
+            constant_layout,
+            texture_layout,
+            sampler,
+            format,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct State {
+    layers: Vec<Layer>,
+    prepare_layer: usize,
+    targets: Option<Targets>,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn prepare(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        blurs: &Batch,
+        transformation: Transformation,
+        scale: f32,
+    ) {
+        if self.layers.len() <= self.prepare_layer {
+            self.layers.push(Layer::new(device, pipeline));
+        }
+
+        let layer = &mut self.layers[self.prepare_layer];
+        layer.prepare(device, encoder, belt, blurs, transformation, scale);
+
+        self.prepare_layer += 1;
+    }
+
+    /// Blurs the contents of the `target` texture for the given layer.
+    ///
+    /// Returns false if the `target` cannot be used as a backdrop.
+    pub fn blur(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::Texture,
+        layer: usize,
+    ) -> bool {
+        if !target.usage().contains(wgpu::TextureUsages::COPY_SRC)
+            || target.sample_count() != 1
+        {
+            return false;
+        }
+
+        let Some(layer) = self.layers.get(layer) else {
+            return false;
+        };
+
+        if !self
+            .targets
+            .as_ref()
+            .is_some_and(|targets| targets.is_compatible(target))
+        {
+            self.targets = Some(Targets::new(device, pipeline, target));
+        }
+
+        let Some(targets) = &self.targets else {
+            return false;
+        };
+
+        encoder.copy_texture_to_texture(
+            target.as_image_copy(),
+            targets.backdrop.as_image_copy(),
+            target.size(),
+        );
+
+        for (label, stage, source, destination) in [
+            (
+                "iced_wgpu.blur.horizontal.render_pass",
+                &pipeline.horizontal,
+                &targets.backdrop_binding,
+                &targets.intermediate,
+            ),
+            (
+                "iced_wgpu.blur.vertical.render_pass",
+                &pipeline.vertical,
+                &targets.intermediate_binding,
+                &targets.blurred,
+            ),
+        ] {
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some(label),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view: destination,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: wgpu::StoreOp::Store,
+                            },
+                        },
+                    )],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+            render_pass.set_pipeline(stage);
+            render_pass.set_bind_group(0, &layer.constants, &[]);
+            render_pass.set_bind_group(1, source, &[]);
+            render_pass.set_vertex_buffer(0, layer.instances.slice(..));
+            render_pass.draw(0..6, 0..layer.instance_count as u32);
+        }
+
+        true
+    }
+
+    /// Draws the blurred backdrop of the given layer.
+    pub fn render(
+        &self,
+        pipeline: &Pipeline,
+        layer: usize,
+        bounds: Rectangle<u32>,
+        render_pass: &mut wgpu::RenderPass<'_>,
+    ) {
+        let (Some(layer), Some(targets)) =
+            (self.layers.get(layer), &self.targets)
+        else {
+            return;
+        };
+
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+
+        render_pass.set_pipeline(&pipeline.composite);
+        render_pass.set_bind_group(0, &layer.constants, &[]);
+        render_pass.set_bind_group(1, &targets.blurred_binding, &[]);
+        render_pass.set_vertex_buffer(0, layer.instances.slice(..));
+        render_pass.draw(0..6, 0..layer.instance_count as u32);
+    }
+
+    pub fn trim(&mut self) {
+        self.prepare_layer = 0;
+    }
+}
+
+struct Layer {
+    constants: wgpu::BindGroup,
+    constants_buffer: wgpu::Buffer,
+    instances: Buffer<Blur>,
+    instance_count: usize,
+}
+
+impl Layer {
+    fn new(device: &wgpu::Device, pipeline: &Pipeline) -> Self {
+        let constants_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu.blur.uniforms_buffer"),
+            size: mem::size_of::<Uniforms>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu.blur.uniforms_bind_group"),
+            layout: &pipeline.constant_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: constants_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
+                },
+            ],
+        });
+
+        let instances = Buffer::new(
+            device,
+            "iced_wgpu.blur.buffer",
+            INITIAL_INSTANCES,
+            wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        );
+
+        Self {
+            constants,
+            constants_buffer,
+            instances,
+            instance_count: 0,
+        }
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        blurs: &Batch,
+        transformation: Transformation,
+        scale: f32,
+    ) {
+        let uniforms = Uniforms::new(transformation, scale);
+        let bytes = bytemuck::bytes_of(&uniforms);
+
+        belt.write_buffer(
+            encoder,
+            &self.constants_buffer,
+            0,
+            (bytes.len() as u64).try_into().expect("Sized uniforms"),
+            device,
+        )
+        .copy_from_slice(bytes);
+
+        let _ = self.instances.resize(device, blurs.len());
+        let _ = self.instances.write(device, encoder, belt, 0, blurs);
+
+        self.instance_count = blurs.len();
+    }
+}
+
+/// The textures used to blur the backdrop.
+struct Targets {
+    size: wgpu::Extent3d,
+    format: wgpu::TextureFormat,
+    backdrop: wgpu::Texture,
+    backdrop_binding: wgpu::BindGroup,
+    intermediate: wgpu::TextureView,
+    intermediate_binding: wgpu::BindGroup,
+    blurred: wgpu::TextureView,
+    blurred_binding: wgpu::BindGroup,
+}
+
+impl Targets {
+    fn new(
+        device: &wgpu::Device,
+        pipeline: &Pipeline,
+        target: &wgpu::Texture,
+    ) -> Self {
+        let texture = |label, format, usage| {
+            device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: target.size(),
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | usage,
+                view_formats: &[],
+            })
+        };
+
+        let binding = |label, view: &wgpu::TextureView| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &pipeline.texture_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(view),
+                }],
+            })
+        };
+
+        let backdrop = texture(
+            "iced_wgpu.blur.backdrop_texture",
+            target.format(),
+            wgpu::TextureUsages::COPY_DST,
+        );
+
+        let intermediate = texture(
+            "iced_wgpu.blur.intermediate_texture",
+            pipeline.format,
+            wgpu::TextureUsages::RENDER_ATTACHMENT,
+        )
+        .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let blurred = texture(
+            "iced_wgpu.blur.blurred_texture",
+            pipeline.format,
+            wgpu::TextureUsages::RENDER_ATTACHMENT,
+        )
+        .create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            size: target.size(),
+            format: target.format(),
+            backdrop_binding: binding(
+                "iced_wgpu.blur.backdrop_bind_group",
+                &backdrop.create_view(&wgpu::TextureViewDescriptor::default()),
+            ),
+            backdrop,
+            intermediate_binding: binding(
+                "iced_wgpu.blur.intermediate_bind_group",
+                &intermediate,
+            ),
+            intermediate,
+            blurred_binding: binding(
+                "iced_wgpu.blur.blurred_bind_group",
+                &blurred,
+            ),
+            blurred,
+        }
+    }
+
+    fn is_compatible(&self, target: &wgpu::Texture) -> bool {
+        self.size == target.size() && self.format == target.format()
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Zeroable, bytemuck::Pod)]
+struct Uniforms {
+    transform: [f32; 16],
+    scale: f32,
+    // Uniforms must be aligned to their largest member,
+    // this uses a mat4x4<f32> which aligns to 16, so align to that
+    _padding: [f32; 3],
+}
+
+impl Uniforms {
+    fn new(transformation: Transformation, scale: f32) -> Uniforms {
+        Self {
+            transform: *transformation.as_ref(),
+            scale,
+            _padding: [0.0; 3],
+        }
+    }
+}
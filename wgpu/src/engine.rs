@@ -1,4 +1,7 @@
+use crate::blur;
+use crate::core::image;
 use crate::graphics::Antialiasing;
+use crate::opacity;
 use crate::primitive;
 use crate::quad;
 use crate::text;
@@ -14,10 +17,16 @@ pub struct Engine {
     pub(crate) format: wgpu::TextureFormat,
 
     pub(crate) quad_pipeline: quad::Pipeline,
+    pub(crate) blur_pipeline: blur::Pipeline,
+    pub(crate) opacity_pipeline: opacity::Pipeline,
     pub(crate) text_pipeline: text::Pipeline,
     pub(crate) triangle_pipeline: triangle::Pipeline,
     #[cfg(any(feature = "image", feature = "svg"))]
     pub(crate) image_pipeline: crate::image::Pipeline,
+    #[cfg(any(feature = "image", feature = "svg"))]
+    pub(crate) image_budget: image::Budget,
+    #[cfg(any(feature = "image", feature = "svg"))]
+    pub(crate) image_cache: Arc<RwLock<crate::image::cache::Shared>>,
     pub(crate) primitive_storage: Arc<RwLock<primitive::Storage>>,
 }
 
@@ -29,10 +38,25 @@ impl Engine {
         format: wgpu::TextureFormat,
         antialiasing: Option<Antialiasing>, // TODO: Initialize AA pipelines lazily
     ) -> Self {
+        #[cfg(any(feature = "image", feature = "svg"))]
+        let image_pipeline = {
+            let backend = _adapter.get_info().backend;
+
+            crate::image::Pipeline::new(&device, format, backend)
+        };
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        let image_cache =
+            Arc::new(RwLock::new(crate::image::cache::Shared::new(
+                image_pipeline.create_cache(&device, image::Budget::default()),
+            )));
+
         Self {
             format,
 
             quad_pipeline: quad::Pipeline::new(&device, format),
+            blur_pipeline: blur::Pipeline::new(&device, format),
+            opacity_pipeline: opacity::Pipeline::new(&device, format),
             text_pipeline: text::Pipeline::new(&device, &queue, format),
             triangle_pipeline: triangle::Pipeline::new(
                 &device,
@@ -41,11 +65,13 @@ impl Engine {
             ),
 
             #[cfg(any(feature = "image", feature = "svg"))]
-            image_pipeline: {
-                let backend = _adapter.get_info().backend;
+            image_pipeline,
+
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_budget: image::Budget::default(),
 
-                crate::image::Pipeline::new(&device, format, backend)
-            },
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_cache,
 
             primitive_storage: Arc::new(RwLock::new(
                 primitive::Storage::default(),
@@ -56,11 +82,30 @@ impl Engine {
         }
     }
 
+    /// Sets the memory [`Budget`](image::Budget) of the image caches
+    /// created by the [`Engine`].
+    ///
+    /// The image cache shared by all the renderers of the [`Engine`] is
+    /// replaced; so the budget must be set before creating any renderer.
+    pub fn with_image_budget(self, _budget: image::Budget) -> Self {
+        Self {
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_budget: _budget,
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_cache: Arc::new(RwLock::new(
+                crate::image::cache::Shared::new(
+                    self.image_pipeline.create_cache(&self.device, _budget),
+                ),
+            )),
+            ..self
+        }
+    }
+
     #[cfg(any(feature = "image", feature = "svg"))]
     pub fn create_image_cache(
         &self,
         device: &wgpu::Device,
     ) -> crate::image::Cache {
-        self.image_pipeline.create_cache(device)
+        self.image_pipeline.create_cache(device, self.image_budget)
     }
 }
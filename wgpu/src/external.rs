@@ -0,0 +1,93 @@
+//! Display textures owned by the application as images.
+//!
+//! Video decoders and camera pipelines can write their frames directly into
+//! a [`wgpu::Texture`]—including textures imported from DMA-BUFs or other
+//! native handles through `wgpu-hal`—and [`register`] it to obtain an
+//! [`image::Handle`]. The pixels are then copied on the GPU every time the
+//! image is drawn; no CPU copies are involved.
+use crate::core::image;
+
+use rustc_hash::FxHashMap;
+use std::sync::{LazyLock, Mutex};
+
+static TEXTURES: LazyLock<Mutex<FxHashMap<image::Id, wgpu::Texture>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// Registers the given [`wgpu::Texture`] and returns an [`image::Handle`]
+/// that draws its contents.
+///
+/// The texture must be created with the device of the renderer, use the
+/// [`wgpu::TextureFormat::Rgba8Unorm`] or
+/// [`wgpu::TextureFormat::Rgba8UnormSrgb`] format, and allow
+/// [`wgpu::TextureUsages::COPY_SRC`]. Otherwise, nothing will be drawn.
+pub fn register(texture: wgpu::Texture) -> image::Handle {
+    let handle =
+        image::Handle::from_external(texture.width(), texture.height());
+
+    if is_compatible(&texture) {
+        let _ = TEXTURES
+            .lock()
+            .expect("Lock external textures")
+            .insert(handle.id(), texture);
+    } else {
+        log::error!(
+            "External texture is not compatible: {:?} ({:?})",
+            texture.format(),
+            texture.usage()
+        );
+    }
+
+    handle
+}
+
+/// Replaces the texture of a registered [`image::Handle`]; useful to swap
+/// the buffers of a video pipeline.
+///
+/// The new texture must have the same dimensions as the old one.
+pub fn update(handle: &image::Handle, texture: wgpu::Texture) {
+    let image::Handle::External { id, width, height } = handle else {
+        return;
+    };
+
+    if texture.width() != *width || texture.height() != *height {
+        log::error!(
+            "External texture has wrong dimensions: {}x{} (expected {width}x{height})",
+            texture.width(),
+            texture.height(),
+        );
+
+        return;
+    }
+
+    if !is_compatible(&texture) {
+        return;
+    }
+
+    let _ = TEXTURES
+        .lock()
+        .expect("Lock external textures")
+        .insert(*id, texture);
+}
+
+/// Unregisters the texture of the given [`image::Handle`], releasing it.
+pub fn unregister(handle: &image::Handle) {
+    let _ = TEXTURES
+        .lock()
+        .expect("Lock external textures")
+        .remove(&handle.id());
+}
+
+pub(crate) fn texture(id: image::Id) -> Option<wgpu::Texture> {
+    TEXTURES
+        .lock()
+        .expect("Lock external textures")
+        .get(&id)
+        .cloned()
+}
+
+fn is_compatible(texture: &wgpu::Texture) -> bool {
+    matches!(
+        texture.format(),
+        wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb
+    ) && texture.usage().contains(wgpu::TextureUsages::COPY_SRC)
+}
@@ -735,17 +735,41 @@ pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
     };
     use lyon::path::iterator::PathIterator;
 
+    let segments_odd = (line_dash.segments.len() % 2 == 1)
+        .then(|| [line_dash.segments, line_dash.segments].concat());
+
+    let intervals = segments_odd.as_deref().unwrap_or(line_dash.segments);
+    let total: f32 = intervals.iter().sum();
+
+    if total <= 0.0 {
+        return path.clone();
+    }
+
+    // Find the interval where the line starts
+    let mut index = 0;
+    let mut remaining = line_dash.start().rem_euclid(total);
+
+    while remaining > 0.0 && remaining >= intervals[index] {
+        remaining -= intervals[index];
+        index = (index + 1) % intervals.len();
+    }
+
     Path::new(|builder| {
-        let segments_odd = (line_dash.segments.len() % 2 == 1)
-            .then(|| [line_dash.segments, line_dash.segments].concat());
+        let mut draw_line = index % 2 == 0;
 
-        let mut draw_line = false;
+        if draw_line {
+            if let Some(lyon::path::Event::Begin { at }) =
+                path.raw().iter().next()
+            {
+                builder.move_to(Point::new(at.x, at.y));
+            }
+        }
 
         walk_along_path(
             path.raw().iter().flattened(
                 lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
             ),
-            0.0,
+            intervals[index] - remaining,
             lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
             &mut RepeatedPattern {
                 callback: |event: WalkerEvent<'_>| {
@@ -764,10 +788,8 @@ pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
 
                     true
                 },
-                index: line_dash.offset,
-                intervals: segments_odd
-                    .as_deref()
-                    .unwrap_or(line_dash.segments),
+                index: (index + 1) % intervals.len(),
+                intervals,
             },
         );
     })
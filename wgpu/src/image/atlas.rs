@@ -13,6 +13,7 @@ use allocator::Allocator;
 pub const SIZE: u32 = 2048;
 
 use crate::core::Size;
+use crate::core::image::{Budget, Growth, Usage};
 use crate::graphics::color;
 
 use std::sync::Arc;
@@ -25,6 +26,7 @@ pub struct Atlas {
     texture_bind_group: wgpu::BindGroup,
     texture_layout: Arc<wgpu::BindGroupLayout>,
     layers: Vec<Layer>,
+    budget: Budget,
 }
 
 impl Atlas {
@@ -32,6 +34,7 @@ impl Atlas {
         device: &wgpu::Device,
         backend: wgpu::Backend,
         texture_layout: Arc<wgpu::BindGroupLayout>,
+        budget: Budget,
     ) -> Self {
         let layers = match backend {
             // On the GL backend we start with 2 layers, to help wgpu figure
@@ -86,6 +89,7 @@ impl Atlas {
             texture_bind_group,
             texture_layout,
             layers,
+            budget,
         }
     }
 
@@ -97,6 +101,39 @@ impl Atlas {
         self.layers.len()
     }
 
+    pub fn usage(&self) -> Usage {
+        const LAYER_BYTES: u64 = SIZE as u64 * SIZE as u64 * 4;
+
+        Usage {
+            allocated: self.layers.len() as u64 * LAYER_BYTES,
+            used: self.layers.iter().map(Layer::area).sum::<u64>() * 4,
+            ..Usage::default()
+        }
+    }
+
+    /// Releases the empty layers at the end of the [`Atlas`].
+    pub fn shrink(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let used = self
+            .layers
+            .iter()
+            .rposition(|layer| !layer.is_empty())
+            .map_or(0, |last| last + 1)
+            .max(self.min_layers());
+
+        if used >= self.layers.len() {
+            return;
+        }
+
+        log::debug!("Shrinking atlas: {} -> {used}", self.layers.len());
+
+        self.layers.truncate(used);
+        self.resize(used, device, encoder);
+    }
+
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
@@ -105,18 +142,7 @@ impl Atlas {
         height: u32,
         data: &[u8],
     ) -> Option<Entry> {
-        let entry = {
-            let current_size = self.layers.len();
-            let entry = self.allocate(width, height)?;
-
-            // We grow the internal texture after allocating if necessary
-            let new_layers = self.layers.len() - current_size;
-            self.grow(new_layers, device, encoder);
-
-            entry
-        };
-
-        log::debug!("Allocated atlas entry: {entry:?}");
+        let entry = self.reserve(device, encoder, width, height)?;
 
         // It is a webgpu requirement that:
         //   BufferCopyView.layout.bytes_per_row % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT == 0
@@ -181,6 +207,97 @@ impl Atlas {
         Some(entry)
     }
 
+    /// Allocates an [`Entry`] of the given dimensions, growing the texture
+    /// of the [`Atlas`] if necessary.
+    pub fn reserve(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        width: u32,
+        height: u32,
+    ) -> Option<Entry> {
+        let current_size = self.layers.len();
+        let entry = self.allocate(width, height);
+
+        // We grow the internal texture after allocating if necessary;
+        // even if the allocation failed halfway
+        if self.layers.len() > current_size {
+            if self.budget.growth == Growth::Double {
+                let doubled = (current_size * 2).min(self.max_layers());
+
+                while self.layers.len() < doubled {
+                    self.layers.push(Layer::Empty);
+                }
+            }
+
+            self.resize(current_size, device, encoder);
+        }
+
+        let entry = entry?;
+
+        log::debug!("Allocated atlas entry: {entry:?}");
+
+        Some(entry)
+    }
+
+    /// Copies the contents of the given texture into the [`Entry`], without
+    /// going through the CPU.
+    ///
+    /// The texture must have the dimensions of the [`Entry`].
+    #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+    pub fn copy(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        entry: &Entry,
+    ) {
+        let copy = |(source_x, source_y): (u32, u32),
+                    allocation: &Allocation,
+                    encoder: &mut wgpu::CommandEncoder| {
+            let (x, y) = allocation.position();
+            let Size { width, height } = allocation.size();
+
+            encoder.copy_texture_to_texture(
+                wgpu::TexelCopyTextureInfo {
+                    texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: source_x,
+                        y: source_y,
+                        z: 0,
+                    },
+                    aspect: wgpu::TextureAspect::default(),
+                },
+                wgpu::TexelCopyTextureInfo {
+                    texture: &self.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x,
+                        y,
+                        z: allocation.layer() as u32,
+                    },
+                    aspect: wgpu::TextureAspect::default(),
+                },
+                wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+            );
+        };
+
+        match entry {
+            Entry::Contiguous(allocation) => {
+                copy((0, 0), allocation, encoder);
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    copy(fragment.position, &fragment.allocation, encoder);
+                }
+            }
+        }
+    }
+
     pub fn remove(&mut self, entry: &Entry) {
         log::debug!("Removing atlas entry: {entry:?}");
 
@@ -211,6 +328,10 @@ impl Atlas {
                 return Some(Entry::Contiguous(Allocation::Full { layer: i }));
             }
 
+            if !self.has_room() {
+                return None;
+            }
+
             self.layers.push(Layer::Full);
 
             return Some(Entry::Contiguous(Allocation::Full {
@@ -230,7 +351,13 @@ impl Atlas {
                 while x < width {
                     let width = std::cmp::min(width - x, SIZE);
 
-                    let allocation = self.allocate(width, height)?;
+                    let Some(allocation) = self.allocate(width, height) else {
+                        for fragment in &fragments {
+                            self.deallocate(&fragment.allocation);
+                        }
+
+                        return None;
+                    };
 
                     if let Entry::Contiguous(allocation) = allocation {
                         fragments.push(entry::Fragment {
@@ -278,6 +405,10 @@ impl Atlas {
             }
         }
 
+        if !self.has_room() {
+            return None;
+        }
+
         // Create new layer with atlas allocator
         let mut allocator = Allocator::new(SIZE);
 
@@ -294,6 +425,34 @@ impl Atlas {
         None
     }
 
+    fn min_layers(&self) -> usize {
+        match self.backend {
+            wgpu::Backend::Gl => 2,
+            _ => 1,
+        }
+    }
+
+    fn max_layers(&self) -> usize {
+        self.budget.max_size.map_or(usize::MAX, |max_size| {
+            let layers = max_size / (u64::from(SIZE) * u64::from(SIZE) * 4);
+
+            (layers as usize).max(self.min_layers())
+        })
+    }
+
+    fn has_room(&self) -> bool {
+        let has_room = self.layers.len() < self.max_layers();
+
+        if !has_room {
+            log::warn!(
+                "Image atlas budget exceeded ({} layers)",
+                self.layers.len()
+            );
+        }
+
+        has_room
+    }
+
     fn deallocate(&mut self, allocation: &Allocation) {
         log::debug!("Deallocating atlas: {allocation:?}");
 
@@ -368,16 +527,14 @@ impl Atlas {
         );
     }
 
-    fn grow(
+    /// Recreates the texture of the [`Atlas`] to fit its current layers,
+    /// keeping the contents of the first `preserved` layers.
+    fn resize(
         &mut self,
-        amount: usize,
+        preserved: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
     ) {
-        if amount == 0 {
-            return;
-        }
-
         // On the GL backend if layers.len() == 6 we need to help wgpu figure out that this texture
         // is still a `GL_TEXTURE_2D_ARRAY` rather than `GL_TEXTURE_CUBE_MAP`. This will over-allocate
         // some unused memory on GL, but it's better than not being able to grow the atlas past a depth
@@ -409,11 +566,7 @@ impl Atlas {
             view_formats: &[],
         });
 
-        let amount_to_copy = self.layers.len() - amount;
-
-        for (i, layer) in
-            self.layers.iter_mut().take(amount_to_copy).enumerate()
-        {
+        for (i, layer) in self.layers.iter_mut().take(preserved).enumerate() {
             if layer.is_empty() {
                 continue;
             }
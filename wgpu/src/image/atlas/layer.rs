@@ -1,4 +1,4 @@
-use crate::image::atlas::Allocator;
+use crate::image::atlas::{self, Allocator};
 
 #[derive(Debug)]
 pub enum Layer {
@@ -19,4 +19,12 @@ impl Layer {
             Layer::Full => 1,
         }
     }
+
+    pub fn area(&self) -> u64 {
+        match self {
+            Layer::Empty => 0,
+            Layer::Busy(allocator) => allocator.area(),
+            Layer::Full => u64::from(atlas::SIZE) * u64::from(atlas::SIZE),
+        }
+    }
 }
@@ -1,11 +1,13 @@
 use crate::core::{self, Size};
 use crate::image::atlas::{self, Atlas};
 
+use rustc_hash::FxHashMap;
 use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct Cache {
     atlas: Atlas,
+    frames: Frames,
     #[cfg(feature = "image")]
     raster: crate::image::raster::Cache,
     #[cfg(feature = "svg")]
@@ -17,11 +19,13 @@ impl Cache {
         device: &wgpu::Device,
         backend: wgpu::Backend,
         layout: Arc<wgpu::BindGroupLayout>,
+        budget: core::image::Budget,
     ) -> Self {
         Self {
-            atlas: Atlas::new(device, backend, layout),
+            atlas: Atlas::new(device, backend, layout, budget),
+            frames: Frames::default(),
             #[cfg(feature = "image")]
-            raster: crate::image::raster::Cache::default(),
+            raster: crate::image::raster::Cache::new(budget.max_size.is_some()),
             #[cfg(feature = "svg")]
             vector: crate::image::vector::Cache::default(),
         }
@@ -35,9 +39,21 @@ impl Cache {
         self.atlas.layer_count()
     }
 
+    pub fn usage(&self) -> core::image::Usage {
+        let usage = self.atlas.usage();
+
+        #[cfg(feature = "image")]
+        let usage = core::image::Usage {
+            images: self.raster.len(),
+            ..usage
+        };
+
+        usage
+    }
+
     #[cfg(feature = "image")]
     pub fn measure_image(&mut self, handle: &core::image::Handle) -> Size<u32> {
-        self.raster.load(handle).dimensions()
+        self.raster.load(handle, &self.frames).dimensions()
     }
 
     #[cfg(feature = "svg")]
@@ -51,8 +67,21 @@ impl Cache {
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         handle: &core::image::Handle,
+        target: Size,
     ) -> Option<&atlas::Entry> {
-        self.raster.upload(device, encoder, handle, &mut self.atlas)
+        self.raster.upload(
+            device,
+            encoder,
+            handle,
+            target,
+            &mut self.atlas,
+            &self.frames,
+        )
+    }
+
+    #[cfg(feature = "image")]
+    pub fn remove_raster(&mut self, handle: &core::image::Handle) {
+        self.raster.remove(handle, &mut self.atlas);
     }
 
     #[cfg(feature = "svg")]
@@ -73,14 +102,136 @@ impl Cache {
             size,
             scale,
             &mut self.atlas,
+            &self.frames,
         )
     }
 
     pub fn trim(&mut self) {
         #[cfg(feature = "image")]
-        self.raster.trim(&mut self.atlas);
+        self.raster.trim(&mut self.atlas, &self.frames);
+
+        #[cfg(feature = "svg")]
+        self.vector.trim(&mut self.atlas, &self.frames);
+    }
+
+    /// Evicts all the entries that were not drawn in the last frame and
+    /// releases the freed memory of the atlas.
+    pub fn evict(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        #[cfg(feature = "image")]
+        self.raster.evict(&mut self.atlas, &self.frames);
 
         #[cfg(feature = "svg")]
-        self.vector.trim(&mut self.atlas);
+        self.vector.evict(&mut self.atlas, &self.frames);
+
+        self.atlas.shrink(device, encoder);
+    }
+}
+
+/// A [`Cache`] shared by all the renderers of an engine; so the images drawn
+/// in multiple windows are only decoded and uploaded once.
+#[derive(Debug)]
+pub struct Shared {
+    cache: Cache,
+    next_renderer: usize,
+}
+
+impl Shared {
+    pub fn new(cache: Cache) -> Self {
+        Self {
+            cache,
+            next_renderer: 0,
+        }
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// Returns the [`Cache`] to be used by the given renderer; so the entries
+    /// it uses are recorded in its current frame.
+    pub fn cache_mut(&mut self, renderer: RendererId) -> &mut Cache {
+        self.cache.frames.current = Some(renderer);
+
+        &mut self.cache
+    }
+
+    pub fn register(&mut self) -> RendererId {
+        let renderer = RendererId(self.next_renderer);
+        self.next_renderer += 1;
+
+        let _ = self.cache.frames.generations.insert(renderer, 0);
+
+        renderer
+    }
+
+    pub fn unregister(&mut self, renderer: RendererId) {
+        let _ = self.cache.frames.generations.remove(&renderer);
+
+        if self.cache.frames.current == Some(renderer) {
+            self.cache.frames.current = None;
+        }
+    }
+
+    /// Finishes the current frame of the given renderer and trims the
+    /// entries of the [`Cache`] that no renderer has used lately.
+    pub fn trim(&mut self, renderer: RendererId) {
+        if let Some(generation) =
+            self.cache.frames.generations.get_mut(&renderer)
+        {
+            *generation += 1;
+        }
+
+        self.cache.trim();
+    }
+}
+
+/// The identifier of a renderer sharing a [`Cache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RendererId(usize);
+
+/// The current frame of every renderer sharing a [`Cache`].
+#[derive(Debug, Default)]
+pub struct Frames {
+    current: Option<RendererId>,
+    generations: FxHashMap<RendererId, u64>,
+}
+
+impl Frames {
+    /// Returns true if an entry was used by any renderer in its current or
+    /// its last finished frame.
+    pub fn is_used(&self, last_used: &LastUsed) -> bool {
+        last_used.0.iter().any(|(renderer, generation)| {
+            self.generations
+                .get(renderer)
+                .is_some_and(|current| generation + 1 >= *current)
+        })
+    }
+}
+
+/// The last frame of each renderer where an entry of a [`Cache`] was used.
+#[derive(Debug, Default)]
+pub struct LastUsed(Vec<(RendererId, u64)>);
+
+impl LastUsed {
+    /// Records the use of the entry in the current frame of the current
+    /// renderer.
+    pub fn record(&mut self, frames: &Frames) {
+        let Some(renderer) = frames.current else {
+            return;
+        };
+
+        let Some(generation) = frames.generations.get(&renderer).copied()
+        else {
+            return;
+        };
+
+        match self.0.iter_mut().find(|(used_by, _)| *used_by == renderer) {
+            Some((_, last_used)) => *last_used = generation,
+            None => self.0.push((renderer, generation)),
+        }
     }
 }
@@ -10,7 +10,9 @@ mod raster;
 mod vector;
 
 use crate::Buffer;
+use crate::core::image::{Budget, Filter};
 use crate::core::{Rectangle, Size, Transformation};
+use crate::graphics::color;
 
 use bytemuck::{Pod, Zeroable};
 
@@ -149,6 +151,14 @@ impl Pipeline {
                             7 => Sint32,
                             // Snap
                             8 => Uint32,
+                            // Blur
+                            9 => Float32,
+                            // Grayscale
+                            10 => Float32,
+                            // Brightness
+                            11 => Float32,
+                            // Tint
+                            12 => Float32x4,
                         ),
                     }],
                     compilation_options:
@@ -201,8 +211,8 @@ impl Pipeline {
         }
     }
 
-    pub fn create_cache(&self, device: &wgpu::Device) -> Cache {
-        Cache::new(device, self.backend, self.texture_layout.clone())
+    pub fn create_cache(&self, device: &wgpu::Device, budget: Budget) -> Cache {
+        Cache::new(device, self.backend, self.texture_layout.clone(), budget)
     }
 }
 
@@ -235,15 +245,19 @@ impl State {
             match &image {
                 #[cfg(feature = "image")]
                 Image::Raster(image, bounds) => {
-                    if let Some(atlas_entry) =
-                        cache.upload_raster(device, encoder, &image.handle)
-                    {
+                    if let Some(atlas_entry) = cache.upload_raster(
+                        device,
+                        encoder,
+                        &image.handle,
+                        bounds.size() * scale,
+                    ) {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
                             f32::from(image.rotation),
                             image.opacity,
                             image.snap,
+                            &image.filter,
                             atlas_entry,
                             match image.filter_method {
                                 crate::core::image::FilterMethod::Nearest => {
@@ -277,6 +291,7 @@ impl State {
                             f32::from(svg.rotation),
                             svg.opacity,
                             true,
+                            &Filter::NONE,
                             atlas_entry,
                             nearest_instances,
                         );
@@ -507,6 +522,10 @@ struct Instance {
     _size_in_atlas: [f32; 2],
     _layer: u32,
     _snap: u32,
+    _blur: f32,
+    _grayscale: f32,
+    _brightness: f32,
+    _tint: [f32; 4],
 }
 
 impl Instance {
@@ -529,6 +548,7 @@ fn add_instances(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    filter: &Filter,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
@@ -546,6 +566,7 @@ fn add_instances(
                 rotation,
                 opacity,
                 snap,
+                filter,
                 allocation,
                 instances,
             );
@@ -575,7 +596,7 @@ fn add_instances(
                 ];
 
                 add_instance(
-                    position, center, size, rotation, opacity, snap,
+                    position, center, size, rotation, opacity, snap, filter,
                     allocation, instances,
                 );
             }
@@ -591,6 +612,7 @@ fn add_instance(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    filter: &Filter,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -614,6 +636,13 @@ fn add_instance(
         ],
         _layer: layer as u32,
         _snap: snap as u32,
+        _blur: filter.blur,
+        _grayscale: filter.grayscale,
+        _brightness: filter.brightness,
+        _tint: filter
+            .tint
+            .map(|tint| color::pack(tint).components())
+            .unwrap_or_default(),
     };
 
     instances.push(instance);
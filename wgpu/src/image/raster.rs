@@ -3,8 +3,9 @@ use crate::core::image;
 use crate::graphics;
 use crate::graphics::image::image_rs;
 use crate::image::atlas::{self, Atlas};
+use crate::image::cache::{Frames, LastUsed};
 
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
@@ -12,7 +13,17 @@ pub enum Memory {
     /// Image data on host
     Host(image_rs::ImageBuffer<image_rs::Rgba<u8>, image::Bytes>),
     /// Storage entry
-    Device(atlas::Entry),
+    Device {
+        /// The entry in the atlas
+        entry: atlas::Entry,
+        /// The original dimensions of the image
+        size: Size<u32>,
+        /// The mip level of the image in the atlas, where each level halves
+        /// the dimensions of the previous one
+        level: u32,
+    },
+    /// Image data on an external texture, not yet allocated
+    External(Size<u32>),
     /// Image not found
     NotFound,
     /// Invalid image data
@@ -28,7 +39,8 @@ impl Memory {
 
                 Size::new(width, height)
             }
-            Memory::Device(entry) => entry.size(),
+            Memory::Device { size, .. } => *size,
+            Memory::External(size) => *size,
             Memory::NotFound => Size::new(1, 1),
             Memory::Invalid => Size::new(1, 1),
         }
@@ -39,27 +51,52 @@ impl Memory {
 #[derive(Debug, Default)]
 pub struct Cache {
     map: FxHashMap<image::Id, Memory>,
-    hits: FxHashSet<image::Id>,
+    used: FxHashMap<image::Id, LastUsed>,
+    recency: FxHashMap<image::Id, u64>,
+    frame: u64,
     should_trim: bool,
+    is_bounded: bool,
 }
 
 impl Cache {
+    /// Creates a new [`Cache`].
+    ///
+    /// A bounded [`Cache`] keeps images that are not drawn until the atlas
+    /// runs out of room; then, the least recently drawn images are evicted.
+    pub fn new(is_bounded: bool) -> Self {
+        Self {
+            is_bounded,
+            ..Self::default()
+        }
+    }
+
     /// Load image
-    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
+    pub fn load(
+        &mut self,
+        handle: &image::Handle,
+        frames: &Frames,
+    ) -> &mut Memory {
         if self.contains(handle) {
-            return self.get(handle).unwrap();
+            return self.get(handle, frames).unwrap();
         }
 
-        let memory = match graphics::image::load(handle) {
-            Ok(image) => Memory::Host(image),
-            Err(image_rs::error::ImageError::IoError(_)) => Memory::NotFound,
-            Err(_) => Memory::Invalid,
-        };
+        let memory =
+            if let image::Handle::External { width, height, .. } = handle {
+                Memory::External(Size::new(*width, *height))
+            } else {
+                match graphics::image::load(handle) {
+                    Ok(image) => Memory::Host(image),
+                    Err(image_rs::error::ImageError::IoError(_)) => {
+                        Memory::NotFound
+                    }
+                    Err(_) => Memory::Invalid,
+                }
+            };
 
         self.should_trim = true;
 
         self.insert(handle, memory);
-        self.get(handle).unwrap()
+        self.get(handle, frames).unwrap()
     }
 
     /// Load image and upload raster data
@@ -68,39 +105,146 @@ impl Cache {
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         handle: &image::Handle,
+        target: Size,
         atlas: &mut Atlas,
+        frames: &Frames,
     ) -> Option<&atlas::Entry> {
-        let memory = self.load(handle);
+        // Downscaled images are reloaded once they need more resolution
+        if let Memory::Device { entry, size, level } = self.load(handle, frames)
+        {
+            if mip_level(*size, target) < *level {
+                if let Ok(image) = graphics::image::load(handle) {
+                    atlas.remove(entry);
+
+                    *self.load(handle, frames) = Memory::Host(image);
+                }
+            }
+        }
+
+        loop {
+            let memory = self.load(handle, frames);
+
+            let entry = match memory {
+                Memory::Host(image) => {
+                    let (width, height) = image.dimensions();
+                    let size = Size::new(width, height);
+                    let level = mip_level(size, target);
 
-        if let Memory::Host(image) = memory {
-            let (width, height) = image.dimensions();
+                    let entry = if level > 0 {
+                        let mip = image_rs::imageops::resize(
+                            image,
+                            (width >> level).max(1),
+                            (height >> level).max(1),
+                            image_rs::imageops::FilterType::Triangle,
+                        );
 
-            let entry = atlas.upload(device, encoder, width, height, image)?;
+                        atlas.upload(
+                            device,
+                            encoder,
+                            mip.width(),
+                            mip.height(),
+                            &mip,
+                        )
+                    } else {
+                        atlas.upload(device, encoder, width, height, image)
+                    };
 
-            *memory = Memory::Device(entry);
+                    entry.map(|entry| Memory::Device { entry, size, level })
+                }
+                Memory::External(size) => atlas
+                    .reserve(device, encoder, size.width, size.height)
+                    .map(|entry| Memory::Device {
+                        entry,
+                        size: *size,
+                        level: 0,
+                    }),
+                _ => break,
+            };
+
+            if let Some(entry) = entry {
+                *memory = entry;
+                break;
+            }
+
+            if !self.is_bounded || !self.evict_least_recent(atlas) {
+                return None;
+            }
         }
 
-        if let Memory::Device(allocation) = memory {
-            Some(allocation)
+        let memory = self.map.get_mut(&handle.id())?;
+
+        // External textures may change at any time, so we copy them every frame
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (
+            image::Handle::External { id, .. },
+            Memory::Device { entry, .. },
+        ) = (handle, &*memory)
+        {
+            if let Some(texture) = crate::external::texture(*id) {
+                atlas.copy(encoder, &texture, entry);
+            }
+        }
+
+        if let Memory::Device { entry, .. } = memory {
+            Some(entry)
         } else {
             None
         }
     }
 
+    /// Remove the image of the given handle from cache
+    pub fn remove(&mut self, handle: &image::Handle, atlas: &mut Atlas) {
+        let id = handle.id();
+
+        if let Some(Memory::Device { entry, .. }) = self.map.remove(&id) {
+            atlas.remove(&entry);
+        }
+
+        let _ = self.used.remove(&id);
+        let _ = self.recency.remove(&id);
+    }
+
+    /// Returns the amount of images uploaded to the atlas
+    pub fn len(&self) -> usize {
+        self.map
+            .values()
+            .filter(|memory| matches!(memory, Memory::Device { .. }))
+            .count()
+    }
+
+    /// Trim cache misses from cache, even if no new entries have landed
+    pub fn evict(&mut self, atlas: &mut Atlas, frames: &Frames) {
+        self.retain_used(atlas, frames);
+    }
+
     /// Trim cache misses from cache
-    pub fn trim(&mut self, atlas: &mut Atlas) {
+    pub fn trim(&mut self, atlas: &mut Atlas, frames: &Frames) {
+        self.frame += 1;
+
+        // Bounded caches only evict entries when they run out of room
+        if self.is_bounded {
+            return;
+        }
+
         // Only trim if new entries have landed in the `Cache`
         if !self.should_trim {
             return;
         }
 
-        let hits = &self.hits;
+        self.retain_used(atlas, frames);
+    }
+
+    /// Removes the entries that no renderer has used lately.
+    fn retain_used(&mut self, atlas: &mut Atlas, frames: &Frames) {
+        self.used.retain(|_, used| frames.is_used(used));
+
+        let used = &self.used;
 
         self.map.retain(|k, memory| {
-            let retain = hits.contains(k);
+            let retain = used.contains_key(k);
 
             if !retain {
-                if let Memory::Device(entry) = memory {
+                if let Memory::Device { entry, .. } = memory {
                     atlas.remove(entry);
                 }
             }
@@ -108,12 +252,45 @@ impl Cache {
             retain
         });
 
-        self.hits.clear();
+        self.recency.retain(|k, _| used.contains_key(k));
         self.should_trim = false;
     }
 
-    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
-        let _ = self.hits.insert(handle.id());
+    /// Evicts the least recently drawn entry in the atlas that has not been
+    /// drawn in the current frame; returning false if there is none.
+    fn evict_least_recent(&mut self, atlas: &mut Atlas) -> bool {
+        let Some(id) = self
+            .map
+            .iter()
+            .filter(|(_, memory)| matches!(memory, Memory::Device { .. }))
+            .filter_map(|(id, _)| {
+                let last_used = self.recency.get(id).copied()?;
+
+                (last_used < self.frame).then_some((*id, last_used))
+            })
+            .min_by_key(|(_, last_used)| *last_used)
+            .map(|(id, _)| id)
+        else {
+            return false;
+        };
+
+        if let Some(Memory::Device { entry, .. }) = self.map.remove(&id) {
+            atlas.remove(&entry);
+        }
+
+        let _ = self.used.remove(&id);
+        let _ = self.recency.remove(&id);
+
+        true
+    }
+
+    fn get(
+        &mut self,
+        handle: &image::Handle,
+        frames: &Frames,
+    ) -> Option<&mut Memory> {
+        self.used.entry(handle.id()).or_default().record(frames);
+        let _ = self.recency.insert(handle.id(), self.frame);
 
         self.map.get_mut(&handle.id())
     }
@@ -126,3 +303,19 @@ impl Cache {
         self.map.contains_key(&handle.id())
     }
 }
+
+/// Returns the mip level needed to display an image of the given size at the
+/// target physical size.
+///
+/// Only images displayed at half their size or smaller are downscaled.
+fn mip_level(size: Size<u32>, target: Size) -> u32 {
+    let ratio = (size.width as f32 / target.width)
+        .min(size.height as f32 / target.height);
+
+    if !ratio.is_finite() || ratio < 2.0 {
+        return 0;
+    }
+
+    (ratio.log2().floor() as u32)
+        .min(size.width.min(size.height).max(1).ilog2())
+}
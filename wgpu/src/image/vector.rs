@@ -1,10 +1,11 @@
 use crate::core::svg;
 use crate::core::{Color, Size};
 use crate::image::atlas::{self, Atlas};
+use crate::image::cache::{Frames, LastUsed};
 
 use resvg::tiny_skia;
 use resvg::usvg;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 use std::fs;
 use std::sync::Arc;
 
@@ -35,8 +36,8 @@ impl Svg {
 pub struct Cache {
     svgs: FxHashMap<u64, Svg>,
     rasterized: FxHashMap<(u64, u32, u32, ColorFilter), atlas::Entry>,
-    svg_hits: FxHashSet<u64>,
-    rasterized_hits: FxHashSet<(u64, u32, u32, ColorFilter)>,
+    svgs_used: FxHashMap<u64, LastUsed>,
+    rasterized_used: FxHashMap<(u64, u32, u32, ColorFilter), LastUsed>,
     should_trim: bool,
     fontdb: Option<Arc<usvg::fontdb::Database>>,
 }
@@ -67,15 +68,20 @@ impl Cache {
             ..usvg::Options::default()
         };
 
-        let svg = match handle.data() {
-            svg::Data::Path(path) => fs::read_to_string(path)
+        let source = crate::graphics::svg::load(handle);
+
+        let svg = match (source, handle.data()) {
+            (Some(source), _) => usvg::Tree::from_str(&source, &options)
+                .map(Svg::Loaded)
+                .unwrap_or(Svg::NotFound),
+            (None, svg::Data::Path(path)) => fs::read_to_string(path)
                 .ok()
                 .and_then(|contents| {
                     usvg::Tree::from_str(&contents, &options).ok()
                 })
                 .map(Svg::Loaded)
                 .unwrap_or(Svg::NotFound),
-            svg::Data::Bytes(bytes) => {
+            (None, svg::Data::Bytes(bytes)) => {
                 match usvg::Tree::from_data(bytes, &options) {
                     Ok(tree) => Svg::Loaded(tree),
                     Err(_) => Svg::NotFound,
@@ -99,6 +105,7 @@ impl Cache {
         [width, height]: [f32; 2],
         scale: f32,
         atlas: &mut Atlas,
+        frames: &Frames,
     ) -> Option<&atlas::Entry> {
         let id = handle.id();
 
@@ -115,8 +122,7 @@ impl Cache {
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
         if self.rasterized.contains_key(&key) {
-            let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert(key);
+            self.record(key, frames);
 
             return self.rasterized.get(&key);
         }
@@ -172,8 +178,7 @@ impl Cache {
 
                 log::debug!("allocating {id} {width}x{height}");
 
-                let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert(key);
+                self.record(key, frames);
                 let _ = self.rasterized.insert(key, allocation);
 
                 self.rasterized.get(&key)
@@ -182,18 +187,27 @@ impl Cache {
         }
     }
 
+    /// Trim cache misses from cache, even if no new entries have landed
+    pub fn evict(&mut self, atlas: &mut Atlas, frames: &Frames) {
+        self.should_trim = true;
+        self.trim(atlas, frames);
+    }
+
     /// Load svg and upload raster data
-    pub fn trim(&mut self, atlas: &mut Atlas) {
+    pub fn trim(&mut self, atlas: &mut Atlas, frames: &Frames) {
         if !self.should_trim {
             return;
         }
 
-        let svg_hits = &self.svg_hits;
-        let rasterized_hits = &self.rasterized_hits;
+        self.svgs_used.retain(|_, used| frames.is_used(used));
+        self.rasterized_used.retain(|_, used| frames.is_used(used));
+
+        let svgs_used = &self.svgs_used;
+        let rasterized_used = &self.rasterized_used;
 
-        self.svgs.retain(|k, _| svg_hits.contains(k));
+        self.svgs.retain(|k, _| svgs_used.contains_key(k));
         self.rasterized.retain(|k, entry| {
-            let retain = rasterized_hits.contains(k);
+            let retain = rasterized_used.contains_key(k);
 
             if !retain {
                 atlas.remove(entry);
@@ -201,10 +215,15 @@ impl Cache {
 
             retain
         });
-        self.svg_hits.clear();
-        self.rasterized_hits.clear();
         self.should_trim = false;
     }
+
+    /// Records the use of the given rasterization, and its SVG, in the
+    /// current frame.
+    fn record(&mut self, key: (u64, u32, u32, ColorFilter), frames: &Frames) {
+        self.svgs_used.entry(key.0).or_default().record(frames);
+        self.rasterized_used.entry(key).or_default().record(frames);
+    }
 }
 
 impl std::fmt::Debug for Svg {
@@ -1,3 +1,4 @@
+use crate::blur;
 use crate::core::{
     self, Background, Color, Point, Rectangle, Svg, Transformation, renderer,
 };
@@ -17,6 +18,8 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub opacity: f32,
+    pub blurs: blur::Batch,
     pub quads: quad::Batch,
     pub triangles: triangle::Batch,
     pub primitives: primitive::Batch,
@@ -28,7 +31,8 @@ pub struct Layer {
 
 impl Layer {
     pub fn is_empty(&self) -> bool {
-        self.quads.is_empty()
+        self.blurs.is_empty()
+            && self.quads.is_empty()
             && self.triangles.is_empty()
             && self.primitives.is_empty()
             && self.images.is_empty()
@@ -45,19 +49,107 @@ impl Layer {
     ) {
         let bounds = quad.bounds * transformation;
 
-        let quad = Quad {
+        // Backdrop blurs are drawn before anything else in the layer,
+        // and the quad is filled with the tint on top
+        let background = if let Background::Blur(blur) = background {
+            self.blurs.push(blur::Blur {
+                position: [bounds.x, bounds.y],
+                size: [bounds.width, bounds.height],
+                border_radius: quad.border.radius.into(),
+                border_smoothing: quad.border.radius.smoothing,
+                radius: blur.radius,
+            });
+
+            Background::Color(blur.tint)
+        } else {
+            background
+        };
+
+        let mut outer_shadows =
+            quad.shadow.iter().filter(|shadow| !shadow.inset);
+        let shadow = outer_shadows.next().copied().unwrap_or_default();
+
+        let base = Quad {
             position: [bounds.x, bounds.y],
             size: [bounds.width, bounds.height],
-            border_color: color::pack(quad.border.color),
+            border_colors: [color::pack(Color::TRANSPARENT); 4],
             border_radius: quad.border.radius.into(),
-            border_width: quad.border.width,
-            shadow_color: color::pack(quad.shadow.color),
-            shadow_offset: quad.shadow.offset.into(),
-            shadow_blur_radius: quad.shadow.blur_radius,
+            border_widths: [0.0; 4],
+            shadow_color: color::pack(Color::TRANSPARENT),
+            shadow_offset: [0.0; 2],
+            shadow_blur_radius: 0.0,
+            shadow_inset: 0,
             snap: quad.snap as u32,
+            border_smoothing: quad.border.radius.smoothing,
         };
 
-        self.quads.add(quad, &background);
+        // Additional outer shadows are drawn below the quad,
+        // from bottom to top
+        for shadow in outer_shadows.rev().filter(|shadow| shadow.is_visible()) {
+            self.quads.add(
+                Quad {
+                    shadow_color: color::pack(shadow.color),
+                    shadow_offset: shadow.offset.into(),
+                    shadow_blur_radius: shadow.blur_radius,
+                    ..base
+                },
+                &Background::Color(Color::TRANSPARENT),
+            );
+        }
+
+        self.quads.add(
+            Quad {
+                border_colors: quad.border.colors().map(color::pack),
+                border_widths: quad.border.widths(),
+                shadow_color: color::pack(shadow.color),
+                shadow_offset: shadow.offset.into(),
+                shadow_blur_radius: shadow.blur_radius,
+                ..base
+            },
+            &background,
+        );
+
+        // Inset shadows are cast inside the border of the quad
+        let [top, right, bottom, left] = quad.border.widths();
+        let [top_left, top_right, bottom_right, bottom_left] =
+            <[f32; 4]>::from(quad.border.radius);
+
+        let inner_bounds = Rectangle {
+            x: quad.bounds.x + left,
+            y: quad.bounds.y + top,
+            width: quad.bounds.width - left - right,
+            height: quad.bounds.height - top - bottom,
+        } * transformation;
+
+        if inner_bounds.width <= 0.0 || inner_bounds.height <= 0.0 {
+            return;
+        }
+
+        for shadow in quad
+            .shadow
+            .iter()
+            .rev()
+            .filter(|shadow| shadow.inset && shadow.is_visible())
+        {
+            self.quads.add(
+                Quad {
+                    position: [inner_bounds.x, inner_bounds.y],
+                    size: [inner_bounds.width, inner_bounds.height],
+                    border_radius: [
+                        (top_left - left.max(top)).max(0.0),
+                        (top_right - top.max(right)).max(0.0),
+                        (bottom_right - right.max(bottom)).max(0.0),
+                        (bottom_left - bottom.max(left)).max(0.0),
+                    ],
+                    shadow_color: color::pack(shadow.color),
+                    shadow_offset: shadow.offset.into(),
+                    shadow_blur_radius: shadow.blur_radius,
+                    shadow_inset: 1,
+                    ..base
+                },
+                &Background::Color(Color::TRANSPARENT),
+            );
+        }
     }
 
     pub fn draw_paragraph(
@@ -140,7 +232,16 @@ impl Layer {
         bounds: Rectangle,
         transformation: Transformation,
     ) {
-        let image = Image::Raster(image, bounds * transformation);
+        let image = Image::Raster(
+            core::Image {
+                filter: core::image::Filter {
+                    blur: image.filter.blur * transformation.scale_factor(),
+                    ..image.filter
+                },
+                ..image
+            },
+            bounds * transformation,
+        );
 
         self.images.push(image);
     }
@@ -277,9 +378,15 @@ impl graphics::Layer for Layer {
         self.bounds = bounds;
     }
 
+    fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity;
+    }
+
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.opacity = 1.0;
 
+        self.blurs.clear();
         self.quads.clear();
         self.triangles.clear();
         self.primitives.clear();
@@ -294,6 +401,8 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            opacity: 1.0,
+            blurs: blur::Batch::default(),
             quads: quad::Batch::default(),
             triangles: triangle::Batch::default(),
             primitives: primitive::Batch::default(),
@@ -30,13 +30,18 @@ pub mod window;
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
+mod blur;
 mod buffer;
 mod color;
 mod engine;
+mod opacity;
 mod quad;
 mod text;
 mod triangle;
 
+#[cfg(all(feature = "image", not(target_arch = "wasm32")))]
+pub mod external;
+
 #[cfg(any(feature = "image", feature = "svg"))]
 #[path = "image/mod.rs"]
 mod image;
@@ -65,8 +70,8 @@ use crate::core::renderer;
 use crate::core::{
     Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
-use crate::graphics::Viewport;
 use crate::graphics::text::{Editor, Paragraph};
+use crate::graphics::{Antialiasing, Viewport};
 
 /// A [`wgpu`] graphics renderer for [`iced`].
 ///
@@ -79,18 +84,20 @@ pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
     layers: layer::Stack,
+    scale_factor: Option<f32>,
 
     quad: quad::State,
+    blur: blur::State,
+    opacity: opacity::State,
     triangle: triangle::State,
+    triangle_pipeline: triangle::Pipeline,
     text: text::State,
     text_viewport: text::Viewport,
 
     #[cfg(any(feature = "svg", feature = "image"))]
     image: image::State,
-
-    // TODO: Centralize all the image feature handling
     #[cfg(any(feature = "svg", feature = "image"))]
-    image_cache: std::cell::RefCell<image::Cache>,
+    image_renderer: image::cache::RendererId,
 
     staging_belt: wgpu::util::StagingBelt,
 }
@@ -101,26 +108,34 @@ impl Renderer {
         default_font: Font,
         default_text_size: Pixels,
     ) -> Self {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        let image_renderer = engine
+            .image_cache
+            .write()
+            .expect("Write image cache")
+            .register();
+
         Self {
             default_font,
             default_text_size,
             layers: layer::Stack::new(),
+            scale_factor: None,
 
             quad: quad::State::new(),
+            blur: blur::State::new(),
+            opacity: opacity::State::new(),
             triangle: triangle::State::new(
                 &engine.device,
                 &engine.triangle_pipeline,
             ),
+            triangle_pipeline: engine.triangle_pipeline.clone(),
             text: text::State::new(),
             text_viewport: engine.text_pipeline.create_viewport(&engine.device),
 
             #[cfg(any(feature = "svg", feature = "image"))]
             image: image::State::new(),
-
             #[cfg(any(feature = "svg", feature = "image"))]
-            image_cache: std::cell::RefCell::new(
-                engine.create_image_cache(&engine.device),
-            ),
+            image_renderer,
 
             // TODO: Resize belt smartly (?)
             // It would be great if the `StagingBelt` API exposed methods
@@ -136,7 +151,7 @@ impl Renderer {
     fn draw(
         &mut self,
         clear_color: Option<Color>,
-        target: &wgpu::TextureView,
+        target: &wgpu::Texture,
         viewport: &Viewport,
     ) -> wgpu::CommandEncoder {
         let mut encoder = self.engine.device.create_command_encoder(
@@ -145,10 +160,24 @@ impl Renderer {
             },
         );
 
-        self.prepare(&mut encoder, viewport);
-        self.render(&mut encoder, target, clear_color, viewport);
+        self.record(&mut encoder, clear_color, target, viewport);
+
+        encoder
+    }
+
+    fn record(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        clear_color: Option<Color>,
+        target: &wgpu::Texture,
+        viewport: &Viewport,
+    ) {
+        self.prepare(encoder, viewport);
+        self.render(encoder, target, clear_color, viewport);
 
         self.quad.trim();
+        self.blur.trim();
+        self.opacity.trim();
         self.triangle.trim();
         self.text.trim();
 
@@ -158,17 +187,23 @@ impl Renderer {
         #[cfg(any(feature = "svg", feature = "image"))]
         {
             self.image.trim();
-            self.image_cache.borrow_mut().trim();
+            self.engine
+                .image_cache
+                .write()
+                .expect("Write image cache")
+                .trim(self.image_renderer);
         }
-
-        encoder
     }
 
+    /// Draws the current layers in the given `frame` and submits them.
+    ///
+    /// The `frame` must have the [`wgpu::TextureUsages::COPY_SRC`] usage
+    /// for backdrop blurs to be rendered.
     pub fn present(
         &mut self,
         clear_color: Option<Color>,
         _format: wgpu::TextureFormat,
-        frame: &wgpu::TextureView,
+        frame: &wgpu::Texture,
         viewport: &Viewport,
     ) -> wgpu::SubmissionIndex {
         let encoder = self.draw(clear_color, frame, viewport);
@@ -179,6 +214,148 @@ impl Renderer {
         submission
     }
 
+    /// Records the draw commands of the current layers into the given
+    /// `encoder`, targeting the given `frame`.
+    ///
+    /// This allows iced to be embedded in an application that owns the
+    /// [`wgpu::Device`] and submits its own command buffers; for instance,
+    /// to draw a user interface on top of a game scene. The [`Engine`] of
+    /// the [`Renderer`] must be created with the same [`wgpu::Device`].
+    ///
+    /// [`Renderer::recall`] must be called once the `encoder` has been
+    /// submitted.
+    ///
+    /// The `frame` must have the [`wgpu::TextureUsages::COPY_SRC`] usage
+    /// for backdrop blurs to be rendered.
+    pub fn render_into(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        clear_color: Option<Color>,
+        frame: &wgpu::Texture,
+        viewport: &Viewport,
+    ) {
+        self.record(encoder, clear_color, frame, viewport);
+        self.staging_belt.finish();
+    }
+
+    /// Recalls the staging buffers used by [`Renderer::render_into`].
+    ///
+    /// This must be called after submitting the encoder given to
+    /// [`Renderer::render_into`], and before rendering again.
+    pub fn recall(&mut self) {
+        self.staging_belt.recall();
+    }
+
+    /// Sets the default [`Font`] of the [`Renderer`].
+    pub fn set_default_font(&mut self, font: Font) {
+        self.default_font = font;
+    }
+
+    /// Sets the [`Antialiasing`] strategy of the [`Renderer`] for triangle
+    /// primitives.
+    pub fn set_antialiasing(&mut self, antialiasing: Option<Antialiasing>) {
+        if self.triangle_pipeline.antialiasing() == antialiasing {
+            return;
+        }
+
+        self.triangle_pipeline = triangle::Pipeline::new(
+            &self.engine.device,
+            self.engine.format,
+            antialiasing,
+        );
+
+        self.triangle =
+            triangle::State::new(&self.engine.device, &self.triangle_pipeline);
+    }
+
+    /// Evicts the images that were not drawn in the last frame from the
+    /// caches of the [`Renderer`], releasing any unused GPU memory.
+    pub fn evict_images(&mut self) {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        {
+            let mut encoder = self.engine.device.create_command_encoder(
+                &wgpu::CommandEncoderDescriptor {
+                    label: Some("iced_wgpu image eviction encoder"),
+                },
+            );
+
+            self.engine
+                .image_cache
+                .write()
+                .expect("Write image cache")
+                .cache_mut(self.image_renderer)
+                .evict(&self.engine.device, &mut encoder);
+
+            let _ = self.engine.queue.submit([encoder.finish()]);
+        }
+    }
+
+    /// Evicts the image of the given [`Handle`](core::image::Handle) from
+    /// the caches of the [`Renderer`].
+    pub fn evict_image(&mut self, handle: &core::image::Handle) {
+        #[cfg(feature = "image")]
+        self.engine
+            .image_cache
+            .write()
+            .expect("Write image cache")
+            .cache_mut(self.image_renderer)
+            .remove_raster(handle);
+
+        #[cfg(not(feature = "image"))]
+        let _ = handle;
+    }
+
+    /// Decodes and uploads the image of the given
+    /// [`Handle`](core::image::Handle) to the caches of the [`Renderer`]
+    /// before it is drawn.
+    pub fn prefetch_image(&mut self, handle: &core::image::Handle) {
+        #[cfg(feature = "image")]
+        {
+            let mut encoder = self.engine.device.create_command_encoder(
+                &wgpu::CommandEncoderDescriptor {
+                    label: Some("iced_wgpu image prefetch encoder"),
+                },
+            );
+
+            let _ = self
+                .engine
+                .image_cache
+                .write()
+                .expect("Write image cache")
+                .cache_mut(self.image_renderer)
+                .upload_raster(
+                    &self.engine.device,
+                    &mut encoder,
+                    handle,
+                    Size::INFINITY,
+                );
+
+            let _ = self.engine.queue.submit([encoder.finish()]);
+        }
+
+        #[cfg(not(feature = "image"))]
+        let _ = handle;
+    }
+
+    /// Returns the memory [`Usage`](core::image::Usage) of the images
+    /// cached by the [`Renderer`].
+    pub fn image_usage(&self) -> core::image::Usage {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        {
+            self.engine
+                .image_cache
+                .read()
+                .expect("Read image cache")
+                .cache()
+                .usage()
+        }
+
+        #[cfg(not(any(feature = "svg", feature = "image")))]
+        {
+            core::image::Usage::default()
+        }
+    }
+
     /// Renders the current surface to an offscreen buffer.
     ///
     /// Returns RGBA bytes of the texture data.
@@ -236,9 +413,7 @@ impl Renderer {
                 view_formats: &[],
             });
 
-        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-        let mut encoder = self.draw(Some(background_color), &view, viewport);
+        let mut encoder = self.draw(Some(background_color), &texture, viewport);
 
         let texture = crate::color::convert(
             &self.engine.device,
@@ -320,6 +495,32 @@ impl Renderer {
                 continue;
             }
 
+            if layer.opacity < 1.0 {
+                self.opacity.prepare(
+                    &self.engine.opacity_pipeline,
+                    &self.engine.device,
+                    &mut self.staging_belt,
+                    encoder,
+                    layer.opacity,
+                );
+            }
+
+            if !layer.blurs.is_empty() {
+                let prepare_span = debug::prepare(debug::Primitive::Quad);
+
+                self.blur.prepare(
+                    &self.engine.blur_pipeline,
+                    &self.engine.device,
+                    &mut self.staging_belt,
+                    encoder,
+                    &layer.blurs,
+                    viewport.projection(),
+                    scale_factor,
+                );
+
+                prepare_span.finish();
+            }
+
             if !layer.quads.is_empty() {
                 let prepare_span = debug::prepare(debug::Primitive::Quad);
 
@@ -340,7 +541,7 @@ impl Renderer {
                 let prepare_span = debug::prepare(debug::Primitive::Triangle);
 
                 self.triangle.prepare(
-                    &self.engine.triangle_pipeline,
+                    &self.triangle_pipeline,
                     &self.engine.device,
                     &mut self.staging_belt,
                     encoder,
@@ -384,7 +585,11 @@ impl Renderer {
                     &self.engine.device,
                     &mut self.staging_belt,
                     encoder,
-                    &mut self.image_cache.borrow_mut(),
+                    self.engine
+                        .image_cache
+                        .write()
+                        .expect("Write image cache")
+                        .cache_mut(self.image_renderer),
                     &layer.images,
                     viewport.projection(),
                     scale_factor,
@@ -415,12 +620,15 @@ impl Renderer {
     fn render(
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
-        frame: &wgpu::TextureView,
+        target: &wgpu::Texture,
         clear_color: Option<Color>,
         viewport: &Viewport,
     ) {
         use std::mem::ManuallyDrop;
 
+        let frame =
+            &target.create_view(&wgpu::TextureViewDescriptor::default());
+
         let mut render_pass = ManuallyDrop::new(encoder.begin_render_pass(
             &wgpu::RenderPassDescriptor {
                 label: Some("iced_wgpu render pass"),
@@ -453,13 +661,16 @@ impl Renderer {
         ));
 
         let mut quad_layer = 0;
+        let mut blur_layer = 0;
+        let mut opacity_layer = 0;
         let mut mesh_layer = 0;
         let mut text_layer = 0;
 
         #[cfg(any(feature = "svg", feature = "image"))]
         let mut image_layer = 0;
         #[cfg(any(feature = "svg", feature = "image"))]
-        let image_cache = self.image_cache.borrow();
+        let image_cache =
+            self.engine.image_cache.read().expect("Read image cache");
 
         let scale_factor = viewport.scale_factor() as f32;
         let physical_bounds = Rectangle::<f32>::from(Rectangle::with_size(
@@ -479,6 +690,92 @@ impl Renderer {
                 continue;
             };
 
+            // Translucent layers are drawn offscreen and composited after
+            let is_translucent = layer.opacity < 1.0;
+
+            let view = if is_translucent {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                let view = &self
+                    .opacity
+                    .target(
+                        &self.engine.opacity_pipeline,
+                        &self.engine.device,
+                        target,
+                    )
+                    .view;
+
+                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu.opacity.render_pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(
+                                        wgpu::Color::TRANSPARENT,
+                                    ),
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            },
+                        )],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    },
+                ));
+
+                view
+            } else {
+                frame
+            };
+
+            if !layer.blurs.is_empty() {
+                let render_span = debug::render(debug::Primitive::Quad);
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                let is_blurred = self.blur.blur(
+                    &self.engine.blur_pipeline,
+                    &self.engine.device,
+                    encoder,
+                    target,
+                    blur_layer,
+                );
+
+                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu render pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            },
+                        )],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    },
+                ));
+
+                if is_blurred {
+                    self.blur.render(
+                        &self.engine.blur_pipeline,
+                        blur_layer,
+                        scissor_rect,
+                        &mut render_pass,
+                    );
+                }
+
+                render_span.finish();
+
+                blur_layer += 1;
+            }
+
             if !layer.quads.is_empty() {
                 let render_span = debug::render(debug::Primitive::Quad);
                 self.quad.render(
@@ -498,9 +795,9 @@ impl Renderer {
 
                 let render_span = debug::render(debug::Primitive::Triangle);
                 mesh_layer += self.triangle.render(
-                    &self.engine.triangle_pipeline,
+                    &self.triangle_pipeline,
                     encoder,
-                    frame,
+                    view,
                     mesh_layer,
                     &layer.triangles,
                     physical_bounds,
@@ -513,7 +810,7 @@ impl Renderer {
                         label: Some("iced_wgpu render pass"),
                         color_attachments: &[Some(
                             wgpu::RenderPassColorAttachment {
-                                view: frame,
+                                view,
                                 resolve_target: None,
                                 ops: wgpu::Operations {
                                     load: wgpu::LoadOp::Load,
@@ -546,7 +843,7 @@ impl Renderer {
                         instance.primitive.render(
                             encoder,
                             &primitive_storage,
-                            frame,
+                            view,
                             &clip_bounds,
                         );
                     }
@@ -559,7 +856,7 @@ impl Renderer {
                         label: Some("iced_wgpu render pass"),
                         color_attachments: &[Some(
                             wgpu::RenderPassColorAttachment {
-                                view: frame,
+                                view,
                                 resolve_target: None,
                                 ops: wgpu::Operations {
                                     load: wgpu::LoadOp::Load,
@@ -579,7 +876,7 @@ impl Renderer {
                 let render_span = debug::render(debug::Primitive::Image);
                 self.image.render(
                     &self.engine.image_pipeline,
-                    &image_cache,
+                    image_cache.cache(),
                     image_layer,
                     scissor_rect,
                     &mut render_pass,
@@ -601,6 +898,38 @@ impl Renderer {
                 );
                 render_span.finish();
             }
+
+            if is_translucent {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu render pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view: frame,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            },
+                        )],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    },
+                ));
+
+                self.opacity.render(
+                    &self.engine.opacity_pipeline,
+                    opacity_layer,
+                    scissor_rect,
+                    &mut render_pass,
+                );
+
+                opacity_layer += 1;
+            }
         }
 
         let _ = ManuallyDrop::into_inner(render_pass);
@@ -619,6 +948,15 @@ impl Renderer {
     }
 }
 
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        #[cfg(any(feature = "svg", feature = "image"))]
+        if let Ok(mut image_cache) = self.engine.image_cache.write() {
+            image_cache.unregister(self.image_renderer);
+        }
+    }
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
         self.layers.push_clip(bounds);
@@ -636,6 +974,14 @@ impl core::Renderer for Renderer {
         self.layers.pop_transformation();
     }
 
+    fn start_opacity(&mut self, opacity: f32) {
+        self.layers.push_opacity(opacity);
+    }
+
+    fn end_opacity(&mut self) {
+        self.layers.pop_opacity();
+    }
+
     fn fill_quad(
         &mut self,
         quad: core::renderer::Quad,
@@ -648,6 +994,14 @@ impl core::Renderer for Renderer {
     fn clear(&mut self) {
         self.layers.clear();
     }
+
+    fn hint(&mut self, scale_factor: f32) {
+        self.scale_factor = Some(scale_factor);
+    }
+
+    fn scale_factor(&self) -> Option<f32> {
+        self.scale_factor
+    }
 }
 
 impl core::text::Renderer for Renderer {
@@ -714,7 +1068,12 @@ impl core::image::Renderer for Renderer {
     type Handle = core::image::Handle;
 
     fn measure_image(&self, handle: &Self::Handle) -> core::Size<u32> {
-        self.image_cache.borrow_mut().measure_image(handle)
+        self.engine
+            .image_cache
+            .write()
+            .expect("Write image cache")
+            .cache_mut(self.image_renderer)
+            .measure_image(handle)
     }
 
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle) {
@@ -726,7 +1085,12 @@ impl core::image::Renderer for Renderer {
 #[cfg(feature = "svg")]
 impl core::svg::Renderer for Renderer {
     fn measure_svg(&self, handle: &core::svg::Handle) -> core::Size<u32> {
-        self.image_cache.borrow_mut().measure_svg(handle)
+        self.engine
+            .image_cache
+            .write()
+            .expect("Write image cache")
+            .cache_mut(self.image_renderer)
+            .measure_svg(handle)
     }
 
     fn draw_svg(&mut self, svg: core::Svg, bounds: Rectangle) {
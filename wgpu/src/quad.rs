@@ -24,14 +24,16 @@ pub struct Quad {
     /// The size of the [`Quad`].
     pub size: [f32; 2],
 
-    /// The border color of the [`Quad`], in __linear RGB__.
-    pub border_color: color::Packed,
+    /// The border colors of the [`Quad`], in __linear RGB__, in the order:
+    /// top, right, bottom, left.
+    pub border_colors: [color::Packed; 4],
 
     /// The border radii of the [`Quad`].
     pub border_radius: [f32; 4],
 
-    /// The border width of the [`Quad`].
-    pub border_width: f32,
+    /// The border widths of the [`Quad`], in the order:
+    /// top, right, bottom, left.
+    pub border_widths: [f32; 4],
 
     /// The shadow color of the [`Quad`].
     pub shadow_color: color::Packed,
@@ -42,8 +44,14 @@ pub struct Quad {
     /// The shadow blur radius of the [`Quad`].
     pub shadow_blur_radius: f32,
 
+    /// Whether the shadow of the [`Quad`] is cast inside of it.
+    pub shadow_inset: u32,
+
     /// Whether the [`Quad`] should be snapped to the pixel grid.
     pub snap: u32,
+
+    /// The smoothing of the corners of the [`Quad`].
+    pub border_smoothing: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -287,6 +295,14 @@ impl Batch {
 
                 Kind::Gradient
             }
+            Background::Blur(blur) => {
+                self.solids.push(Solid {
+                    color: color::pack(blur.tint),
+                    quad,
+                });
+
+                Kind::Solid
+            }
         };
 
         match self.order.last_mut() {
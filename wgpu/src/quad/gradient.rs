@@ -122,6 +122,59 @@ impl Pipeline {
                     ),
                 });
 
+            // The shadow of the quad is not used by gradients, so the
+            // remaining attributes are located by their offset
+            let attributes = [
+                wgpu::vertex_attr_array!(
+                    // Colors 1-2
+                    0 => Uint32x4,
+                    // Colors 3-4
+                    1 => Uint32x4,
+                    // Colors 5-6
+                    2 => Uint32x4,
+                    // Colors 7-8
+                    3 => Uint32x4,
+                    // Offsets 1-8
+                    4 => Uint32x4,
+                    // Direction
+                    5 => Float32x4,
+                    // Position & Scale
+                    6 => Float32x4,
+                    // Border top color
+                    7 => Float32x4,
+                    // Border right color
+                    8 => Float32x4,
+                    // Border bottom color
+                    9 => Float32x4,
+                    // Border left color
+                    10 => Float32x4,
+                    // Border radius
+                    11 => Float32x4,
+                    // Border widths
+                    12 => Float32x4,
+                )
+                .as_slice(),
+                &[
+                    // Snap
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Uint32,
+                        offset: std::mem::offset_of!(Gradient, quad.snap)
+                            as u64,
+                        shader_location: 13,
+                    },
+                    // Border smoothing
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32,
+                        offset: std::mem::offset_of!(
+                            Gradient,
+                            quad.border_smoothing
+                        ) as u64,
+                        shader_location: 14,
+                    },
+                ],
+            ]
+            .concat();
+
             let pipeline = device.create_render_pipeline(
                 &wgpu::RenderPipelineDescriptor {
                     label: Some("iced_wgpu.quad.gradient.pipeline"),
@@ -133,30 +186,7 @@ impl Pipeline {
                             array_stride: std::mem::size_of::<Gradient>()
                                 as u64,
                             step_mode: wgpu::VertexStepMode::Instance,
-                            attributes: &wgpu::vertex_attr_array!(
-                                // Colors 1-2
-                                0 => Uint32x4,
-                                // Colors 3-4
-                                1 => Uint32x4,
-                                // Colors 5-6
-                                2 => Uint32x4,
-                                // Colors 7-8
-                                3 => Uint32x4,
-                                // Offsets 1-8
-                                4 => Uint32x4,
-                                // Direction
-                                5 => Float32x4,
-                                // Position & Scale
-                                6 => Float32x4,
-                                // Border color
-                                7 => Float32x4,
-                                // Border radius
-                                8 => Float32x4,
-                                // Border width
-                                9 => Float32,
-                                // Snap
-                                10 => Uint32,
-                            ),
+                            attributes: &attributes,
                         }],
                         compilation_options:
                             wgpu::PipelineCompilationOptions::default(),
@@ -1,5 +1,5 @@
 //! Configure a renderer.
-use crate::core::{Font, Pixels};
+use crate::core::{Font, Pixels, image};
 use crate::graphics::{self, Antialiasing};
 
 /// The settings of a [`Renderer`].
@@ -27,6 +27,9 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The memory [`Budget`](image::Budget) of the image atlas.
+    pub image_budget: image::Budget,
 }
 
 impl Default for Settings {
@@ -37,6 +40,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_budget: image::Budget::default(),
         }
     }
 }
@@ -47,6 +51,7 @@ impl From<graphics::Settings> for Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            image_budget: settings.image_budget,
             ..Settings::default()
         }
     }
@@ -0,0 +1,111 @@
+@group(0) @binding(1) var u_sampler: sampler;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+// The amount of samples taken on each side of a pixel
+const SAMPLES: i32 = 12;
+
+struct BlurVertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+    @location(0) pos: vec2<f32>,
+    @location(1) scale: vec2<f32>,
+    @location(2) border_radius: vec4<f32>,
+    @location(3) border_smoothing: f32,
+    @location(4) radius: f32,
+}
+
+struct BlurVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) pos: vec2<f32>,
+    @location(1) scale: vec2<f32>,
+    @location(2) border_radius: vec4<f32>,
+    @location(3) border_smoothing: f32,
+    @location(4) radius: f32,
+}
+
+fn blur_vertex(input: BlurVertexInput, expansion: f32) -> BlurVertexOutput {
+    var out: BlurVertexOutput;
+
+    let pos = input.pos * globals.scale - expansion;
+    let scale = input.scale * globals.scale + expansion * 2.0;
+
+    let min_border_radius = min(input.scale.x, input.scale.y) * 0.5;
+    let border_radius = min(input.border_radius, vec4<f32>(min_border_radius));
+
+    let transform = mat4x4<f32>(
+        vec4<f32>(scale.x, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, scale.y, 0.0, 0.0),
+        vec4<f32>(0.0, 0.0, 1.0, 0.0),
+        vec4<f32>(pos, 0.0, 1.0)
+    );
+
+    out.position = globals.transform * transform * vec4<f32>(vertex_position(input.vertex_index), 0.0, 1.0);
+    out.pos = input.pos * globals.scale;
+    out.scale = input.scale * globals.scale;
+    out.border_radius = border_radius * globals.scale;
+    out.border_smoothing = input.border_smoothing;
+    out.radius = input.radius * globals.scale;
+
+    return out;
+}
+
+// The first pass covers the blur radius around the region, since
+// the second pass needs to sample it
+@vertex
+fn blur_vs_expanded(input: BlurVertexInput) -> BlurVertexOutput {
+    return blur_vertex(input, input.radius * globals.scale);
+}
+
+@vertex
+fn blur_vs_main(input: BlurVertexInput) -> BlurVertexOutput {
+    return blur_vertex(input, 1.0);
+}
+
+fn gaussian_blur(position: vec2<f32>, radius: f32, direction: vec2<f32>) -> vec4<f32> {
+    let size = vec2<f32>(textureDimensions(u_texture));
+    let sigma = max(radius / 2.0, 0.0001);
+    let step = radius / f32(SAMPLES);
+
+    var color = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    var total = 0.0;
+
+    for (var i = -SAMPLES; i <= SAMPLES; i++) {
+        let offset = f32(i) * step;
+        let weight = exp(-(offset * offset) / (2.0 * sigma * sigma));
+
+        color += textureSampleLevel(
+            u_texture,
+            u_sampler,
+            (position + direction * offset) / size,
+            0.0
+        ) * weight;
+
+        total += weight;
+    }
+
+    return color / total;
+}
+
+@fragment
+fn blur_fs_horizontal(input: BlurVertexOutput) -> @location(0) vec4<f32> {
+    return gaussian_blur(input.position.xy, input.radius, vec2<f32>(1.0, 0.0));
+}
+
+@fragment
+fn blur_fs_vertical(input: BlurVertexOutput) -> @location(0) vec4<f32> {
+    return gaussian_blur(input.position.xy, input.radius, vec2<f32>(0.0, 1.0));
+}
+
+@fragment
+fn blur_fs_composite(input: BlurVertexOutput) -> @location(0) vec4<f32> {
+    let size = vec2<f32>(textureDimensions(u_texture));
+    let color = textureSampleLevel(u_texture, u_sampler, input.position.xy / size, 0.0);
+
+    let dist = rounded_box_sdf(
+        -(input.position.xy - input.pos - input.scale * 0.5) * 2.0,
+        input.scale,
+        input.border_radius * 2.0,
+        input.border_smoothing
+    ) / 2.0;
+
+    return color * clamp(0.5 - dist, 0.0, 1.0);
+}
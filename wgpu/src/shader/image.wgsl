@@ -18,6 +18,10 @@ struct VertexInput {
     @location(6) atlas_scale: vec2<f32>,
     @location(7) layer: i32,
     @location(8) snap: u32,
+    @location(9) blur: f32,
+    @location(10) grayscale: f32,
+    @location(11) brightness: f32,
+    @location(12) tint: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -25,6 +29,11 @@ struct VertexOutput {
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
     @location(2) opacity: f32,
+    @location(3) @interpolate(flat) atlas_bounds: vec4<f32>,
+    @location(4) @interpolate(flat) blur: vec2<f32>,
+    @location(5) @interpolate(flat) grayscale: f32,
+    @location(6) @interpolate(flat) brightness: f32,
+    @location(7) @interpolate(flat) tint: vec4<f32>,
 }
 
 @vertex
@@ -38,6 +47,11 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.uv = vec2<f32>(v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
     out.opacity = input.opacity;
+    out.atlas_bounds = vec4<f32>(input.atlas_pos, input.atlas_pos + input.atlas_scale);
+    out.blur = input.blur * input.atlas_scale / max(input.scale, vec2<f32>(1.0));
+    out.grayscale = input.grayscale;
+    out.brightness = input.brightness;
+    out.tint = input.tint;
 
     // Calculate the vertex position and move the center to the origin
     v_pos = input.pos + v_pos * input.scale - input.center;
@@ -67,5 +81,32 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Sample the texture at the given UV coordinate and layer.
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
+    var color = textureSampleLevel(u_texture, u_sampler, input.uv, i32(input.layer), 0.0);
+
+    if input.blur.x > 0.0 || input.blur.y > 0.0 {
+        // Gaussian kernel over premultiplied samples, clamped to the atlas entry
+        var sum = vec4<f32>(0.0);
+        var weight = 0.0;
+
+        for (var x = -4; x <= 4; x++) {
+            for (var y = -4; y <= 4; y++) {
+                let offset = vec2<f32>(f32(x), f32(y)) / 4.0;
+                let w = exp(-2.0 * dot(offset, offset));
+                let uv = clamp(input.uv + offset * input.blur, input.atlas_bounds.xy, input.atlas_bounds.zw);
+                let texel = textureSampleLevel(u_texture, u_sampler, uv, i32(input.layer), 0.0);
+
+                sum += vec4<f32>(texel.rgb * texel.a, texel.a) * w;
+                weight += w;
+            }
+        }
+
+        sum /= weight;
+        color = vec4<f32>(sum.rgb / max(sum.a, 0.0001), sum.a);
+    }
+
+    let luminance = dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    var rgb = mix(color.rgb, vec3<f32>(luminance), input.grayscale);
+    rgb = mix(rgb, input.tint.rgb, input.tint.a) * input.brightness;
+
+    return vec4<f32>(rgb, color.a * input.opacity);
 }
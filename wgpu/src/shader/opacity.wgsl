@@ -0,0 +1,16 @@
+@group(0) @binding(0) var<uniform> u_opacity: vec4<f32>;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+// A single triangle covering the whole viewport
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    // The layer is drawn with premultiplied alpha, so every channel is scaled
+    return textureLoad(u_texture, vec2<i32>(position.xy), 0) * u_opacity.x;
+}
@@ -5,9 +5,94 @@ struct Globals {
 
 @group(0) @binding(0) var<uniform> globals: Globals;
 
-fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>) -> f32 {
+fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>, smoothing: f32) -> f32 {
     var box_half = select(corners.yz, corners.xw, p.x > 0.0);
     var corner = select(box_half.y, box_half.x, p.y > 0.0);
     var q = abs(p) - size + corner;
-    return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - corner;
+    return min(max(q.x, q.y), 0.0) + corner_length(max(q, vec2(0.0)), smoothing) - corner;
+}
+
+// Returns the length of the given vector using the norm of a superellipse,
+// which turns circular corners into continuous ones as smoothing increases.
+fn corner_length(q: vec2<f32>, smoothing: f32) -> f32 {
+    let m = max(q.x, q.y);
+
+    if smoothing <= 0.0 || m <= 0.0 {
+        return length(q);
+    }
+
+    let n = 2.0 + smoothing * 3.0;
+    let r = max(q / m, vec2<f32>(0.000001, 0.000001));
+
+    return m * pow(pow(r.x, n) + pow(r.y, n), 1.0 / n);
+}
+
+// Returns the signed distance to the inner edge of a border with the given
+// widths (top, right, bottom, left).
+fn border_inner_sdf(
+    position: vec2<f32>,
+    pos: vec2<f32>,
+    scale: vec2<f32>,
+    corners: vec4<f32>,
+    widths: vec4<f32>,
+    smoothing: f32
+) -> f32 {
+    let inner_pos = pos + vec2<f32>(widths.w, widths.x);
+    let inner_scale = max(scale - vec2<f32>(widths.y + widths.w, widths.x + widths.z), vec2<f32>(0.0, 0.0));
+
+    let inner_corners = max(corners - vec4<f32>(
+        max(widths.w, widths.x),
+        max(widths.x, widths.y),
+        max(widths.y, widths.z),
+        max(widths.z, widths.w),
+    ), vec4<f32>(0.0, 0.0, 0.0, 0.0));
+
+    return rounded_box_sdf(
+        -(position - inner_pos - inner_scale * 0.5) * 2.0,
+        inner_scale,
+        inner_corners * 2.0,
+        smoothing
+    ) / 2.0;
+}
+
+// Picks the border color of the side closest to the given position,
+// relative to the width of each side.
+fn border_side_color(
+    position: vec2<f32>,
+    pos: vec2<f32>,
+    scale: vec2<f32>,
+    widths: vec4<f32>,
+    top: vec4<f32>,
+    right: vec4<f32>,
+    bottom: vec4<f32>,
+    left: vec4<f32>
+) -> vec4<f32> {
+    let p = position - pos;
+    let w = max(widths, vec4<f32>(0.0001, 0.0001, 0.0001, 0.0001));
+
+    let distances = vec4<f32>(
+        p.y / w.x,
+        (scale.x - p.x) / w.y,
+        (scale.y - p.y) / w.z,
+        p.x / w.w,
+    );
+
+    var color = top;
+    var closest = distances.x;
+
+    if distances.y < closest {
+        color = right;
+        closest = distances.y;
+    }
+
+    if distances.z < closest {
+        color = bottom;
+        closest = distances.z;
+    }
+
+    if distances.w < closest {
+        color = left;
+    }
+
+    return color;
 }
@@ -7,10 +7,14 @@ struct GradientVertexInput {
     @location(4) @interpolate(flat) offsets: vec4<u32>,
     @location(5) direction: vec4<f32>,
     @location(6) position_and_scale: vec4<f32>,
-    @location(7) border_color: vec4<f32>,
-    @location(8) border_radius: vec4<f32>,
-    @location(9) border_width: f32,
-    @location(10) snap: u32,
+    @location(7) border_top_color: vec4<f32>,
+    @location(8) border_right_color: vec4<f32>,
+    @location(9) border_bottom_color: vec4<f32>,
+    @location(10) border_left_color: vec4<f32>,
+    @location(11) border_radius: vec4<f32>,
+    @location(12) border_widths: vec4<f32>,
+    @location(13) snap: u32,
+    @location(14) border_smoothing: f32,
 }
 
 struct GradientVertexOutput {
@@ -22,9 +26,13 @@ struct GradientVertexOutput {
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
     @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(8) border_top_color: vec4<f32>,
+    @location(9) border_right_color: vec4<f32>,
+    @location(10) border_bottom_color: vec4<f32>,
+    @location(11) border_left_color: vec4<f32>,
+    @location(12) border_radius: vec4<f32>,
+    @location(13) border_widths: vec4<f32>,
+    @location(14) border_smoothing: f32,
 }
 
 @vertex
@@ -65,9 +73,13 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.offsets = input.offsets;
     out.direction = input.direction * globals.scale;
     out.position_and_scale = vec4<f32>(pos + pos_snap, scale + scale_snap);
-    out.border_color = premultiply(input.border_color);
+    out.border_top_color = premultiply(input.border_top_color);
+    out.border_right_color = premultiply(input.border_right_color);
+    out.border_bottom_color = premultiply(input.border_bottom_color);
+    out.border_left_color = premultiply(input.border_left_color);
     out.border_radius = border_radius * globals.scale;
-    out.border_width = input.border_width * globals.scale;
+    out.border_widths = input.border_widths * globals.scale;
+    out.border_smoothing = input.border_smoothing;
 
     return out;
 }
@@ -169,14 +181,35 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
     var dist: f32 = rounded_box_sdf(
         -(input.position.xy - pos - scale / 2.0) * 2.0,
         scale,
-        input.border_radius * 2.0
+        input.border_radius * 2.0,
+        input.border_smoothing
     ) / 2.0;
 
-    if (input.border_width > 0.0) {
+    if any(input.border_widths > vec4<f32>(0.0, 0.0, 0.0, 0.0)) {
+        let inner_dist = border_inner_sdf(
+            input.position.xy,
+            pos,
+            scale,
+            input.border_radius,
+            input.border_widths,
+            input.border_smoothing
+        );
+
+        let border_color = border_side_color(
+            input.position.xy,
+            pos,
+            scale,
+            input.border_widths,
+            input.border_top_color,
+            input.border_right_color,
+            input.border_bottom_color,
+            input.border_left_color
+        );
+
         mixed_color = mix(
             mixed_color,
-            input.border_color,
-            clamp(0.5 + dist + input.border_width, 0.0, 1.0)
+            border_color,
+            clamp(0.5 + inner_dist, 0.0, 1.0)
         );
     }
 
@@ -3,26 +3,36 @@ struct SolidVertexInput {
     @location(0) color: vec4<f32>,
     @location(1) pos: vec2<f32>,
     @location(2) scale: vec2<f32>,
-    @location(3) border_color: vec4<f32>,
-    @location(4) border_radius: vec4<f32>,
-    @location(5) border_width: f32,
-    @location(6) shadow_color: vec4<f32>,
-    @location(7) shadow_offset: vec2<f32>,
-    @location(8) shadow_blur_radius: f32,
-    @location(9) snap: u32,
+    @location(3) border_top_color: vec4<f32>,
+    @location(4) border_right_color: vec4<f32>,
+    @location(5) border_bottom_color: vec4<f32>,
+    @location(6) border_left_color: vec4<f32>,
+    @location(7) border_radius: vec4<f32>,
+    @location(8) border_widths: vec4<f32>,
+    @location(9) shadow_color: vec4<f32>,
+    @location(10) shadow_offset: vec2<f32>,
+    @location(11) shadow_blur_radius: f32,
+    @location(12) shadow_inset: u32,
+    @location(13) snap: u32,
+    @location(14) border_smoothing: f32,
 }
 
 struct SolidVertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) color: vec4<f32>,
-    @location(1) border_color: vec4<f32>,
-    @location(2) pos: vec2<f32>,
-    @location(3) scale: vec2<f32>,
-    @location(4) border_radius: vec4<f32>,
-    @location(5) border_width: f32,
-    @location(6) shadow_color: vec4<f32>,
-    @location(7) shadow_offset: vec2<f32>,
-    @location(8) shadow_blur_radius: f32,
+    @location(1) border_top_color: vec4<f32>,
+    @location(2) border_right_color: vec4<f32>,
+    @location(3) border_bottom_color: vec4<f32>,
+    @location(4) border_left_color: vec4<f32>,
+    @location(5) pos: vec2<f32>,
+    @location(6) scale: vec2<f32>,
+    @location(7) border_radius: vec4<f32>,
+    @location(8) border_widths: vec4<f32>,
+    @location(9) shadow_color: vec4<f32>,
+    @location(10) shadow_offset: vec2<f32>,
+    @location(11) shadow_blur_radius: f32,
+    @location(12) @interpolate(flat) shadow_inset: u32,
+    @location(13) border_smoothing: f32,
 }
 
 @vertex
@@ -57,14 +67,19 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 
     out.position = globals.transform * transform * vec4<f32>(vertex_position(input.vertex_index), 0.0, 1.0);
     out.color = premultiply(input.color);
-    out.border_color = premultiply(input.border_color);
+    out.border_top_color = premultiply(input.border_top_color);
+    out.border_right_color = premultiply(input.border_right_color);
+    out.border_bottom_color = premultiply(input.border_bottom_color);
+    out.border_left_color = premultiply(input.border_left_color);
     out.pos = input.pos * globals.scale + pos_snap;
     out.scale = input.scale * globals.scale + scale_snap;
     out.border_radius = border_radius * globals.scale;
-    out.border_width = input.border_width * globals.scale;
+    out.border_widths = input.border_widths * globals.scale;
     out.shadow_color = premultiply(input.shadow_color);
     out.shadow_offset = input.shadow_offset * globals.scale;
     out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_inset = input.shadow_inset;
+    out.border_smoothing = input.border_smoothing;
 
     return out;
 }
@@ -78,14 +93,35 @@ fn solid_fs_main(
     var dist = rounded_box_sdf(
         -(input.position.xy - input.pos - input.scale * 0.5) * 2.0,
         input.scale,
-        input.border_radius * 2.0
+        input.border_radius * 2.0,
+        input.border_smoothing
     ) / 2.0;
 
-    if (input.border_width > 0.0) {
+    if any(input.border_widths > vec4<f32>(0.0, 0.0, 0.0, 0.0)) {
+        let inner_dist = border_inner_sdf(
+            input.position.xy,
+            input.pos,
+            input.scale,
+            input.border_radius,
+            input.border_widths,
+            input.border_smoothing
+        );
+
+        let border_color = border_side_color(
+            input.position.xy,
+            input.pos,
+            input.scale,
+            input.border_widths,
+            input.border_top_color,
+            input.border_right_color,
+            input.border_bottom_color,
+            input.border_left_color
+        );
+
         mixed_color = mix(
             input.color,
-            input.border_color,
-            clamp(0.5 + dist + input.border_width, 0.0, 1.0)
+            border_color,
+            clamp(0.5 + inner_dist, 0.0, 1.0)
         );
     }
 
@@ -93,11 +129,22 @@ fn solid_fs_main(
 
     let quad_color = mixed_color * quad_alpha;
 
-    if input.shadow_color.a > 0.0 {
+    if bool(input.shadow_inset) {
+        let shadow_dist: f32 = rounded_box_sdf(
+            -(input.position.xy - input.pos - input.shadow_offset - input.scale/2.0) * 2.0,
+            input.scale,
+            input.border_radius * 2.0,
+            input.border_smoothing
+        ) / 2.0;
+        let shadow_alpha = smoothstep(-input.shadow_blur_radius, input.shadow_blur_radius, shadow_dist);
+
+        return input.shadow_color * shadow_alpha * quad_alpha;
+    } else if input.shadow_color.a > 0.0 {
         var shadow_dist: f32 = rounded_box_sdf(
             -(input.position.xy - input.pos - input.shadow_offset - input.scale/2.0) * 2.0,
             input.scale,
-            input.border_radius * 2.0
+            input.border_radius * 2.0,
+            input.border_smoothing
         ) / 2.0;
         let shadow_alpha = 1.0 - smoothstep(-input.shadow_blur_radius, input.shadow_blur_radius, max(shadow_dist, 0.0));
 
@@ -155,6 +155,7 @@ impl Storage {
 
 #[derive(Debug, Clone)]
 pub struct Pipeline {
+    antialiasing: Option<Antialiasing>,
     msaa: Option<msaa::Pipeline>,
     solid: solid::Pipeline,
     gradient: gradient::Pipeline,
@@ -311,11 +312,16 @@ impl Pipeline {
         antialiasing: Option<Antialiasing>,
     ) -> Pipeline {
         Pipeline {
+            antialiasing,
             msaa: antialiasing.map(|a| msaa::Pipeline::new(device, format, a)),
             solid: solid::Pipeline::new(device, format, antialiasing),
             gradient: gradient::Pipeline::new(device, format, antialiasing),
         }
     }
+
+    pub fn antialiasing(&self) -> Option<Antialiasing> {
+        self.antialiasing
+    }
 }
 
 fn render<'a>(
@@ -1,5 +1,5 @@
 //! Connect a window with a renderer.
-use crate::core::Color;
+use crate::core::{self, Color, image, window};
 use crate::graphics::color;
 use crate::graphics::compositor;
 use crate::graphics::error;
@@ -183,7 +183,8 @@ impl Compositor {
                         queue,
                         format,
                         settings.antialiasing,
-                    );
+                    )
+                    .with_image_budget(settings.image_budget);
 
                     return Ok(Compositor {
                         instance,
@@ -202,6 +203,33 @@ impl Compositor {
 
         Err(Error::RequestDeviceFailed(errors))
     }
+
+    fn configure(
+        &self,
+        surface: &mut wgpu::Surface<'static>,
+        width: u32,
+        height: u32,
+        present_mode: wgpu::PresentMode,
+    ) {
+        // Backdrop blurs need to copy the contents of the surface
+        let usage = wgpu::TextureUsages::RENDER_ATTACHMENT
+            | (surface.get_capabilities(&self.adapter).usages
+                & wgpu::TextureUsages::COPY_SRC);
+
+        surface.configure(
+            &self.engine.device,
+            &wgpu::SurfaceConfiguration {
+                usage,
+                format: self.format,
+                present_mode,
+                width,
+                height,
+                alpha_mode: self.alpha_mode,
+                view_formats: vec![],
+                desired_maximum_frame_latency: 1,
+            },
+        );
+    }
 }
 
 /// Creates a [`Compositor`] with the given [`Settings`] and window.
@@ -222,14 +250,10 @@ pub fn present(
 ) -> Result<(), compositor::SurfaceError> {
     match surface.get_current_texture() {
         Ok(frame) => {
-            let view = &frame
-                .texture
-                .create_view(&wgpu::TextureViewDescriptor::default());
-
             let _submission = renderer.present(
                 Some(background_color),
                 frame.texture.format(),
-                view,
+                &frame.texture,
                 viewport,
             );
 
@@ -319,19 +343,43 @@ impl graphics::Compositor for Compositor {
         width: u32,
         height: u32,
     ) {
-        surface.configure(
-            &self.engine.device,
-            &wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                format: self.format,
-                present_mode: self.settings.present_mode,
-                width,
-                height,
-                alpha_mode: self.alpha_mode,
-                view_formats: vec![],
-                desired_maximum_frame_latency: 1,
-            },
-        );
+        let present_mode = self.settings.present_mode;
+
+        self.configure(surface, width, height, present_mode);
+    }
+
+    fn configure_surface_with_present_mode(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+        present_mode: window::PresentMode,
+    ) {
+        let supported = surface.get_capabilities(&self.adapter).present_modes;
+
+        let candidates: &[wgpu::PresentMode] = match present_mode {
+            window::PresentMode::Fifo => &[wgpu::PresentMode::Fifo],
+            window::PresentMode::Mailbox => &[wgpu::PresentMode::Mailbox],
+            window::PresentMode::Immediate => &[wgpu::PresentMode::Immediate],
+            window::PresentMode::LowLatency => {
+                &[wgpu::PresentMode::Mailbox, wgpu::PresentMode::Immediate]
+            }
+        };
+
+        let present_mode = candidates
+            .iter()
+            .copied()
+            .find(|mode| supported.contains(mode))
+            .unwrap_or_else(|| {
+                log::warn!(
+                    "Present mode {present_mode:?} is not supported; \
+                    falling back to vsync"
+                );
+
+                wgpu::PresentMode::AutoVsync
+            });
+
+        self.configure(surface, width, height, present_mode);
     }
 
     fn fetch_information(&self) -> compositor::Information {
@@ -351,6 +399,20 @@ impl graphics::Compositor for Compositor {
         background_color: Color,
         on_pre_present: impl FnOnce(),
     ) -> Result<(), compositor::SurfaceError> {
+        // Translucent backgrounds must be premultiplied to be composited
+        // properly with the windows behind; like in background effects.
+        let background_color =
+            if self.alpha_mode == wgpu::CompositeAlphaMode::PreMultiplied {
+                Color {
+                    r: background_color.r * background_color.a,
+                    g: background_color.g * background_color.a,
+                    b: background_color.b * background_color.a,
+                    a: background_color.a,
+                }
+            } else {
+                background_color
+            };
+
         present(
             renderer,
             surface,
@@ -368,4 +430,44 @@ impl graphics::Compositor for Compositor {
     ) -> Vec<u8> {
         renderer.screenshot(viewport, background_color)
     }
+
+    fn set_antialiasing(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        antialiasing: Option<graphics::Antialiasing>,
+    ) {
+        renderer.set_antialiasing(antialiasing);
+    }
+
+    fn set_default_font(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        font: Option<core::Font>,
+    ) {
+        renderer.set_default_font(font.unwrap_or(self.settings.default_font));
+    }
+
+    fn evict_images(&mut self, renderer: &mut Self::Renderer) {
+        renderer.evict_images();
+    }
+
+    fn evict_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &image::Handle,
+    ) {
+        renderer.evict_image(handle);
+    }
+
+    fn prefetch_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &image::Handle,
+    ) {
+        renderer.prefetch_image(handle);
+    }
+
+    fn image_usage(&self, renderer: &Self::Renderer) -> image::Usage {
+        renderer.image_usage()
+    }
 }
@@ -16,19 +16,23 @@
 //!     button("Press me!").on_press(Message::ButtonPressed).into()
 //! }
 //! ```
+use crate::core::accessibility;
+use crate::core::animation::Transition;
 use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::theme::palette;
+use crate::core::shadow::Shadows;
+use crate::core::theme::{Elevation, Styles, palette};
+use crate::core::time::Duration;
 use crate::core::touch;
 use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Event, Layout, Length, Padding,
-    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 
 /// A generic widget that produces a message when pressed.
@@ -82,6 +86,7 @@ where
     clip: bool,
     class: Theme::Class<'a>,
     status: Option<Status>,
+    transition: Option<Duration>,
 }
 
 enum OnPress<'a, Message> {
@@ -119,6 +124,7 @@ where
             clip: false,
             class: Theme::default(),
             status: None,
+            transition: None,
         }
     }
 
@@ -180,6 +186,15 @@ where
         self
     }
 
+    /// Sets the duration of the transitions of the [`Button`] between
+    /// each [`Status`].
+    ///
+    /// By default, the [`Style`] of a [`Button`] changes instantly.
+    pub fn transition(mut self, duration: impl Into<Duration>) -> Self {
+        self.transition = Some(duration.into());
+        self
+    }
+
     /// Sets the style of the [`Button`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -199,9 +214,10 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 struct State {
     is_pressed: bool,
+    transition: Option<Transition<Status>>,
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -255,6 +271,20 @@ where
         )
     }
 
+    fn accessibility(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        let node = accessibility::Node::new(accessibility::Role::Button);
+
+        Some(if self.on_press.is_some() {
+            node.action(accessibility::Action::Click)
+        } else {
+            node
+        })
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -262,6 +292,15 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        if let Some(node) = self.accessibility(tree, layout) {
+            operation.accessible(
+                None,
+                tree.accessibility_id(),
+                layout.bounds(),
+                &node,
+            );
+        }
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -336,6 +375,15 @@ where
 
                 state.is_pressed = false;
             }
+            Event::Accessibility(request)
+                if request.is_for(tree)
+                    && request.action == accessibility::Action::Click =>
+            {
+                if let Some(on_press) = &self.on_press {
+                    shell.publish(on_press.get());
+                    shell.capture_event();
+                }
+            }
             _ => {}
         }
 
@@ -353,8 +401,20 @@ where
             Status::Active
         };
 
-        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
             self.status = Some(current_status);
+
+            if let Some(duration) = self.transition {
+                let state = tree.state.downcast_mut::<State>();
+
+                let transition = state.transition.get_or_insert_with(|| {
+                    Transition::new(current_status, duration)
+                });
+
+                if transition.update(current_status, *now) {
+                    shell.request_redraw();
+                }
+            }
         } else if self.status.is_some_and(|status| status != current_status) {
             shell.request_redraw();
         }
@@ -372,12 +432,21 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let style =
-            theme.style(&self.class, self.status.unwrap_or(Status::Disabled));
+        let status = self.status.unwrap_or(Status::Disabled);
+        let state = tree.state.downcast_ref::<State>();
+
+        let style = match state.transition {
+            Some(transition) if self.transition.is_some() => transition
+                .interpolate(
+                    |status| theme.style(&self.class, status),
+                    Style::mix,
+                ),
+            _ => theme.style(&self.class, status),
+        };
 
         if style.background.is_some()
-            || style.border.width > 0.0
-            || style.shadow.color.a > 0.0
+            || style.border.has_width()
+            || style.shadow.is_visible()
         {
             renderer.fill_quad(
                 renderer::Quad {
@@ -491,8 +560,8 @@ pub struct Style {
     pub text_color: Color,
     /// The [`Border`] of the button.
     pub border: Border,
-    /// The [`Shadow`] of the button.
-    pub shadow: Shadow,
+    /// The [`Shadows`] of the button.
+    pub shadow: Shadows,
     /// Whether the button should be snapped to the pixel grid.
     pub snap: bool,
 }
@@ -505,6 +574,41 @@ impl Style {
             ..self
         }
     }
+
+    /// Interpolates between the [`Style`] and the `other` one by the
+    /// given factor, from `0.0` to `1.0`.
+    pub fn mix(self, other: Self, factor: f32) -> Self {
+        let background = match (self.background, other.background) {
+            (Some(a), Some(b)) => Some(a.mix(b, factor)),
+            (Some(a), None) => Some(a.scale_alpha(1.0 - factor)),
+            (None, Some(b)) => Some(b.scale_alpha(factor)),
+            (None, None) => None,
+        };
+
+        Self {
+            background,
+            text_color: self.text_color.mix(other.text_color, factor),
+            border: self.border.mix(other.border, factor),
+            shadow: if factor < 0.5 {
+                self.shadow
+            } else {
+                other.shadow
+            },
+            snap: other.snap,
+        }
+    }
+
+    /// Updates the [`Style`] with the shadows of the given [`Elevation`].
+    pub fn with_elevation(
+        self,
+        theme: &Theme,
+        elevation: impl Into<Elevation>,
+    ) -> Self {
+        Self {
+            shadow: elevation.into().shadow(theme.extended_palette()),
+            ..self
+        }
+    }
 }
 
 impl Default for Style {
@@ -513,7 +617,7 @@ impl Default for Style {
             background: None,
             text_color: Color::BLACK,
             border: Border::default(),
-            shadow: Shadow::default(),
+            shadow: Shadows::default(),
             snap: cfg!(feature = "crisp"),
         }
     }
@@ -586,7 +690,23 @@ impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(primary)
+        Box::new(|theme: &Theme, status: Status| {
+            let style = primary(theme, status);
+
+            match theme.extension::<Styles>() {
+                Some(styles) => Style {
+                    background: styles
+                        .button
+                        .background
+                        .map(Background::from)
+                        .or(style.background),
+                    text_color: styles.button.text_color(style.text_color),
+                    border: styles.button.border(style.border),
+                    ..style
+                },
+                None => style,
+            }
+        })
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
@@ -597,7 +717,7 @@ impl Catalog for Theme {
 /// A primary button; denoting a main action.
 pub fn primary(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
-    let base = styled(palette.primary.base);
+    let base = styled(theme, palette.primary.base);
 
     match status {
         Status::Active | Status::Pressed => base,
@@ -612,7 +732,7 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
 /// A secondary button; denoting a complementary action.
 pub fn secondary(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
-    let base = styled(palette.secondary.base);
+    let base = styled(theme, palette.secondary.base);
 
     match status {
         Status::Active | Status::Pressed => base,
@@ -627,7 +747,7 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
 /// A success button; denoting a good outcome.
 pub fn success(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
-    let base = styled(palette.success.base);
+    let base = styled(theme, palette.success.base);
 
     match status {
         Status::Active | Status::Pressed => base,
@@ -642,7 +762,7 @@ pub fn success(theme: &Theme, status: Status) -> Style {
 /// A warning button; denoting a risky action.
 pub fn warning(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
-    let base = styled(palette.warning.base);
+    let base = styled(theme, palette.warning.base);
 
     match status {
         Status::Active | Status::Pressed => base,
@@ -657,7 +777,7 @@ pub fn warning(theme: &Theme, status: Status) -> Style {
 /// A danger button; denoting a destructive action.
 pub fn danger(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
-    let base = styled(palette.danger.base);
+    let base = styled(theme, palette.danger.base);
 
     match status {
         Status::Active | Status::Pressed => base,
@@ -688,11 +808,11 @@ pub fn text(theme: &Theme, status: Status) -> Style {
     }
 }
 
-fn styled(pair: palette::Pair) -> Style {
+fn styled(theme: &Theme, pair: palette::Pair) -> Style {
     Style {
         background: Some(Background::Color(pair.color)),
         text_color: pair.text,
-        border: border::rounded(2),
+        border: border::rounded(theme.tokens().radius.small),
         ..Style::default()
     }
 }
@@ -57,9 +57,10 @@ pub use crate::core::event::Event;
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
     Fill, Gradient, Image, LineCap, LineDash, LineJoin, Path, Stroke, Style,
-    Text, fill, gradient, path, stroke,
+    Text, export, fill, gradient, path, stroke,
 };
 
+use crate::core::accessibility;
 use crate::core::event;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
@@ -226,6 +227,12 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        if let Event::Accessibility(request) = event {
+            if !request.is_for(tree) {
+                return;
+            }
+        }
+
         let bounds = layout.bounds();
 
         let state = tree.state.downcast_mut::<P::State>();
@@ -279,6 +286,16 @@ where
         self.program.mouse_interaction(state, bounds, cursor)
     }
 
+    fn accessibility(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        let state = tree.state.downcast_ref::<P::State>();
+
+        self.program.accessibility(state, layout.bounds())
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -2,6 +2,7 @@ use crate::Action;
 use crate::canvas::mouse;
 use crate::canvas::{Event, Geometry};
 use crate::core::Rectangle;
+use crate::core::accessibility;
 use crate::graphics::geometry;
 
 /// The state and logic of a [`Canvas`].
@@ -70,6 +71,23 @@ where
     ) -> mouse::Interaction {
         mouse::Interaction::default()
     }
+
+    /// Describes the [`Program`] to assistive technologies, if it is
+    /// accessible.
+    ///
+    /// Any [`accessibility::Action`] declared in the returned
+    /// [`accessibility::Node`] is delivered to [`update`](Self::update) as an
+    /// [`Event::Accessibility`].
+    ///
+    /// By default, it returns `None`; and the [`Program`] is invisible to
+    /// assistive technologies.
+    fn accessibility(
+        &self,
+        _state: &Self::State,
+        _bounds: Rectangle,
+    ) -> Option<accessibility::Node> {
+        None
+    }
 }
 
 impl<Message, Theme, Renderer, T> Program<Message, Theme, Renderer> for &T
@@ -108,4 +126,12 @@ where
     ) -> mouse::Interaction {
         T::mouse_interaction(self, state, bounds, cursor)
     }
+
+    fn accessibility(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+    ) -> Option<accessibility::Node> {
+        T::accessibility(self, state, bounds)
+    }
 }
@@ -0,0 +1,487 @@
+//! Caption buttons minimize, maximize, and close a window with a custom title bar.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{caption, drag_area, horizontal_space, row, text};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Drag,
+//!     Minimize,
+//!     ToggleMaximize,
+//!     Close,
+//! }
+//!
+//! fn title_bar(state: &State) -> Element<'_, Message> {
+//!     drag_area(row![
+//!         text("My Application"),
+//!         horizontal_space(),
+//!         caption::minimize(Message::Minimize),
+//!         caption::maximize(Message::ToggleMaximize),
+//!         caption::close(Message::Close),
+//!     ])
+//!     .on_drag(Message::Drag)
+//!     .on_double_click(Message::ToggleMaximize)
+//!     .into()
+//! }
+//! # struct State;
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::Tree;
+use crate::core::widget::tree::{self, State};
+use crate::core::window;
+use crate::core::{
+    Background, Color, Element, Event, Layout, Length, Pixels, Rectangle,
+    Shell, Size, Theme, Widget,
+};
+
+/// A button that minimizes, maximizes, or closes a window.
+#[allow(missing_debug_implementations)]
+pub struct Caption<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    kind: Kind,
+    on_press: Option<Message>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+/// The kind of a [`Caption`] button; which determines its icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Minimizes the window.
+    Minimize,
+    /// Maximizes the window.
+    Maximize,
+    /// Restores a maximized window.
+    Restore,
+    /// Closes the window.
+    Close,
+}
+
+impl<'a, Message, Theme> Caption<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default width of a [`Caption`] button.
+    pub const DEFAULT_WIDTH: f32 = 46.0;
+
+    /// The default height of a [`Caption`] button.
+    pub const DEFAULT_HEIGHT: f32 = 32.0;
+
+    /// Creates a new [`Caption`] button of the given [`Kind`].
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            on_press: None,
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Caption`] button
+    /// is pressed.
+    ///
+    /// Unless `on_press` is called, the [`Caption`] button will be disabled.
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(on_press);
+        self
+    }
+
+    /// Sets the width of the [`Caption`] button.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Caption`] button.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Caption`] button.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Caption`] button.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Pressed(bool);
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Caption<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Pressed>()
+    }
+
+    fn state(&self) -> State {
+        State::new(Pressed::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn crate::core::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Pressed(is_pressed) = tree.state.downcast_mut::<Pressed>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.on_press.is_some() && cursor.is_over(layout.bounds()) {
+                    *is_pressed = true;
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(on_press) = &self.on_press {
+                    if *is_pressed {
+                        *is_pressed = false;
+
+                        if cursor.is_over(layout.bounds()) {
+                            shell.publish(on_press.clone());
+                        }
+
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                *is_pressed = false;
+            }
+            _ => {}
+        }
+
+        let current_status = if self.on_press.is_none() {
+            Status::Disabled
+        } else if cursor.is_over(layout.bounds()) {
+            if *is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.status = Some(current_status);
+        } else if self.status.is_some_and(|status| status != current_status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style =
+            theme.style(&self.class, self.status.unwrap_or(Status::Active));
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let size = style.icon_size.0;
+        let icon = Rectangle {
+            x: (bounds.center_x() - size / 2.0).round(),
+            y: (bounds.center_y() - size / 2.0).round(),
+            width: size,
+            height: size,
+        };
+
+        let mut line = |x: f32, y: f32, width: f32, height: f32| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: icon.x + x,
+                        y: icon.y + y,
+                        width,
+                        height,
+                    },
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                style.icon_color,
+            );
+        };
+
+        match self.kind {
+            Kind::Minimize => {
+                line(0.0, (size / 2.0).round(), size, 1.0);
+            }
+            Kind::Maximize => {
+                line(0.0, 0.0, size, 1.0);
+                line(0.0, size - 1.0, size, 1.0);
+                line(0.0, 0.0, 1.0, size);
+                line(size - 1.0, 0.0, 1.0, size);
+            }
+            Kind::Restore => {
+                let offset = (size / 5.0).round();
+                let front = size - offset;
+
+                line(0.0, offset, front, 1.0);
+                line(0.0, size - 1.0, front, 1.0);
+                line(0.0, offset, 1.0, front);
+                line(front - 1.0, offset, 1.0, front);
+
+                line(offset, 0.0, front, 1.0);
+                line(size - 1.0, 0.0, 1.0, front);
+            }
+            Kind::Close => {
+                let steps = size as usize;
+
+                for i in 0..steps {
+                    let i = i as f32;
+
+                    line(i, i, 1.0, 1.0);
+                    line(size - 1.0 - i, i, 1.0, 1.0);
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Caption<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(caption: Caption<'a, Message, Theme>) -> Self {
+        Element::new(caption)
+    }
+}
+
+/// Creates a new [`Caption`] button that minimizes the window.
+///
+/// The message should be handled with `window::minimize`.
+pub fn minimize<'a, Message, Theme>(
+    on_press: Message,
+) -> Caption<'a, Message, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    Caption::new(Kind::Minimize).on_press(on_press)
+}
+
+/// Creates a new [`Caption`] button that maximizes the window.
+///
+/// The message should be handled with `window::toggle_maximize`.
+pub fn maximize<'a, Message, Theme>(
+    on_press: Message,
+) -> Caption<'a, Message, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    Caption::new(Kind::Maximize).on_press(on_press)
+}
+
+/// Creates a new [`Caption`] button that restores a maximized window.
+///
+/// The message should be handled with `window::toggle_maximize`.
+pub fn restore<'a, Message, Theme>(
+    on_press: Message,
+) -> Caption<'a, Message, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    Caption::new(Kind::Restore).on_press(on_press)
+}
+
+/// Creates a new [`Caption`] button that closes the window.
+///
+/// The message should be handled with `window::close`.
+pub fn close<'a, Message, Theme>(
+    on_press: Message,
+) -> Caption<'a, Message, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    let mut caption = Caption::new(Kind::Close).on_press(on_press);
+    caption.class = Theme::close();
+    caption
+}
+
+/// The possible status of a [`Caption`] button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Caption`] button can be pressed.
+    Active,
+    /// The [`Caption`] button can be pressed and it is being hovered.
+    Hovered,
+    /// The [`Caption`] button is being pressed.
+    Pressed,
+    /// The [`Caption`] button cannot be pressed.
+    Disabled,
+}
+
+/// The appearance of a [`Caption`] button.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the button.
+    pub background: Option<Background>,
+    /// The [`Color`] of the icon.
+    pub icon_color: Color,
+    /// The size of the icon.
+    pub icon_size: Pixels,
+}
+
+/// The theme catalog of a [`Caption`] button.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The class of a [`Caption`] button that closes the window.
+    fn close<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Caption`] button.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn close<'a>() -> Self::Class<'a> {
+        Box::new(danger)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Caption`] button.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let base = Style {
+        background: None,
+        icon_color: palette.background.base.text,
+        icon_size: Pixels(10.0),
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            background: Some(palette.background.weak.color.into()),
+            ..base
+        },
+        Status::Pressed => Style {
+            background: Some(palette.background.strong.color.into()),
+            ..base
+        },
+        Status::Disabled => Style {
+            icon_color: base.icon_color.scale_alpha(0.5),
+            ..base
+        },
+    }
+}
+
+/// The danger style of a [`Caption`] button; used to close windows.
+pub fn danger(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Hovered => Style {
+            background: Some(palette.danger.base.color.into()),
+            icon_color: palette.danger.base.text,
+            ..default(theme, status)
+        },
+        Status::Pressed => Style {
+            background: Some(palette.danger.strong.color.into()),
+            icon_color: palette.danger.strong.text,
+            ..default(theme, status)
+        },
+        Status::Active | Status::Disabled => default(theme, status),
+    }
+}
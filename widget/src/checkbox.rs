@@ -30,12 +30,13 @@
 //! }
 //! ```
 //! ![Checkbox drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
-use crate::core::theme::palette;
+use crate::core::theme::{Styles, palette};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
@@ -306,7 +307,7 @@ where
 
     fn update(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -327,6 +328,15 @@ where
                     }
                 }
             }
+            Event::Accessibility(request)
+                if request.is_for(tree)
+                    && request.action == accessibility::Action::Click =>
+            {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish((on_toggle)(!self.is_checked));
+                    shell.capture_event();
+                }
+            }
             _ => {}
         }
 
@@ -448,13 +458,38 @@ where
         }
     }
 
+    fn accessibility(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        let node = accessibility::Node::new(accessibility::Role::CheckBox)
+            .name(self.label.clone())
+            .toggled(self.is_checked);
+
+        Some(if self.on_toggle.is_some() {
+            node.action(accessibility::Action::Click)
+        } else {
+            node
+        })
+    }
+
     fn operate(
         &self,
-        _state: &mut Tree,
+        state: &mut Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        if let Some(node) = self.accessibility(state, layout) {
+            operation.accessible(
+                None,
+                state.accessibility_id(),
+                layout.bounds(),
+                &node,
+            );
+        }
+
         operation.text(None, layout.bounds(), &self.label);
     }
 }
@@ -542,7 +577,19 @@ impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(primary)
+        Box::new(|theme: &Theme, status: Status| {
+            let style = primary(theme, status);
+
+            match theme.extension::<Styles>() {
+                Some(styles) => Style {
+                    background: styles.checkbox.background(style.background),
+                    text_color: styles.checkbox.text_color.or(style.text_color),
+                    border: styles.checkbox.border(style.border),
+                    ..style
+                },
+                None => style,
+            }
+        })
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
@@ -688,6 +735,7 @@ fn styled(
             } else {
                 border_color
             },
+            ..Border::default()
         },
         text_color: None,
     }
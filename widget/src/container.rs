@@ -26,13 +26,14 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::shadow::Shadows;
 use crate::core::theme;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
     self, Background, Clipboard, Color, Element, Event, Layout, Length,
-    Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Theme, Vector,
-    Widget, color,
+    Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+    color,
 };
 use crate::runtime::task::{self, Task};
 
@@ -443,8 +444,8 @@ pub fn draw_background<Renderer>(
     Renderer: core::Renderer,
 {
     if style.background.is_some()
-        || style.border.width > 0.0
-        || style.shadow.color.a > 0.0
+        || style.border.has_width()
+        || style.shadow.is_visible()
     {
         renderer.fill_quad(
             renderer::Quad {
@@ -591,8 +592,8 @@ pub struct Style {
     pub background: Option<Background>,
     /// The [`Border`] of the container.
     pub border: Border,
-    /// The [`Shadow`] of the container.
-    pub shadow: Shadow,
+    /// The [`Shadows`] of the container.
+    pub shadow: Shadows,
     /// Whether the container should be snapped to the pixel grid.
     pub snap: bool,
 }
@@ -623,7 +624,7 @@ impl Style {
     }
 
     /// Updates the shadow of the [`Style`].
-    pub fn shadow(self, shadow: impl Into<Shadow>) -> Self {
+    pub fn shadow(self, shadow: impl Into<Shadows>) -> Self {
         Self {
             shadow: shadow.into(),
             ..self
@@ -698,7 +699,7 @@ pub fn rounded_box(theme: &Theme) -> Style {
 
     Style {
         background: Some(palette.background.weak.color.into()),
-        border: border::rounded(2),
+        border: border::rounded(theme.tokens().radius.small),
         ..Style::default()
     }
 }
@@ -711,54 +712,79 @@ pub fn bordered_box(theme: &Theme) -> Style {
         background: Some(palette.background.weakest.color.into()),
         border: Border {
             width: 1.0,
-            radius: 5.0.into(),
+            radius: theme.tokens().radius.medium.into(),
             color: palette.background.strong.color,
+            ..Border::default()
         },
         ..Style::default()
     }
 }
 
+/// An elevated [`Container`]; with the surface tint and shadows of
+/// the given [`Elevation`].
+///
+/// [`Elevation`]: theme::Elevation
+pub fn elevated(
+    theme: &Theme,
+    elevation: impl Into<theme::Elevation>,
+) -> Style {
+    let palette = theme.extended_palette();
+    let elevation = elevation.into();
+    let surface = elevation.surface(palette);
+
+    Style {
+        background: Some(surface.color.into()),
+        text_color: Some(surface.text),
+        border: border::rounded(theme.tokens().radius.medium),
+        shadow: elevation.shadow(palette),
+        ..Style::default()
+    }
+}
+
 /// A [`Container`] with a dark background and white text.
-pub fn dark(_theme: &Theme) -> Style {
-    style(theme::palette::Pair {
-        color: color!(0x111111),
-        text: Color::WHITE,
-    })
+pub fn dark(theme: &Theme) -> Style {
+    style(
+        theme,
+        theme::palette::Pair {
+            color: color!(0x111111),
+            text: Color::WHITE,
+        },
+    )
 }
 
 /// A [`Container`] with a primary background color.
 pub fn primary(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    style(palette.primary.base)
+    style(theme, palette.primary.base)
 }
 
 /// A [`Container`] with a secondary background color.
 pub fn secondary(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    style(palette.secondary.base)
+    style(theme, palette.secondary.base)
 }
 
 /// A [`Container`] with a success background color.
 pub fn success(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    style(palette.success.base)
+    style(theme, palette.success.base)
 }
 
 /// A [`Container`] with a danger background color.
 pub fn danger(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    style(palette.danger.base)
+    style(theme, palette.danger.base)
 }
 
-fn style(pair: theme::palette::Pair) -> Style {
+fn style(theme: &Theme, pair: theme::palette::Pair) -> Style {
     Style {
         background: Some(pair.color.into()),
         text_color: Some(pair.text),
-        border: border::rounded(2),
+        border: border::rounded(theme.tokens().radius.small),
         ..Style::default()
     }
 }
@@ -0,0 +1,315 @@
+//! A container for receiving files dropped into the window.
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree, tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::path::PathBuf;
+
+/// Emit messages when files are dragged over or dropped on its content.
+///
+/// Unlike listening to [`window::Event::FileDropped`], a [`DropZone`] only
+/// reacts to files dropped inside its own bounds; so applications with
+/// multiple panes can know which one the files were dropped on.
+#[allow(missing_debug_implementations)]
+pub struct DropZone<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_enter: Option<Message>,
+    on_hover: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_exit: Option<Message>,
+    on_drop: Option<Box<dyn Fn(Vec<PathBuf>, Point) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> DropZone<'a, Message, Theme, Renderer> {
+    /// Creates a [`DropZone`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        DropZone {
+            content: content.into(),
+            on_enter: None,
+            on_hover: None,
+            on_exit: None,
+            on_drop: None,
+        }
+    }
+
+    /// The message to emit when files are dragged into the area.
+    #[must_use]
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// The message to emit when files are dragged over the area, with the
+    /// cursor position relative to the area.
+    #[must_use]
+    pub fn on_hover(
+        mut self,
+        on_hover: impl Fn(Point) -> Message + 'a,
+    ) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// The message to emit when the dragged files leave the area without
+    /// being dropped.
+    #[must_use]
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// The message to emit when files are dropped in the area, with the
+    /// dropped files and the cursor position relative to the area.
+    ///
+    /// All the files dropped at once are produced in a single message.
+    #[must_use]
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(Vec<PathBuf>, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+}
+
+/// Local state of the [`DropZone`].
+#[derive(Default)]
+struct State {
+    is_dragging: bool,
+    is_hovered: bool,
+    cursor_position: Option<Point>,
+    dropped: Vec<PathBuf>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Message: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        update(self, tree, event, layout, cursor, shell);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DropZone<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        zone: DropZone<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(zone)
+    }
+}
+
+/// Processes the given [`Event`] and updates the [`State`] of a [`DropZone`]
+/// accordingly.
+fn update<Message: Clone, Theme, Renderer>(
+    widget: &mut DropZone<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+) {
+    let state: &mut State = tree.state.downcast_mut();
+    let bounds = layout.bounds();
+
+    match event {
+        Event::Window(window::Event::FileHovered(_)) => {
+            state.is_dragging = true;
+        }
+        Event::Window(window::Event::FilesHoveredLeft) => {
+            state.is_dragging = false;
+        }
+        Event::Window(window::Event::FileDropped(path)) => {
+            // Files dropped at once produce an event each; so we gather
+            // them until the next redraw.
+            state.dropped.push(path.clone());
+            shell.request_redraw();
+        }
+        Event::Window(window::Event::RedrawRequested(_))
+            if !state.dropped.is_empty() =>
+        {
+            let dropped = std::mem::take(&mut state.dropped);
+
+            state.is_dragging = false;
+            state.is_hovered = false;
+            state.cursor_position = None;
+
+            if let Some(position) = cursor.position_in(bounds) {
+                if let Some(on_drop) = &widget.on_drop {
+                    shell.publish(on_drop(dropped, position));
+                    shell.capture_event();
+                }
+            }
+
+            return;
+        }
+        _ => {}
+    }
+
+    let was_hovered = state.is_hovered;
+    let position = cursor.position_in(bounds);
+
+    state.is_hovered = state.is_dragging && position.is_some();
+
+    if state.is_hovered && !was_hovered {
+        if let Some(on_enter) = &widget.on_enter {
+            shell.publish(on_enter.clone());
+        }
+    } else if !state.is_hovered && was_hovered {
+        state.cursor_position = None;
+
+        if let Some(on_exit) = &widget.on_exit {
+            shell.publish(on_exit.clone());
+        }
+    }
+
+    if state.is_hovered && state.cursor_position != position {
+        state.cursor_position = position;
+
+        if let (Some(on_hover), Some(position)) = (&widget.on_hover, position) {
+            shell.publish(on_hover(position));
+        }
+    }
+}
@@ -161,7 +161,7 @@ where
         {
             let style = theme.style(&self.class);
 
-            if style.shadow.color.a > 0.0 {
+            if style.shadow.is_visible() {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: layout.bounds().shrink(1.0),
@@ -325,7 +325,7 @@ where
                 {
                     let style = theme.style(&self.float.class);
 
-                    if style.shadow.color.a > 0.0 {
+                    if style.shadow.is_visible() {
                         renderer.fill_quad(
                             renderer::Quad {
                                 bounds: bounds.shrink(1.0),
@@ -0,0 +1,370 @@
+//! A container that scopes the keyboard navigation of its focusable widgets.
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::Focusable;
+use crate::core::widget::{Id, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// Scope the Tab navigation of the focusable widgets in its content.
+///
+/// While one of its widgets is focused, pressing Tab (or Shift+Tab) moves
+/// the focus to the next (or previous) focusable widget of the scope; in
+/// [tab index](Self::tab_index) order first, and in tree order after.
+///
+/// Past the last widget, the focus leaves the scope; unless it is
+/// [trapped](Self::trap), which is handy for dialogs.
+///
+/// A [`FocusScope`] does not take the focus by itself. Use
+/// [`focus_next_in`](crate::focus_next_in) with its [`Id`] to move the focus
+/// into it; for instance, when a dialog opens.
+#[allow(missing_debug_implementations)]
+pub struct FocusScope<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    id: Option<Id>,
+    is_trapped: bool,
+    tab_indices: Vec<(Id, u32)>,
+}
+
+impl<'a, Message, Theme, Renderer> FocusScope<'a, Message, Theme, Renderer> {
+    /// Creates a [`FocusScope`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        FocusScope {
+            content: content.into(),
+            id: None,
+            is_trapped: false,
+            tab_indices: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`FocusScope`].
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets whether the focus is trapped inside the [`FocusScope`]; wrapping
+    /// around instead of leaving it.
+    #[must_use]
+    pub fn trap(mut self, is_trapped: bool) -> Self {
+        self.is_trapped = is_trapped;
+        self
+    }
+
+    /// Sets the tab index of the focusable widget with the given [`Id`].
+    ///
+    /// Widgets with a tab index are visited first, in ascending order; and
+    /// the rest after them, in tree order.
+    #[must_use]
+    pub fn tab_index(mut self, id: impl Into<Id>, index: u32) -> Self {
+        self.tab_indices.push((id.into(), index));
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusScope<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            },
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Tab),
+            modifiers,
+            ..
+        }) = event
+        else {
+            return;
+        };
+
+        let mut focusables = Focusables::default();
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut focusables,
+        );
+
+        let Some(focused) = focusables.focused else {
+            return;
+        };
+
+        let order = self.tab_order(&focusables.ids);
+        let Some(position) = order.iter().position(|i| *i == focused) else {
+            return;
+        };
+
+        let next = if modifiers.shift() {
+            position.checked_sub(1)
+        } else {
+            Some(position + 1).filter(|next| *next < order.len())
+        };
+
+        let next = match next {
+            Some(next) => next,
+            None if self.is_trapped => {
+                if modifiers.shift() {
+                    order.len() - 1
+                } else {
+                    0
+                }
+            }
+            None => return,
+        };
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut FocusIndex {
+                target: order[next],
+                current: 0,
+            },
+        );
+
+        shell.capture_event();
+        shell.request_redraw();
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<Message, Theme, Renderer> FocusScope<'_, Message, Theme, Renderer> {
+    /// Returns the indices of the given focusable widgets in tab order.
+    fn tab_order(&self, ids: &[Option<Id>]) -> Vec<usize> {
+        let tab_index = |id: &Option<Id>| {
+            let id = id.as_ref()?;
+
+            self.tab_indices
+                .iter()
+                .find(|(target, _)| target == id)
+                .map(|(_, index)| *index)
+        };
+
+        let mut order: Vec<usize> = (0..ids.len()).collect();
+
+        // The sort is stable; so ties keep their tree order
+        order.sort_by_key(|i| match tab_index(&ids[*i]) {
+            Some(index) => (false, index),
+            None => (true, 0),
+        });
+
+        order
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<FocusScope<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        scope: FocusScope<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(scope)
+    }
+}
+
+/// Gathers the focusable widgets of a [`FocusScope`] in tree order.
+#[derive(Default)]
+struct Focusables {
+    ids: Vec<Option<Id>>,
+    focused: Option<usize>,
+}
+
+impl Operation for Focusables {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Focusable,
+    ) {
+        if state.is_focused() {
+            self.focused = Some(self.ids.len());
+        }
+
+        self.ids.push(id.cloned());
+    }
+}
+
+/// Focuses the focusable widget of a [`FocusScope`] at the given index in
+/// tree order, unfocusing the rest.
+struct FocusIndex {
+    target: usize,
+    current: usize,
+}
+
+impl Operation for FocusIndex {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Focusable,
+    ) {
+        if self.current == self.target {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+}
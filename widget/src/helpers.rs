@@ -25,7 +25,10 @@ use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, Grid, MouseArea, Pin, Pop, Row, Space, Stack, Themer};
+use crate::{
+    Column, DragArea, DropZone, FocusScope, Grid, MouseArea, Pin, Pop, Row,
+    Space, Stack, Themer, Transition,
+};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -1002,6 +1005,21 @@ where
     Pop::new(content)
 }
 
+/// Creates a new [`Transition`] showing the view of the given key.
+///
+/// When the key changes, the [`Transition`] animates the change from the
+/// view of the previous key to the new one.
+pub fn transition<'a, Key, Message, Theme, Renderer>(
+    key: Key,
+    view: impl Fn(&Key) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Transition<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Renderer: core::Renderer,
+{
+    Transition::new(key, view)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// Scrollables let users navigate an endless amount of content with a scrollbar.
@@ -2022,6 +2040,15 @@ where
     crate::QRCode::new(data)
 }
 
+/// Creates a new [`Lottie`] widget that plays the given [`Animation`].
+///
+/// [`Lottie`]: crate::Lottie
+/// [`Animation`]: crate::lottie::Animation
+#[cfg(feature = "lottie")]
+pub fn lottie(animation: &crate::lottie::Animation) -> crate::Lottie<'_> {
+    crate::Lottie::new(animation)
+}
+
 /// Creates a new [`Shader`].
 ///
 /// [`Shader`]: crate::Shader
@@ -2033,6 +2060,30 @@ where
     crate::Shader::new(program)
 }
 
+/// Focuses the focusable widget with the given [`Id`], unfocusing any
+/// other.
+///
+/// [`Id`]: core::widget::Id
+pub fn focus<T>(id: impl Into<core::widget::Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus(id.into())))
+}
+
+/// Unfocuses the focused widget, if any.
+pub fn unfocus<T>() -> Task<T> {
+    task::effect(Action::widget(operation::focusable::unfocus()))
+}
+
+/// Produces the [`Id`] of the focused widget, if any.
+///
+/// Focused widgets without an [`Id`] are ignored.
+///
+/// [`Id`]: core::widget::Id
+pub fn focused() -> Task<Option<core::widget::Id>> {
+    task::widget(operation::focusable::find_focused())
+        .collect()
+        .map(|ids| ids.into_iter().next())
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_previous()))
@@ -2043,6 +2094,26 @@ pub fn focus_next<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_next()))
 }
 
+/// Focuses the previous focusable widget inside the container with the
+/// given [`Id`].
+///
+/// [`Id`]: core::widget::Id
+pub fn focus_previous_in<T>(container: impl Into<core::widget::Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_previous_in(
+        container.into(),
+    )))
+}
+
+/// Focuses the next focusable widget inside the container with the given
+/// [`Id`].
+///
+/// [`Id`]: core::widget::Id
+pub fn focus_next_in<T>(container: impl Into<core::widget::Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_next_in(
+        container.into(),
+    )))
+}
+
 /// Creates a new [`MouseArea`].
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -2053,6 +2124,45 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`DragArea`].
+///
+/// A [`DragArea`] produces messages to drag the window around when its
+/// content is pressed; like the title bar of a borderless window.
+pub fn drag_area<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragArea<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    DragArea::new(widget)
+}
+
+/// Creates a new [`DropZone`].
+///
+/// A [`DropZone`] produces messages when files are dragged over or dropped
+/// on its content.
+pub fn drop_zone<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropZone<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    DropZone::new(widget)
+}
+
+/// Creates a new [`FocusScope`].
+///
+/// A [`FocusScope`] keeps the Tab navigation of its content together; and,
+/// optionally, traps it.
+pub fn focus_scope<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FocusScope<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    FocusScope::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -16,26 +16,63 @@
 //! }
 //! ```
 //! <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+pub mod progressive;
 pub mod viewer;
 pub use viewer::Viewer;
 
+#[cfg(feature = "animated-image")]
+pub mod animated;
+#[cfg(feature = "animated-image")]
+pub use animated::{Animated, Frames, Repeat};
+
+#[cfg(all(feature = "remote-image", not(target_arch = "wasm32")))]
+pub mod remote;
+#[cfg(all(feature = "remote-image", not(target_arch = "wasm32")))]
+pub use remote::Remote;
+
 use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::Tree;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Rotation, Size,
-    Vector, Widget,
+    ContentFit, Element, Layout, Length, Padding, Point, Rectangle, Rotation,
+    Size, Vector, Widget,
 };
+use crate::runtime::Task;
 
-pub use image::{FilterMethod, Handle};
+pub use image::{Filter, FilterMethod, Handle};
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Decodes the image of the given [`Handle`] off the UI thread; producing a
+/// partially decoded preview first, when the format allows it.
+///
+/// See [`progressive::decode`] for more details.
+pub fn decode(handle: impl Into<Handle>) -> Task<progressive::Progress> {
+    progressive::decode(handle)
+}
+
+/// Creates a new [`Animated`] image that plays the given [`Frames`].
+#[cfg(feature = "animated-image")]
+pub fn animated(frames: &Frames) -> Animated<'_> {
+    Animated::new(frames)
+}
+
+/// Creates a new [`Remote`] image that displays the given URL once loaded.
+#[cfg(all(feature = "remote-image", not(target_arch = "wasm32")))]
+pub fn remote<'a, Message, Theme, Renderer>(
+    url: impl Into<String>,
+) -> Remote<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    Remote::new(url)
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -65,6 +102,8 @@ pub struct Image<Handle = image::Handle> {
     opacity: f32,
     scale: f32,
     expand: bool,
+    filter: Filter,
+    nine_slice: Option<NineSlice>,
 }
 
 impl<Handle> Image<Handle> {
@@ -80,6 +119,8 @@ impl<Handle> Image<Handle> {
             opacity: 1.0,
             scale: 1.0,
             expand: false,
+            filter: Filter::NONE,
+            nine_slice: None,
         }
     }
 
@@ -145,6 +186,48 @@ impl<Handle> Image<Handle> {
         self.scale = scale.into();
         self
     }
+
+    /// Sets the [`Filter`] effects of the [`Image`]; like blur, grayscale,
+    /// brightness, or tint.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Slices the [`Image`] into nine regions with the given insets, in
+    /// pixels of the image.
+    ///
+    /// The corners keep their size, while the edges and the center are
+    /// stretched to fill the bounds of the [`Image`]. This is useful for
+    /// textured buttons or chat bubbles.
+    pub fn nine_slice(mut self, insets: impl Into<Padding>) -> Self {
+        self.nine_slice = Some(NineSlice {
+            insets: insets.into(),
+            tile: self.nine_slice.is_some_and(|slice| slice.tile),
+        });
+        self
+    }
+
+    /// Sets whether the edges and the center of a [nine-sliced] [`Image`]
+    /// should be tiled instead of stretched.
+    ///
+    /// [nine-sliced]: Self::nine_slice
+    pub fn tile(mut self, tile: bool) -> Self {
+        if let Some(nine_slice) = &mut self.nine_slice {
+            nine_slice.tile = tile;
+        }
+
+        self
+    }
+}
+
+/// The slicing of an [`Image`] into nine regions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NineSlice {
+    /// The insets of the corners, in pixels of the image.
+    pub insets: Padding,
+    /// Whether the edges and the center are tiled instead of stretched.
+    pub tile: bool,
 }
 
 /// Computes the layout of an [`Image`].
@@ -246,6 +329,7 @@ pub fn draw<Renderer, Handle>(
     rotation: Rotation,
     opacity: f32,
     scale: f32,
+    filter: Filter,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone,
@@ -263,6 +347,7 @@ pub fn draw<Renderer, Handle>(
                     filter_method,
                     rotation,
                     opacity,
+                    filter,
                     drawing_bounds,
                 );
             });
@@ -274,17 +359,157 @@ pub fn draw<Renderer, Handle>(
             filter_method,
             rotation,
             opacity,
+            filter,
             drawing_bounds,
         );
     }
 }
 
+/// Draws a [`NineSlice`] of an [`Image`] filling the bounds of the given
+/// [`Layout`].
+pub fn draw_nine_slice<Renderer, Handle>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    handle: &Handle,
+    filter_method: FilterMethod,
+    opacity: f32,
+    filter: Filter,
+    nine_slice: NineSlice,
+) where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    let bounds = layout.bounds();
+    let Size { width, height } = renderer.measure_image(handle);
+    let image_size = Size::new(width as f32, height as f32);
+
+    let Padding {
+        top,
+        right,
+        bottom,
+        left,
+    } = nine_slice.insets;
+
+    // Corners shrink when the bounds cannot fit them
+    let scale = 1.0f32
+        .min(bounds.width / (left + right).max(1.0))
+        .min(bounds.height / (top + bottom).max(1.0));
+
+    let columns = [
+        (0.0, left, bounds.x, left * scale),
+        (
+            left,
+            image_size.width - right,
+            bounds.x + left * scale,
+            bounds.width - (left + right) * scale,
+        ),
+        (
+            image_size.width - right,
+            image_size.width,
+            bounds.x + bounds.width - right * scale,
+            right * scale,
+        ),
+    ];
+
+    let rows = [
+        (0.0, top, bounds.y, top * scale),
+        (
+            top,
+            image_size.height - bottom,
+            bounds.y + top * scale,
+            bounds.height - (top + bottom) * scale,
+        ),
+        (
+            image_size.height - bottom,
+            image_size.height,
+            bounds.y + bounds.height - bottom * scale,
+            bottom * scale,
+        ),
+    ];
+
+    for (row, &(source_y, source_bottom, y, target_height)) in
+        rows.iter().enumerate()
+    {
+        for (column, &(source_x, source_right, x, target_width)) in
+            columns.iter().enumerate()
+        {
+            let source = Rectangle {
+                x: source_x,
+                y: source_y,
+                width: source_right - source_x,
+                height: source_bottom - source_y,
+            };
+
+            let target = Rectangle {
+                x,
+                y,
+                width: target_width,
+                height: target_height,
+            };
+
+            if source.width <= 0.0
+                || source.height <= 0.0
+                || target.width <= 0.0
+                || target.height <= 0.0
+            {
+                continue;
+            }
+
+            // Only the edges and the center are tiled
+            let tile_width = if nine_slice.tile && column == 1 {
+                source.width * scale
+            } else {
+                target.width
+            };
+
+            let tile_height = if nine_slice.tile && row == 1 {
+                source.height * scale
+            } else {
+                target.height
+            };
+
+            renderer.with_layer(target, |renderer| {
+                let mut tile_y = target.y;
+
+                while tile_y < target.y + target.height {
+                    let mut tile_x = target.x;
+
+                    while tile_x < target.x + target.width {
+                        let scale_x = tile_width / source.width;
+                        let scale_y = tile_height / source.height;
+
+                        render(
+                            renderer,
+                            handle,
+                            filter_method,
+                            Rotation::default(),
+                            opacity,
+                            filter,
+                            Rectangle {
+                                x: tile_x - source.x * scale_x,
+                                y: tile_y - source.y * scale_y,
+                                width: image_size.width * scale_x,
+                                height: image_size.height * scale_y,
+                            },
+                        );
+
+                        tile_x += tile_width;
+                    }
+
+                    tile_y += tile_height;
+                }
+            });
+        }
+    }
+}
+
 fn render<Renderer, Handle>(
     renderer: &mut Renderer,
     handle: &Handle,
     filter_method: FilterMethod,
     rotation: Rotation,
     opacity: f32,
+    filter: Filter,
     drawing_bounds: Rectangle,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
@@ -297,6 +522,7 @@ fn render<Renderer, Handle>(
             rotation: rotation.radians(),
             opacity,
             snap: true,
+            filter,
         },
         drawing_bounds,
     );
@@ -343,6 +569,20 @@ where
         _cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        if let Some(nine_slice) = self.nine_slice {
+            draw_nine_slice(
+                renderer,
+                layout,
+                &self.handle,
+                self.filter_method,
+                self.opacity,
+                self.filter,
+                nine_slice,
+            );
+
+            return;
+        }
+
         draw(
             renderer,
             layout,
@@ -353,6 +593,7 @@ where
             self.rotation,
             self.opacity,
             self.scale,
+            self.filter,
         );
     }
 }
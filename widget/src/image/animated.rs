@@ -0,0 +1,373 @@
+//! Play animated images; like GIF, APNG, or WebP.
+use crate::core::image::{self, FilterMethod, Handle};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Layout, Length, Rectangle, Rotation,
+    Shell, Size, Widget,
+};
+use crate::graphics;
+
+use std::path::Path;
+use std::sync::Arc;
+
+/// The delay of frames that do not specify a sensible one.
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// The minimum delay of a frame before it is considered unspecified.
+const MIN_DELAY: Duration = Duration::from_millis(20);
+
+/// The decoded frames of an animated image.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    frames: Arc<[Frame]>,
+}
+
+#[derive(Debug, Clone)]
+struct Frame {
+    handle: Handle,
+    delay: Duration,
+}
+
+impl Frames {
+    /// Decodes the [`Frames`] of the animated image in the given bytes.
+    ///
+    /// Images that are not animated are decoded as a single frame.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let frames = graphics::image::load_frames(bytes.as_ref())
+            .map_err(|error| Error::Decoding(Arc::new(error)))?;
+
+        if frames.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        Ok(Self {
+            frames: frames
+                .into_iter()
+                .map(|(buffer, delay)| Frame {
+                    handle: Handle::from_rgba(
+                        buffer.width(),
+                        buffer.height(),
+                        buffer.into_raw(),
+                    ),
+                    // Like browsers do, very short delays are slowed down
+                    delay: if delay < MIN_DELAY {
+                        DEFAULT_DELAY
+                    } else {
+                        delay
+                    },
+                })
+                .collect(),
+        })
+    }
+
+    /// Reads and decodes the [`Frames`] of the animated image at the
+    /// given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes =
+            std::fs::read(path).map_err(|error| Error::Io(Arc::new(error)))?;
+
+        Self::from_bytes(bytes)
+    }
+
+    /// Returns the amount of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if there are no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the total [`Duration`] of a single loop of the animation.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    /// Returns the image [`Handle`] of the frame at the given index.
+    pub fn get(&self, index: usize) -> Option<&Handle> {
+        self.frames.get(index).map(|frame| &frame.handle)
+    }
+}
+
+/// An error produced when loading animated [`Frames`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read.
+    #[error("the image could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+    /// The image could not be decoded.
+    #[error("the image could not be decoded: {0}")]
+    Decoding(Arc<graphics::image::image_rs::ImageError>),
+    /// The image has no frames.
+    #[error("the image has no frames")]
+    Empty,
+}
+
+/// How many times an animated image is played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Repeat {
+    /// The animation loops forever.
+    #[default]
+    Forever,
+    /// The animation is played the given amount of times, and then
+    /// stops at its last frame.
+    Times(u32),
+}
+
+/// A frame that plays an animated image while keeping aspect ratio.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::image;
+///
+/// struct State {
+///    frames: image::Frames,
+///    is_playing: bool,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     image::animated(&state.frames).playing(state.is_playing).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Animated<'a> {
+    frames: &'a Frames,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    opacity: f32,
+    is_playing: bool,
+    repeat: Repeat,
+}
+
+impl<'a> Animated<'a> {
+    /// Creates a new [`Animated`] image with the given [`Frames`].
+    pub fn new(frames: &'a Frames) -> Self {
+        Self {
+            frames,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
+            opacity: 1.0,
+            is_playing: true,
+            repeat: Repeat::default(),
+        }
+    }
+
+    /// Sets the width of the [`Animated`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Animated`] image.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the opacity of the [`Animated`] image.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets whether the [`Animated`] image is playing or paused.
+    ///
+    /// Paused images keep showing their current frame.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets how many times the [`Animated`] image is played.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    fn handle(&self, state: &State) -> &'a Handle {
+        self.frames
+            .get(state.current)
+            .or_else(|| self.frames.get(0))
+            .expect("Animated image frames must not be empty")
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    current: usize,
+    next_frame_at: Option<Instant>,
+    loops: u32,
+}
+
+impl State {
+    fn advance(&mut self, frames: &Frames, repeat: Repeat, now: Instant) {
+        let Some(mut next_frame_at) = self.next_frame_at else {
+            self.next_frame_at = Some(now + frames.frames[self.current].delay);
+            return;
+        };
+
+        while now >= next_frame_at {
+            if self.current + 1 < frames.len() {
+                self.current += 1;
+            } else {
+                self.loops = self.loops.saturating_add(1);
+
+                if let Repeat::Times(times) = repeat {
+                    if self.loops >= times {
+                        self.next_frame_at = None;
+                        return;
+                    }
+                }
+
+                self.current = 0;
+            }
+
+            next_frame_at += frames.frames[self.current].delay;
+        }
+
+        self.next_frame_at = Some(next_frame_at);
+    }
+
+    fn is_finished(&self, repeat: Repeat) -> bool {
+        matches!(repeat, Repeat::Times(times) if self.loops >= times)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Animated<'_>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+
+        super::layout(
+            renderer,
+            limits,
+            self.handle(state),
+            self.width,
+            self.height,
+            self.content_fit,
+            Rotation::default(),
+            false,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.current >= self.frames.len() {
+            *state = State::default();
+        }
+
+        if !self.is_playing
+            || self.frames.len() < 2
+            || state.is_finished(self.repeat)
+        {
+            state.next_frame_at = None;
+            return;
+        }
+
+        state.advance(self.frames, self.repeat, *now);
+
+        if let Some(next_frame_at) = state.next_frame_at {
+            shell.request_redraw_at(next_frame_at);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        super::draw(
+            renderer,
+            layout,
+            viewport,
+            self.handle(state),
+            self.content_fit,
+            self.filter_method,
+            Rotation::default(),
+            self.opacity,
+            1.0,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+{
+    fn from(animated: Animated<'a>) -> Self {
+        Element::new(animated)
+    }
+}
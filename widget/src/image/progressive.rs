@@ -0,0 +1,275 @@
+//! Decode large images in the background, showing partial results first.
+use crate::core::image::Handle;
+use crate::graphics;
+use crate::graphics::image::image_rs;
+use crate::runtime::Task;
+use crate::runtime::futures::futures::channel::mpsc;
+
+use std::sync::Arc;
+
+/// The amount of partial images produced while decoding a non-interlaced
+/// image.
+const PARTIAL_STEPS: u32 = 8;
+
+/// The progress of an image being decoded in the background.
+#[derive(Debug, Clone)]
+pub enum Progress {
+    /// A part of the image has been decoded.
+    ///
+    /// For interlaced images, this is the whole image at a lower level of
+    /// detail; otherwise, the rows decoded so far, with the rest of the image
+    /// left transparent.
+    ///
+    /// Only produced for formats that can be decoded progressively; which is,
+    /// currently, PNG.
+    Preview(Handle),
+    /// The image has been fully decoded.
+    Done(Handle),
+    /// The image could not be decoded.
+    Failed(Error),
+}
+
+/// An error produced when decoding an image.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("the image could not be decoded: {0}")]
+pub struct Error(Arc<image_rs::ImageError>);
+
+/// Decodes the image of the given [`Handle`] off the UI thread.
+///
+/// A [`Progress::Preview`] is produced every time a meaningful part of the
+/// image has been decoded; after every pass of an interlaced PNG, or every
+/// few rows of a non-interlaced one. The [`Handle`] of [`Progress::Done`]
+/// contains the decoded pixels; so the renderer does not need to decode them
+/// again.
+pub fn decode(handle: impl Into<Handle>) -> Task<Progress> {
+    let handle = handle.into();
+    let (sender, receiver) = mpsc::unbounded();
+
+    let work = move || {
+        let bytes = match &handle {
+            Handle::Path(_, path) => std::fs::read(path).ok().map(Into::into),
+            Handle::Bytes(_, bytes) => Some(bytes.clone()),
+            _ => None,
+        };
+
+        if let Some(bytes) = bytes {
+            if bytes.starts_with(PNG_SIGNATURE) {
+                let result = decode_png(&bytes, |progress| {
+                    let _ = sender.unbounded_send(progress);
+                });
+
+                match result {
+                    Ok(()) => return,
+                    Err(error) => {
+                        log::debug!(
+                            "Progressive decoding failed; \
+                            falling back to a full decode: {error}"
+                        );
+                    }
+                }
+            }
+        }
+
+        let progress = match graphics::image::load(&handle) {
+            Ok(image) => Progress::Done(Handle::from_rgba(
+                image.width(),
+                image.height(),
+                image.into_raw(),
+            )),
+            Err(error) => Progress::Failed(Error(Arc::new(error))),
+        };
+
+        let _ = sender.unbounded_send(progress);
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = std::thread::spawn(work);
+
+    #[cfg(target_arch = "wasm32")]
+    work();
+
+    Task::stream(receiver)
+}
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// The origin and spacing of the pixels of each Adam7 pass.
+const ADAM7_PASSES: [(u32, u32, u32, u32); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+/// The size of the blocks of pixels known after each Adam7 pass.
+const ADAM7_BLOCKS: [(u32, u32); 7] =
+    [(8, 8), (4, 8), (4, 4), (2, 4), (2, 2), (1, 2), (1, 1)];
+
+/// Decodes the given PNG row by row; producing a [`Progress::Preview`] as
+/// parts of it become available, and a [`Progress::Done`] at the end.
+fn decode_png(
+    bytes: &[u8],
+    mut on_progress: impl FnMut(Progress),
+) -> Result<(), png::DecodingError> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info()?;
+
+    let (width, height) = reader.info().size();
+    let (color_type, _) = reader.output_color_type();
+    let samples = color_type.samples();
+    let stride = width as usize * samples;
+
+    let mut buffer = vec![0; stride * height as usize];
+
+    if reader.info().interlaced {
+        let mut passes = ADAM7_PASSES
+            .iter()
+            .zip(ADAM7_BLOCKS)
+            .map(|(&(x, y, _, step_y), block)| {
+                let lines = if width > x && height > y {
+                    (height - y).div_ceil(step_y)
+                } else {
+                    0
+                };
+
+                (lines, block)
+            })
+            .filter(|(lines, _)| *lines > 0)
+            .peekable();
+
+        let mut remaining = passes.peek().map_or(0, |(lines, _)| *lines);
+
+        while let Some(row) = reader.next_interlaced_row()? {
+            let png::InterlaceInfo::Adam7(info) = row.interlace() else {
+                continue;
+            };
+
+            png::expand_interlaced_row(
+                &mut buffer,
+                stride,
+                row.data(),
+                info,
+                (samples * 8) as u8,
+            );
+
+            remaining = remaining.saturating_sub(1);
+
+            if remaining == 0 {
+                let Some((_, block)) = passes.next() else {
+                    break;
+                };
+
+                match passes.peek() {
+                    Some((lines, _)) => {
+                        remaining = *lines;
+
+                        on_progress(Progress::Preview(Handle::from_rgba(
+                            width,
+                            height,
+                            to_rgba(
+                                &fill_blocks(&buffer, width, samples, block),
+                                color_type,
+                            ),
+                        )));
+                    }
+                    None => break,
+                }
+            }
+        }
+    } else {
+        let step = height.div_ceil(PARTIAL_STEPS).max(1);
+        let mut rows = 0;
+
+        while let Some(row) = reader.next_row()? {
+            let start = rows as usize * stride;
+            buffer[start..start + stride].copy_from_slice(row.data());
+
+            rows += 1;
+
+            if rows % step == 0 && rows < height {
+                on_progress(Progress::Preview(Handle::from_rgba(
+                    width,
+                    height,
+                    partial(
+                        &buffer[..rows as usize * stride],
+                        color_type,
+                        width,
+                        height,
+                    ),
+                )));
+            }
+        }
+    }
+
+    on_progress(Progress::Done(Handle::from_rgba(
+        width,
+        height,
+        to_rgba(&buffer, color_type),
+    )));
+
+    Ok(())
+}
+
+/// Fills every block of the given size with its top-left pixel; which is the
+/// one known after the matching Adam7 pass.
+fn fill_blocks(
+    buffer: &[u8],
+    width: u32,
+    samples: usize,
+    (block_width, block_height): (u32, u32),
+) -> Vec<u8> {
+    let stride = width as usize * samples;
+    let mut filled = buffer.to_vec();
+
+    for (y, row) in filled.chunks_exact_mut(stride).enumerate() {
+        let source_y = y - y % block_height as usize;
+        let source = &buffer[source_y * stride..(source_y + 1) * stride];
+
+        for (x, pixel) in row.chunks_exact_mut(samples).enumerate() {
+            let source_x = x - x % block_width as usize;
+
+            pixel.copy_from_slice(
+                &source[source_x * samples..(source_x + 1) * samples],
+            );
+        }
+    }
+
+    filled
+}
+
+/// Converts the given decoded rows to RGBA; leaving the rest of the image
+/// transparent.
+fn partial(
+    rows: &[u8],
+    color_type: png::ColorType,
+    width: u32,
+    height: u32,
+) -> Vec<u8> {
+    let mut partial = to_rgba(rows, color_type);
+    partial.resize(width as usize * height as usize * 4, 0);
+
+    partial
+}
+
+/// Converts the 8-bit pixels of the given color type to RGBA.
+fn to_rgba(pixels: &[u8], color_type: png::ColorType) -> Vec<u8> {
+    match color_type {
+        png::ColorType::Rgba => pixels.to_vec(),
+        png::ColorType::Rgb => pixels
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => pixels
+            .chunks_exact(2)
+            .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+            .collect(),
+        png::ColorType::Grayscale | png::ColorType::Indexed => {
+            pixels.iter().flat_map(|&g| [g, g, g, 255]).collect()
+        }
+    }
+}
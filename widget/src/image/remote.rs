@@ -0,0 +1,608 @@
+//! Display images downloaded from the network.
+//!
+//! Downloads are [`Task`]s started with [`load`]; so they run in the
+//! executor of the application, like any other [`Task`]. A [`Remote`] image
+//! displays the image of its URL once it has been loaded; showing a
+//! placeholder in the meantime.
+//!
+//! Downloaded images are cached in memory, up to a [`cache_budget`]; and on
+//! disk if a [`cache_directory`] is set.
+//!
+//! Downloads need the `tokio` executor, which is enabled by the
+//! `remote-image` feature of `iced`.
+use crate::Space;
+use crate::core::image::{self, Bytes, FilterMethod, Handle};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Layout, Length, Rectangle, Rotation,
+    Shell, Size, Widget,
+};
+use crate::runtime::Task;
+use crate::runtime::futures::blocking;
+
+use rustc_hash::{FxHashMap, FxHasher};
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
+
+/// The default amount of bytes of downloaded images kept in memory.
+const DEFAULT_BUDGET: usize = 64 * 1024 * 1024;
+
+static CACHE: LazyLock<Mutex<Cache>> =
+    LazyLock::new(|| Mutex::new(Cache::new(DEFAULT_BUDGET)));
+
+/// The download status of a remote image.
+#[derive(Debug, Clone)]
+pub enum Status {
+    /// The image is being downloaded.
+    Loading,
+    /// The image was downloaded successfully.
+    Loaded(Handle),
+    /// The image could not be downloaded.
+    Failed(Error),
+}
+
+/// An error produced when downloading a remote image.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The request failed.
+    #[error("the image could not be downloaded: {0}")]
+    Request(Arc<reqwest::Error>),
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Request(Arc::new(error))
+    }
+}
+
+/// Downloads the image at the given URL and caches it; producing its
+/// [`Handle`] when done.
+///
+/// The cached [`Handle`] is produced right away if the image was already
+/// downloaded. Any [`Remote`] image of the URL will display it once the
+/// application handles the output of the [`Task`].
+///
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::runtime::Task; }
+/// use iced::Task;
+/// use iced::widget::image::{self, remote};
+///
+/// enum Message {
+///     ImageLoaded(Result<image::Handle, remote::Error>),
+/// }
+///
+/// fn boot() -> Task<Message> {
+///     remote::load("https://iced.rs/logo.png").map(Message::ImageLoaded)
+/// }
+/// ```
+pub fn load(url: impl Into<String>) -> Task<Result<Handle, Error>> {
+    let url = url.into();
+
+    let directory = {
+        let mut cache = cache();
+
+        if let Some(Status::Loaded(handle)) = cache.get(&url) {
+            return Task::done(Ok(handle));
+        }
+
+        cache.insert(url.clone(), Status::Loading, 0);
+        cache.directory.clone()
+    };
+
+    Task::future(async move {
+        let path = directory.map(|directory| {
+            let mut hasher = FxHasher::default();
+            url.hash(&mut hasher);
+
+            directory.join(format!("{:016x}", hasher.finish()))
+        });
+
+        match download(&url, path).await {
+            Ok(bytes) => {
+                let size = bytes.len();
+                let handle = Handle::from_bytes(bytes);
+
+                cache().insert(url, Status::Loaded(handle.clone()), size);
+
+                Ok(handle)
+            }
+            Err(error) => {
+                log::warn!("Failed to download image {url}: {error}");
+
+                cache().insert(url, Status::Failed(error.clone()), 0);
+
+                Err(error)
+            }
+        }
+    })
+}
+
+/// Returns the download [`Status`] of the image at the given URL, if it is
+/// in the memory cache.
+pub fn status(url: &str) -> Option<Status> {
+    cache().get(url)
+}
+
+/// Removes the image at the given URL from the memory cache.
+///
+/// Failed downloads can be retried by evicting them and calling [`load`]
+/// again.
+pub fn evict(url: &str) {
+    cache().remove(url);
+}
+
+/// Sets the directory where downloaded images are cached on disk.
+///
+/// By default, images are only cached in memory.
+pub fn cache_directory(directory: impl Into<PathBuf>) {
+    cache().directory = Some(directory.into());
+}
+
+/// Sets the maximum amount of bytes of downloaded images kept in memory.
+///
+/// The least recently displayed images are evicted first when the budget
+/// is exceeded; they are downloaded again—or read from the
+/// [`cache_directory`]—the next time they are loaded.
+///
+/// By default, 64 MiB of images are kept in memory.
+pub fn cache_budget(bytes: usize) {
+    let mut cache = cache();
+
+    cache.budget = bytes;
+    cache.trim();
+}
+
+fn cache() -> MutexGuard<'static, Cache> {
+    CACHE.lock().expect("Lock remote image cache")
+}
+
+async fn download(url: &str, path: Option<PathBuf>) -> Result<Bytes, Error> {
+    if let Some(path) = path.clone() {
+        if let Some(Ok(bytes)) =
+            blocking::spawn(move || std::fs::read(path)).await
+        {
+            return Ok(Bytes::from(bytes));
+        }
+    }
+
+    let bytes = reqwest::get(url).await?.error_for_status()?.bytes().await?;
+
+    if let Some(path) = path {
+        let contents = bytes.clone();
+
+        let result = blocking::spawn(move || {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::write(path, contents)
+        })
+        .await;
+
+        if let Some(Err(error)) = result {
+            log::warn!("Failed to cache image {url} on disk: {error}");
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// A memory cache of remote images, bounded by a byte budget.
+#[derive(Debug)]
+struct Cache {
+    entries: FxHashMap<String, Entry>,
+    directory: Option<PathBuf>,
+    budget: usize,
+    size: usize,
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    status: Status,
+    size: usize,
+    last_used: u64,
+}
+
+impl Cache {
+    fn new(budget: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            directory: None,
+            budget,
+            size: 0,
+            clock: 0,
+        }
+    }
+
+    fn get(&mut self, url: &str) -> Option<Status> {
+        let entry = self.entries.get_mut(url)?;
+
+        self.clock += 1;
+        entry.last_used = self.clock;
+
+        Some(entry.status.clone())
+    }
+
+    fn insert(&mut self, url: String, status: Status, size: usize) {
+        self.remove(&url);
+
+        self.clock += 1;
+        self.size += size;
+
+        let _ = self.entries.insert(
+            url,
+            Entry {
+                status,
+                size,
+                last_used: self.clock,
+            },
+        );
+
+        self.trim();
+    }
+
+    fn remove(&mut self, url: &str) {
+        if let Some(entry) = self.entries.remove(url) {
+            self.size -= entry.size;
+        }
+    }
+
+    /// Evicts the least recently used images until the cache fits in its
+    /// budget; except the most recent one, so an image bigger than the
+    /// budget can still be displayed.
+    fn trim(&mut self) {
+        while self.size > self.budget {
+            let Some(url) = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| {
+                    entry.size > 0 && entry.last_used < self.clock
+                })
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(url, _)| url.clone())
+            else {
+                break;
+            };
+
+            self.remove(&url);
+        }
+    }
+}
+
+/// A frame that displays an image downloaded from a URL while keeping
+/// aspect ratio.
+///
+/// The image must be downloaded with [`load`]. A placeholder is shown until
+/// the download finishes, or if it fails.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{image, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     image::remote("https://iced.rs/logo.png")
+///         .placeholder(text("Loading..."))
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Remote<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    url: String,
+    placeholder: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    opacity: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Remote<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+{
+    /// Creates a new [`Remote`] image that displays the given URL once
+    /// loaded.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            placeholder: Space::new(Length::Shrink, Length::Shrink).into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the placeholder shown while the [`Remote`] image is downloading,
+    /// or if the download failed.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the [`Remote`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Remote`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Remote`] image.
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Remote`] image.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the opacity of the [`Remote`] image.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    url: String,
+    handle: Option<Handle>,
+}
+
+impl State {
+    /// Synchronizes the [`State`] with the cache.
+    ///
+    /// The current image is kept if it was evicted from the cache while
+    /// being displayed.
+    fn refresh(&mut self, url: &str) {
+        if self.url != url {
+            url.clone_into(&mut self.url);
+            self.handle = None;
+        }
+
+        match status(url) {
+            Some(Status::Loaded(handle)) => {
+                self.handle = Some(handle);
+            }
+            Some(Status::Loading | Status::Failed(_)) => {
+                self.handle = None;
+            }
+            None => {}
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Remote<'_, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.placeholder)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.placeholder));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.refresh(&self.url);
+
+        if let Some(handle) = &state.handle {
+            return super::layout(
+                renderer,
+                limits,
+                handle,
+                self.width,
+                self.height,
+                self.content_fit,
+                Rotation::default(),
+                false,
+            );
+        }
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let placeholder = self.placeholder.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits,
+        );
+
+        let size = limits.resolve(self.width, self.height, placeholder.size());
+
+        layout::Node::with_children(size, vec![placeholder])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.handle.is_some() {
+            return;
+        }
+
+        if let Some(layout) = layout.children().next() {
+            self.placeholder.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match layout.children().next() {
+            Some(layout) if state.handle.is_none() => {
+                self.placeholder.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(handle) = &state.handle {
+            super::draw(
+                renderer,
+                layout,
+                viewport,
+                handle,
+                self.content_fit,
+                self.filter_method,
+                Rotation::default(),
+                self.opacity,
+                1.0,
+            );
+        } else if let Some(layout) = layout.children().next() {
+            self.placeholder.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Remote<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+{
+    fn from(remote: Remote<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(remote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded() -> Status {
+        Status::Loaded(Handle::from_bytes(Bytes::new()))
+    }
+
+    #[test]
+    fn least_recently_used_images_are_evicted_first() {
+        let mut cache = Cache::new(100);
+
+        cache.insert("a".to_owned(), loaded(), 40);
+        cache.insert("b".to_owned(), loaded(), 40);
+        let _ = cache.get("a");
+        cache.insert("c".to_owned(), loaded(), 40);
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+        assert_eq!(cache.size, 80);
+    }
+
+    #[test]
+    fn images_bigger_than_the_budget_are_kept_until_replaced() {
+        let mut cache = Cache::new(100);
+
+        cache.insert("a".to_owned(), loaded(), 40);
+        cache.insert("b".to_owned(), loaded(), 200);
+
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+
+        cache.insert("c".to_owned(), Status::Loading, 0);
+        cache.insert("c".to_owned(), loaded(), 40);
+
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+        assert_eq!(cache.size, 40);
+    }
+}
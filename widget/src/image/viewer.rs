@@ -352,6 +352,7 @@ where
                         rotation: Radians(0.0),
                         opacity: 1.0,
                         snap: true,
+                        filter: image::Filter::NONE,
                     },
                     drawing_bounds,
                 );
@@ -1,13 +1,16 @@
 //! Keyed columns distribute content vertically while keeping continuity.
+use crate::core::animation;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Alignment, Clipboard, Element, Event, Layout, Length, Padding, Pixels,
-    Rectangle, Shell, Size, Vector, Widget,
+    Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
 /// A container that distributes its contents vertically while keeping continuity.
@@ -45,6 +48,7 @@ pub struct Column<
     height: Length,
     max_width: f32,
     align_items: Alignment,
+    transition: Option<Duration>,
     keys: Vec<Key>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
@@ -78,6 +82,7 @@ where
             height: Length::Shrink,
             max_width: f32::INFINITY,
             align_items: Alignment::Start,
+            transition: None,
             keys,
             children,
         }
@@ -142,6 +147,21 @@ where
         self
     }
 
+    /// Animates the children of the [`Column`] from their old position to
+    /// their new one whenever the layout changes, taking the given duration.
+    ///
+    /// Children are tracked by their key; so reordering, inserting, or
+    /// resizing elements will smoothly slide the rest into place. Only
+    /// positions are animated—new children appear immediately.
+    ///
+    /// Nothing is animated when motion is [reduced].
+    ///
+    /// [reduced]: crate::core::animation::reduce_motion
+    pub fn animate_layout(mut self, duration: impl Into<Duration>) -> Self {
+        self.transition = Some(duration.into());
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -200,6 +220,41 @@ where
     Key: Copy + PartialEq,
 {
     keys: Vec<Key>,
+    placements: Vec<Placement<Key>>,
+    now: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Placement<Key> {
+    key: Key,
+    from: Point,
+    target: Point,
+    start: Option<Instant>,
+}
+
+impl<Key> Placement<Key> {
+    fn is_settled(&self) -> bool {
+        self.from == self.target
+    }
+
+    fn position(&self, now: Option<Instant>, duration: Duration) -> Point {
+        if self.is_settled() {
+            return self.target;
+        }
+
+        let (Some(start), Some(now)) = (self.start, now) else {
+            return self.from;
+        };
+
+        let progress = (now.saturating_duration_since(start).as_secs_f32()
+            / duration.as_secs_f32().max(f32::EPSILON))
+        .min(1.0);
+
+        // Ease in-out
+        let t = progress * progress * (3.0 - 2.0 * progress);
+
+        self.from + (self.target - self.from) * t
+    }
 }
 
 impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -215,6 +270,8 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             keys: self.keys.clone(),
+            placements: Vec::new(),
+            now: None,
         })
     }
 
@@ -263,7 +320,7 @@ where
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
+        let node = layout::flex::resolve(
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
@@ -274,7 +331,54 @@ where
             self.align_items,
             &self.children,
             &mut tree.children,
-        )
+        );
+
+        let Some(duration) =
+            self.transition.filter(|_| !animation::is_motion_reduced())
+        else {
+            return node;
+        };
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let mut placements = Vec::with_capacity(self.keys.len());
+
+        let children = node
+            .children()
+            .iter()
+            .zip(&self.keys)
+            .map(|(child, key)| {
+                let target = child.bounds().position();
+
+                let placement = match state
+                    .placements
+                    .iter()
+                    .find(|placement| placement.key == *key)
+                {
+                    Some(previous) if previous.target != target => Placement {
+                        key: *key,
+                        from: previous.position(state.now, duration),
+                        target,
+                        start: None,
+                    },
+                    Some(previous) => *previous,
+                    None => Placement {
+                        key: *key,
+                        from: target,
+                        target,
+                        start: None,
+                    },
+                };
+
+                let position = placement.position(state.now, duration);
+                placements.push(placement);
+
+                child.clone().move_to(position)
+            })
+            .collect();
+
+        state.placements = placements;
+
+        layout::Node::with_children(node.size(), children)
     }
 
     fn operate(
@@ -308,6 +412,43 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        if let (
+            Some(duration),
+            Event::Window(window::Event::RedrawRequested(now)),
+        ) = (self.transition, event)
+        {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            state.now = Some(*now);
+
+            let mut is_moving = false;
+            let mut is_animating = false;
+
+            for placement in &mut state.placements {
+                if placement.is_settled() {
+                    continue;
+                }
+
+                let start = *placement.start.get_or_insert(*now);
+
+                if now.saturating_duration_since(start) >= duration {
+                    placement.from = placement.target;
+                    placement.start = None;
+                } else {
+                    is_animating = true;
+                }
+
+                is_moving = true;
+            }
+
+            if is_moving {
+                shell.invalidate_layout();
+            }
+
+            if is_animating {
+                shell.request_redraw();
+            }
+        }
+
         for ((child, state), layout) in self
             .children
             .iter_mut()
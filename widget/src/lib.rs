@@ -10,6 +10,9 @@ pub use iced_runtime::core;
 
 mod action;
 mod column;
+mod drag_area;
+mod drop_zone;
+mod focus_scope;
 mod mouse_area;
 mod pin;
 mod space;
@@ -17,6 +20,7 @@ mod stack;
 mod themer;
 
 pub mod button;
+pub mod caption;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
@@ -38,6 +42,7 @@ pub mod text_editor;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod transition;
 pub mod vertical_slider;
 
 mod helpers;
@@ -53,6 +58,8 @@ pub use crate::lazy::helpers::*;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use caption::Caption;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
@@ -61,8 +68,14 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use drag_area::DragArea;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
+pub use focus_scope::FocusScope;
+#[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
 pub use mouse_area::MouseArea;
@@ -103,6 +116,8 @@ pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use transition::Transition;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
 
 #[cfg(feature = "wgpu")]
@@ -126,6 +141,13 @@ pub mod image;
 #[doc(no_inline)]
 pub use image::Image;
 
+#[cfg(feature = "lottie")]
+pub mod lottie;
+
+#[cfg(feature = "lottie")]
+#[doc(no_inline)]
+pub use lottie::Lottie;
+
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
@@ -0,0 +1,510 @@
+//! Play Lottie and dotLottie vector animations.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::lottie;
+//!
+//! struct State {
+//!    spinner: lottie::Animation,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     lottie(&state.spinner).speed(1.5).into()
+//! }
+//! ```
+use crate::core::image::{self, Handle};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Layout, Length, Point, Rectangle,
+    Shell, Size, Widget,
+};
+use crate::image::FilterMethod;
+
+use rustc_hash::FxHashMap;
+use std::io::{self, Read};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// A decoded Lottie animation.
+#[derive(Clone)]
+pub struct Animation {
+    raw: Arc<Mutex<rlottie::Animation>>,
+    size: Size<u32>,
+    frames: usize,
+    frame_rate: f64,
+}
+
+impl Animation {
+    /// Parses an [`Animation`] from Lottie JSON.
+    pub fn from_json(json: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let json = json.as_ref();
+        let key = format!("{:x}", fingerprint(json));
+
+        let raw = rlottie::Animation::from_data(json.to_vec(), key, "")
+            .ok_or(Error::Invalid)?;
+
+        Ok(Self::new(raw))
+    }
+
+    /// Parses the first [`Animation`] of a dotLottie archive.
+    pub fn from_dotlottie(bytes: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let mut archive = zip::ZipArchive::new(io::Cursor::new(bytes.as_ref()))
+            .map_err(|error| Error::Archive(Arc::new(error)))?;
+
+        let name = archive
+            .file_names()
+            .filter(|name| {
+                name.starts_with("animations/") && name.ends_with(".json")
+            })
+            .min()
+            .map(str::to_owned)
+            .ok_or(Error::Invalid)?;
+
+        let mut json = Vec::new();
+
+        let _ = archive
+            .by_name(&name)
+            .map_err(|error| Error::Archive(Arc::new(error)))?
+            .read_to_end(&mut json)
+            .map_err(|error| Error::Io(Arc::new(error)))?;
+
+        Self::from_json(json)
+    }
+
+    /// Reads and parses the [`Animation`] at the given path.
+    ///
+    /// Files with a `.lottie` extension are parsed as dotLottie archives,
+    /// while any other file is parsed as Lottie JSON.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let bytes =
+            std::fs::read(path).map_err(|error| Error::Io(Arc::new(error)))?;
+
+        if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("lottie"))
+        {
+            Self::from_dotlottie(bytes)
+        } else {
+            Self::from_json(bytes)
+        }
+    }
+
+    /// Returns the intrinsic size of the [`Animation`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the amount of frames of the [`Animation`].
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Returns the frame rate of the [`Animation`], in frames per second.
+    pub fn frame_rate(&self) -> f64 {
+        self.frame_rate
+    }
+
+    /// Returns the total [`Duration`] of the [`Animation`].
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.frames as f64 / self.frame_rate)
+    }
+
+    fn new(raw: rlottie::Animation) -> Self {
+        let size = raw.size();
+
+        Self {
+            size: Size::new(size.width as u32, size.height as u32),
+            frames: raw.totalframe(),
+            frame_rate: raw.framerate().max(1.0),
+            raw: Arc::new(Mutex::new(raw)),
+        }
+    }
+
+    fn render(&self, frame: usize, size: Size<u32>) -> Handle {
+        let mut surface = rlottie::Surface::new(rlottie::Size::new(
+            size.width as usize,
+            size.height as usize,
+        ));
+
+        if let Ok(mut raw) = self.raw.lock() {
+            raw.render(frame, &mut surface);
+        }
+
+        // rlottie produces premultiplied BGRA pixels
+        let pixels: Vec<u8> = surface
+            .data()
+            .iter()
+            .flat_map(|pixel| {
+                let unpremultiply = |channel: u8| {
+                    if pixel.a == 0 {
+                        0
+                    } else {
+                        (u16::from(channel) * 255 / u16::from(pixel.a)).min(255)
+                            as u8
+                    }
+                };
+
+                [
+                    unpremultiply(pixel.r),
+                    unpremultiply(pixel.g),
+                    unpremultiply(pixel.b),
+                    pixel.a,
+                ]
+            })
+            .collect();
+
+        Handle::from_rgba(size.width, size.height, pixels)
+    }
+}
+
+impl std::fmt::Debug for Animation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Animation")
+            .field("size", &self.size)
+            .field("frames", &self.frames)
+            .field("frame_rate", &self.frame_rate)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error produced when loading a Lottie [`Animation`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read.
+    #[error("the animation could not be read: {0}")]
+    Io(Arc<io::Error>),
+    /// The dotLottie archive is invalid.
+    #[error("invalid dotLottie archive: {0}")]
+    Archive(Arc<zip::result::ZipError>),
+    /// The animation is not valid Lottie.
+    #[error("invalid Lottie animation")]
+    Invalid,
+}
+
+/// A widget that plays a Lottie [`Animation`].
+#[allow(missing_debug_implementations)]
+pub struct Lottie<'a> {
+    animation: &'a Animation,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    speed: f32,
+    segment: Option<Range<usize>>,
+    is_playing: bool,
+    is_looping: bool,
+}
+
+impl<'a> Lottie<'a> {
+    /// Creates a new [`Lottie`] widget that plays the given [`Animation`].
+    pub fn new(animation: &'a Animation) -> Self {
+        Self {
+            animation,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            speed: 1.0,
+            segment: None,
+            is_playing: true,
+            is_looping: true,
+        }
+    }
+
+    /// Sets the width of the [`Lottie`] widget.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Lottie`] widget.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Lottie`] widget.
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the playback speed of the [`Lottie`] widget.
+    ///
+    /// A speed of `1.0` plays the [`Animation`] at its own frame rate.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Restricts the playback of the [`Lottie`] widget to the given range
+    /// of frames.
+    pub fn segment(mut self, frames: Range<usize>) -> Self {
+        self.segment = Some(frames);
+        self
+    }
+
+    /// Sets whether the [`Lottie`] widget is playing or paused.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Lottie`] widget loops or stops at the last frame.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    fn fitted_size(&self, bounds: Size) -> Size {
+        self.content_fit.fit(
+            Size::new(
+                self.animation.size.width as f32,
+                self.animation.size.height as f32,
+            ),
+            bounds,
+        )
+    }
+
+    fn segment_range(&self) -> Range<usize> {
+        let frames = self.animation.frames.max(1);
+
+        match &self.segment {
+            Some(segment) => {
+                let start = segment.start.min(frames - 1);
+                let end = segment.end.clamp(start + 1, frames);
+
+                start..end
+            }
+            None => 0..frames,
+        }
+    }
+}
+
+/// The maximum amount of memory, in bytes, that the rasterized frames of
+/// a [`Lottie`] widget can take.
+const MAX_CACHE_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Default)]
+struct State {
+    playhead: f64,
+    last_tick: Option<Instant>,
+    frame: Option<usize>,
+    frames: Frames,
+}
+
+/// The rasterized frames of an [`Animation`] at some physical size.
+///
+/// Every frame keeps its own [`Handle`]; so it is only uploaded once and
+/// reused in every loop of the [`Animation`].
+#[derive(Debug, Default)]
+struct Frames {
+    size: Size<u32>,
+    handles: FxHashMap<usize, Handle>,
+    uncached: Option<(usize, Handle)>,
+}
+
+impl Frames {
+    fn get(&self, frame: usize) -> Option<&Handle> {
+        match &self.uncached {
+            Some((uncached, handle)) if *uncached == frame => Some(handle),
+            _ => self.handles.get(&frame),
+        }
+    }
+
+    fn render(&mut self, animation: &Animation, frame: usize, size: Size<u32>) {
+        if self.size != size {
+            self.size = size;
+            self.handles.clear();
+            self.uncached = None;
+        }
+
+        if self.get(frame).is_some() {
+            return;
+        }
+
+        let handle = animation.render(frame, size);
+        let frame_size = size.width as usize * size.height as usize * 4;
+
+        if (self.handles.len() + 1) * frame_size <= MAX_CACHE_SIZE {
+            let _ = self.handles.insert(frame, handle);
+        } else {
+            self.uncached = Some((frame, handle));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Lottie<'_>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let intrinsic = Size::new(
+            self.animation.size.width as f32,
+            self.animation.size.height as f32,
+        );
+
+        let bounds = limits.resolve(self.width, self.height, intrinsic);
+        let fitted = self.content_fit.fit(intrinsic, bounds);
+
+        layout::Node::new(Size {
+            width: match self.width {
+                Length::Shrink => bounds.width.min(fitted.width),
+                _ => bounds.width,
+            },
+            height: match self.height {
+                Length::Shrink => bounds.height.min(fitted.height),
+                _ => bounds.height,
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let segment = self.segment_range();
+        let length = (segment.end - segment.start) as f64;
+        let frame_rate = self.animation.frame_rate * f64::from(self.speed);
+
+        if self.is_playing && frame_rate > 0.0 {
+            if let Some(last_tick) = state.last_tick {
+                let elapsed = now.saturating_duration_since(last_tick);
+                state.playhead += elapsed.as_secs_f64() * frame_rate;
+            }
+
+            if self.is_looping {
+                state.playhead %= length;
+            } else {
+                state.playhead = state.playhead.min(length - 1.0);
+            }
+
+            state.last_tick = Some(*now);
+
+            if self.is_looping || state.playhead < length - 1.0 {
+                shell.request_redraw_at(
+                    *now + Duration::from_secs_f64(1.0 / frame_rate),
+                );
+            }
+        } else {
+            state.last_tick = None;
+        }
+
+        let frame = segment.start + state.playhead as usize;
+        let fitted = self.fitted_size(layout.bounds().size());
+        let scale_factor = renderer.scale_factor().unwrap_or(1.0);
+
+        let size = Size::new(
+            ((fitted.width * scale_factor).ceil() as u32).max(1),
+            ((fitted.height * scale_factor).ceil() as u32).max(1),
+        );
+
+        state.frames.render(self.animation, frame, size);
+        state.frame = Some(frame);
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let Some(handle) =
+            state.frame.and_then(|frame| state.frames.get(frame))
+        else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        let fitted = self.fitted_size(bounds.size());
+
+        let position = match self.content_fit {
+            ContentFit::None => bounds.position(),
+            _ => Point::new(
+                bounds.center_x() - fitted.width / 2.0,
+                bounds.center_y() - fitted.height / 2.0,
+            ),
+        };
+
+        let image = image::Image::new(handle.clone())
+            .filter_method(FilterMethod::Linear);
+        let drawing_bounds = Rectangle::new(position, fitted);
+
+        if fitted.width > bounds.width || fitted.height > bounds.height {
+            if let Some(bounds) = bounds.intersection(viewport) {
+                renderer.with_layer(bounds, |renderer| {
+                    renderer.draw_image(image, drawing_bounds);
+                });
+            }
+        } else {
+            renderer.draw_image(image, drawing_bounds);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Lottie<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+{
+    fn from(lottie: Lottie<'a>) -> Self {
+        Element::new(lottie)
+    }
+}
+
+fn fingerprint(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
@@ -50,6 +50,8 @@ use crate::core::theme;
 use crate::core::{
     self, Color, Element, Length, Padding, Pixels, Theme, color,
 };
+use crate::runtime::Task;
+use crate::runtime::clipboard;
 use crate::{column, container, rich_text, row, scrollable, span, text};
 
 use std::borrow::BorrowMut;
@@ -357,6 +359,30 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
         .map(|(item, _source, _broken_links)| item)
 }
 
+/// Renders the given Markdown as HTML.
+///
+/// See [`copy`] to copy Markdown to the clipboard with its formatting.
+pub fn to_html(markdown: &str) -> String {
+    let parser = pulldown_cmark::Parser::new_ext(
+        markdown,
+        pulldown_cmark::Options::ENABLE_TABLES
+            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+    );
+
+    let mut html = String::with_capacity(markdown.len() * 3 / 2);
+    pulldown_cmark::html::push_html(&mut html, parser);
+
+    html
+}
+
+/// Copies the given Markdown to the clipboard, preserving its formatting.
+///
+/// The Markdown is written as HTML, with its source as the plain text
+/// alternative for applications that do not support HTML.
+pub fn copy<T>(markdown: &str) -> Task<T> {
+    clipboard::write_html(to_html(markdown), markdown.to_owned())
+}
+
 #[derive(Debug, Default)]
 struct State {
     leftover: String,
@@ -540,11 +540,13 @@ where
             };
 
             if is_selected {
+                let [_, right, _, left] = style.border.widths();
+
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + style.border.width,
-                            width: bounds.width - style.border.width * 2.0,
+                            x: bounds.x + left,
+                            width: bounds.width - left - right,
                             ..bounds
                         },
                         border: border::rounded(style.border.radius),
@@ -650,6 +652,7 @@ pub fn default(theme: &Theme) -> Style {
             width: 1.0,
             radius: 0.0.into(),
             color: palette.background.strong.color,
+            ..Border::default()
         },
         text_color: palette.background.weak.text,
         selected_text_color: palette.primary.strong.text,
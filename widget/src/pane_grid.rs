@@ -12,6 +12,7 @@
 //! * Tracking of the last active pane
 //! * Mouse-based resizing
 //! * Drag and drop to reorganize panes
+//! * Tearing panes out of the window
 //! * Hotkey support
 //! * Configurable modifier keys
 //! * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -110,6 +111,7 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Tearing panes out of the window
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -599,42 +601,48 @@ where
                             if cursor_position.distance(origin)
                                 > DRAG_DEADBAND_DISTANCE
                             {
-                                let event = if let Some(edge) =
-                                    in_edge(layout, cursor_position)
-                                {
-                                    DragEvent::Dropped {
-                                        pane,
-                                        target: Target::Edge(edge),
-                                    }
-                                } else {
-                                    let dropped_region = self
-                                        .panes
-                                        .iter()
-                                        .copied()
-                                        .zip(&self.contents)
-                                        .zip(layout.children())
-                                        .find_map(|(target, layout)| {
-                                            layout_region(
-                                                layout,
-                                                cursor_position,
-                                            )
-                                            .map(|region| (target, region))
-                                        });
-
-                                    match dropped_region {
-                                        Some(((target, _), region))
-                                            if pane != target =>
-                                        {
-                                            DragEvent::Dropped {
-                                                pane,
-                                                target: Target::Pane(
-                                                    target, region,
-                                                ),
+                                let event =
+                                    if !viewport.contains(cursor_position) {
+                                        DragEvent::TornOut {
+                                            pane,
+                                            position: cursor_position,
+                                        }
+                                    } else if let Some(edge) =
+                                        in_edge(layout, cursor_position)
+                                    {
+                                        DragEvent::Dropped {
+                                            pane,
+                                            target: Target::Edge(edge),
+                                        }
+                                    } else {
+                                        let dropped_region = self
+                                            .panes
+                                            .iter()
+                                            .copied()
+                                            .zip(&self.contents)
+                                            .zip(layout.children())
+                                            .find_map(|(target, layout)| {
+                                                layout_region(
+                                                    layout,
+                                                    cursor_position,
+                                                )
+                                                .map(|region| (target, region))
+                                            });
+
+                                        match dropped_region {
+                                            Some(((target, _), region))
+                                                if pane != target =>
+                                            {
+                                                DragEvent::Dropped {
+                                                    pane,
+                                                    target: Target::Pane(
+                                                        target, region,
+                                                    ),
+                                                }
                                             }
+                                            _ => DragEvent::Canceled { pane },
                                         }
-                                        _ => DragEvent::Canceled { pane },
-                                    }
-                                };
+                                    };
 
                                 shell.publish(on_drag(event));
                             } else {
@@ -1204,6 +1212,24 @@ pub enum DragEvent {
         /// The picked [`Pane`].
         pane: Pane,
     },
+
+    /// A [`Pane`] was picked and then dropped outside of the window.
+    ///
+    /// This can be used to tear the [`Pane`] out into a new window; for
+    /// instance, by opening one at [`window::Position::RelativeTo`] the
+    /// given position.
+    ///
+    /// If the position lays on top of another window of the application,
+    /// `window::get_window_at` can be used to find it and merge the [`Pane`]
+    /// into it instead.
+    TornOut {
+        /// The picked [`Pane`].
+        pane: Pane,
+
+        /// The position where the [`Pane`] was dropped, relative to the
+        /// window.
+        position: Point,
+    },
 }
 
 /// The [`Target`] area a pane can be dropped on.
@@ -1344,6 +1370,7 @@ pub fn default(theme: &Theme) -> Style {
                 width: 2.0,
                 color: palette.primary.strong.color,
                 radius: 0.0.into(),
+                ..Border::default()
             },
         },
         hovered_split: Line {
@@ -68,6 +68,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
+use crate::core::theme::Styles;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -884,7 +885,19 @@ impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> StyleFn<'a, Self> {
-        Box::new(default)
+        Box::new(|theme: &Theme, status: Status| {
+            let style = default(theme, status);
+
+            match theme.extension::<Styles>() {
+                Some(styles) => Style {
+                    background: styles.pick_list.background(style.background),
+                    text_color: styles.pick_list.text_color(style.text_color),
+                    border: styles.pick_list.border(style.border),
+                    ..style
+                },
+                None => style,
+            }
+        })
     }
 
     fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
@@ -902,19 +915,17 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder_color: palette.background.strong.color,
         handle_color: palette.background.weak.text,
         border: Border {
-            radius: 2.0.into(),
+            radius: theme.tokens().radius.small.into(),
             width: 1.0,
             color: palette.background.strong.color,
+            ..Border::default()
         },
     };
 
     match status {
         Status::Active => active,
         Status::Hovered | Status::Opened { .. } => Style {
-            border: Border {
-                color: palette.primary.strong.color,
-                ..active.border
-            },
+            border: active.border.color(palette.primary.strong.color),
             ..active
         },
     }
@@ -218,10 +218,7 @@ where
             renderer.fill_quad(
                 renderer::Quad {
                     bounds,
-                    border: Border {
-                        color: Color::TRANSPARENT,
-                        ..style.border
-                    },
+                    border: style.border.color(Color::TRANSPARENT),
                     ..renderer::Quad::default()
                 },
                 style.bar,
@@ -56,6 +56,7 @@
 //!     column![a, b, c, all].into()
 //! }
 //! ```
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::layout;
@@ -325,9 +326,22 @@ where
         )
     }
 
+    fn accessibility(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        Some(
+            accessibility::Node::new(accessibility::Role::RadioButton)
+                .name(self.label.clone())
+                .toggled(self.is_selected)
+                .action(accessibility::Action::Click),
+        )
+    }
+
     fn update(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -344,6 +358,13 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Accessibility(request)
+                if request.is_for(state)
+                    && request.action == accessibility::Action::Click =>
+            {
+                shell.publish(self.on_click.clone());
+                shell.capture_event();
+            }
             _ => {}
         }
 
@@ -416,6 +437,7 @@ where
                         radius: (size / 2.0).into(),
                         width: style.border_width,
                         color: style.border_color,
+                        ..Border::default()
                     },
                     ..renderer::Quad::default()
                 },
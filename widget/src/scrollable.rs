@@ -1076,8 +1076,7 @@ where
                     if scrollbar.bounds.width > 0.0
                         && scrollbar.bounds.height > 0.0
                         && (style.background.is_some()
-                            || (style.border.color != Color::TRANSPARENT
-                                && style.border.width > 0.0))
+                            || style.border.is_visible())
                     {
                         renderer.fill_quad(
                             renderer::Quad {
@@ -1095,9 +1094,7 @@ where
                         if scroller.bounds.width > 0.0
                             && scroller.bounds.height > 0.0
                             && (style.scroller.color != Color::TRANSPARENT
-                                || (style.scroller.border.color
-                                    != Color::TRANSPARENT
-                                    && style.scroller.border.width > 0.0))
+                                || style.scroller.border.is_visible())
                         {
                             renderer.fill_quad(
                                 renderer::Quad {
@@ -2064,13 +2061,14 @@ impl Catalog for Theme {
 /// The default style of a [`Scrollable`].
 pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
+    let radius = theme.tokens().radius.small;
 
     let scrollbar = Rail {
         background: Some(palette.background.weak.color.into()),
-        border: border::rounded(2),
+        border: border::rounded(radius),
         scroller: Scroller {
             color: palette.background.strong.color,
-            border: border::rounded(2),
+            border: border::rounded(radius),
         },
     };
 
@@ -1,6 +1,9 @@
 //! A custom shader widget for wgpu applications.
+pub mod effect;
+
 mod program;
 
+pub use effect::Effect;
 pub use program::Program;
 
 use crate::core::event;
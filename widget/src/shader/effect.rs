@@ -0,0 +1,770 @@
+//! Draw fragment shader effects without writing a whole pipeline.
+use crate::core::mouse;
+use crate::core::time::Instant;
+use crate::core::window;
+use crate::core::{Event, Point, Rectangle};
+use crate::graphics::Viewport;
+use crate::renderer::wgpu::wgpu;
+use crate::shader::{self, Action, Program, Storage};
+
+#[cfg(feature = "image")]
+use crate::core::image;
+
+use rustc_hash::{FxHashMap, FxHasher};
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{Arc, Mutex};
+
+/// The WGSL code prepended to the fragment shader of every [`Effect`].
+///
+/// It declares the built-in uniforms and the vertex stage of the [`Effect`].
+pub const PRELUDE: &str = include_str!("effect.wgsl");
+
+/// A [`Program`] that draws a fragment shader over its bounds.
+///
+/// The shader must define a `fs_main` entry point that takes a `Fragment`
+/// and returns the color of the pixel. The following bindings are available:
+///
+/// - `iced.resolution`, `iced.mouse`, `iced.time`, `iced.is_pressed`, and
+///   `iced.scale_factor` in `@group(0) @binding(0)`.
+/// - A linear `iced_sampler` in `@group(0) @binding(1)`.
+/// - Each [`texture`] in `@group(1)`, in insertion order.
+/// - Each [`storage`] buffer in `@group(2)`, in insertion order.
+///
+/// Resolution and mouse coordinates are in physical pixels; and time is
+/// measured in seconds since the [`Effect`] was first drawn.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::shader::{Effect, Shader};
+///
+/// const PLASMA: &str = "
+/// @fragment
+/// fn fs_main(fragment: Fragment) -> @location(0) vec4<f32> {
+///     let uv = fragment.uv;
+///     let t = iced.time;
+///
+///     return vec4<f32>(0.5 + 0.5 * cos(t + uv.xyx + vec3(0.0, 2.0, 4.0)), 1.0);
+/// }
+/// ";
+///
+/// fn view<'a, Message: 'a>() -> Element<'a, Message> {
+///     Shader::new(Effect::new(PLASMA).animated(true))
+///         .width(300)
+///         .height(300)
+///         .into()
+/// }
+/// ```
+///
+/// [`texture`]: Self::texture
+/// [`storage`]: Self::storage
+#[derive(Debug, Clone)]
+pub struct Effect {
+    source: Arc<str>,
+    hash: u64,
+    #[cfg(feature = "image")]
+    textures: Vec<image::Handle>,
+    storage: Vec<Arc<[u8]>>,
+    is_animated: bool,
+}
+
+impl Effect {
+    /// Creates a new [`Effect`] with the given WGSL fragment shader.
+    pub fn new(fragment: impl Into<Cow<'static, str>>) -> Self {
+        let source: Arc<str> = format!("{PRELUDE}\n{}", fragment.into()).into();
+
+        let hash = {
+            let mut hasher = FxHasher::default();
+            source.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        Self {
+            source,
+            hash,
+            #[cfg(feature = "image")]
+            textures: Vec::new(),
+            storage: Vec::new(),
+            is_animated: false,
+        }
+    }
+
+    /// Binds the image of the given [`image::Handle`] as the next texture
+    /// of the [`Effect`].
+    #[cfg(feature = "image")]
+    pub fn texture(mut self, handle: impl Into<image::Handle>) -> Self {
+        self.textures.push(handle.into());
+        self
+    }
+
+    /// Binds the given bytes as the next read-only storage buffer of
+    /// the [`Effect`].
+    pub fn storage(mut self, data: impl Into<Arc<[u8]>>) -> Self {
+        self.storage.push(data.into());
+        self
+    }
+
+    /// Sets whether the [`Effect`] should be redrawn on every frame; useful
+    /// for effects that depend on time.
+    pub fn animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+}
+
+/// The internal state of an [`Effect`].
+#[derive(Debug)]
+pub struct State {
+    id: Id,
+    start: Option<Instant>,
+    now: Option<Instant>,
+    is_pressed: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: Id(NEXT.fetch_add(1, atomic::Ordering::Relaxed)),
+            start: None,
+            now: None,
+            is_pressed: false,
+        }
+    }
+}
+
+/// The instances of effects that are no longer in use, so their GPU
+/// resources can be freed.
+static DROPPED: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+#[derive(Debug)]
+struct Id(u64);
+
+impl Drop for Id {
+    fn drop(&mut self) {
+        if let Ok(mut dropped) = DROPPED.lock() {
+            dropped.push(self.0);
+        }
+    }
+}
+
+impl<Message> Program<Message> for Effect {
+    type State = State;
+    type Primitive = Primitive;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Action<Message>> {
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let _ = state.start.get_or_insert(*now);
+                state.now = Some(*now);
+
+                self.is_animated.then(Action::request_redraw)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if cursor.is_over(bounds) =>
+            {
+                Some(Action::request_redraw())
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(bounds) =>
+            {
+                state.is_pressed = true;
+
+                Some(Action::request_redraw())
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                Some(Action::request_redraw())
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+    ) -> Self::Primitive {
+        let time = match (state.start, state.now) {
+            (Some(start), Some(now)) => {
+                now.saturating_duration_since(start).as_secs_f32()
+            }
+            _ => 0.0,
+        };
+
+        Primitive {
+            id: state.id.0,
+            effect: self.clone(),
+            time,
+            mouse: cursor
+                .position_from(bounds.position())
+                .unwrap_or(Point::ORIGIN),
+            is_pressed: state.is_pressed,
+        }
+    }
+}
+
+/// The primitive of an [`Effect`].
+#[derive(Debug)]
+pub struct Primitive {
+    id: u64,
+    effect: Effect,
+    time: f32,
+    mouse: Point,
+    is_pressed: bool,
+}
+
+impl shader::Primitive for Primitive {
+    fn prepare(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        storage: &mut Storage,
+        bounds: &Rectangle,
+        viewport: &Viewport,
+    ) {
+        if !storage.has::<Pipeline>() {
+            storage.store(Pipeline::new(device));
+        }
+
+        let pipeline = storage.get_mut::<Pipeline>().unwrap();
+
+        pipeline.prepare(device, queue, format, self, bounds, viewport);
+    }
+
+    fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        storage: &Storage,
+        target: &wgpu::TextureView,
+        clip_bounds: &Rectangle<u32>,
+    ) {
+        if let Some(pipeline) = storage.get::<Pipeline>() {
+            pipeline.render(self.id, encoder, target, clip_bounds);
+        }
+    }
+}
+
+type Key = (u64, usize, usize, wgpu::TextureFormat);
+
+struct Pipeline {
+    constants_layout: wgpu::BindGroupLayout,
+    texture_layouts: FxHashMap<usize, wgpu::BindGroupLayout>,
+    storage_layouts: FxHashMap<usize, wgpu::BindGroupLayout>,
+    sampler: wgpu::Sampler,
+    pipelines: FxHashMap<Key, wgpu::RenderPipeline>,
+    instances: FxHashMap<u64, Instance>,
+    #[cfg(feature = "image")]
+    textures: FxHashMap<image::Id, wgpu::TextureView>,
+}
+
+struct Instance {
+    key: Key,
+    bounds: Rectangle,
+    uniforms: wgpu::Buffer,
+    constants: wgpu::BindGroup,
+    #[cfg(feature = "image")]
+    textures: Vec<image::Id>,
+    texture_group: Option<wgpu::BindGroup>,
+    storage: Vec<wgpu::Buffer>,
+    storage_group: Option<wgpu::BindGroup>,
+}
+
+const UNIFORMS_SIZE: u64 = 32;
+
+impl Pipeline {
+    fn new(device: &wgpu::Device) -> Self {
+        let constants_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_widget.shader.effect.constants_layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                UNIFORMS_SIZE,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering,
+                        ),
+                        count: None,
+                    },
+                ],
+            });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("iced_widget.shader.effect.sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..wgpu::SamplerDescriptor::default()
+        });
+
+        Self {
+            constants_layout,
+            texture_layouts: FxHashMap::default(),
+            storage_layouts: FxHashMap::default(),
+            sampler,
+            pipelines: FxHashMap::default(),
+            instances: FxHashMap::default(),
+            #[cfg(feature = "image")]
+            textures: FxHashMap::default(),
+        }
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        primitive: &Primitive,
+        bounds: &Rectangle,
+        viewport: &Viewport,
+    ) {
+        if let Ok(mut dropped) = DROPPED.lock() {
+            for id in dropped.drain(..) {
+                let _ = self.instances.remove(&id);
+            }
+        }
+
+        let effect = &primitive.effect;
+
+        #[cfg(feature = "image")]
+        let textures = effect.textures.len();
+        #[cfg(not(feature = "image"))]
+        let textures = 0;
+
+        let key = (effect.hash, textures, effect.storage.len(), format);
+
+        let texture_layout = self
+            .texture_layouts
+            .entry(textures)
+            .or_insert_with(|| texture_layout(device, textures))
+            .clone();
+
+        let storage_layout = self
+            .storage_layouts
+            .entry(effect.storage.len())
+            .or_insert_with(|| storage_layout(device, effect.storage.len()))
+            .clone();
+
+        if !self.pipelines.contains_key(&key) {
+            let pipeline = create_pipeline(
+                device,
+                format,
+                &effect.source,
+                &[&self.constants_layout, &texture_layout, &storage_layout],
+            );
+
+            let _ = self.pipelines.insert(key, pipeline);
+        }
+
+        let instance =
+            self.instances.entry(primitive.id).or_insert_with(|| {
+                let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("iced_widget.shader.effect.uniforms"),
+                    size: UNIFORMS_SIZE,
+                    usage: wgpu::BufferUsages::UNIFORM
+                        | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                });
+
+                let constants =
+                    device.create_bind_group(&wgpu::BindGroupDescriptor {
+                        label: Some("iced_widget.shader.effect.constants"),
+                        layout: &self.constants_layout,
+                        entries: &[
+                            wgpu::BindGroupEntry {
+                                binding: 0,
+                                resource: uniforms.as_entire_binding(),
+                            },
+                            wgpu::BindGroupEntry {
+                                binding: 1,
+                                resource: wgpu::BindingResource::Sampler(
+                                    &self.sampler,
+                                ),
+                            },
+                        ],
+                    });
+
+                Instance {
+                    key,
+                    bounds: Rectangle::default(),
+                    uniforms,
+                    constants,
+                    #[cfg(feature = "image")]
+                    textures: Vec::new(),
+                    texture_group: None,
+                    storage: Vec::new(),
+                    storage_group: None,
+                }
+            });
+
+        let scale_factor = viewport.scale_factor() as f32;
+
+        instance.key = key;
+        instance.bounds = *bounds * scale_factor;
+
+        let uniforms: [f32; 8] = [
+            instance.bounds.width,
+            instance.bounds.height,
+            primitive.mouse.x * scale_factor,
+            primitive.mouse.y * scale_factor,
+            primitive.time,
+            if primitive.is_pressed { 1.0 } else { 0.0 },
+            scale_factor,
+            0.0,
+        ];
+
+        queue.write_buffer(
+            &instance.uniforms,
+            0,
+            &uniforms
+                .iter()
+                .flat_map(|value| value.to_ne_bytes())
+                .collect::<Vec<_>>(),
+        );
+
+        #[cfg(feature = "image")]
+        let mut are_textures_outdated = false;
+
+        #[cfg(feature = "image")]
+        {
+            let ids: Vec<image::Id> =
+                effect.textures.iter().map(image::Handle::id).collect();
+
+            if instance.texture_group.is_none() || instance.textures != ids {
+                // Textures are shared by all the effects using the same image
+                for handle in &effect.textures {
+                    let _ = self
+                        .textures
+                        .entry(handle.id())
+                        .or_insert_with(|| upload(device, queue, handle));
+                }
+
+                let entries: Vec<_> = ids
+                    .iter()
+                    .enumerate()
+                    .map(|(binding, id)| wgpu::BindGroupEntry {
+                        binding: binding as u32,
+                        resource: wgpu::BindingResource::TextureView(
+                            &self.textures[id],
+                        ),
+                    })
+                    .collect();
+
+                instance.texture_group = Some(device.create_bind_group(
+                    &wgpu::BindGroupDescriptor {
+                        label: Some("iced_widget.shader.effect.textures"),
+                        layout: &texture_layout,
+                        entries: &entries,
+                    },
+                ));
+
+                instance.textures = ids;
+                are_textures_outdated = true;
+            }
+        }
+
+        #[cfg(not(feature = "image"))]
+        if instance.texture_group.is_none() {
+            instance.texture_group =
+                Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_widget.shader.effect.textures"),
+                    layout: &texture_layout,
+                    entries: &[],
+                }));
+        }
+
+        let is_outdated = instance.storage_group.is_none()
+            || instance.storage.len() != effect.storage.len()
+            || instance
+                .storage
+                .iter()
+                .zip(&effect.storage)
+                .any(|(buffer, data)| buffer.size() < storage_size(data));
+
+        if is_outdated {
+            instance.storage = effect
+                .storage
+                .iter()
+                .map(|data| {
+                    device.create_buffer(&wgpu::BufferDescriptor {
+                        label: Some("iced_widget.shader.effect.storage"),
+                        size: storage_size(data),
+                        usage: wgpu::BufferUsages::STORAGE
+                            | wgpu::BufferUsages::COPY_DST,
+                        mapped_at_creation: false,
+                    })
+                })
+                .collect();
+
+            let entries: Vec<_> = instance
+                .storage
+                .iter()
+                .enumerate()
+                .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                    binding: binding as u32,
+                    resource: buffer.as_entire_binding(),
+                })
+                .collect();
+
+            instance.storage_group =
+                Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_widget.shader.effect.storage"),
+                    layout: &storage_layout,
+                    entries: &entries,
+                }));
+        }
+
+        for (buffer, data) in instance.storage.iter().zip(&effect.storage) {
+            if data.is_empty() {
+                continue;
+            }
+
+            // Writes must be a multiple of `wgpu::COPY_BUFFER_ALIGNMENT`
+            let size = storage_size(data) as usize;
+
+            if data.len() == size {
+                queue.write_buffer(buffer, 0, data);
+            } else {
+                let mut padded = data.to_vec();
+                padded.resize(size, 0);
+
+                queue.write_buffer(buffer, 0, &padded);
+            }
+        }
+
+        #[cfg(feature = "image")]
+        if are_textures_outdated {
+            let instances = &self.instances;
+
+            self.textures.retain(|id, _| {
+                instances
+                    .values()
+                    .any(|instance| instance.textures.contains(id))
+            });
+        }
+    }
+
+    fn render(
+        &self,
+        id: u64,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        clip_bounds: &Rectangle<u32>,
+    ) {
+        let Some(instance) = self.instances.get(&id) else {
+            return;
+        };
+
+        let (Some(pipeline), Some(textures), Some(storage)) = (
+            self.pipelines.get(&instance.key),
+            &instance.texture_group,
+            &instance.storage_group,
+        ) else {
+            return;
+        };
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_widget.shader.effect.pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        pass.set_viewport(
+            instance.bounds.x,
+            instance.bounds.y,
+            instance.bounds.width,
+            instance.bounds.height,
+            0.0,
+            1.0,
+        );
+
+        pass.set_scissor_rect(
+            clip_bounds.x,
+            clip_bounds.y,
+            clip_bounds.width,
+            clip_bounds.height,
+        );
+
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &instance.constants, &[]);
+        pass.set_bind_group(1, textures, &[]);
+        pass.set_bind_group(2, storage, &[]);
+        pass.draw(0..3, 0..1);
+    }
+}
+
+fn texture_layout(
+    device: &wgpu::Device,
+    count: usize,
+) -> wgpu::BindGroupLayout {
+    let entries: Vec<_> = (0..count as u32)
+        .map(|binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float {
+                    filterable: true,
+                },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        })
+        .collect();
+
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("iced_widget.shader.effect.texture_layout"),
+        entries: &entries,
+    })
+}
+
+fn storage_layout(
+    device: &wgpu::Device,
+    count: usize,
+) -> wgpu::BindGroupLayout {
+    let entries: Vec<_> = (0..count as u32)
+        .map(|binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        })
+        .collect();
+
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("iced_widget.shader.effect.storage_layout"),
+        entries: &entries,
+    })
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    source: &str,
+    layouts: &[&wgpu::BindGroupLayout],
+) -> wgpu::RenderPipeline {
+    let layout =
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("iced_widget.shader.effect.pipeline"),
+            bind_group_layouts: layouts,
+            push_constant_ranges: &[],
+        });
+
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("iced_widget.shader.effect.shader"),
+        source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
+    });
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("iced_widget.shader.effect.pipeline"),
+        layout: Some(&layout),
+        vertex: wgpu::VertexState {
+            module: &shader,
+            entry_point: Some("iced_vs_main"),
+            buffers: &[],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: &shader,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        }),
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        multiview: None,
+        cache: None,
+    })
+}
+
+/// Storage buffers cannot be empty and must be a multiple of 4 bytes.
+fn storage_size(data: &[u8]) -> u64 {
+    (data.len() as u64).max(4).next_multiple_of(4)
+}
+
+#[cfg(feature = "image")]
+fn upload(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    handle: &image::Handle,
+) -> wgpu::TextureView {
+    use crate::graphics;
+    use wgpu::util::DeviceExt;
+
+    let (width, height, pixels) = match graphics::image::load(handle) {
+        Ok(image) => (image.width(), image.height(), image.into_raw()),
+        Err(error) => {
+            log::warn!("Failed to load effect texture: {error}");
+
+            (1, 1, image::Bytes::from_static(&[0, 0, 0, 0]))
+        }
+    };
+
+    let texture = device.create_texture_with_data(
+        queue,
+        &wgpu::TextureDescriptor {
+            label: Some("iced_widget.shader.effect.texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        wgpu::util::TextureDataOrder::LayerMajor,
+        &pixels,
+    );
+
+    texture.create_view(&wgpu::TextureViewDescriptor::default())
+}
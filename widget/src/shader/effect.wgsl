@@ -0,0 +1,28 @@
+struct Uniforms {
+    resolution: vec2<f32>,
+    mouse: vec2<f32>,
+    time: f32,
+    is_pressed: f32,
+    scale_factor: f32,
+    _padding: f32,
+}
+
+struct Fragment {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0) var<uniform> iced: Uniforms;
+@group(0) @binding(1) var iced_sampler: sampler;
+
+@vertex
+fn iced_vs_main(@builtin(vertex_index) index: u32) -> Fragment {
+    // A single triangle covering the whole viewport
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+
+    var fragment: Fragment;
+    fragment.position = vec4<f32>(uv * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+    fragment.uv = uv;
+
+    return fragment;
+}
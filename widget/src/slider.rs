@@ -28,6 +28,7 @@
 //!     }
 //! }
 //! ```
+use crate::core::accessibility;
 use crate::core::border::{self, Border};
 use crate::core::keyboard;
 use crate::core::keyboard::key::{self, Key};
@@ -242,6 +243,23 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn accessibility(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        Some(
+            accessibility::Node::new(accessibility::Role::Slider)
+                .numeric(
+                    self.value.into(),
+                    (*self.range.start()).into()..=(*self.range.end()).into(),
+                    self.step.into(),
+                )
+                .action(accessibility::Action::Increment)
+                .action(accessibility::Action::Decrement),
+        )
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -253,6 +271,7 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        let accessibility_id = tree.accessibility_id();
         let state = tree.state.downcast_mut::<State>();
 
         let mut update = || {
@@ -411,6 +430,21 @@ where
                 )) => {
                     state.keyboard_modifiers = *modifiers;
                 }
+                Event::Accessibility(request)
+                    if request.target == accessibility_id =>
+                {
+                    match request.action {
+                        accessibility::Action::Increment => {
+                            let _ = increment(current_value).map(change);
+                        }
+                        accessibility::Action::Decrement => {
+                            let _ = decrement(current_value).map(change);
+                        }
+                        _ => {}
+                    }
+
+                    shell.capture_event();
+                }
                 _ => {}
             }
         };
@@ -514,6 +548,7 @@ where
                     radius: handle_border_radius,
                     width: style.handle.border_width,
                     color: style.handle.border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
@@ -681,6 +716,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
                 radius: 2.0.into(),
                 width: 0.0,
                 color: Color::TRANSPARENT,
+                ..Border::default()
             },
         },
         handle: Handle {
@@ -15,14 +15,17 @@
 //!     svg("tiger.svg").into()
 //! }
 //! ```
+use crate::core::animation;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::svg;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    Color, ContentFit, Element, Layout, Length, Point, Rectangle, Rotation,
-    Size, Theme, Vector, Widget,
+    Clipboard, Color, ContentFit, Element, Event, Layout, Length, Point,
+    Rectangle, Rotation, Shell, Size, Theme, Vector, Widget,
 };
 
 use std::path::PathBuf;
@@ -63,6 +66,8 @@ where
     class: Theme::Class<'a>,
     rotation: Rotation,
     opacity: f32,
+    style_sheet: String,
+    animated: bool,
 }
 
 impl<'a, Theme> Svg<'a, Theme>
@@ -79,6 +84,8 @@ where
             class: Theme::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
+            style_sheet: String::new(),
+            animated: false,
         }
     }
 
@@ -146,6 +153,42 @@ where
         self.opacity = opacity.into();
         self
     }
+
+    /// Overrides the fill [`Color`] of the elements of the [`Svg`] with the
+    /// given CSS class.
+    pub fn fill(mut self, class: &str, color: impl Into<Color>) -> Self {
+        self.style_sheet.push_str(&format!(
+            ".{class} {{ fill: {}; }}\n",
+            to_css(color.into())
+        ));
+        self
+    }
+
+    /// Overrides the stroke [`Color`] of the elements of the [`Svg`] with
+    /// the given CSS class.
+    pub fn stroke(mut self, class: &str, color: impl Into<Color>) -> Self {
+        self.style_sheet.push_str(&format!(
+            ".{class} {{ stroke: {}; }}\n",
+            to_css(color.into())
+        ));
+        self
+    }
+
+    /// Sets whether the SMIL animations of the [`Svg`] should play.
+    ///
+    /// Animations start playing when the [`Svg`] is first drawn. They
+    /// are frozen at their start if the system prefers reduced motion.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Injects the given CSS style sheet into the [`Svg`].
+    pub fn css(mut self, style_sheet: impl AsRef<str>) -> Self {
+        self.style_sheet.push_str(style_sheet.as_ref());
+        self.style_sheet.push('\n');
+        self
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -154,6 +197,14 @@ where
     Renderer: svg::Renderer,
     Theme: Catalog,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -195,9 +246,34 @@ where
         layout::Node::new(final_size)
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.animated || animation::is_motion_reduced() {
+            return;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            let _ = state.start.get_or_insert(*now);
+            state.now = Some(*now);
+
+            shell.request_redraw();
+        }
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -241,10 +317,37 @@ where
 
         let style = theme.style(&self.class, status);
 
+        let handle = if self.animated {
+            let state = tree.state.downcast_ref::<State>();
+
+            let time = match (state.start, state.now) {
+                (Some(start), Some(now)) => {
+                    now.saturating_duration_since(start)
+                }
+                _ => Duration::ZERO,
+            };
+
+            self.handle.clone().with_time(time)
+        } else {
+            self.handle.clone()
+        };
+
+        let handle = match style.current_color {
+            Some(color) => handle.with_style_sheet(format!(
+                "svg {{ color: {}; }}\n{}",
+                to_css(color),
+                self.style_sheet
+            )),
+            None if !self.style_sheet.is_empty() => {
+                handle.with_style_sheet(self.style_sheet.as_str())
+            }
+            None => handle,
+        };
+
         let render = |renderer: &mut Renderer| {
             renderer.draw_svg(
                 svg::Svg {
-                    handle: self.handle.clone(),
+                    handle: handle.clone(),
                     color: style.color,
                     rotation: self.rotation.radians(),
                     opacity: self.opacity,
@@ -274,6 +377,12 @@ where
     }
 }
 
+#[derive(Debug, Default)]
+struct State {
+    start: Option<Instant>,
+    now: Option<Instant>,
+}
+
 /// The possible status of an [`Svg`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -292,6 +401,20 @@ pub struct Style {
     ///
     /// `None` keeps the original color.
     pub color: Option<Color>,
+
+    /// The [`Color`] used by the `currentColor` keyword of an [`Svg`].
+    ///
+    /// Unlike [`Style::color`], it only recolors the elements that use
+    /// `currentColor` and keeps the rest of the intrinsic colors.
+    ///
+    /// `None` keeps the original `currentColor`.
+    pub current_color: Option<Color>,
+}
+
+fn to_css(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    format!("rgba({r}, {g}, {b}, {})", f32::from(a) / 255.0)
 }
 
 /// The theme catalog of an [`Svg`].
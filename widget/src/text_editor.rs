@@ -42,6 +42,7 @@ use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{self, LineHeight, Text, Wrapping};
+use crate::core::theme::Styles;
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
 use crate::core::widget::{self, Widget};
@@ -119,6 +120,7 @@ pub struct TextEditor<
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    copy_on_select: bool,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -150,6 +152,7 @@ where
             class: Theme::default(),
             key_binding: None,
             on_edit: None,
+            copy_on_select: false,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -290,6 +293,7 @@ where
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
+            copy_on_select: self.copy_on_select,
             highlighter_settings: settings,
             highlighter_format: to_format,
             last_status: self.last_status,
@@ -307,6 +311,18 @@ where
         self
     }
 
+    /// Sets whether the selection of the [`TextEditor`] should be copied
+    /// to the primary clipboard automatically.
+    ///
+    /// This is the usual behavior of text widgets in X11 and Wayland. It has
+    /// no effect on platforms without a primary clipboard.
+    ///
+    /// By default, it is disabled.
+    pub fn copy_on_select(mut self, copy_on_select: bool) -> Self {
+        self.copy_on_select = copy_on_select;
+        self
+    }
+
     /// Sets the style of the [`TextEditor`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -506,6 +522,7 @@ pub struct State<Highlighter: text::Highlighter> {
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     partial_scroll: f32,
+    primary_selection: Option<String>,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
@@ -581,6 +598,7 @@ where
             last_click: None,
             drag_click: None,
             partial_scroll: 0.0,
+            primary_selection: None,
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
@@ -705,6 +723,21 @@ where
                                 ),
                         );
                     }
+
+                    if self.copy_on_select {
+                        let selection = self.content.selection();
+
+                        if selection.is_some()
+                            && selection != state.primary_selection
+                        {
+                            clipboard.write(
+                                clipboard::Kind::Primary,
+                                selection.clone().unwrap_or_default(),
+                            );
+                        }
+
+                        state.primary_selection = selection;
+                    }
                 }
             }
             _ => {}
@@ -735,6 +768,20 @@ where
                     shell.publish(on_edit(action));
                     shell.capture_event();
                 }
+                Update::PastePrimary(position) => {
+                    if let Some(contents) =
+                        clipboard.read(clipboard::Kind::Primary)
+                    {
+                        state.focus = Some(Focus::now());
+
+                        shell.publish(on_edit(Action::Click(position)));
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(contents),
+                        ))));
+                    }
+
+                    shell.capture_event();
+                }
                 Update::Drag(position) => {
                     shell.publish(on_edit(Action::Drag(position)));
                 }
@@ -1207,6 +1254,7 @@ impl<Message> Binding<Message> {
 
 enum Update<Message> {
     Click(mouse::Click),
+    PastePrimary(Point),
     Drag(Point),
     Release,
     Scroll(f32),
@@ -1254,6 +1302,12 @@ impl<Message> Update<Message> {
                         None
                     }
                 }
+                mouse::Event::ButtonPressed(mouse::Button::Middle) => {
+                    let cursor_position = cursor.position_in(bounds)?
+                        - Vector::new(padding.top, padding.left);
+
+                    Some(Update::PastePrimary(cursor_position))
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     Some(Update::Release)
                 }
@@ -1403,7 +1457,19 @@ impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Box::new(|theme: &Theme, status: Status| {
+            let style = default(theme, status);
+
+            match theme.extension::<Styles>() {
+                Some(styles) => Style {
+                    background: styles.text_editor.background(style.background),
+                    value: styles.text_editor.text_color(style.value),
+                    border: styles.text_editor.border(style.border),
+                    ..style
+                },
+                None => style,
+            }
+        })
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
@@ -1414,13 +1480,19 @@ impl Catalog for Theme {
 /// The default style of a [`TextEditor`].
 pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
+    let high_contrast = theme.is_high_contrast();
 
     let active = Style {
         background: Background::Color(palette.background.base.color),
         border: Border {
-            radius: 2.0.into(),
+            radius: theme.tokens().radius.small.into(),
             width: 1.0,
-            color: palette.background.strong.color,
+            color: if high_contrast {
+                palette.background.base.text
+            } else {
+                palette.background.strong.color
+            },
+            ..Border::default()
         },
         icon: palette.background.weak.text,
         placeholder: palette.background.strong.color,
@@ -1431,17 +1503,14 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     match status {
         Status::Active => active,
         Status::Hovered => Style {
-            border: Border {
-                color: palette.background.base.text,
-                ..active.border
-            },
+            border: active.border.color(palette.background.base.text),
             ..active
         },
         Status::Focused { .. } => Style {
-            border: Border {
-                color: palette.primary.strong.color,
-                ..active.border
-            },
+            border: active
+                .border
+                .color(palette.primary.strong.color)
+                .width(if high_contrast { 3.0 } else { 1.0 }),
             ..active
         },
         Status::Disabled => Style {
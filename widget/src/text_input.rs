@@ -40,6 +40,7 @@ pub use value::Value;
 
 use editor::Editor;
 
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::input_method;
@@ -50,6 +51,7 @@ use crate::core::mouse::{self, click};
 use crate::core::renderer;
 use crate::core::text::paragraph::{self, Paragraph as _};
 use crate::core::text::{self, Text};
+use crate::core::theme::Styles;
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
@@ -119,6 +121,7 @@ pub struct TextInput<
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    copy_on_select: bool,
     icon: Option<Icon<Renderer::Font>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
@@ -150,6 +153,7 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            copy_on_select: false,
             icon: None,
             class: Theme::default(),
             last_status: None,
@@ -226,6 +230,19 @@ where
         self
     }
 
+    /// Sets whether the selection of the [`TextInput`] should be copied
+    /// to the primary clipboard automatically.
+    ///
+    /// This is the usual behavior of text widgets in X11 and Wayland. It has
+    /// no effect on platforms without a primary clipboard, nor on secure
+    /// inputs.
+    ///
+    /// By default, it is disabled.
+    pub fn copy_on_select(mut self, copy_on_select: bool) -> Self {
+        self.copy_on_select = copy_on_select;
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -679,6 +696,31 @@ where
         self.layout(tree, renderer, limits, None)
     }
 
+    fn accessibility(
+        &self,
+        tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let node = accessibility::Node::new(accessibility::Role::TextInput)
+            .name(self.placeholder.clone())
+            .value(value.to_string())
+            .focused(state.is_focused());
+
+        Some(if self.on_input.is_some() {
+            node.action(accessibility::Action::Focus)
+        } else {
+            node
+        })
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -686,6 +728,15 @@ where
         _renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        if let Some(node) = self.accessibility(tree, layout) {
+            operation.accessible(
+                self.id.as_ref().map(|id| &id.0),
+                tree.accessibility_id(),
+                layout.bounds(),
+                &node,
+            );
+        }
+
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
         operation.focusable(
@@ -830,6 +881,87 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                let Some(on_input) = &self.on_input else {
+                    return;
+                };
+
+                let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                else {
+                    return;
+                };
+
+                let state = state::<Renderer>(tree);
+
+                let Some(content) = clipboard.read(clipboard::Kind::Primary)
+                else {
+                    return;
+                };
+
+                let content: String =
+                    content.chars().filter(|c| !c.is_control()).collect();
+
+                let text_layout = layout.children().next().unwrap();
+
+                let target = {
+                    let text_bounds = text_layout.bounds();
+
+                    let alignment_offset = alignment_offset(
+                        text_bounds.width,
+                        state.value.raw().min_width(),
+                        self.alignment,
+                    );
+
+                    cursor_position.x - text_bounds.x - alignment_offset
+                };
+
+                let value = if self.is_secure {
+                    self.value.secure()
+                } else {
+                    self.value.clone()
+                };
+
+                let position = if target > 0.0 {
+                    find_cursor_position(
+                        text_layout.bounds(),
+                        &value,
+                        state,
+                        target,
+                    )
+                } else {
+                    None
+                }
+                .unwrap_or(0);
+
+                state.cursor.move_to(position);
+                state.is_pasting = None;
+
+                let now = Instant::now();
+
+                state.is_focused = Some(Focus {
+                    updated_at: now,
+                    now,
+                    is_window_focused: true,
+                });
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut state.cursor);
+                editor.paste(Value::new(&content));
+
+                let message = if let Some(paste) = &self.on_paste {
+                    (paste)(editor.contents())
+                } else {
+                    (on_input)(editor.contents())
+                };
+
+                shell.publish(message);
+                shell.capture_event();
+
+                update_cache(state, &self.value);
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
@@ -1308,6 +1440,19 @@ where
                     }
                 }
             },
+            Event::Accessibility(request)
+                if request.is_for(tree)
+                    && request.action == accessibility::Action::Focus =>
+            {
+                if self.on_input.is_some() {
+                    let state = state::<Renderer>(tree);
+
+                    state.focus();
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
             Event::Window(window::Event::Unfocused) => {
                 let state = state::<Renderer>(tree);
 
@@ -1354,6 +1499,21 @@ where
                             &self.value,
                         ));
                     }
+
+                    if self.copy_on_select && !self.is_secure {
+                        let selection = state.cursor.selection(&self.value);
+
+                        if let Some((start, end)) = selection {
+                            if selection != state.primary_selection {
+                                clipboard.write(
+                                    clipboard::Kind::Primary,
+                                    self.value.select(start, end).to_string(),
+                                );
+                            }
+                        }
+
+                        state.primary_selection = selection;
+                    }
                 }
             }
             _ => {}
@@ -1542,6 +1702,7 @@ pub struct State<P: text::Paragraph> {
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    primary_selection: Option<(usize, usize)>,
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
@@ -1800,7 +1961,19 @@ impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        Box::new(|theme: &Theme, status: Status| {
+            let style = default(theme, status);
+
+            match theme.extension::<Styles>() {
+                Some(styles) => Style {
+                    background: styles.text_input.background(style.background),
+                    value: styles.text_input.text_color(style.value),
+                    border: styles.text_input.border(style.border),
+                    ..style
+                },
+                None => style,
+            }
+        })
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
@@ -1811,13 +1984,19 @@ impl Catalog for Theme {
 /// The default style of a [`TextInput`].
 pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
+    let high_contrast = theme.is_high_contrast();
 
     let active = Style {
         background: Background::Color(palette.background.base.color),
         border: Border {
-            radius: 2.0.into(),
+            radius: theme.tokens().radius.small.into(),
             width: 1.0,
-            color: palette.background.strongest.color,
+            color: if high_contrast {
+                palette.background.base.text
+            } else {
+                palette.background.strongest.color
+            },
+            ..Border::default()
         },
         icon: palette.background.weak.text,
         placeholder: palette.background.strongest.color,
@@ -1828,17 +2007,14 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     match status {
         Status::Active => active,
         Status::Hovered => Style {
-            border: Border {
-                color: palette.background.base.text,
-                ..active.border
-            },
+            border: active.border.color(palette.background.base.text),
             ..active
         },
         Status::Focused { .. } => Style {
-            border: Border {
-                color: palette.primary.strong.color,
-                ..active.border
-            },
+            border: active
+                .border
+                .color(palette.primary.strong.color)
+                .width(if high_contrast { 3.0 } else { 1.0 }),
             ..active
         },
         Status::Disabled => Style {
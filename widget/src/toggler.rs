@@ -30,6 +30,7 @@
 //!     }
 //! }
 //! ```
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
@@ -315,9 +316,28 @@ where
         )
     }
 
+    fn accessibility(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<accessibility::Node> {
+        let mut node = accessibility::Node::new(accessibility::Role::Switch)
+            .toggled(self.is_toggled);
+
+        if let Some(label) = self.label.as_deref() {
+            node = node.name(label);
+        }
+
+        Some(if self.on_toggle.is_some() {
+            node.action(accessibility::Action::Click)
+        } else {
+            node
+        })
+    }
+
     fn update(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -340,6 +360,13 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Accessibility(request)
+                if request.is_for(state)
+                    && request.action == accessibility::Action::Click =>
+            {
+                shell.publish(on_toggle(!self.is_toggled));
+                shell.capture_event();
+            }
             _ => {}
         }
 
@@ -440,6 +467,7 @@ where
                     radius: border_radius.into(),
                     width: style.background_border_width,
                     color: style.background_border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
@@ -465,6 +493,7 @@ where
                     radius: border_radius.into(),
                     width: style.foreground_border_width,
                     color: style.foreground_border_color,
+                    ..Border::default()
                 },
                 ..renderer::Quad::default()
             },
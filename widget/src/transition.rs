@@ -0,0 +1,436 @@
+//! Animate the change between two views when a key changes.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::transition::{Direction, Effect};
+//! use iced::widget::{text, transition};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Screen {
+//!     Welcome,
+//!     Settings,
+//! }
+//!
+//! struct State {
+//!     screen: Screen,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     transition(state.screen, |screen| match screen {
+//!         Screen::Welcome => text("Welcome!").into(),
+//!         Screen::Settings => text("Settings").into(),
+//!     })
+//!     .effect(Effect::Slide(Direction::Left))
+//!     .into()
+//! }
+//! ```
+use crate::core::animation;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::cell::RefCell;
+
+/// A widget that animates the change between two views when its key changes.
+///
+/// The view of each key is produced by a closure, so the outgoing view can
+/// be drawn while it leaves. Only the incoming view receives events.
+#[allow(missing_debug_implementations)]
+pub struct Transition<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    key: Key,
+    content: Element<'a, Message, Theme, Renderer>,
+    view: Box<dyn Fn(&Key) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    outgoing: RefCell<Option<Element<'a, Message, Theme, Renderer>>>,
+    effect: Effect,
+    duration: Duration,
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    Transition<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Transition`] showing the view of the given key.
+    pub fn new(
+        key: Key,
+        view: impl Fn(&Key) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            content: view(&key),
+            key,
+            view: Box::new(view),
+            outgoing: RefCell::new(None),
+            effect: Effect::default(),
+            duration: Duration::from_millis(250),
+        }
+    }
+
+    /// Sets the [`Effect`] of the [`Transition`].
+    pub fn effect(mut self, effect: Effect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Sets the duration of the [`Transition`].
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+}
+
+/// The effect of a [`Transition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Effect {
+    /// The outgoing view fades out while the incoming one fades in.
+    #[default]
+    Fade,
+
+    /// The incoming view pushes the outgoing one in the given [`Direction`].
+    Slide(Direction),
+}
+
+/// The direction of a sliding [`Effect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The views move to the left.
+    Left,
+    /// The views move to the right.
+    Right,
+    /// The views move up.
+    Up,
+    /// The views move down.
+    Down,
+}
+
+impl Direction {
+    fn offset(self, size: Size) -> Vector {
+        match self {
+            Self::Left => Vector::new(size.width, 0.0),
+            Self::Right => Vector::new(-size.width, 0.0),
+            Self::Up => Vector::new(0.0, size.height),
+            Self::Down => Vector::new(0.0, -size.height),
+        }
+    }
+}
+
+struct State<Key> {
+    key: Key,
+    previous: Option<Key>,
+    start: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl<Key> State<Key> {
+    fn progress(&self, duration: Duration) -> f32 {
+        let (Some(start), Some(now)) = (self.start, self.now) else {
+            return 0.0;
+        };
+
+        let t = (now.saturating_duration_since(start).as_secs_f32()
+            / duration.as_secs_f32().max(f32::EPSILON))
+        .min(1.0);
+
+        t * t * (3.0 - 2.0 * t)
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Transition<'_, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            key: self.key.clone(),
+            previous: None,
+            start: None,
+            now: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+
+        if state.key == self.key {
+            children[0].diff(&self.content);
+        } else {
+            let outgoing =
+                std::mem::replace(&mut children[0], Tree::new(&self.content));
+
+            children.truncate(1);
+
+            let previous = std::mem::replace(&mut state.key, self.key.clone());
+
+            if self.duration.is_zero() || animation::is_motion_reduced() {
+                state.previous = None;
+            } else {
+                children.push(outgoing);
+
+                state.previous = Some(previous);
+                state.start = None;
+            }
+        }
+
+        if let Some(previous) = &state.previous {
+            let outgoing = (self.view)(previous);
+            children[1].diff(&outgoing);
+
+            *self.outgoing.borrow_mut() = Some(outgoing);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = content.size();
+        let mut children = vec![content];
+
+        if let (Some(outgoing), Some(tree)) =
+            (self.outgoing.borrow().as_ref(), tree.children.get_mut(1))
+        {
+            children.push(outgoing.as_widget().layout(tree, renderer, limits));
+        }
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            state.now = Some(*now);
+
+            if state.previous.is_some() {
+                let _ = state.start.get_or_insert(*now);
+
+                if state.progress(self.duration) >= 1.0 {
+                    state.previous = None;
+                    tree.children.truncate(1);
+
+                    *self.outgoing.get_mut() = None;
+                    shell.invalidate_layout();
+                }
+
+                shell.request_redraw();
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+
+        let outgoing = self.outgoing.borrow();
+
+        let (Some(outgoing), Some(outgoing_tree), Some(outgoing_layout), true) = (
+            outgoing.as_ref(),
+            tree.children.get(1),
+            children.next(),
+            state.previous.is_some(),
+        ) else {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+
+            return;
+        };
+
+        let progress = state.progress(self.duration);
+
+        let draw_outgoing = |renderer: &mut Renderer| {
+            outgoing.as_widget().draw(
+                outgoing_tree,
+                renderer,
+                theme,
+                style,
+                outgoing_layout,
+                mouse::Cursor::Unavailable,
+                viewport,
+            );
+        };
+
+        let draw_content = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        };
+
+        match self.effect {
+            Effect::Fade => {
+                renderer.with_opacity(1.0 - progress, draw_outgoing);
+                renderer.with_opacity(progress, draw_content);
+            }
+            Effect::Slide(direction) => {
+                let bounds = layout.bounds();
+                let offset = direction.offset(bounds.size());
+
+                renderer.with_layer(bounds, |renderer| {
+                    renderer
+                        .with_translation(offset * -progress, draw_outgoing);
+
+                    renderer.with_translation(
+                        offset * (1.0 - progress),
+                        draw_content,
+                    );
+                });
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<Transition<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(transition: Transition<'a, Key, Message, Theme, Renderer>) -> Self {
+        Element::new(transition)
+    }
+}
@@ -0,0 +1,34 @@
+//! Detect the accent color chosen in the native system.
+use crate::core::Color;
+
+/// Returns the accent color of the system, if any.
+#[cfg(target_os = "windows")]
+pub fn color() -> Option<Color> {
+    use windows_sys::Win32::Graphics::Dwm::DwmGetColorizationColor;
+
+    let mut color: u32 = 0;
+    let mut is_opaque = 0;
+
+    // SAFETY: Both pointers refer to locals that live for the whole call.
+    #[allow(unsafe_code)]
+    let result =
+        unsafe { DwmGetColorizationColor(&raw mut color, &raw mut is_opaque) };
+
+    if result < 0 {
+        return None;
+    }
+
+    // The color is laid out as 0xAARRGGBB
+    let [_a, r, g, b] = color.to_be_bytes();
+
+    Some(Color::from_rgb8(r, g, b))
+}
+
+/// Returns the accent color of the system, if any.
+///
+/// Only Windows exposes its accent color; so this always returns `None`
+/// on macOS and Linux.
+#[cfg(not(target_os = "windows"))]
+pub fn color() -> Option<Color> {
+    None
+}
@@ -0,0 +1,486 @@
+//! Expose windows to the assistive technologies of the native system.
+use crate::core::accessibility::Request;
+use crate::core::widget::Operation;
+use crate::runtime::accessibility::Priority;
+
+use winit::event::WindowEvent;
+use winit::event_loop::ActiveEventLoop;
+use winit::window::Window;
+
+use std::sync::Arc;
+
+#[cfg(feature = "a11y")]
+use crate::core::Rectangle;
+#[cfg(feature = "a11y")]
+use crate::core::accessibility::{self, Node};
+#[cfg(feature = "a11y")]
+use crate::core::widget::Id;
+
+/// The accessibility tree of a window.
+#[cfg(feature = "a11y")]
+pub struct Adapter {
+    raw: accesskit_winit::Adapter,
+    state: Arc<std::sync::Mutex<State>>,
+}
+
+#[cfg(feature = "a11y")]
+impl Adapter {
+    /// Creates a new [`Adapter`] for the given [`Window`] with the given
+    /// title.
+    ///
+    /// The [`Window`] must not be visible yet.
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window: &Arc<Window>,
+        title: &str,
+    ) -> Self {
+        use std::sync::Mutex;
+
+        let state = Arc::new(Mutex::new(State {
+            title: title.to_owned(),
+            announcements: [String::new(), String::new()],
+            widgets: Vec::new(),
+            focus: None,
+            scale_factor: window.scale_factor(),
+            requests: Vec::new(),
+            is_active: false,
+        }));
+
+        let raw = accesskit_winit::Adapter::with_direct_handlers(
+            event_loop,
+            window,
+            Activation {
+                state: state.clone(),
+                window: window.clone(),
+            },
+            Actions {
+                state: state.clone(),
+                window: window.clone(),
+            },
+            Deactivation(state.clone()),
+        );
+
+        Self { raw, state }
+    }
+
+    /// Processes the given [`WindowEvent`] of the [`Window`] of the
+    /// [`Adapter`].
+    pub fn process_event(&mut self, window: &Window, event: &WindowEvent) {
+        self.raw.process_event(window, event);
+    }
+
+    /// Announces the given text to screen readers with the given
+    /// [`Priority`].
+    pub fn announce(&mut self, text: String, priority: Priority) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        let index = priority as usize;
+
+        // Live regions are only announced when they change; so repeated
+        // announcements are cleared first
+        if state.announcements[index] == text {
+            state.announcements[index].clear();
+            self.raw.update_if_active(|| state.update());
+        }
+
+        state.announcements[index] = text;
+        self.raw.update_if_active(|| state.update());
+    }
+
+    /// Updates the accessible widgets of the [`Adapter`] by running the
+    /// [`Operation`] given to `operate` on the user interface of its
+    /// [`Window`].
+    ///
+    /// The user interface is only traversed while some assistive technology
+    /// is listening.
+    pub fn update(
+        &mut self,
+        scale_factor: f64,
+        operate: impl FnOnce(&mut dyn Operation),
+    ) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        if !state.is_active {
+            return;
+        }
+
+        let mut collect = Collect {
+            widgets: Vec::new(),
+            scopes: vec![Scope::default()],
+            focus: None,
+        };
+
+        operate(&mut collect);
+
+        if state.widgets == collect.widgets
+            && state.focus == collect.focus
+            && state.scale_factor == scale_factor
+        {
+            return;
+        }
+
+        state.widgets = collect.widgets;
+        state.focus = collect.focus;
+        state.scale_factor = scale_factor;
+
+        self.raw.update_if_active(|| state.update());
+    }
+
+    /// Returns the pending [`Request`]s of assistive technologies.
+    pub fn requests(&mut self) -> Vec<Request> {
+        self.state
+            .lock()
+            .map(|mut state| std::mem::take(&mut state.requests))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "a11y")]
+impl std::fmt::Debug for Adapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Adapter").finish_non_exhaustive()
+    }
+}
+
+/// The accessibility tree of a window.
+#[cfg(not(feature = "a11y"))]
+#[derive(Debug)]
+pub struct Adapter;
+
+#[cfg(not(feature = "a11y"))]
+impl Adapter {
+    /// Creates a new [`Adapter`] for the given [`Window`] with the given
+    /// title.
+    pub fn new(
+        _event_loop: &ActiveEventLoop,
+        _window: &Arc<Window>,
+        _title: &str,
+    ) -> Self {
+        Self
+    }
+
+    /// Processes the given [`WindowEvent`] of the [`Window`] of the
+    /// [`Adapter`].
+    pub fn process_event(&mut self, _window: &Window, _event: &WindowEvent) {}
+
+    /// Announces the given text to screen readers with the given
+    /// [`Priority`].
+    pub fn announce(&mut self, _text: String, _priority: Priority) {}
+
+    /// Updates the accessible widgets of the [`Adapter`] by running the
+    /// [`Operation`] given to `operate` on the user interface of its
+    /// [`Window`].
+    pub fn update(
+        &mut self,
+        _scale_factor: f64,
+        _operate: impl FnOnce(&mut dyn Operation),
+    ) {
+    }
+
+    /// Returns the pending [`Request`]s of assistive technologies.
+    pub fn requests(&mut self) -> Vec<Request> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "a11y")]
+struct State {
+    title: String,
+    announcements: [String; 2],
+    widgets: Vec<Widget>,
+    focus: Option<accessibility::Id>,
+    scale_factor: f64,
+    requests: Vec<Request>,
+    is_active: bool,
+}
+
+/// An accessible widget of a user interface.
+#[cfg(feature = "a11y")]
+#[derive(Debug, PartialEq)]
+struct Widget {
+    id: accessibility::Id,
+    parent: Option<accessibility::Id>,
+    bounds: Rectangle,
+    node: Node,
+}
+
+#[cfg(feature = "a11y")]
+impl State {
+    const ROOT: accesskit::NodeId = accesskit::NodeId(0);
+    const POLITE: accesskit::NodeId = accesskit::NodeId(1);
+    const ASSERTIVE: accesskit::NodeId = accesskit::NodeId(2);
+    const FIRST_WIDGET: u64 = 3;
+
+    fn node_id(id: accessibility::Id) -> accesskit::NodeId {
+        accesskit::NodeId(Self::FIRST_WIDGET + id.raw())
+    }
+
+    fn update(&self) -> accesskit::TreeUpdate {
+        use accesskit::{Live, Node, Role, Tree, TreeUpdate};
+        use std::collections::HashMap;
+
+        let mut children: HashMap<_, Vec<_>> = HashMap::new();
+
+        for widget in &self.widgets {
+            children
+                .entry(widget.parent)
+                .or_default()
+                .push(Self::node_id(widget.id));
+        }
+
+        let mut root = Node::new(Role::Window);
+        root.set_label(self.title.clone());
+        root.set_children(
+            [Self::POLITE, Self::ASSERTIVE]
+                .into_iter()
+                .chain(children.remove(&None).unwrap_or_default())
+                .collect::<Vec<_>>(),
+        );
+
+        let [polite, assertive] = [
+            (Live::Polite, &self.announcements[Priority::Polite as usize]),
+            (
+                Live::Assertive,
+                &self.announcements[Priority::Assertive as usize],
+            ),
+        ]
+        .map(|(live, text)| {
+            let mut node = Node::new(Role::Status);
+            node.set_live(live);
+            node.set_label(text.clone());
+            node
+        });
+
+        TreeUpdate {
+            nodes: [
+                (Self::ROOT, root),
+                (Self::POLITE, polite),
+                (Self::ASSERTIVE, assertive),
+            ]
+            .into_iter()
+            .chain(self.widgets.iter().map(|widget| {
+                let mut node = self::widget(widget, self.scale_factor);
+
+                if let Some(children) = children.remove(&Some(widget.id)) {
+                    node.set_children(children);
+                }
+
+                (Self::node_id(widget.id), node)
+            }))
+            .collect(),
+            tree: Some(Tree::new(Self::ROOT)),
+            focus: self.focus.map_or(Self::ROOT, Self::node_id),
+        }
+    }
+
+    fn request(&self, request: accesskit::ActionRequest) -> Option<Request> {
+        use crate::core::accessibility::Action;
+
+        let widget = self
+            .widgets
+            .iter()
+            .find(|widget| Self::node_id(widget.id) == request.target)?;
+
+        let action = match request.action {
+            accesskit::Action::Click => Action::Click,
+            accesskit::Action::Focus => Action::Focus,
+            accesskit::Action::Increment => Action::Increment,
+            accesskit::Action::Decrement => Action::Decrement,
+            _ => return None,
+        };
+
+        widget.node.actions.contains(&action).then_some(Request {
+            action,
+            target: widget.id,
+        })
+    }
+}
+
+#[cfg(feature = "a11y")]
+fn widget(widget: &Widget, scale_factor: f64) -> accesskit::Node {
+    use crate::core::accessibility::{Action, Role};
+
+    let Widget { bounds, node, .. } = widget;
+
+    let mut widget = accesskit::Node::new(match node.role {
+        Role::Button => accesskit::Role::Button,
+        Role::CheckBox => accesskit::Role::CheckBox,
+        Role::Group => accesskit::Role::Group,
+        Role::Image => accesskit::Role::Image,
+        Role::Label => accesskit::Role::Label,
+        Role::Link => accesskit::Role::Link,
+        Role::ListBox => accesskit::Role::ListBox,
+        Role::ProgressIndicator => accesskit::Role::ProgressIndicator,
+        Role::RadioButton => accesskit::Role::RadioButton,
+        Role::ScrollView => accesskit::Role::ScrollView,
+        Role::Slider => accesskit::Role::Slider,
+        Role::Switch => accesskit::Role::Switch,
+        Role::TextInput => accesskit::Role::TextInput,
+        Role::Unknown => accesskit::Role::Unknown,
+    });
+
+    widget.set_bounds(accesskit::Rect {
+        x0: f64::from(bounds.x) * scale_factor,
+        y0: f64::from(bounds.y) * scale_factor,
+        x1: f64::from(bounds.x + bounds.width) * scale_factor,
+        y1: f64::from(bounds.y + bounds.height) * scale_factor,
+    });
+
+    if let Some(name) = &node.name {
+        widget.set_label(name.clone());
+    }
+
+    if let Some(value) = &node.value {
+        widget.set_value(value.clone());
+    }
+
+    if let Some(numeric) = &node.numeric {
+        widget.set_numeric_value(numeric.value);
+        widget.set_min_numeric_value(*numeric.range.start());
+        widget.set_max_numeric_value(*numeric.range.end());
+        widget.set_numeric_value_step(numeric.step);
+    }
+
+    if let Some(toggled) = node.toggled {
+        widget.set_toggled(if toggled {
+            accesskit::Toggled::True
+        } else {
+            accesskit::Toggled::False
+        });
+    }
+
+    for action in &node.actions {
+        widget.add_action(match action {
+            Action::Click => accesskit::Action::Click,
+            Action::Focus => accesskit::Action::Focus,
+            Action::Increment => accesskit::Action::Increment,
+            Action::Decrement => accesskit::Action::Decrement,
+        });
+    }
+
+    widget
+}
+
+/// Collects the accessible widgets of a user interface.
+///
+/// The widgets reported inside a [`Operation::container`] right after an
+/// accessible widget, and within its bounds, are nested under it.
+#[cfg(feature = "a11y")]
+struct Collect {
+    widgets: Vec<Widget>,
+    scopes: Vec<Scope>,
+    focus: Option<accessibility::Id>,
+}
+
+/// A container visited by [`Collect`].
+#[cfg(feature = "a11y")]
+#[derive(Default)]
+struct Scope {
+    parent: Option<accessibility::Id>,
+    last: Option<(accessibility::Id, Rectangle)>,
+}
+
+#[cfg(feature = "a11y")]
+impl Operation for Collect {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        let scope = self.scopes.last().expect("Scope must exist");
+
+        let parent = match scope.last {
+            Some((last, last_bounds)) if bounds.is_within(&last_bounds) => {
+                Some(last)
+            }
+            _ => scope.parent,
+        };
+
+        self.scopes.push(Scope { parent, last: None });
+        operate_on_children(self);
+        let _ = self.scopes.pop();
+    }
+
+    fn accessible(
+        &mut self,
+        _id: Option<&Id>,
+        node_id: accessibility::Id,
+        bounds: Rectangle,
+        node: &Node,
+    ) {
+        let scope = self.scopes.last_mut().expect("Scope must exist");
+
+        self.widgets.push(Widget {
+            id: node_id,
+            parent: scope.parent,
+            bounds,
+            node: node.clone(),
+        });
+
+        scope.last = Some((node_id, bounds));
+
+        if node.is_focused {
+            self.focus = Some(node_id);
+        }
+    }
+}
+
+#[cfg(feature = "a11y")]
+struct Activation {
+    state: Arc<std::sync::Mutex<State>>,
+    window: Arc<Window>,
+}
+
+#[cfg(feature = "a11y")]
+impl accesskit::ActivationHandler for Activation {
+    fn request_initial_tree(&mut self) -> Option<accesskit::TreeUpdate> {
+        let mut state = self.state.lock().ok()?;
+        state.is_active = true;
+
+        // The accessible widgets are collected on redraw
+        self.window.request_redraw();
+
+        Some(state.update())
+    }
+}
+
+#[cfg(feature = "a11y")]
+struct Actions {
+    state: Arc<std::sync::Mutex<State>>,
+    window: Arc<Window>,
+}
+
+#[cfg(feature = "a11y")]
+impl accesskit::ActionHandler for Actions {
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        if let Some(request) = state.request(request) {
+            state.requests.push(request);
+
+            // Requests are delivered to the user interface on redraw
+            self.window.request_redraw();
+        }
+    }
+}
+
+#[cfg(feature = "a11y")]
+struct Deactivation(Arc<std::sync::Mutex<State>>);
+
+#[cfg(feature = "a11y")]
+impl accesskit::DeactivationHandler for Deactivation {
+    fn deactivate_accessibility(&mut self) {
+        if let Ok(mut state) = self.0.lock() {
+            state.is_active = false;
+            state.widgets.clear();
+            state.focus = None;
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Apply background effects to windows of the native system.
+use crate::core::window::Effect;
+
+use winit::window::Window;
+
+/// Applies the given background [`Effect`] to the [`Window`].
+#[cfg(target_os = "windows")]
+pub fn set_effect(window: &Window, effect: Effect) {
+    use windows_sys::Win32::Graphics::Dwm::{
+        DWM_SYSTEMBACKDROP_TYPE, DWMSBT_MAINWINDOW, DWMSBT_NONE,
+        DWMSBT_TABBEDWINDOW, DWMSBT_TRANSIENTWINDOW, DWMWA_SYSTEMBACKDROP_TYPE,
+        DwmExtendFrameIntoClientArea, DwmSetWindowAttribute,
+    };
+    use windows_sys::Win32::UI::Controls::MARGINS;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    let Ok(handle) = window.window_handle() else {
+        return;
+    };
+
+    let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+        return;
+    };
+
+    let hwnd = handle.hwnd.get() as _;
+
+    let backdrop: DWM_SYSTEMBACKDROP_TYPE = match effect {
+        Effect::None => DWMSBT_NONE,
+        Effect::Mica => DWMSBT_MAINWINDOW,
+        Effect::Acrylic | Effect::Blur => DWMSBT_TRANSIENTWINDOW,
+        Effect::MicaAlt => DWMSBT_TABBEDWINDOW,
+    };
+
+    // The backdrop is only drawn behind the frame; so we extend the frame
+    // into the whole window when an effect is enabled.
+    let extent = if effect == Effect::None { 0 } else { -1 };
+
+    let margins = MARGINS {
+        cxLeftWidth: extent,
+        cxRightWidth: extent,
+        cyTopHeight: extent,
+        cyBottomHeight: extent,
+    };
+
+    // SAFETY: The window handle is valid while the window is alive and
+    // both pointers refer to locals that live for the whole call.
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        let _ = DwmExtendFrameIntoClientArea(hwnd, &raw const margins);
+
+        DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_SYSTEMBACKDROP_TYPE as _,
+            (&raw const backdrop).cast(),
+            size_of::<DWM_SYSTEMBACKDROP_TYPE>() as u32,
+        )
+    };
+
+    if result < 0 {
+        log::warn!("Background effect {effect:?} is not supported");
+    }
+}
+
+/// Applies the given background [`Effect`] to the [`Window`].
+///
+/// Only Windows honors the kind of [`Effect`]; here, every effect other
+/// than [`Effect::None`] is shown as a blur of the windows behind.
+#[cfg(not(target_os = "windows"))]
+pub fn set_effect(window: &Window, effect: Effect) {
+    if !matches!(effect, Effect::None | Effect::Blur) {
+        log::debug!("Background effect {effect:?} is shown as a blur");
+    }
+
+    window.set_blur(effect != Effect::None);
+}
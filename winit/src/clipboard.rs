@@ -1,6 +1,9 @@
 //! Access the clipboard.
 
 use crate::core::clipboard::Kind;
+use crate::runtime::clipboard::Image;
+
+use std::path::PathBuf;
 use std::sync::Arc;
 use winit::window::{Window, WindowId};
 
@@ -9,6 +12,9 @@ use winit::window::{Window, WindowId};
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
+    #[cfg(not(target_arch = "wasm32"))]
+    formats: Option<arboard::Clipboard>,
+    custom: custom::Clipboard,
 }
 
 enum State {
@@ -35,12 +41,23 @@ impl Clipboard {
         let clipboard =
             unsafe { window_clipboard::Clipboard::connect(&window) };
 
+        let custom = custom::Clipboard::new(&window);
+
         let state = match clipboard {
             Ok(clipboard) => State::Connected { clipboard, window },
             Err(_) => State::Unavailable,
         };
 
-        Clipboard { state }
+        Clipboard {
+            state,
+            #[cfg(not(target_arch = "wasm32"))]
+            formats: arboard::Clipboard::new()
+                .inspect_err(|error| {
+                    log::warn!("error connecting to clipboard: {error}");
+                })
+                .ok(),
+            custom,
+        }
     }
 
     /// Creates a new [`Clipboard`] that isn't associated with a window.
@@ -48,6 +65,9 @@ impl Clipboard {
     pub fn unconnected() -> Clipboard {
         Clipboard {
             state: State::Unavailable,
+            #[cfg(not(target_arch = "wasm32"))]
+            formats: None,
+            custom: custom::Clipboard::unavailable(),
         }
     }
 
@@ -84,6 +104,148 @@ impl Clipboard {
         }
     }
 
+    /// Reads the current content of the [`Clipboard`] as an [`Image`].
+    pub fn read_image(&mut self) -> Option<Image> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let image = self.formats.as_mut()?.get_image().ok()?;
+
+            Some(Image {
+                width: u32::try_from(image.width).ok()?,
+                height: u32::try_from(image.height).ok()?,
+                pixels: image.bytes.into_owned().into(),
+            })
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        None
+    }
+
+    /// Writes the given [`Image`] to the [`Clipboard`].
+    pub fn write_image(&mut self, image: Image) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(formats) = &mut self.formats else {
+                return;
+            };
+
+            let result = formats.set_image(arboard::ImageData {
+                width: image.width as usize,
+                height: image.height as usize,
+                bytes: Vec::from(image.pixels).into(),
+            });
+
+            if let Err(error) = result {
+                log::warn!("error writing image to clipboard: {error}");
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        let _ = image;
+    }
+
+    /// Reads the current content of the [`Clipboard`] as HTML.
+    pub fn read_html(&mut self) -> Option<String> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.formats.as_mut()?.get().html().ok()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        None
+    }
+
+    /// Writes the given HTML to the [`Clipboard`], together with a plain
+    /// text alternative.
+    pub fn write_html(&mut self, html: String, alt_text: String) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(formats) = &mut self.formats else {
+                return;
+            };
+
+            if let Err(error) = formats.set_html(html, Some(alt_text)) {
+                log::warn!("error writing HTML to clipboard: {error}");
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        let _ = (html, alt_text);
+    }
+
+    /// Reads the current content of the [`Clipboard`] as a list of file
+    /// paths.
+    pub fn read_files(&mut self) -> Option<Vec<PathBuf>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.formats.as_mut()?.get().file_list().ok()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        None
+    }
+
+    /// Writes the given list of file paths to the [`Clipboard`].
+    pub fn write_files(&mut self, paths: Vec<PathBuf>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(formats) = &mut self.formats else {
+                return;
+            };
+
+            if let Err(error) = formats.set().file_list(&paths) {
+                log::warn!("error writing files to clipboard: {error}");
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        let _ = paths;
+    }
+
+    /// Reads the current content of the [`Clipboard`] with the given MIME
+    /// type.
+    pub fn read_data(&mut self, mime: &str) -> Option<Vec<u8>> {
+        match mime {
+            "text/plain" | "text/plain;charset=utf-8" | "UTF8_STRING" => {
+                self.read(Kind::Standard).map(String::into_bytes)
+            }
+            "text/html" => self.read_html().map(String::into_bytes),
+            _ => self.custom.read(mime),
+        }
+    }
+
+    /// Writes the given data with the given MIME type to the [`Clipboard`].
+    ///
+    /// Other applications can read the data if they ask for the same MIME
+    /// type; or its registered clipboard format, on Windows.
+    pub fn write_data(&mut self, mime: String, bytes: Vec<u8>) {
+        match mime.as_str() {
+            "text/plain" | "text/plain;charset=utf-8" | "UTF8_STRING" => {
+                match String::from_utf8(bytes) {
+                    Ok(text) => self.write(Kind::Standard, text),
+                    Err(error) => {
+                        log::warn!("error writing text to clipboard: {error}");
+                    }
+                }
+            }
+            "text/html" => match String::from_utf8(bytes) {
+                Ok(html) => {
+                    let alt_text = plain_text(&html);
+
+                    self.write_html(html, alt_text);
+                }
+                Err(error) => {
+                    log::warn!("error writing HTML to clipboard: {error}");
+                }
+            },
+            _ => {
+                if let Err(error) = self.custom.write(&mime, bytes) {
+                    log::warn!("error writing {mime} to clipboard: {error}");
+                }
+            }
+        }
+    }
+
     /// Returns the identifier of the window used to create the [`Clipboard`], if any.
     pub fn window_id(&self) -> Option<WindowId> {
         match &self.state {
@@ -102,3 +264,368 @@ impl crate::core::Clipboard for Clipboard {
         self.write(kind, contents);
     }
 }
+
+/// Returns the text of the given HTML, without its markup.
+fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+
+        rest = match rest[start..].find('>') {
+            Some(end) => &rest[start + end + 1..],
+            None => "",
+        };
+    }
+
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+mod custom {
+    use winit::raw_window_handle::{HasDisplayHandle, RawDisplayHandle};
+    use winit::window::Window;
+
+    use std::io::Read;
+    use std::time::Duration;
+
+    /// The clipboard of custom formats, served by the X11 or Wayland
+    /// selection of the display of the application.
+    pub enum Clipboard {
+        X11(x11_clipboard::Clipboard),
+        Wayland,
+        Unavailable,
+    }
+
+    impl Clipboard {
+        pub fn new(window: &Window) -> Self {
+            match window.display_handle().map(|handle| handle.as_raw()) {
+                Ok(RawDisplayHandle::Wayland(_)) => Self::Wayland,
+                Ok(RawDisplayHandle::Xlib(_) | RawDisplayHandle::Xcb(_)) => {
+                    x11_clipboard::Clipboard::new()
+                        .map(Self::X11)
+                        .unwrap_or_else(|error| {
+                            log::warn!(
+                                "error connecting to X11 clipboard: {error}"
+                            );
+
+                            Self::Unavailable
+                        })
+                }
+                _ => Self::Unavailable,
+            }
+        }
+
+        pub fn unavailable() -> Self {
+            Self::Unavailable
+        }
+
+        pub fn read(&mut self, mime: &str) -> Option<Vec<u8>> {
+            match self {
+                Self::X11(clipboard) => {
+                    let target = clipboard.getter.get_atom(mime).ok()?;
+
+                    clipboard
+                        .load(
+                            clipboard.getter.atoms.clipboard,
+                            target,
+                            clipboard.getter.atoms.property,
+                            Duration::from_secs(1),
+                        )
+                        .ok()
+                }
+                Self::Wayland => {
+                    use wl_clipboard_rs::paste;
+
+                    let (mut pipe, _) = paste::get_contents(
+                        paste::ClipboardType::Regular,
+                        paste::Seat::Unspecified,
+                        paste::MimeType::Specific(mime),
+                    )
+                    .ok()?;
+
+                    let mut bytes = Vec::new();
+                    let _ = pipe.read_to_end(&mut bytes).ok()?;
+
+                    Some(bytes)
+                }
+                Self::Unavailable => None,
+            }
+        }
+
+        pub fn write(
+            &mut self,
+            mime: &str,
+            bytes: Vec<u8>,
+        ) -> Result<(), String> {
+            match self {
+                Self::X11(clipboard) => {
+                    let target = clipboard
+                        .setter
+                        .get_atom(mime)
+                        .map_err(|error| error.to_string())?;
+
+                    clipboard
+                        .store(clipboard.setter.atoms.clipboard, target, bytes)
+                        .map_err(|error| error.to_string())
+                }
+                Self::Wayland => {
+                    use wl_clipboard_rs::copy;
+
+                    copy::Options::new()
+                        .copy(
+                            copy::Source::Bytes(bytes.into_boxed_slice()),
+                            copy::MimeType::Specific(mime.to_owned()),
+                        )
+                        .map_err(|error| error.to_string())
+                }
+                Self::Unavailable => Err(String::from("clipboard unavailable")),
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod custom {
+    use objc2_app_kit::NSPasteboard;
+    use objc2_foundation::{NSData, NSString};
+    use winit::window::Window;
+
+    /// The clipboard of custom formats, backed by the general pasteboard;
+    /// where MIME types are used as pasteboard types.
+    pub struct Clipboard;
+
+    impl Clipboard {
+        pub fn new(_window: &Window) -> Self {
+            Self
+        }
+
+        pub fn unavailable() -> Self {
+            Self
+        }
+
+        #[allow(unsafe_code)]
+        pub fn read(&mut self, mime: &str) -> Option<Vec<u8>> {
+            let kind = NSString::from_str(mime);
+
+            // SAFETY: The general pasteboard is thread-safe, and `kind` is
+            // a valid pasteboard type.
+            let data = unsafe {
+                NSPasteboard::generalPasteboard().dataForType(&kind)
+            }?;
+
+            Some(data.bytes().to_vec())
+        }
+
+        #[allow(unsafe_code)]
+        pub fn write(
+            &mut self,
+            mime: &str,
+            bytes: Vec<u8>,
+        ) -> Result<(), String> {
+            let kind = NSString::from_str(mime);
+            let data = NSData::with_bytes(&bytes);
+
+            // SAFETY: The general pasteboard is thread-safe, and `kind` is
+            // a valid pasteboard type.
+            let is_written = unsafe {
+                let pasteboard = NSPasteboard::generalPasteboard();
+                let _ = pasteboard.clearContents();
+
+                pasteboard.setData_forType(Some(&data), &kind)
+            };
+
+            if is_written {
+                Ok(())
+            } else {
+                Err(String::from("pasteboard rejected the data"))
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod custom {
+    use windows_sys::Win32::System::DataExchange::{
+        CloseClipboard, EmptyClipboard, GetClipboardData, OpenClipboard,
+        RegisterClipboardFormatW, SetClipboardData,
+    };
+    use windows_sys::Win32::System::Memory::{
+        GMEM_MOVEABLE, GlobalAlloc, GlobalFree, GlobalLock, GlobalSize,
+        GlobalUnlock,
+    };
+    use winit::window::Window;
+
+    use std::ptr;
+
+    /// The clipboard of custom formats, where MIME types are registered as
+    /// clipboard formats.
+    pub struct Clipboard;
+
+    impl Clipboard {
+        pub fn new(_window: &Window) -> Self {
+            Self
+        }
+
+        pub fn unavailable() -> Self {
+            Self
+        }
+
+        #[allow(unsafe_code)]
+        pub fn read(&mut self, mime: &str) -> Option<Vec<u8>> {
+            let format = register(mime)?;
+
+            // SAFETY: The clipboard is opened and closed in this scope, and
+            // its data is only accessed while locked.
+            unsafe {
+                if OpenClipboard(ptr::null_mut()) == 0 {
+                    return None;
+                }
+
+                let handle = GetClipboardData(format);
+
+                let bytes = if handle.is_null() {
+                    None
+                } else {
+                    let data = GlobalLock(handle);
+
+                    if data.is_null() {
+                        None
+                    } else {
+                        let bytes = std::slice::from_raw_parts(
+                            data.cast::<u8>(),
+                            GlobalSize(handle),
+                        )
+                        .to_vec();
+
+                        let _ = GlobalUnlock(handle);
+
+                        Some(bytes)
+                    }
+                };
+
+                let _ = CloseClipboard();
+
+                bytes
+            }
+        }
+
+        #[allow(unsafe_code)]
+        pub fn write(
+            &mut self,
+            mime: &str,
+            bytes: Vec<u8>,
+        ) -> Result<(), String> {
+            let format = register(mime)
+                .ok_or_else(|| String::from("invalid clipboard format"))?;
+
+            // SAFETY: The clipboard is opened and closed in this scope, and
+            // the allocated memory is owned by the clipboard once set.
+            unsafe {
+                let handle = GlobalAlloc(GMEM_MOVEABLE, bytes.len().max(1));
+
+                if handle.is_null() {
+                    return Err(String::from("out of memory"));
+                }
+
+                let data = GlobalLock(handle);
+
+                if data.is_null() {
+                    let _ = GlobalFree(handle);
+
+                    return Err(String::from("out of memory"));
+                }
+
+                ptr::copy_nonoverlapping(
+                    bytes.as_ptr(),
+                    data.cast::<u8>(),
+                    bytes.len(),
+                );
+
+                let _ = GlobalUnlock(handle);
+
+                if OpenClipboard(ptr::null_mut()) == 0 {
+                    let _ = GlobalFree(handle);
+
+                    return Err(String::from("clipboard is busy"));
+                }
+
+                let _ = EmptyClipboard();
+                let is_set = !SetClipboardData(format, handle).is_null();
+
+                let _ = CloseClipboard();
+
+                if is_set {
+                    Ok(())
+                } else {
+                    let _ = GlobalFree(handle);
+
+                    Err(String::from("clipboard rejected the data"))
+                }
+            }
+        }
+    }
+
+    #[allow(unsafe_code)]
+    fn register(mime: &str) -> Option<u32> {
+        let name: Vec<u16> =
+            mime.encode_utf16().chain(std::iter::once(0)).collect();
+
+        // SAFETY: `name` is a null-terminated UTF-16 string.
+        let format = unsafe { RegisterClipboardFormatW(name.as_ptr()) };
+
+        (format != 0).then_some(format)
+    }
+}
+
+#[cfg(not(any(
+    all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android"
+        ))
+    ),
+    target_os = "macos",
+    target_os = "windows",
+)))]
+mod custom {
+    use winit::window::Window;
+
+    /// Custom formats are not supported on this platform.
+    pub struct Clipboard;
+
+    impl Clipboard {
+        pub fn new(_window: &Window) -> Self {
+            Self
+        }
+
+        pub fn unavailable() -> Self {
+            Self
+        }
+
+        pub fn read(&mut self, _mime: &str) -> Option<Vec<u8>> {
+            None
+        }
+
+        pub fn write(
+            &mut self,
+            _mime: &str,
+            _bytes: Vec<u8>,
+        ) -> Result<(), String> {
+            Err(String::from("custom formats are not supported"))
+        }
+    }
+}
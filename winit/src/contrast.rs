@@ -0,0 +1,77 @@
+//! Detect the high contrast preference of the native system.
+use std::sync::atomic::{self, AtomicU8};
+
+const UNKNOWN: u8 = 0;
+const DISABLED: u8 = 1;
+const ENABLED: u8 = 2;
+
+static PREFERENCE: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+/// Returns true if the user has enabled the high contrast mode of
+/// the system.
+///
+/// The preference is detected once and cached until [`refresh`] is called.
+pub fn is_high_contrast() -> bool {
+    match PREFERENCE.load(atomic::Ordering::Relaxed) {
+        UNKNOWN => refresh(),
+        preference => preference == ENABLED,
+    }
+}
+
+/// Detects the high contrast mode of the system again, caching the result.
+///
+/// This should only be called when the system reports a change.
+pub fn refresh() -> bool {
+    let is_high_contrast = detect();
+
+    PREFERENCE.store(
+        if is_high_contrast { ENABLED } else { DISABLED },
+        atomic::Ordering::Relaxed,
+    );
+
+    is_high_contrast
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> bool {
+    use windows_sys::Win32::UI::Accessibility::{
+        HCF_HIGHCONTRASTON, HIGHCONTRASTW,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        SPI_GETHIGHCONTRAST, SystemParametersInfoW,
+    };
+
+    let mut high_contrast = HIGHCONTRASTW {
+        cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+        dwFlags: 0,
+        lpszDefaultScheme: std::ptr::null_mut(),
+    };
+
+    // SAFETY: The pointer refers to a properly sized `HIGHCONTRASTW`
+    // structure that lives for the whole call.
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETHIGHCONTRAST,
+            high_contrast.cbSize,
+            (&raw mut high_contrast).cast(),
+            0,
+        )
+    };
+
+    result != 0 && high_contrast.dwFlags & HCF_HIGHCONTRASTON != 0
+}
+
+/// Desktop environments do not share a common setting; so the GTK theme
+/// is used as a hint.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect() -> bool {
+    std::env::var("GTK_THEME")
+        .is_ok_and(|theme| theme.to_ascii_lowercase().contains("highcontrast"))
+}
+
+/// Detection is not supported on this platform yet.
+#[cfg(not(any(target_os = "windows", all(unix, not(target_os = "macos")))))]
+fn detect() -> bool {
+    false
+}
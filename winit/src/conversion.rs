@@ -5,9 +5,10 @@
 use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::system;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, Point, Rectangle, Size};
 
 /// Converts some [`window::Settings`] into some `WindowAttributes` from `winit`.
 pub fn window_attributes(
@@ -18,11 +19,18 @@ pub fn window_attributes(
 ) -> winit::window::WindowAttributes {
     let mut attributes = winit::window::WindowAttributes::default();
 
+    let size = match settings.aspect_ratio {
+        Some(ratio) if ratio > 0.0 => {
+            Size::new(settings.size.width, settings.size.width / ratio)
+        }
+        _ => settings.size,
+    };
+
     attributes = attributes
         .with_title(title)
         .with_inner_size(winit::dpi::LogicalSize {
-            width: settings.size.width,
-            height: settings.size.height,
+            width: size.width,
+            height: size.height,
         })
         .with_maximized(settings.maximized)
         .with_fullscreen(
@@ -44,7 +52,7 @@ pub fn window_attributes(
         .with_visible(settings.visible);
 
     if let Some(position) =
-        position(primary_monitor.as_ref(), settings.size, settings.position)
+        position(primary_monitor.as_ref(), size, settings.position)
     {
         attributes = attributes.with_position(position);
     }
@@ -63,6 +71,14 @@ pub fn window_attributes(
         });
     }
 
+    if let Some(increments) = settings.resize_increments {
+        attributes =
+            attributes.with_resize_increments(winit::dpi::LogicalSize {
+                width: increments.width,
+                height: increments.height,
+            });
+    }
+
     #[cfg(any(
         target_os = "dragonfly",
         target_os = "freebsd",
@@ -88,7 +104,7 @@ pub fn window_attributes(
             .with_skip_taskbar(settings.platform_specific.skip_taskbar);
 
         attributes = attributes.with_undecorated_shadow(
-            settings.platform_specific.undecorated_shadow,
+            settings.shadow || settings.platform_specific.undecorated_shadow,
         );
     }
 
@@ -103,7 +119,13 @@ pub fn window_attributes(
             )
             .with_fullsize_content_view(
                 settings.platform_specific.fullsize_content_view,
-            );
+            )
+            .with_has_shadow(settings.decorations || settings.shadow);
+
+        if let Some(identifier) = &settings.platform_specific.tabbing_identifier
+        {
+            attributes = attributes.with_tabbing_identifier(identifier);
+        }
     }
 
     #[cfg(target_os = "linux")]
@@ -112,14 +134,28 @@ pub fn window_attributes(
         {
             use winit::platform::x11::WindowAttributesExtX11;
 
+            let platform = &settings.platform_specific;
+
             attributes = attributes
-                .with_override_redirect(
-                    settings.platform_specific.override_redirect,
-                )
+                .with_override_redirect(platform.override_redirect)
                 .with_name(
-                    &settings.platform_specific.application_id,
-                    &settings.platform_specific.application_id,
+                    &platform.application_id,
+                    platform
+                        .wm_instance
+                        .as_ref()
+                        .unwrap_or(&platform.application_id),
+                );
+
+            if !platform.window_type.is_empty() {
+                attributes = attributes.with_x11_window_type(
+                    platform
+                        .window_type
+                        .iter()
+                        .copied()
+                        .map(x11_window_type)
+                        .collect(),
                 );
+            }
         }
         #[cfg(feature = "wayland")]
         {
@@ -135,6 +171,48 @@ pub fn window_attributes(
     attributes
 }
 
+/// Converts a [`window::settings::WindowType`] into its X11 counterpart.
+#[cfg(all(target_os = "linux", feature = "x11"))]
+pub fn x11_window_type(
+    window_type: window::settings::WindowType,
+) -> winit::platform::x11::WindowType {
+    use window::settings::WindowType;
+    use winit::platform::x11;
+
+    match window_type {
+        WindowType::Desktop => x11::WindowType::Desktop,
+        WindowType::Dock => x11::WindowType::Dock,
+        WindowType::Toolbar => x11::WindowType::Toolbar,
+        WindowType::Menu => x11::WindowType::Menu,
+        WindowType::Utility => x11::WindowType::Utility,
+        WindowType::Splash => x11::WindowType::Splash,
+        WindowType::Dialog => x11::WindowType::Dialog,
+        WindowType::DropdownMenu => x11::WindowType::DropdownMenu,
+        WindowType::PopupMenu => x11::WindowType::PopupMenu,
+        WindowType::Tooltip => x11::WindowType::Tooltip,
+        WindowType::Notification => x11::WindowType::Notification,
+        WindowType::Combo => x11::WindowType::Combo,
+        WindowType::Dnd => x11::WindowType::Dnd,
+        WindowType::Normal => x11::WindowType::Normal,
+    }
+}
+
+/// Converts the theme of a winit window into a system [`Appearance`].
+///
+/// [`Appearance`]: system::Appearance
+pub fn appearance(theme: Option<winit::window::Theme>) -> system::Appearance {
+    system::Appearance {
+        mode: match theme {
+            Some(winit::window::Theme::Light) => system::Mode::Light,
+            Some(winit::window::Theme::Dark) => system::Mode::Dark,
+            None => system::Mode::Unspecified,
+        },
+        accent_color: crate::accent::color(),
+        high_contrast: crate::contrast::is_high_contrast(),
+        reduced_motion: crate::motion::is_reduced(),
+    }
+}
+
 /// Converts a winit window event into an iced event.
 pub fn window_event(
     event: winit::event::WindowEvent,
@@ -285,6 +363,9 @@ pub fn window_event(
                 self::modifiers(new_modifiers.state()),
             )))
         }
+        WindowEvent::ThemeChanged(theme) => Some(Event::System(
+            system::Event::AppearanceChanged(appearance(Some(theme))),
+        )),
         WindowEvent::Ime(event) => Some(Event::InputMethod(match event {
             Ime::Enabled => input_method::Event::Opened,
             Ime::Preedit(content, size) => input_method::Event::Preedit(
@@ -336,6 +417,9 @@ pub fn window_level(level: window::Level) -> winit::window::WindowLevel {
 
 /// Converts a [`window::Position`] to a [`winit`] logical position for a given monitor.
 ///
+/// Positions relative to other windows or the mouse cursor must be resolved
+/// beforehand; otherwise, they fall back to the given monitor.
+///
 /// [`winit`]: https://github.com/rust-windowing/winit
 pub fn position(
     monitor: Option<&winit::monitor::MonitorHandle>,
@@ -379,7 +463,28 @@ pub fn position(
                 None
             }
         }
-        window::Position::Centered => {
+        window::Position::OnMonitor(_, position) => {
+            if let Some(monitor) = monitor {
+                let start: winit::dpi::LogicalPosition<f32> =
+                    monitor.position().to_logical(monitor.scale_factor());
+
+                Some(winit::dpi::Position::Logical(
+                    winit::dpi::LogicalPosition {
+                        x: f64::from(start.x + position.x),
+                        y: f64::from(start.y + position.y),
+                    },
+                ))
+            } else {
+                Some(winit::dpi::Position::Logical(
+                    winit::dpi::LogicalPosition {
+                        x: f64::from(position.x),
+                        y: f64::from(position.y),
+                    },
+                ))
+            }
+        }
+        window::Position::AtCursor | window::Position::RelativeTo(..) => None,
+        window::Position::Centered | window::Position::CenteredOnParent(_) => {
             if let Some(monitor) = monitor {
                 let start = monitor.position();
 
@@ -418,13 +523,56 @@ pub fn fullscreen(
         window::Mode::Fullscreen => {
             Some(winit::window::Fullscreen::Borderless(monitor))
         }
+        window::Mode::ExclusiveFullscreen(video_mode) => {
+            let closest = monitor.as_ref().and_then(|monitor| {
+                monitor.video_modes().min_by_key(|candidate| {
+                    let candidate = self::video_mode(candidate);
+
+                    (
+                        candidate.size.width.abs_diff(video_mode.size.width)
+                            + candidate
+                                .size
+                                .height
+                                .abs_diff(video_mode.size.height),
+                        candidate
+                            .refresh_rate_millihertz
+                            .abs_diff(video_mode.refresh_rate_millihertz),
+                        candidate.bit_depth.abs_diff(video_mode.bit_depth),
+                    )
+                })
+            });
+
+            Some(match closest {
+                Some(video_mode) => {
+                    winit::window::Fullscreen::Exclusive(video_mode)
+                }
+                None => winit::window::Fullscreen::Borderless(monitor),
+            })
+        }
+    }
+}
+
+/// Converts a [`winit`] video mode into a [`window::VideoMode`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn video_mode(
+    video_mode: &winit::monitor::VideoModeHandle,
+) -> window::VideoMode {
+    let size = video_mode.size();
+
+    window::VideoMode {
+        size: Size::new(size.width, size.height),
+        bit_depth: video_mode.bit_depth(),
+        refresh_rate_millihertz: video_mode.refresh_rate_millihertz(),
     }
 }
 
 /// Converts a [`window::Mode`] to a visibility flag.
 pub fn visible(mode: window::Mode) -> bool {
     match mode {
-        window::Mode::Windowed | window::Mode::Fullscreen => true,
+        window::Mode::Windowed
+        | window::Mode::Fullscreen
+        | window::Mode::ExclusiveFullscreen(_) => true,
         window::Mode::Hidden => false,
     }
 }
@@ -435,7 +583,12 @@ pub fn visible(mode: window::Mode) -> bool {
 pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     match mode {
         None => window::Mode::Windowed,
-        Some(_) => window::Mode::Fullscreen,
+        Some(winit::window::Fullscreen::Borderless(_)) => {
+            window::Mode::Fullscreen
+        }
+        Some(winit::window::Fullscreen::Exclusive(video_mode)) => {
+            window::Mode::ExclusiveFullscreen(self::video_mode(&video_mode))
+        }
     }
 }
 
@@ -448,7 +601,7 @@ pub fn mouse_interaction(
     use mouse::Interaction;
 
     match interaction {
-        Interaction::None | Interaction::Idle => {
+        Interaction::None | Interaction::Idle | Interaction::Custom(_) => {
             winit::window::CursorIcon::Default
         }
         Interaction::Pointer => winit::window::CursorIcon::Pointer,
@@ -1180,6 +1333,35 @@ pub fn icon(icon: window::Icon) -> Option<winit::window::Icon> {
     winit::window::Icon::from_rgba(pixels, size.width, size.height).ok()
 }
 
+/// Converts a `winit` monitor handle into a [`window::Monitor`].
+pub fn monitor(
+    monitor: &winit::monitor::MonitorHandle,
+    primary: Option<&winit::monitor::MonitorHandle>,
+) -> window::Monitor {
+    let scale_factor = monitor.scale_factor();
+
+    let position: winit::dpi::LogicalPosition<f32> =
+        monitor.position().to_logical(scale_factor);
+
+    let size: winit::dpi::LogicalSize<f32> =
+        monitor.size().to_logical(scale_factor);
+
+    window::Monitor {
+        name: monitor.name(),
+        bounds: Rectangle {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        },
+        scale_factor: scale_factor as f32,
+        refresh_rate: monitor
+            .refresh_rate_millihertz()
+            .map(|millihertz| millihertz as f32 / 1000.0),
+        is_primary: primary == Some(monitor),
+    }
+}
+
 /// Convertions some [`input_method::Purpose`] to its `winit` counterpart.
 pub fn ime_purpose(
     purpose: input_method::Purpose,
@@ -0,0 +1,146 @@
+//! Drag data out of the application.
+use crate::futures::futures::channel::oneshot;
+use crate::runtime::dnd::{Outcome, Payload, Preview};
+
+/// Starts dragging the given [`Payload`] out of the given window.
+#[cfg(all(feature = "dnd", any(target_os = "windows", target_os = "macos")))]
+pub fn start(
+    window: &winit::window::Window,
+    payload: Payload,
+    preview: Option<Preview>,
+    channel: oneshot::Sender<Outcome>,
+) {
+    use std::sync::{Arc, Mutex};
+
+    let item = match payload {
+        Payload::Files(paths) => drag::DragItem::Files(paths),
+    };
+
+    let image = match preview.as_ref().map(encode).unwrap_or_else(blank) {
+        Ok(image) => drag::Image::Raw(image),
+        Err(error) => {
+            log::warn!("Invalid drag preview: {error}");
+
+            let _ = channel.send(Outcome::Canceled);
+            return;
+        }
+    };
+
+    // The outcome is sent only once; even if the drag fails to start
+    let channel = Arc::new(Mutex::new(Some(channel)));
+
+    let send = move |outcome: Outcome| {
+        if let Some(channel) = channel.lock().expect("Lock drag channel").take()
+        {
+            let _ = channel.send(outcome);
+        }
+    };
+
+    let result = drag::start_drag(
+        window,
+        item,
+        image,
+        {
+            let send = send.clone();
+
+            move |result, _position| {
+                send(match result {
+                    drag::DragResult::Dropped => Outcome::Dropped,
+                    drag::DragResult::Cancel => Outcome::Canceled,
+                });
+            }
+        },
+        drag::Options::default(),
+    );
+
+    if let Err(error) = result {
+        log::warn!("Failed to start drag: {error}");
+
+        send(Outcome::Canceled);
+    }
+}
+
+/// Starts dragging the given [`Payload`] out of the given window.
+#[cfg(not(all(
+    feature = "dnd",
+    any(target_os = "windows", target_os = "macos")
+)))]
+pub fn start(
+    _window: &winit::window::Window,
+    _payload: Payload,
+    _preview: Option<Preview>,
+    channel: oneshot::Sender<Outcome>,
+) {
+    log::warn!("Dragging out of the application is not supported");
+
+    let _ = channel.send(Outcome::Unsupported);
+}
+
+/// Encodes the [`Preview`] as a PNG image.
+#[cfg(all(feature = "dnd", any(target_os = "windows", target_os = "macos")))]
+fn encode(preview: &Preview) -> Result<Vec<u8>, png::EncodingError> {
+    let (width, height, pixels) = prepare(preview);
+
+    let mut bytes = Vec::new();
+
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+
+    Ok(bytes)
+}
+
+/// Applies the opacity of the [`Preview`] to its pixels and pads them to
+/// move its hotspot to the center; since the platforms center the drag
+/// image under the cursor.
+#[cfg(all(feature = "dnd", any(target_os = "windows", target_os = "macos")))]
+fn prepare(preview: &Preview) -> (u32, u32, Vec<u8>) {
+    if preview.width == 0 || preview.height == 0 {
+        return (1, 1, vec![0; 4]);
+    }
+
+    let hotspot_x =
+        preview.hotspot.x.round().clamp(0.0, preview.width as f32) as u32;
+    let hotspot_y =
+        preview.hotspot.y.round().clamp(0.0, preview.height as f32) as u32;
+
+    let half_width = hotspot_x.max(preview.width - hotspot_x);
+    let half_height = hotspot_y.max(preview.height - hotspot_y);
+
+    let width = half_width * 2;
+    let height = half_height * 2;
+    let left = (half_width - hotspot_x) as usize;
+    let top = (half_height - hotspot_y) as usize;
+
+    let mut pixels = vec![0; width as usize * height as usize * 4];
+
+    let rows = preview
+        .pixels
+        .chunks_exact(preview.width as usize * 4)
+        .take(preview.height as usize);
+
+    for (y, row) in rows.enumerate() {
+        let start = ((top + y) * width as usize + left) * 4;
+        let target = &mut pixels[start..start + row.len()];
+
+        for (target, source) in
+            target.chunks_exact_mut(4).zip(row.chunks_exact(4))
+        {
+            target[..3].copy_from_slice(&source[..3]);
+            target[3] = (f32::from(source[3]) * preview.opacity).round() as u8;
+        }
+    }
+
+    (width, height, pixels)
+}
+
+/// Encodes a single transparent pixel; since the platforms always need an
+/// image to drag.
+#[cfg(all(feature = "dnd", any(target_os = "windows", target_os = "macos")))]
+fn blank() -> Result<Vec<u8>, png::EncodingError> {
+    encode(&Preview::from_rgba(1, 1, vec![0; 4]))
+}
@@ -32,8 +32,21 @@ pub mod conversion;
 #[cfg(feature = "system")]
 pub mod system;
 
+mod accent;
+mod accessibility;
+mod backdrop;
+mod contrast;
+mod dnd;
 mod error;
+mod modal;
+mod motion;
+mod persistence;
+mod pip;
+mod popup;
 mod proxy;
+mod taskbar;
+mod tiling;
+mod tray;
 mod window;
 
 pub use clipboard::Clipboard;
@@ -41,11 +54,10 @@ pub use error::Error;
 pub use proxy::Proxy;
 
 use crate::core::mouse;
-use crate::core::renderer;
 use crate::core::theme;
 use crate::core::time::Instant;
 use crate::core::widget::operation;
-use crate::core::{Point, Settings, Size};
+use crate::core::{Point, Renderer as _, Settings, Size};
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::task;
@@ -53,6 +65,7 @@ use crate::futures::futures::{Future, StreamExt};
 use crate::futures::subscription;
 use crate::futures::{Executor, Runtime};
 use crate::graphics::{Compositor, compositor};
+use crate::modal::Modal;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::{Action, Task};
 
@@ -106,8 +119,10 @@ where
         task
     };
 
-    if let Some(stream) = runtime::task::into_stream(task) {
-        runtime.run(stream);
+    if let Some((stream, priority)) =
+        runtime::task::into_stream_with_priority(task)
+    {
+        runtime.run_with_priority(stream, priority);
     }
 
     runtime.track(subscription::into_recipes(
@@ -126,6 +141,7 @@ where
         is_daemon,
         graphics_settings,
         settings.fonts,
+        settings.frame_rate,
     ));
 
     let context = task::Context::from_waker(task::noop_waker_ref());
@@ -137,6 +153,7 @@ where
         sender: mpsc::UnboundedSender<Event<Action<Message>>>,
         receiver: mpsc::UnboundedReceiver<Control>,
         error: Option<Error>,
+        cursors: FxHashMap<mouse::Custom, Option<winit::window::CustomCursor>>,
 
         #[cfg(target_arch = "wasm32")]
         canvas: Option<web_sys::HtmlCanvasElement>,
@@ -149,6 +166,7 @@ where
         sender: event_sender,
         receiver: control_receiver,
         error: None,
+        cursors: FxHashMap::default(),
 
         #[cfg(target_arch = "wasm32")]
         canvas: None,
@@ -302,9 +320,27 @@ where
                                 title,
                                 monitor,
                                 on_open,
+                                parent,
+                                modal,
                             } => {
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
+                                let transition = settings.transition;
+                                let frame_rate = settings.frame_rate;
+                                let level = settings.level;
+                                let aspect_ratio = settings.aspect_ratio;
+                                let resize_border = settings.resize_border;
+                                let resizable_edges = settings.resizable_edges;
+                                let min_size = settings.min_size;
+                                let max_size = settings.max_size;
+                                let scale_factor = settings.scale_factor;
+                                let default_font = settings.default_font;
+                                let popups = settings.popups;
+                                let owner = settings.owner;
+                                let mut persistence = settings
+                                    .persistence
+                                    .clone()
+                                    .map(persistence::Persistence::new);
 
                                 let visible = settings.visible;
 
@@ -322,6 +358,22 @@ where
                                     )
                                     .with_visible(false);
 
+                                let window_attributes = match &mut persistence {
+                                    Some(persistence) => persistence.restore(
+                                        window_attributes,
+                                        event_loop.available_monitors(),
+                                    ),
+                                    None => window_attributes,
+                                };
+
+                                let window_attributes = match &parent {
+                                    Some(parent) => modal::attributes(
+                                        window_attributes,
+                                        parent,
+                                    ),
+                                    None => window_attributes,
+                                };
+
                                 #[cfg(target_arch = "wasm32")]
                                 let window_attributes = {
                                     use winit::platform::web::WindowAttributesExtWebSys;
@@ -343,15 +395,23 @@ where
                                 let position =
                                     window_attributes.position.take();
 
-                                let window = event_loop
-                                    .create_window(window_attributes)
-                                    .expect("Create window");
+                                let window = Arc::new(
+                                    event_loop
+                                        .create_window(window_attributes)
+                                        .expect("Create window"),
+                                );
 
                                 #[cfg(target_os = "macos")]
                                 if let Some(position) = position {
                                     window.set_outer_position(position);
                                 }
 
+                                // The adapter must be created before the
+                                // window is visible
+                                let accessibility = accessibility::Adapter::new(
+                                    event_loop, &window, &title,
+                                );
+
                                 #[cfg(target_arch = "wasm32")]
                                 {
                                     use winit::platform::web::WindowExtWebSys;
@@ -402,13 +462,89 @@ where
                                     event_loop,
                                     Event::WindowCreated {
                                         id,
-                                        window: Arc::new(window),
+                                        window,
                                         exit_on_close_request,
+                                        transition,
+                                        frame_rate,
+                                        level,
+                                        aspect_ratio,
+                                        resize_border,
+                                        resizable_edges,
+                                        min_size,
+                                        max_size,
+                                        scale_factor,
+                                        default_font,
+                                        popups,
+                                        owner,
+                                        accessibility,
+                                        persistence,
                                         make_visible: visible,
                                         on_open,
+                                        modal,
                                     },
                                 );
                             }
+                            Control::CreatePopup {
+                                id,
+                                owner,
+                                bounds,
+                                scale_factor,
+                            } => {
+                                match event_loop.create_window(
+                                    popup::attributes(
+                                        &owner,
+                                        bounds,
+                                        scale_factor,
+                                    ),
+                                ) {
+                                    Ok(window) => {
+                                        self.process_event(
+                                            event_loop,
+                                            Event::PopupCreated {
+                                                id,
+                                                window: Arc::new(window),
+                                                bounds,
+                                            },
+                                        );
+                                    }
+                                    Err(error) => {
+                                        log::warn!(
+                                            "Error creating popup window; \
+                                            drawing overlays inside of \
+                                            window {id:?}: {error}"
+                                        );
+                                    }
+                                }
+                            }
+                            Control::SetCursor { window, cursor } => {
+                                let custom = self
+                                    .cursors
+                                    .entry(cursor)
+                                    .or_insert_with(|| {
+                                        let data = cursor.data();
+
+                                        winit::window::CustomCursor::from_rgba(
+                                            data.rgba.clone(),
+                                            data.size.width as u16,
+                                            data.size.height as u16,
+                                            data.hotspot.x as u16,
+                                            data.hotspot.y as u16,
+                                        )
+                                        .inspect_err(|error| {
+                                            log::error!(
+                                                "Error creating custom cursor: {error}"
+                                            );
+                                        })
+                                        .ok()
+                                        .map(|source| {
+                                            event_loop.create_custom_cursor(source)
+                                        })
+                                    });
+
+                                if let Some(custom) = custom {
+                                    window.set_cursor(custom.clone());
+                                }
+                            }
                             Control::Exit => {
                                 event_loop.exit();
                             }
@@ -453,8 +589,28 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: Arc<winit::window::Window>,
         exit_on_close_request: bool,
+        transition: window::Transition,
+        frame_rate: Option<window::FrameRate>,
+        level: window::Level,
+        aspect_ratio: Option<f32>,
+        resize_border: f32,
+        resizable_edges: window::Edges,
+        min_size: Option<Size>,
+        max_size: Option<Size>,
+        scale_factor: Option<f64>,
+        default_font: Option<core::Font>,
+        popups: bool,
+        owner: Option<window::Id>,
+        accessibility: accessibility::Adapter,
+        persistence: Option<persistence::Persistence>,
         make_visible: bool,
-        on_open: oneshot::Sender<window::Id>,
+        on_open: Option<oneshot::Sender<window::Id>>,
+        modal: Option<Modal>,
+    },
+    PopupCreated {
+        id: window::Id,
+        window: Arc<winit::window::Window>,
+        bounds: core::Rectangle,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -464,12 +620,24 @@ enum Control {
     ChangeFlow(winit::event_loop::ControlFlow),
     Exit,
     Crash(Error),
+    SetCursor {
+        window: Arc<winit::window::Window>,
+        cursor: mouse::Custom,
+    },
     CreateWindow {
         id: window::Id,
         settings: window::Settings,
         title: String,
         monitor: Option<winit::monitor::MonitorHandle>,
-        on_open: oneshot::Sender<window::Id>,
+        on_open: Option<oneshot::Sender<window::Id>>,
+        parent: Option<Arc<winit::window::Window>>,
+        modal: Option<Modal>,
+    },
+    CreatePopup {
+        id: window::Id,
+        owner: Arc<winit::window::Window>,
+        bounds: core::Rectangle,
+        scale_factor: f64,
     },
 }
 
@@ -482,6 +650,7 @@ async fn run_instance<P>(
     is_daemon: bool,
     graphics_settings: graphics::Settings,
     default_fonts: Vec<Cow<'static, [u8]>>,
+    default_frame_rate: window::FrameRate,
 ) where
     P: Program + 'static,
     P::Theme: theme::Base,
@@ -494,12 +663,16 @@ async fn run_instance<P>(
 
     let mut compositor = None;
     let mut events = Vec::new();
+    let mut system_theme = None;
     let mut messages = Vec::new();
     let mut actions = 0;
 
     let mut ui_caches = FxHashMap::default();
     let mut user_interfaces = ManuallyDrop::new(FxHashMap::default());
     let mut clipboard = Clipboard::unconnected();
+    let mut debouncer = runtime::task::Debouncer::new();
+
+    crate::core::animation::reduce_motion(motion::is_reduced());
 
     loop {
         // Empty the queue if possible
@@ -518,8 +691,23 @@ async fn run_instance<P>(
                 id,
                 window,
                 exit_on_close_request,
+                transition,
+                frame_rate,
+                level,
+                aspect_ratio,
+                resize_border,
+                resizable_edges,
+                min_size,
+                max_size,
+                scale_factor,
+                default_font,
+                popups,
+                owner,
+                accessibility,
+                persistence,
                 make_visible,
                 on_open,
+                modal,
             } => {
                 if compositor.is_none() {
                     let (compositor_sender, compositor_receiver) =
@@ -595,8 +783,25 @@ async fn run_instance<P>(
                         .as_mut()
                         .expect("Compositor must be initialized"),
                     exit_on_close_request,
+                    transition,
+                    frame_rate.unwrap_or(default_frame_rate),
+                    level,
+                    aspect_ratio,
+                    resize_border,
+                    resizable_edges,
+                    min_size,
+                    max_size,
+                    scale_factor,
+                    default_font,
+                    popups,
+                    owner,
+                    accessibility,
+                    persistence,
+                    modal,
                 );
 
+                window.update_size_limits(window.size());
+
                 let logical_size = window.state.logical_size();
 
                 let _ = user_interfaces.insert(
@@ -627,9 +832,19 @@ async fn run_instance<P>(
                     clipboard = Clipboard::connect(window.raw.clone());
                 }
 
-                let _ = on_open.send(id);
+                if let Some(on_open) = on_open {
+                    let _ = on_open.send(id);
+                }
+
                 is_window_opening = false;
             }
+            Event::PopupCreated { id, window, bounds } => {
+                let Some(compositor) = &mut compositor else {
+                    continue;
+                };
+
+                window_manager.insert_popup(id, window, compositor, bounds);
+            }
             Event::EventLoopAwakened(event) => {
                 match event {
                     event::Event::NewEvents(event::StartCause::Init) => {
@@ -683,6 +898,7 @@ async fn run_instance<P>(
                             &mut events,
                             &mut messages,
                             &mut clipboard,
+                            &mut debouncer,
                             &mut control_sender,
                             &mut user_interfaces,
                             &mut window_manager,
@@ -706,6 +922,14 @@ async fn run_instance<P>(
                             continue;
                         };
 
+                        events.extend(
+                            window.accessibility.requests().into_iter().map(
+                                |request| {
+                                    (id, core::Event::Accessibility(request))
+                                },
+                            ),
+                        );
+
                         let physical_size = window.state.physical_size();
 
                         if physical_size.width == 0 || physical_size.height == 0
@@ -718,6 +942,10 @@ async fn run_instance<P>(
                         {
                             let logical_size = window.state.logical_size();
 
+                            window
+                                .renderer
+                                .hint(window.state.scale_factor() as f32);
+
                             let layout_span = debug::layout(id);
                             let ui = user_interfaces
                                 .remove(&id)
@@ -729,18 +957,17 @@ async fn run_instance<P>(
                             );
                             layout_span.finish();
 
-                            compositor.configure_surface(
-                                &mut window.surface,
-                                physical_size.width,
-                                physical_size.height,
-                            );
+                            window.configure_surface(compositor);
 
                             window.viewport_version =
                                 window.state.viewport_version();
                         }
 
+                        let now = Instant::now();
+                        window.last_redraw = Some(now);
+
                         let redraw_event = core::Event::Window(
-                            window::Event::RedrawRequested(Instant::now()),
+                            window::Event::RedrawRequested(now),
                         );
 
                         let cursor = window.state.cursor();
@@ -749,6 +976,8 @@ async fn run_instance<P>(
                             .get_mut(&id)
                             .expect("Get user interface");
 
+                        ui.set_overlay_bounds(window.overlay_bounds());
+
                         let draw_span = debug::draw(id);
                         let (ui_state, _) = ui.update(
                             &[redraw_event.clone()],
@@ -758,16 +987,51 @@ async fn run_instance<P>(
                             &mut messages,
                         );
 
-                        ui.draw(
-                            &mut window.renderer,
-                            window.state.theme(),
-                            &renderer::Style {
-                                text_color: window.state.text_color(),
+                        let has_popup = window.update_popup(
+                            id,
+                            ui.overlay_extent(),
+                            &mut control_sender,
+                        );
+
+                        window.draw_with_transition(
+                            now,
+                            |renderer, theme, style| {
+                                if has_popup {
+                                    ui.draw_base(
+                                        renderer, theme, style, cursor,
+                                    );
+                                } else {
+                                    ui.draw(renderer, theme, style, cursor);
+                                }
                             },
-                            cursor,
                         );
+                        window.draw_modal_overlay();
+
+                        if has_popup {
+                            window.draw_popup(
+                                compositor,
+                                |renderer, theme, style| {
+                                    ui.draw_overlay(
+                                        renderer, theme, style, cursor,
+                                    );
+                                },
+                            );
+                        }
+
                         draw_span.finish();
 
+                        window
+                            .accessibility
+                            .update(window.state.scale_factor(), |operation| {
+                                ui.operate(&window.renderer, operation)
+                            });
+
+                        if window.is_closed() {
+                            proxy.send_action(Action::Window(
+                                runtime::window::Action::Close(id),
+                            ));
+                        }
+
                         runtime.broadcast(subscription::Event::Interaction {
                             window: id,
                             event: redraw_event,
@@ -782,7 +1046,10 @@ async fn run_instance<P>(
                         {
                             window.request_redraw(redraw_request);
                             window.request_input_method(input_method);
-                            window.update_mouse(mouse_interaction);
+                            window.update_mouse(
+                                mouse_interaction,
+                                &mut control_sender,
+                            );
                         }
 
                         window.draw_preedit();
@@ -840,19 +1107,114 @@ async fn run_instance<P>(
                             continue;
                         }
 
+                        window_manager.follow_owner(window_id, &window_event);
+
                         let Some((id, window)) =
                             window_manager.get_mut_alias(window_id)
                         else {
                             continue;
                         };
 
-                        if matches!(
-                            window_event,
-                            winit::event::WindowEvent::Resized(_)
-                        ) {
+                        // Popups only forward pointer input to their owner
+                        let is_popup = window.raw.id() != window_id;
+
+                        let Some(window_event) =
+                            window.route_event(window_id, window_event)
+                        else {
+                            continue;
+                        };
+
+                        if !is_popup {
+                            window
+                                .accessibility
+                                .process_event(&window.raw, &window_event);
+                        }
+
+                        // Windows below a modal ignore any user input
+                        if let Some(modal) = window.modal {
+                            match window_event {
+                                winit::event::WindowEvent::MouseInput {
+                                    state: winit::event::ElementState::Pressed,
+                                    ..
+                                } => {
+                                    if let Some(modal) =
+                                        window_manager.get(modal)
+                                    {
+                                        modal.raw.focus_window();
+                                    }
+
+                                    continue;
+                                }
+                                winit::event::WindowEvent::MouseInput {
+                                    ..
+                                }
+                                | winit::event::WindowEvent::MouseWheel {
+                                    ..
+                                }
+                                | winit::event::WindowEvent::CursorMoved {
+                                    ..
+                                }
+                                | winit::event::WindowEvent::KeyboardInput {
+                                    ..
+                                }
+                                | winit::event::WindowEvent::Ime(_)
+                                | winit::event::WindowEvent::Touch(_)
+                                | winit::event::WindowEvent::DroppedFile(_)
+                                | winit::event::WindowEvent::HoveredFile(_) => {
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                        }
+
+                        if let winit::event::WindowEvent::Resized(size) =
+                            window_event
+                        {
+                            window.keep_aspect_ratio(size);
                             window.raw.request_redraw();
                         }
 
+                        if !is_popup
+                            && matches!(
+                                window_event,
+                                winit::event::WindowEvent::Resized(_)
+                                    | winit::event::WindowEvent::Moved(_)
+                            )
+                        {
+                            if let Some(persistence) = &mut window.persistence {
+                                persistence.track(&window.raw);
+                            }
+                        }
+
+                        match window_event {
+                            winit::event::WindowEvent::CursorMoved {
+                                position,
+                                ..
+                            } if !is_popup => {
+                                window.update_resize_border(Some(position));
+                            }
+                            winit::event::WindowEvent::CursorLeft {
+                                ..
+                            } => {
+                                window.update_resize_border(None);
+                            }
+                            winit::event::WindowEvent::MouseInput {
+                                state: winit::event::ElementState::Pressed,
+                                button: winit::event::MouseButton::Left,
+                                ..
+                            } if !is_popup => {
+                                if let Some(direction) = window.resize_direction
+                                {
+                                    let _ = window
+                                        .raw
+                                        .drag_resize_window(direction);
+
+                                    continue;
+                                }
+                            }
+                            _ => {}
+                        }
+
                         if matches!(
                             window_event,
                             winit::event::WindowEvent::CloseRequested
@@ -867,20 +1229,91 @@ async fn run_instance<P>(
                                 &mut events,
                                 &mut messages,
                                 &mut clipboard,
+                                &mut debouncer,
                                 &mut control_sender,
                                 &mut user_interfaces,
                                 &mut window_manager,
                                 &mut ui_caches,
                                 &mut is_window_opening,
                             );
+                        } else if matches!(
+                            window_event,
+                            winit::event::WindowEvent::CloseRequested
+                        ) && window.is_close_held
+                        {
+                            // The application is already handling a close
+                            // request; e.g. with a confirmation dialog
+                            continue;
                         } else {
                             window.state.update(&window.raw, &window_event);
 
+                            if let event::WindowEvent::Focused(is_focused) =
+                                window_event
+                            {
+                                window.is_focused = is_focused;
+
+                                // Some platforms (e.g. X11) do not clear
+                                // attention requests on their own
+                                if is_focused && window.is_requesting_attention
+                                {
+                                    window.raw.request_user_attention(None);
+                                    window.is_requesting_attention = false;
+                                }
+                            }
+
+                            // Accent colors change without notice; so they
+                            // are checked whenever a window gains focus
+                            if matches!(
+                                window_event,
+                                event::WindowEvent::Focused(true)
+                                    | event::WindowEvent::ThemeChanged(_)
+                            ) {
+                                let appearance = match window_event {
+                                    event::WindowEvent::ThemeChanged(theme) => {
+                                        let _ = contrast::refresh();
+                                        let _ = motion::refresh();
+
+                                        conversion::appearance(Some(theme))
+                                    }
+                                    _ => conversion::appearance(
+                                        window.raw.theme(),
+                                    ),
+                                };
+
+                                let theme =
+                                    (appearance.mode, appearance.accent_color);
+
+                                if system_theme
+                                    .replace(theme)
+                                    .is_some_and(|last| last != theme)
+                                {
+                                    events.push((
+                                        id,
+                                        crate::core::Event::System(
+                                            crate::core::system::Event::ThemeChanged(
+                                                appearance,
+                                            ),
+                                        ),
+                                    ));
+                                }
+                            }
+
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
+                                if let crate::core::Event::System(
+                                    crate::core::system::Event::AppearanceChanged(
+                                        appearance,
+                                    ),
+                                ) = event
+                                {
+                                    crate::core::animation::reduce_motion(
+                                        appearance.reduced_motion,
+                                    );
+                                }
+
                                 events.push((id, event));
                             }
                         }
@@ -917,16 +1350,19 @@ async fn run_instance<P>(
                                 continue;
                             }
 
-                            let (ui_state, statuses) = user_interfaces
+                            let ui = user_interfaces
                                 .get_mut(&id)
-                                .expect("Get user interface")
-                                .update(
-                                    &window_events,
-                                    window.state.cursor(),
-                                    &mut window.renderer,
-                                    &mut clipboard,
-                                    &mut messages,
-                                );
+                                .expect("Get user interface");
+
+                            ui.set_overlay_bounds(window.overlay_bounds());
+
+                            let (ui_state, statuses) = ui.update(
+                                &window_events,
+                                window.state.cursor(),
+                                &mut window.renderer,
+                                &mut clipboard,
+                                &mut messages,
+                            );
 
                             #[cfg(feature = "unconditional-rendering")]
                             window.request_redraw(
@@ -939,7 +1375,10 @@ async fn run_instance<P>(
                                     mouse_interaction,
                                     ..
                                 } => {
-                                    window.update_mouse(mouse_interaction);
+                                    window.update_mouse(
+                                        mouse_interaction,
+                                        &mut control_sender,
+                                    );
 
                                     #[cfg(not(
                                         feature = "unconditional-rendering"
@@ -1064,8 +1503,10 @@ fn update<P: Program, E: Executor>(
     for message in messages.drain(..) {
         let task = runtime.enter(|| program.update(message));
 
-        if let Some(stream) = runtime::task::into_stream(task) {
-            runtime.run(stream);
+        if let Some((stream, priority)) =
+            runtime::task::into_stream_with_priority(task)
+        {
+            runtime.run_with_priority(stream, priority);
         }
     }
 
@@ -1082,6 +1523,7 @@ fn run_action<P, C>(
     events: &mut Vec<(window::Id, core::Event)>,
     messages: &mut Vec<P::Message>,
     clipboard: &mut Clipboard,
+    debouncer: &mut runtime::task::Debouncer,
     control_sender: &mut mpsc::UnboundedSender<Control>,
     interfaces: &mut FxHashMap<
         window::Id,
@@ -1096,6 +1538,8 @@ fn run_action<P, C>(
     P::Theme: theme::Base,
 {
     use crate::runtime::clipboard;
+    use crate::runtime::dnd;
+    use crate::runtime::image;
     use crate::runtime::system;
     use crate::runtime::window;
 
@@ -1110,10 +1554,72 @@ fn run_action<P, C>(
             clipboard::Action::Write { target, contents } => {
                 clipboard.write(target, contents);
             }
+            clipboard::Action::ReadImage(channel) => {
+                let _ = channel.send(clipboard.read_image());
+            }
+            clipboard::Action::WriteImage(image) => {
+                clipboard.write_image(image);
+            }
+            clipboard::Action::ReadHtml(channel) => {
+                let _ = channel.send(clipboard.read_html());
+            }
+            clipboard::Action::WriteHtml { html, alt_text } => {
+                clipboard.write_html(html, alt_text);
+            }
+            clipboard::Action::ReadFiles(channel) => {
+                let _ = channel.send(clipboard.read_files());
+            }
+            clipboard::Action::WriteFiles(paths) => {
+                clipboard.write_files(paths);
+            }
+            clipboard::Action::ReadData { mime, channel } => {
+                let _ = channel.send(clipboard.read_data(&mime));
+            }
+            clipboard::Action::WriteData { mime, bytes } => {
+                clipboard.write_data(mime, bytes);
+            }
         },
         Action::Window(action) => match action {
-            window::Action::Open(id, settings, channel) => {
-                let monitor = window_manager.last_monitor();
+            window::Action::Open(id, mut settings, channel) => {
+                let (position, monitor) = window_manager
+                    .resolve_position(settings.position, settings.size);
+
+                settings.position = position;
+
+                let owner = settings
+                    .owner
+                    .and_then(|owner| window_manager.get(owner))
+                    .map(|owner| owner.raw.clone());
+
+                control_sender
+                    .start_send(Control::CreateWindow {
+                        id,
+                        settings,
+                        title: program.title(id),
+                        monitor,
+                        on_open: Some(channel),
+                        parent: owner,
+                        modal: None,
+                    })
+                    .expect("Send control action");
+
+                *is_window_opening = true;
+            }
+            window::Action::OpenModal(id, parent, mut settings, channel) => {
+                let Some(parent_window) = window_manager.get_mut(parent) else {
+                    let _ = channel.send(None);
+                    return;
+                };
+
+                parent_window.modal = Some(id);
+                parent_window.raw.request_redraw();
+
+                let parent_raw = parent_window.raw.clone();
+
+                let (position, monitor) = window_manager
+                    .resolve_position(settings.position, settings.size);
+
+                settings.position = position;
 
                 control_sender
                     .start_send(Control::CreateWindow {
@@ -1121,17 +1627,56 @@ fn run_action<P, C>(
                         settings,
                         title: program.title(id),
                         monitor,
-                        on_open: channel,
+                        on_open: None,
+                        parent: Some(parent_raw),
+                        modal: Some(Modal {
+                            parent,
+                            result: channel,
+                        }),
                     })
                     .expect("Send control action");
 
                 *is_window_opening = true;
             }
+            window::Action::CloseModal(id, result) => {
+                if let Some(modal) = window_manager
+                    .get_mut(id)
+                    .and_then(|window| window.parent.take())
+                {
+                    window_manager.dismiss(modal, Some(result));
+                }
+
+                run_action(
+                    Action::Window(window::Action::Close(id)),
+                    program,
+                    compositor,
+                    events,
+                    messages,
+                    clipboard,
+                    debouncer,
+                    control_sender,
+                    interfaces,
+                    window_manager,
+                    ui_caches,
+                    is_window_opening,
+                );
+            }
             window::Action::Close(id) => {
+                if window_manager
+                    .get_mut(id)
+                    .is_some_and(|window| window.start_closing())
+                {
+                    return;
+                }
+
                 let _ = ui_caches.remove(&id);
                 let _ = interfaces.remove(&id);
 
                 if let Some(window) = window_manager.remove(id) {
+                    if let Some(persistence) = &window.persistence {
+                        persistence.save(&window.raw);
+                    }
+
                     if clipboard.window_id() == Some(window.raw.id()) {
                         *clipboard = window_manager
                             .first()
@@ -1144,12 +1689,61 @@ fn run_action<P, C>(
                         id,
                         core::Event::Window(core::window::Event::Closed),
                     ));
+
+                    // Owned windows are closed together with their owner
+                    for owned in window_manager.owned(id) {
+                        run_action(
+                            Action::Window(window::Action::Close(owned)),
+                            program,
+                            compositor,
+                            events,
+                            messages,
+                            clipboard,
+                            debouncer,
+                            control_sender,
+                            interfaces,
+                            window_manager,
+                            ui_caches,
+                            is_window_opening,
+                        );
+                    }
                 }
 
                 if window_manager.is_empty() {
                     *compositor = None;
                 }
             }
+            window::Action::HoldClose(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.is_close_held = true;
+                }
+            }
+            window::Action::ConfirmClose(id) => {
+                if window_manager
+                    .get(id)
+                    .is_some_and(|window| window.is_close_held)
+                {
+                    run_action(
+                        Action::Window(window::Action::Close(id)),
+                        program,
+                        compositor,
+                        events,
+                        messages,
+                        clipboard,
+                        debouncer,
+                        control_sender,
+                        interfaces,
+                        window_manager,
+                        ui_caches,
+                        is_window_opening,
+                    );
+                }
+            }
+            window::Action::CancelClose(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.is_close_held = false;
+                }
+            }
             window::Action::GetOldest(channel) => {
                 let id =
                     window_manager.iter_mut().next().map(|(id, _window)| id);
@@ -1176,6 +1770,9 @@ fn run_action<P, C>(
             }
             window::Action::Resize(id, size) => {
                 if let Some(window) = window_manager.get_mut(id) {
+                    // Fixed axes must follow the new size
+                    window.update_size_limits(size);
+
                     let _ = window.raw.request_inner_size(
                         winit::dpi::LogicalSize {
                             width: size.width,
@@ -1186,22 +1783,14 @@ fn run_action<P, C>(
             }
             window::Action::SetMinSize(id, size) => {
                 if let Some(window) = window_manager.get_mut(id) {
-                    window.raw.set_min_inner_size(size.map(|size| {
-                        winit::dpi::LogicalSize {
-                            width: size.width,
-                            height: size.height,
-                        }
-                    }));
+                    window.min_size = size;
+                    window.update_size_limits(window.size());
                 }
             }
             window::Action::SetMaxSize(id, size) => {
                 if let Some(window) = window_manager.get_mut(id) {
-                    window.raw.set_max_inner_size(size.map(|size| {
-                        winit::dpi::LogicalSize {
-                            width: size.width,
-                            height: size.height,
-                        }
-                    }));
+                    window.max_size = size;
+                    window.update_size_limits(window.size());
                 }
             }
             window::Action::SetResizeIncrements(id, increments) => {
@@ -1214,6 +1803,169 @@ fn run_action<P, C>(
                     }));
                 }
             }
+            window::Action::EnterPip(id, size) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let restore = pip::enter(&window.raw, window.level, size);
+
+                    // Entering again only resizes the frame; so the
+                    // original state is kept
+                    window.pip = Some(window.pip.unwrap_or(restore));
+                    window.level = core::window::Level::AlwaysOnTop;
+                }
+            }
+            window::Action::ExitPip(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    if let Some(restore) = window.pip.take() {
+                        pip::exit(&window.raw, restore);
+
+                        window.level = restore.level();
+                    }
+                }
+            }
+            window::Action::SetShadow(id, shadow) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "windows")]
+                    {
+                        use winit::platform::windows::WindowExtWindows;
+
+                        window.raw.set_undecorated_shadow(shadow);
+                    }
+
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.set_has_shadow(
+                            window.raw.is_decorated() || shadow,
+                        );
+                    }
+
+                    #[cfg(not(any(
+                        target_os = "windows",
+                        target_os = "macos"
+                    )))]
+                    let _ = (window, shadow);
+                }
+            }
+            window::Action::SetResizeBorder(id, width) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.resize_border = width;
+                }
+            }
+            window::Action::SetResizableEdges(id, edges) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.resizable_edges = edges;
+                    window.update_size_limits(window.size());
+                }
+            }
+            window::Action::SetAspectRatio(id, ratio) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.aspect_ratio = ratio;
+
+                    window.keep_aspect_ratio(window.raw.inner_size());
+                }
+            }
+            window::Action::SetPresentMode(id, present_mode) => {
+                if let (Some(compositor), Some(window)) =
+                    (compositor.as_mut(), window_manager.get_mut(id))
+                {
+                    window.present_mode = Some(present_mode);
+
+                    let size = window.state.physical_size();
+
+                    if size.width > 0 && size.height > 0 {
+                        window.configure_surface(compositor);
+                    }
+
+                    window.raw.request_redraw();
+                }
+            }
+            window::Action::SetFrameRate(id, frame_rate) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.frame_rate = frame_rate;
+                }
+            }
+            window::Action::SetTabbingIdentifier(id, identifier) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.set_tabbing_identifier(&identifier);
+                    }
+
+                    #[cfg(not(target_os = "macos"))]
+                    let _ = (window, identifier);
+                }
+            }
+            window::Action::SelectNextTab(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.select_next_tab();
+                    }
+
+                    #[cfg(not(target_os = "macos"))]
+                    let _ = window;
+                }
+            }
+            window::Action::SelectPreviousTab(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.select_previous_tab();
+                    }
+
+                    #[cfg(not(target_os = "macos"))]
+                    let _ = window;
+                }
+            }
+            window::Action::SelectTab(id, index) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.select_tab_at_index(index);
+                    }
+
+                    #[cfg(not(target_os = "macos"))]
+                    let _ = (window, index);
+                }
+            }
+            window::Action::GetTabCount(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "macos")]
+                    let count = {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.num_tabs()
+                    };
+
+                    #[cfg(not(target_os = "macos"))]
+                    let count = {
+                        let _ = window;
+
+                        1
+                    };
+
+                    let _ = channel.send(count);
+                }
+            }
+            window::Action::SetAntialiasing(id, antialiasing) => {
+                if let (Some(compositor), Some(window)) =
+                    (compositor.as_mut(), window_manager.get_mut(id))
+                {
+                    compositor
+                        .set_antialiasing(&mut window.renderer, antialiasing);
+
+                    window.raw.request_redraw();
+                }
+            }
             window::Action::SetResizable(id, resizable) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_resizable(resizable);
@@ -1265,6 +2017,9 @@ fn run_action<P, C>(
                     let _ = channel.send(position);
                 }
             }
+            window::Action::GetWindowAt(id, position, channel) => {
+                let _ = channel.send(window_manager.window_at(id, position));
+            }
             window::Action::GetScaleFactor(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let scale_factor = window.raw.scale_factor();
@@ -1272,6 +2027,31 @@ fn run_action<P, C>(
                     let _ = channel.send(scale_factor as f32);
                 }
             }
+            window::Action::SetDefaultFont(id, font) => {
+                if let (Some(compositor), Some(window)) =
+                    (compositor.as_mut(), window_manager.get_mut(id))
+                {
+                    compositor.set_default_font(&mut window.renderer, font);
+                    window.default_font = font;
+
+                    if let Some(popup) = &mut window.popup {
+                        compositor.set_default_font(&mut popup.renderer, font);
+                    }
+
+                    // The layout may be measured with the old font
+                    if let Some(ui) = interfaces.remove(&id) {
+                        let _ = interfaces.insert(
+                            id,
+                            ui.relayout(
+                                window.state.logical_size(),
+                                &mut window.renderer,
+                            ),
+                        );
+                    }
+
+                    window.raw.request_redraw();
+                }
+            }
             window::Action::Move(id, position) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_outer_position(
@@ -1322,6 +2102,8 @@ fn run_action<P, C>(
                     window.raw.request_user_attention(
                         attention_type.map(conversion::user_attention),
                     );
+
+                    window.is_requesting_attention = attention_type.is_some();
                 }
             }
             window::Action::GainFocus(id) => {
@@ -1334,6 +2116,103 @@ fn run_action<P, C>(
                     window
                         .raw
                         .set_window_level(conversion::window_level(level));
+
+                    window.level = level;
+                }
+            }
+            window::Action::SetProgress(id, progress) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    taskbar::set_progress(&window.raw, progress);
+                }
+            }
+            window::Action::SetBadge(id, count) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    taskbar::set_badge(&window.raw, count);
+                }
+            }
+            window::Action::SetBackgroundEffect(id, effect) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    backdrop::set_effect(&window.raw, effect);
+                }
+            }
+            window::Action::GetLevel(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let _ = channel.send(window.level);
+                }
+            }
+            window::Action::GetVideoModes(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let video_modes = window
+                        .raw
+                        .current_monitor()
+                        .map(|monitor| {
+                            monitor
+                                .video_modes()
+                                .map(|video_mode| {
+                                    conversion::video_mode(&video_mode)
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    let _ = channel.send(video_modes);
+                }
+            }
+            window::Action::GetMonitors(channel) => {
+                let monitors = window_manager
+                    .first()
+                    .map(|window| {
+                        let primary = window.raw.primary_monitor();
+
+                        window
+                            .raw
+                            .available_monitors()
+                            .map(|monitor| {
+                                conversion::monitor(&monitor, primary.as_ref())
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let _ = channel.send(monitors);
+            }
+            window::Action::Tile(id, layout) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    tiling::tile(&window.raw, layout);
+                }
+            }
+            window::Action::MoveToMonitor(id, monitor) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let target =
+                        window.raw.available_monitors().find(|handle| {
+                            conversion::monitor(handle, None).bounds
+                                == monitor.bounds
+                        });
+
+                    let Some(target) = target else {
+                        return;
+                    };
+
+                    if window.raw.fullscreen().is_some() {
+                        window.raw.set_fullscreen(Some(
+                            winit::window::Fullscreen::Borderless(Some(target)),
+                        ));
+
+                        return;
+                    }
+
+                    let start = target.position();
+                    let area = target.size();
+                    let size = window.raw.outer_size();
+
+                    window.raw.set_outer_position(
+                        winit::dpi::PhysicalPosition {
+                            x: start.x
+                                + (area.width as i32 - size.width as i32) / 2,
+                            y: start.y
+                                + (area.height as i32 - size.height as i32) / 2,
+                        },
+                    );
                 }
             }
             window::Action::ShowSystemMenu(id) => {
@@ -1365,19 +2244,36 @@ fn run_action<P, C>(
                     f(handle);
                 }
             }
-            window::Action::Screenshot(id, channel) => {
+            window::Action::Screenshot(id, scale_factor, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     if let Some(compositor) = compositor {
+                        let viewport = match scale_factor {
+                            Some(scale_factor) => {
+                                let size = window.state.logical_size();
+
+                                graphics::Viewport::with_physical_size(
+                                    Size::new(
+                                        (size.width * scale_factor).round()
+                                            as u32,
+                                        (size.height * scale_factor).round()
+                                            as u32,
+                                    ),
+                                    f64::from(scale_factor),
+                                )
+                            }
+                            None => window.state.viewport().clone(),
+                        };
+
                         let bytes = compositor.screenshot(
                             &mut window.renderer,
-                            window.state.viewport(),
+                            &viewport,
                             window.state.background_color(),
                         );
 
                         let _ = channel.send(core::window::Screenshot::new(
                             bytes,
-                            window.state.physical_size(),
-                            window.state.viewport().scale_factor(),
+                            viewport.physical_size(),
+                            viewport.scale_factor(),
                         ));
                     }
                 }
@@ -1409,6 +2305,13 @@ fn run_action<P, C>(
                     }
                 }
             }
+            system::Action::QueryAppearance(channel) => {
+                let theme = window_manager
+                    .first()
+                    .and_then(|window| window.raw.theme());
+
+                let _ = channel.send(conversion::appearance(theme));
+            }
         },
         Action::Widget(operation) => {
             let mut current_operation = Some(operation);
@@ -1429,6 +2332,71 @@ fn run_action<P, C>(
                 }
             }
         }
+        Action::Image(action) => match action {
+            image::Action::EvictUnused => {
+                if let Some(compositor) = compositor {
+                    for (_id, window) in window_manager.iter_mut() {
+                        compositor.evict_images(&mut window.renderer);
+                    }
+                }
+            }
+            image::Action::Evict(handle) => {
+                if let Some(compositor) = compositor {
+                    for (_id, window) in window_manager.iter_mut() {
+                        compositor.evict_image(&mut window.renderer, &handle);
+                    }
+                }
+            }
+            image::Action::Prefetch(handle) => {
+                if let Some(compositor) = compositor {
+                    for (_id, window) in window_manager.iter_mut() {
+                        compositor
+                            .prefetch_image(&mut window.renderer, &handle);
+                    }
+                }
+            }
+            image::Action::QueryUsage(channel) => {
+                let usage = compositor
+                    .as_ref()
+                    .map(|compositor| {
+                        window_manager
+                            .iter_mut()
+                            .map(|(_id, window)| {
+                                compositor.image_usage(&window.renderer)
+                            })
+                            .fold(core::image::Usage::default(), |a, b| a + b)
+                    })
+                    .unwrap_or_default();
+
+                let _ = channel.send(usage);
+            }
+        },
+        Action::Dnd(action) => match action {
+            dnd::Action::StartDrag {
+                payload,
+                preview,
+                channel,
+            } => {
+                if let Some(window) = window_manager.focused_mut() {
+                    crate::dnd::start(&window.raw, payload, preview, channel);
+                } else {
+                    let _ = channel.send(dnd::Outcome::Canceled);
+                }
+            }
+        },
+        Action::Tray(action) => {
+            tray::run(action);
+        }
+        Action::Debounce(action) => {
+            debouncer.run(action);
+        }
+        Action::Accessibility(action) => match action {
+            runtime::accessibility::Action::Announce(text, priority) => {
+                if let Some(window) = window_manager.focused_mut() {
+                    window.accessibility.announce(text, priority);
+                }
+            }
+        },
         Action::LoadFont { bytes, channel } => {
             if let Some(compositor) = compositor {
                 // TODO: Error handling (?)
@@ -1438,6 +2406,12 @@ fn run_action<P, C>(
             }
         }
         Action::Exit => {
+            for (_id, window) in window_manager.iter_mut() {
+                if let Some(persistence) = &window.persistence {
+                    persistence.save(&window.raw);
+                }
+            }
+
             control_sender
                 .start_send(Control::Exit)
                 .expect("Send control action");
@@ -0,0 +1,69 @@
+//! Open windows as modals of other windows.
+use crate::core::window::Id;
+use crate::futures::futures::channel::oneshot;
+
+use winit::window::{Window, WindowAttributes};
+
+use std::any::Any;
+
+/// The parent of a modal window and the channel of its result.
+#[derive(Debug)]
+pub struct Modal {
+    /// The [`Id`] of the parent window.
+    pub parent: Id,
+    /// The channel of the result of the modal.
+    pub result: oneshot::Sender<Option<Box<dyn Any + Send>>>,
+}
+
+/// Keeps a new window with the given [`WindowAttributes`] above its parent
+/// [`Window`], if supported by the platform.
+#[cfg(target_os = "windows")]
+pub fn attributes(
+    attributes: WindowAttributes,
+    parent: &Window,
+) -> WindowAttributes {
+    use winit::platform::windows::WindowAttributesExtWindows;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    let Ok(handle) = parent.window_handle() else {
+        return attributes;
+    };
+
+    let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+        return attributes;
+    };
+
+    // Owned windows are always kept above their owner
+    attributes.with_owner_window(handle.hwnd.get())
+}
+
+/// Keeps a new window with the given [`WindowAttributes`] above its parent
+/// [`Window`], if supported by the platform.
+#[cfg(target_os = "macos")]
+pub fn attributes(
+    attributes: WindowAttributes,
+    parent: &Window,
+) -> WindowAttributes {
+    use winit::raw_window_handle::HasWindowHandle;
+
+    let Ok(handle) = parent.window_handle() else {
+        return attributes;
+    };
+
+    // SAFETY: The parent window is alive while the new window is created;
+    // on macOS, the new window is attached as a child window of the parent.
+    #[allow(unsafe_code)]
+    unsafe {
+        attributes.with_parent_window(Some(handle.as_raw()))
+    }
+}
+
+/// Keeps a new window with the given [`WindowAttributes`] above its parent
+/// [`Window`], if supported by the platform.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub fn attributes(
+    attributes: WindowAttributes,
+    _parent: &Window,
+) -> WindowAttributes {
+    attributes
+}
@@ -0,0 +1,79 @@
+//! Detect the reduced motion preference of the native system.
+use std::sync::atomic::{self, AtomicU8};
+
+const UNKNOWN: u8 = 0;
+const DISABLED: u8 = 1;
+const ENABLED: u8 = 2;
+
+static PREFERENCE: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+/// Returns true if the user prefers reduced motion.
+///
+/// The preference is detected once and cached until [`refresh`] is called;
+/// since detecting it may spawn a process in some platforms.
+pub fn is_reduced() -> bool {
+    match PREFERENCE.load(atomic::Ordering::Relaxed) {
+        UNKNOWN => refresh(),
+        preference => preference == ENABLED,
+    }
+}
+
+/// Detects the reduced motion preference again, caching the result.
+///
+/// This should only be called when the system reports a change.
+pub fn refresh() -> bool {
+    let is_reduced = detect();
+
+    PREFERENCE.store(
+        if is_reduced { ENABLED } else { DISABLED },
+        atomic::Ordering::Relaxed,
+    );
+
+    is_reduced
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> bool {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        SPI_GETCLIENTAREAANIMATION, SystemParametersInfoW,
+    };
+
+    let mut is_enabled: i32 = 1;
+
+    // SAFETY: The pointer refers to a `BOOL` that lives for the whole call.
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            (&raw mut is_enabled).cast(),
+            0,
+        )
+    };
+
+    result != 0 && is_enabled == 0
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> bool {
+    std::process::Command::new("defaults")
+        .args(["read", "com.apple.universalaccess", "reduceMotion"])
+        .output()
+        .is_ok_and(|output| output.stdout.trim_ascii() == b"1")
+}
+
+/// Desktop environments do not share a common setting; so the GNOME
+/// preference is queried, if available.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect() -> bool {
+    std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+        .output()
+        .is_ok_and(|output| output.stdout.trim_ascii() == b"false")
+}
+
+/// Detection is not supported on this platform yet.
+#[cfg(not(any(target_os = "windows", unix)))]
+fn detect() -> bool {
+    false
+}
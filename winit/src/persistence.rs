@@ -0,0 +1,310 @@
+//! Save and restore the state of windows across runs.
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::monitor::MonitorHandle;
+use winit::window::{Window, WindowAttributes};
+
+use std::fs;
+use std::path::PathBuf;
+
+/// The minimum amount of physical pixels of a restored window that must be
+/// visible in some monitor.
+const MIN_VISIBLE: i32 = 64;
+
+/// The persisted state of a window, saved in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Persistence {
+    path: PathBuf,
+    restored: Option<Bounds>,
+}
+
+impl Persistence {
+    /// Creates a new [`Persistence`] saved in the file at the given path.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            restored: None,
+        }
+    }
+
+    /// Restores the state saved in the file, if any, into the
+    /// [`WindowAttributes`].
+    ///
+    /// The saved state is ignored if the window would not be visible in any
+    /// of the given monitors; for instance, if its monitor was disconnected.
+    pub fn restore(
+        &mut self,
+        attributes: WindowAttributes,
+        mut monitors: impl Iterator<Item = MonitorHandle>,
+    ) -> WindowAttributes {
+        let Some(snapshot) = fs::read_to_string(&self.path)
+            .ok()
+            .as_deref()
+            .and_then(Snapshot::parse)
+        else {
+            return attributes;
+        };
+
+        if !monitors.any(|monitor| {
+            snapshot
+                .bounds
+                .is_visible_in(monitor.position(), monitor.size())
+        }) {
+            log::warn!(
+                "Ignoring saved window state at {}: outside of any monitor",
+                self.path.display()
+            );
+
+            return attributes;
+        }
+
+        // A window restored maximized keeps its saved bounds until it is
+        // unmaximized
+        self.restored = Some(snapshot.bounds);
+
+        attributes
+            .with_position(snapshot.bounds.position)
+            .with_inner_size(snapshot.bounds.size)
+            .with_maximized(snapshot.is_maximized)
+    }
+
+    /// Tracks the current bounds of the [`Window`]; unless it is maximized,
+    /// minimized, or fullscreen.
+    ///
+    /// The tracked bounds are saved for maximized windows, so they can be
+    /// unmaximized to their previous bounds after being restored.
+    pub fn track(&mut self, window: &Window) {
+        if window.is_maximized()
+            || window.is_minimized() == Some(true)
+            || window.fullscreen().is_some()
+        {
+            return;
+        }
+
+        let Ok(position) = window.outer_position() else {
+            return;
+        };
+
+        self.restored = Some(Bounds {
+            position,
+            size: window.inner_size(),
+        });
+    }
+
+    /// Saves the state of the [`Window`] in the file.
+    pub fn save(&self, window: &Window) {
+        let is_maximized = window.is_maximized();
+
+        // The bounds of maximized and minimized windows are meaningless
+        let bounds = if is_maximized
+            || window.is_minimized() == Some(true)
+            || window.fullscreen().is_some()
+        {
+            self.restored
+        } else {
+            window.outer_position().ok().map(|position| Bounds {
+                position,
+                size: window.inner_size(),
+            })
+        };
+
+        let Some(bounds) = bounds else {
+            return;
+        };
+
+        let snapshot = Snapshot {
+            bounds,
+            is_maximized,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        if let Err(error) = fs::write(&self.path, snapshot.to_string()) {
+            log::warn!(
+                "Error saving window state at {}: {error}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// The position and size of a window, in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    position: PhysicalPosition<i32>,
+    size: PhysicalSize<u32>,
+}
+
+impl Bounds {
+    /// Returns true if enough of the [`Bounds`] are visible in a monitor
+    /// with the given position and size.
+    fn is_visible_in(
+        &self,
+        origin: PhysicalPosition<i32>,
+        area: PhysicalSize<u32>,
+    ) -> bool {
+        let left = self.position.x.max(origin.x);
+        let top = self.position.y.max(origin.y);
+
+        let right = self
+            .position
+            .x
+            .saturating_add_unsigned(self.size.width)
+            .min(origin.x.saturating_add_unsigned(area.width));
+
+        let bottom = self
+            .position
+            .y
+            .saturating_add_unsigned(self.size.height)
+            .min(origin.y.saturating_add_unsigned(area.height));
+
+        right.saturating_sub(left) >= MIN_VISIBLE
+            && bottom.saturating_sub(top) >= MIN_VISIBLE
+    }
+}
+
+/// The saved state of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Snapshot {
+    bounds: Bounds,
+    is_maximized: bool,
+}
+
+impl Snapshot {
+    fn parse(contents: &str) -> Option<Self> {
+        let mut x = None;
+        let mut y = None;
+        let mut width = None;
+        let mut height = None;
+        let mut is_maximized = false;
+
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+
+            let value = value.trim();
+
+            match key.trim() {
+                "x" => x = value.parse().ok(),
+                "y" => y = value.parse().ok(),
+                "width" => width = value.parse().ok(),
+                "height" => height = value.parse().ok(),
+                "maximized" => is_maximized = value == "true",
+                _ => {}
+            }
+        }
+
+        let size = PhysicalSize::new(width?, height?);
+
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+
+        Some(Self {
+            bounds: Bounds {
+                position: PhysicalPosition::new(x?, y?),
+                size,
+            },
+            is_maximized,
+        })
+    }
+}
+
+impl std::fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "x={}", self.bounds.position.x)?;
+        writeln!(f, "y={}", self.bounds.position.y)?;
+        writeln!(f, "width={}", self.bounds.size.width)?;
+        writeln!(f, "height={}", self.bounds.size.height)?;
+        writeln!(f, "maximized={}", self.is_maximized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(x: i32, y: i32, width: u32, height: u32) -> Bounds {
+        Bounds {
+            position: PhysicalPosition::new(x, y),
+            size: PhysicalSize::new(width, height),
+        }
+    }
+
+    #[test]
+    fn snapshot_round_trips() {
+        let snapshot = Snapshot {
+            bounds: bounds(-1920, 40, 1280, 720),
+            is_maximized: true,
+        };
+
+        assert_eq!(Snapshot::parse(&snapshot.to_string()), Some(snapshot));
+    }
+
+    #[test]
+    fn snapshot_ignores_unknown_lines() {
+        let snapshot = Snapshot::parse(
+            "# comment\nx=10\ny=20\nversion=2\nwidth=300\nheight=200\n",
+        );
+
+        assert_eq!(
+            snapshot,
+            Some(Snapshot {
+                bounds: bounds(10, 20, 300, 200),
+                is_maximized: false,
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_snapshots_are_rejected() {
+        for contents in [
+            "",
+            "x=10\ny=20\nwidth=300\n",
+            "x=10\ny=20\nwidth=300\nheight=-200\n",
+            "x=ten\ny=20\nwidth=300\nheight=200\n",
+            "x=10\ny=20\nwidth=0\nheight=200\n",
+            "x=99999999999\ny=20\nwidth=300\nheight=200\n",
+        ] {
+            assert_eq!(Snapshot::parse(contents), None, "{contents:?}");
+        }
+    }
+
+    #[test]
+    fn bounds_are_visible_if_enough_overlaps_the_monitor() {
+        let origin = PhysicalPosition::new(0, 0);
+        let area = PhysicalSize::new(1920, 1080);
+
+        assert!(bounds(100, 100, 800, 600).is_visible_in(origin, area));
+        assert!(bounds(1856, 1016, 800, 600).is_visible_in(origin, area));
+        assert!(!bounds(1857, 100, 800, 600).is_visible_in(origin, area));
+        assert!(!bounds(-800, 100, 800, 600).is_visible_in(origin, area));
+        assert!(!bounds(100, 1080, 800, 600).is_visible_in(origin, area));
+    }
+
+    #[test]
+    fn bounds_are_visible_in_monitors_with_negative_origins() {
+        let origin = PhysicalPosition::new(-1920, -200);
+        let area = PhysicalSize::new(1920, 1080);
+
+        assert!(bounds(-1000, 0, 800, 600).is_visible_in(origin, area));
+        assert!(!bounds(0, 0, 800, 600).is_visible_in(origin, area));
+    }
+
+    #[test]
+    fn huge_bounds_do_not_overflow() {
+        let origin = PhysicalPosition::new(i32::MAX - 100, 0);
+        let area = PhysicalSize::new(u32::MAX, 1080);
+
+        assert!(
+            bounds(i32::MAX - 100, 0, u32::MAX, u32::MAX)
+                .is_visible_in(origin, area)
+        );
+        assert!(!bounds(i32::MAX - 10, 0, 800, 600).is_visible_in(
+            PhysicalPosition::new(0, 0),
+            PhysicalSize::new(1920, 1080)
+        ));
+    }
+}
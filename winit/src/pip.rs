@@ -0,0 +1,88 @@
+//! Shrink windows into a compact picture-in-picture frame.
+use crate::conversion;
+use crate::core::window::Level;
+use crate::core::{Rectangle, Size};
+use crate::tiling;
+
+use winit::window::Window;
+
+/// The logical distance between a picture-in-picture window and the edges of
+/// its monitor.
+const MARGIN: f32 = 16.0;
+
+/// The state of a window before entering picture-in-picture.
+#[derive(Debug, Clone, Copy)]
+pub struct Restore {
+    position: Option<winit::dpi::PhysicalPosition<i32>>,
+    size: winit::dpi::PhysicalSize<u32>,
+    level: Level,
+    is_decorated: bool,
+    is_maximized: bool,
+}
+
+impl Restore {
+    /// Returns the [`Level`] of the window before entering
+    /// picture-in-picture.
+    pub fn level(&self) -> Level {
+        self.level
+    }
+}
+
+/// Turns the [`Window`] into a frameless, always-on-top window of the given
+/// logical size; placed at the bottom-right corner of its monitor.
+///
+/// Returns the [`Restore`] state needed to [`exit`] picture-in-picture.
+pub fn enter(window: &Window, level: Level, size: Size) -> Restore {
+    let restore = Restore {
+        position: window.outer_position().ok(),
+        size: window.inner_size(),
+        level,
+        is_decorated: window.is_decorated(),
+        is_maximized: window.is_maximized(),
+    };
+
+    window.set_maximized(false);
+    window.set_decorations(false);
+    window.set_window_level(conversion::window_level(Level::AlwaysOnTop));
+
+    let scale_factor = window.scale_factor() as f32;
+    let physical = size * scale_factor;
+
+    let _ = window.request_inner_size(winit::dpi::PhysicalSize {
+        width: physical.width.round() as u32,
+        height: physical.height.round() as u32,
+    });
+
+    if let Some(area) = tiling::work_area(window) {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = area;
+
+        let margin = MARGIN * scale_factor;
+
+        window.set_outer_position(winit::dpi::PhysicalPosition {
+            x: (x + width - physical.width - margin).round() as i32,
+            y: (y + height - physical.height - margin).round() as i32,
+        });
+    }
+
+    restore
+}
+
+/// Restores the [`Window`] to the state it had before entering
+/// picture-in-picture.
+pub fn exit(window: &Window, restore: Restore) {
+    window.set_decorations(restore.is_decorated);
+    window.set_window_level(conversion::window_level(restore.level));
+
+    let _ = window.request_inner_size(restore.size);
+
+    if let Some(position) = restore.position {
+        window.set_outer_position(position);
+    }
+
+    window.set_maximized(restore.is_maximized);
+}
@@ -0,0 +1,279 @@
+//! Draw the overlays of a window in borderless popup windows.
+use crate::core::{Color, Font, Rectangle, Renderer as _, Size};
+use crate::graphics::{Compositor, Viewport, compositor};
+use crate::modal;
+
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{Touch, WindowEvent};
+use winit::window::{Window, WindowAttributes};
+
+use std::sync::Arc;
+
+/// A borderless window where the overlay of its owner is drawn.
+#[allow(missing_debug_implementations)]
+pub struct Popup<C>
+where
+    C: Compositor,
+{
+    /// The raw window of the [`Popup`].
+    pub raw: Arc<Window>,
+    /// The renderer of the [`Popup`].
+    pub renderer: C::Renderer,
+    /// The logical bounds of the [`Popup`], relative to the contents of its
+    /// owner.
+    pub bounds: Rectangle,
+    surface: C::Surface,
+    physical_size: Size<u32>,
+    surface_size: Size<u32>,
+    is_visible: bool,
+}
+
+impl<C> Popup<C>
+where
+    C: Compositor,
+{
+    /// Creates a new [`Popup`] placed at the given logical bounds, relative
+    /// to the contents of its owner.
+    pub fn new(
+        raw: Arc<Window>,
+        compositor: &mut C,
+        bounds: Rectangle,
+        scale_factor: f64,
+        default_font: Option<Font>,
+    ) -> Self {
+        let (_, size) = physical_bounds(bounds, scale_factor);
+        let physical_size = Size::new(size.width, size.height);
+
+        let surface = compositor.create_surface(
+            raw.clone(),
+            physical_size.width,
+            physical_size.height,
+        );
+
+        let mut renderer = compositor.create_renderer();
+
+        if let Some(font) = default_font {
+            compositor.set_default_font(&mut renderer, Some(font));
+        }
+
+        renderer.hint(scale_factor as f32);
+
+        Self {
+            raw,
+            renderer,
+            bounds,
+            surface,
+            physical_size,
+            surface_size: physical_size,
+            is_visible: false,
+        }
+    }
+
+    /// Returns true if the [`Popup`] is visible.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Returns the physical offset of the [`Popup`] from the contents of its
+    /// owner, with the given scale factor.
+    pub fn offset(&self, scale_factor: f64) -> PhysicalPosition<f64> {
+        let (position, _) = physical_bounds(self.bounds, scale_factor);
+
+        PhysicalPosition::new(f64::from(position.x), f64::from(position.y))
+    }
+
+    /// Places the [`Popup`] at the given logical bounds, relative to the
+    /// contents of the `owner` window; and shows it.
+    pub fn show(
+        &mut self,
+        owner: &Window,
+        bounds: Rectangle,
+        scale_factor: f64,
+    ) {
+        let Ok(origin) = owner.inner_position() else {
+            return;
+        };
+
+        if self.bounds != bounds || !self.is_visible {
+            let (position, size) = physical_bounds(bounds, scale_factor);
+
+            self.raw.set_outer_position(PhysicalPosition::new(
+                origin.x + position.x,
+                origin.y + position.y,
+            ));
+
+            let _ = self.raw.request_inner_size(size);
+
+            self.bounds = bounds;
+            self.physical_size = Size::new(size.width, size.height);
+        }
+
+        if !self.is_visible {
+            self.raw.set_visible(true);
+            self.is_visible = true;
+        }
+    }
+
+    /// Presents the current contents of the renderer of the [`Popup`], with
+    /// the given scale factor.
+    pub fn present(&mut self, compositor: &mut C, scale_factor: f64) {
+        if self.surface_size != self.physical_size {
+            compositor.configure_surface(
+                &mut self.surface,
+                self.physical_size.width,
+                self.physical_size.height,
+            );
+
+            self.surface_size = self.physical_size;
+        }
+
+        let viewport =
+            Viewport::with_physical_size(self.physical_size, scale_factor);
+
+        match compositor.present(
+            &mut self.renderer,
+            &mut self.surface,
+            &viewport,
+            Color::TRANSPARENT,
+            || self.raw.pre_present_notify(),
+        ) {
+            Ok(()) => {}
+            Err(compositor::SurfaceError::OutOfMemory) => {
+                panic!("Out of memory when presenting popup surface");
+            }
+            Err(error) => {
+                log::error!("Error {error:?} when presenting popup surface.");
+            }
+        }
+    }
+
+    /// Hides the [`Popup`].
+    pub fn hide(&mut self) {
+        if self.is_visible {
+            self.raw.set_visible(false);
+            self.is_visible = false;
+        }
+    }
+}
+
+/// Returns true if popup windows can be placed next to the `owner` window.
+pub fn is_supported(owner: &Window) -> bool {
+    // Wayland does not allow windows to know or choose their position
+    !cfg!(target_arch = "wasm32") && owner.inner_position().is_ok()
+}
+
+/// Returns the logical space available to the overlays of the `owner` window
+/// of the given logical size; from its top-left corner to the bottom-right
+/// corner of its monitor.
+pub fn available_space(owner: &Window, size: Size, scale_factor: f64) -> Size {
+    let (Ok(origin), Some(monitor)) =
+        (owner.inner_position(), owner.current_monitor())
+    else {
+        return size;
+    };
+
+    let start = monitor.position();
+    let end = monitor.size();
+
+    let width = f64::from(start.x + end.width as i32 - origin.x) / scale_factor;
+    let height =
+        f64::from(start.y + end.height as i32 - origin.y) / scale_factor;
+
+    Size::new(size.width.max(width as f32), size.height.max(height as f32))
+}
+
+/// Returns the [`WindowAttributes`] of a popup window of the `owner` window
+/// with the given logical bounds, relative to its contents.
+pub fn attributes(
+    owner: &Window,
+    bounds: Rectangle,
+    scale_factor: f64,
+) -> WindowAttributes {
+    let (position, size) = physical_bounds(bounds, scale_factor);
+
+    let origin = owner
+        .inner_position()
+        .unwrap_or(PhysicalPosition::new(0, 0));
+
+    let attributes = WindowAttributes::default()
+        .with_title("")
+        .with_decorations(false)
+        .with_transparent(true)
+        .with_resizable(false)
+        .with_active(false)
+        .with_visible(false)
+        .with_inner_size(size)
+        .with_position(PhysicalPosition::new(
+            origin.x + position.x,
+            origin.y + position.y,
+        ));
+
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    let attributes = {
+        use winit::platform::x11::{WindowAttributesExtX11, WindowType};
+
+        attributes
+            .with_override_redirect(true)
+            .with_x11_window_type(vec![WindowType::PopupMenu])
+    };
+
+    #[cfg(target_os = "windows")]
+    let attributes = {
+        use winit::platform::windows::WindowAttributesExtWindows;
+
+        attributes.with_skip_taskbar(true)
+    };
+
+    // Popups are kept above their owner
+    modal::attributes(attributes, owner)
+}
+
+/// Forwards the given [`WindowEvent`] of a popup window to its owner; moving
+/// any pointer position by the given physical offset.
+///
+/// Returns `None` if the event only concerns the popup window.
+pub fn forward(
+    event: WindowEvent,
+    offset: PhysicalPosition<f64>,
+) -> Option<WindowEvent> {
+    match event {
+        WindowEvent::CursorMoved {
+            device_id,
+            position,
+        } => Some(WindowEvent::CursorMoved {
+            device_id,
+            position: PhysicalPosition::new(
+                position.x + offset.x,
+                position.y + offset.y,
+            ),
+        }),
+        WindowEvent::Touch(touch) => Some(WindowEvent::Touch(Touch {
+            location: PhysicalPosition::new(
+                touch.location.x + offset.x,
+                touch.location.y + offset.y,
+            ),
+            ..touch
+        })),
+        WindowEvent::CursorLeft { .. }
+        | WindowEvent::MouseInput { .. }
+        | WindowEvent::MouseWheel { .. } => Some(event),
+        _ => None,
+    }
+}
+
+fn physical_bounds(
+    bounds: Rectangle,
+    scale_factor: f64,
+) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+    let position = PhysicalPosition::new(
+        (f64::from(bounds.x) * scale_factor).round() as i32,
+        (f64::from(bounds.y) * scale_factor).round() as i32,
+    );
+
+    let size = PhysicalSize::new(
+        ((f64::from(bounds.width) * scale_factor).ceil() as u32).max(1),
+        ((f64::from(bounds.height) * scale_factor).ceil() as u32).max(1),
+    );
+
+    (position, size)
+}
@@ -39,5 +39,6 @@ pub(crate) fn information(
         memory_used,
         graphics_adapter: graphics_info.adapter,
         graphics_backend: graphics_info.backend,
+        power: runtime::system::Power::current(),
     }
 }
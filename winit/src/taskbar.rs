@@ -0,0 +1,414 @@
+//! Show the progress and badges of a window in the taskbar of the native
+//! system.
+use crate::core::window::Progress;
+
+use winit::window::Window;
+
+/// Shows the given [`Progress`] in the taskbar button of the [`Window`].
+///
+/// `None` hides any progress.
+#[cfg(target_os = "windows")]
+pub fn set_progress(window: &Window, progress: Option<Progress>) {
+    use windows::Win32::UI::Shell::{
+        TBPF_INDETERMINATE, TBPF_NOPROGRESS, TBPF_NORMAL,
+    };
+
+    const PROGRESS_TOTAL: u64 = 10_000;
+
+    let Some(hwnd) = win32::hwnd(window) else {
+        return;
+    };
+
+    let result = win32::with_taskbar(|taskbar| {
+        // SAFETY: The window handle is valid while the window is alive.
+        #[allow(unsafe_code)]
+        unsafe {
+            match progress {
+                None => taskbar.SetProgressState(hwnd, TBPF_NOPROGRESS),
+                Some(Progress::Indeterminate) => {
+                    taskbar.SetProgressState(hwnd, TBPF_INDETERMINATE)
+                }
+                Some(Progress::Determinate(value)) => {
+                    taskbar.SetProgressState(hwnd, TBPF_NORMAL)?;
+                    taskbar.SetProgressValue(
+                        hwnd,
+                        (value.clamp(0.0, 1.0) * PROGRESS_TOTAL as f32) as u64,
+                        PROGRESS_TOTAL,
+                    )
+                }
+            }
+        }
+    });
+
+    if let Err(error) = result {
+        log::warn!("Failed to show taskbar progress: {error}");
+    }
+}
+
+/// Shows the given [`Progress`] in the dock icon of the application.
+///
+/// `None` hides any progress.
+#[cfg(target_os = "macos")]
+pub fn set_progress(_window: &Window, progress: Option<Progress>) {
+    use objc2_app_kit::{
+        NSApplication, NSImageView, NSProgressIndicator,
+        NSProgressIndicatorStyle,
+    };
+    use objc2_foundation::{MainThreadMarker, NSPoint, NSRect, NSSize};
+
+    const BAR_HEIGHT: f64 = 16.0;
+
+    let Some(mtm) = MainThreadMarker::new() else {
+        log::warn!("Dock progress can only be shown from the main thread");
+        return;
+    };
+
+    let application = NSApplication::sharedApplication(mtm);
+
+    // SAFETY: AppKit is only used from the main thread, as ensured by
+    // the marker.
+    #[allow(unsafe_code)]
+    unsafe {
+        let dock_tile = application.dockTile();
+
+        match progress {
+            None => {
+                dock_tile.setContentView(None);
+            }
+            Some(progress) => {
+                let size = dock_tile.size();
+
+                // The dock tile is replaced by the icon of the application
+                // with a progress bar at its bottom
+                let icon = NSImageView::initWithFrame(
+                    mtm.alloc(),
+                    NSRect::new(NSPoint::new(0.0, 0.0), size),
+                );
+                icon.setImage(application.applicationIconImage().as_deref());
+
+                let bar = NSProgressIndicator::initWithFrame(
+                    mtm.alloc(),
+                    NSRect::new(
+                        NSPoint::new(0.0, 0.0),
+                        NSSize::new(size.width, BAR_HEIGHT),
+                    ),
+                );
+                bar.setStyle(NSProgressIndicatorStyle::Bar);
+
+                match progress {
+                    Progress::Indeterminate => {
+                        bar.setIndeterminate(true);
+                    }
+                    Progress::Determinate(value) => {
+                        bar.setIndeterminate(false);
+                        bar.setMinValue(0.0);
+                        bar.setMaxValue(1.0);
+                        bar.setDoubleValue(f64::from(value.clamp(0.0, 1.0)));
+                    }
+                }
+
+                icon.addSubview(&bar);
+                dock_tile.setContentView(Some(&icon));
+            }
+        }
+
+        dock_tile.display();
+    }
+}
+
+/// Shows the given [`Progress`] in the taskbar button of the [`Window`].
+///
+/// Taskbar progress is not supported on this platform yet.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub fn set_progress(_window: &Window, _progress: Option<Progress>) {}
+
+/// Shows the given count as a badge in the taskbar button of the [`Window`].
+///
+/// `None` or `0` hides the badge.
+#[cfg(target_os = "windows")]
+pub fn set_badge(window: &Window, count: Option<u32>) {
+    use windows::Win32::UI::WindowsAndMessaging::HICON;
+    use windows::core::PCWSTR;
+    use windows_sys::Win32::UI::WindowsAndMessaging::DestroyIcon;
+
+    let Some(hwnd) = win32::hwnd(window) else {
+        return;
+    };
+
+    let count = count.filter(|count| *count > 0);
+
+    let icon = match count {
+        Some(count) => {
+            let Some(icon) = win32::badge_icon(count) else {
+                log::warn!("Failed to draw taskbar badge");
+                return;
+            };
+
+            Some(icon)
+        }
+        None => None,
+    };
+
+    let description: Vec<u16> = count
+        .map(|count| count.to_string())
+        .unwrap_or_default()
+        .encode_utf16()
+        .chain([0])
+        .collect();
+
+    let result = win32::with_taskbar(|taskbar| {
+        // SAFETY: The window handle is valid while the window is alive, and
+        // the description is a null-terminated string.
+        #[allow(unsafe_code)]
+        unsafe {
+            taskbar.SetOverlayIcon(
+                hwnd,
+                HICON(icon.unwrap_or(std::ptr::null_mut())),
+                PCWSTR(description.as_ptr()),
+            )
+        }
+    });
+
+    if let Some(icon) = icon {
+        // SAFETY: The taskbar keeps its own copy of the icon.
+        #[allow(unsafe_code)]
+        unsafe {
+            let _ = DestroyIcon(icon);
+        }
+    }
+
+    if let Err(error) = result {
+        log::warn!("Failed to show taskbar badge: {error}");
+    }
+}
+
+/// Shows the given count as a badge in the dock icon of the application.
+///
+/// `None` or `0` hides the badge.
+#[cfg(target_os = "macos")]
+pub fn set_badge(_window: &Window, count: Option<u32>) {
+    use objc2_app_kit::NSApplication;
+    use objc2_foundation::{MainThreadMarker, NSString};
+
+    let Some(mtm) = MainThreadMarker::new() else {
+        log::warn!("Dock badges can only be set from the main thread");
+        return;
+    };
+
+    let label = count
+        .filter(|count| *count > 0)
+        .map(|count| NSString::from_str(&count.to_string()));
+
+    // SAFETY: AppKit is only used from the main thread, as ensured by
+    // the marker.
+    #[allow(unsafe_code)]
+    unsafe {
+        NSApplication::sharedApplication(mtm)
+            .dockTile()
+            .setBadgeLabel(label.as_deref());
+    }
+}
+
+/// Shows the given count as a badge in the taskbar button of the [`Window`].
+///
+/// Badges are not supported on this platform yet.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub fn set_badge(_window: &Window, _count: Option<u32>) {}
+
+#[cfg(target_os = "windows")]
+mod win32 {
+    use std::cell::RefCell;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::Com::{
+        CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED, CoCreateInstance,
+        CoInitializeEx,
+    };
+    use windows::Win32::UI::Shell::{ITaskbarList3, TaskbarList};
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use winit::window::Window;
+
+    thread_local! {
+        // COM objects are bound to the apartment of the thread creating them;
+        // so the taskbar is created once per thread.
+        static TASKBAR: RefCell<Option<ITaskbarList3>> =
+            const { RefCell::new(None) };
+    }
+
+    /// Calls the given closure with the taskbar of the current thread.
+    pub fn with_taskbar(
+        f: impl FnOnce(&ITaskbarList3) -> windows::core::Result<()>,
+    ) -> windows::core::Result<()> {
+        TASKBAR.with_borrow_mut(|slot| {
+            let taskbar = match slot.take() {
+                Some(taskbar) => taskbar,
+                None => create()?,
+            };
+
+            let result = f(&taskbar);
+            *slot = Some(taskbar);
+
+            result
+        })
+    }
+
+    fn create() -> windows::core::Result<ITaskbarList3> {
+        // SAFETY: COM may be initialized more than once in the same thread,
+        // and `HrInit` is called before any other method of the taskbar; as
+        // documented.
+        #[allow(unsafe_code)]
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+            let taskbar: ITaskbarList3 =
+                CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER)?;
+
+            taskbar.HrInit()?;
+
+            Ok(taskbar)
+        }
+    }
+
+    /// Draws the given count in a red circle, to be shown as the overlay
+    /// icon of a taskbar button; counts above 99 are drawn as 99.
+    ///
+    /// The text is drawn by GDI with the default GUI font of the system.
+    pub fn badge_icon(
+        count: u32,
+    ) -> Option<windows_sys::Win32::UI::WindowsAndMessaging::HICON> {
+        use std::ptr;
+        use windows_sys::Win32::Foundation::RECT;
+        use windows_sys::Win32::Graphics::Gdi::{
+            BI_RGB, BITMAPINFO, BITMAPINFOHEADER, CreateBitmap,
+            CreateCompatibleDC, CreateDIBSection, CreateSolidBrush,
+            DEFAULT_GUI_FONT, DIB_RGB_COLORS, DT_CENTER, DT_SINGLELINE,
+            DT_VCENTER, DeleteDC, DeleteObject, DrawTextW, Ellipse, GdiFlush,
+            GetStockObject, NULL_PEN, SelectObject, SetBkMode, SetTextColor,
+            TRANSPARENT,
+        };
+        use windows_sys::Win32::UI::WindowsAndMessaging::{
+            CreateIconIndirect, ICONINFO,
+        };
+
+        const SIZE: i32 = 16;
+        const RED: u32 = 0x001C_2BC4;
+        const WHITE: u32 = 0x00FF_FFFF;
+
+        let mut text: Vec<u16> =
+            count.min(99).to_string().encode_utf16().collect();
+
+        // SAFETY: Every GDI object is created, selected and deleted in this
+        // call; and the pixels of the bitmap are only accessed after GDI has
+        // finished drawing into them.
+        #[allow(unsafe_code)]
+        unsafe {
+            let dc = CreateCompatibleDC(ptr::null_mut());
+
+            if dc.is_null() {
+                return None;
+            }
+
+            let mut info: BITMAPINFO = std::mem::zeroed();
+            info.bmiHeader.biSize = size_of::<BITMAPINFOHEADER>() as u32;
+            info.bmiHeader.biWidth = SIZE;
+            info.bmiHeader.biHeight = -SIZE;
+            info.bmiHeader.biPlanes = 1;
+            info.bmiHeader.biBitCount = 32;
+            info.bmiHeader.biCompression = BI_RGB;
+
+            let mut bits = ptr::null_mut();
+
+            let color = CreateDIBSection(
+                dc,
+                &info,
+                DIB_RGB_COLORS,
+                &mut bits,
+                ptr::null_mut(),
+                0,
+            );
+
+            if color.is_null() || bits.is_null() {
+                let _ = DeleteDC(dc);
+                return None;
+            }
+
+            let brush = CreateSolidBrush(RED);
+
+            let previous_bitmap = SelectObject(dc, color);
+            let previous_brush = SelectObject(dc, brush);
+            let previous_pen = SelectObject(dc, GetStockObject(NULL_PEN));
+            let previous_font =
+                SelectObject(dc, GetStockObject(DEFAULT_GUI_FONT));
+
+            // Without a pen, the right and bottom edges are excluded
+            let _ = Ellipse(dc, 0, 0, SIZE + 1, SIZE + 1);
+
+            let mut bounds = RECT {
+                left: 0,
+                top: 0,
+                right: SIZE,
+                bottom: SIZE,
+            };
+
+            let _ = SetBkMode(dc, TRANSPARENT);
+            let _ = SetTextColor(dc, WHITE);
+            let _ = DrawTextW(
+                dc,
+                text.as_mut_ptr(),
+                text.len() as i32,
+                &mut bounds,
+                DT_CENTER | DT_VCENTER | DT_SINGLELINE,
+            );
+
+            let _ = GdiFlush();
+
+            // GDI does not write the alpha channel; so every pixel drawn is
+            // made opaque, and the rest stays transparent
+            let pixels = std::slice::from_raw_parts_mut(
+                bits.cast::<u8>(),
+                (SIZE * SIZE * 4) as usize,
+            );
+
+            for pixel in pixels.chunks_exact_mut(4) {
+                if pixel[..3].iter().any(|channel| *channel != 0) {
+                    pixel[3] = u8::MAX;
+                }
+            }
+
+            let _ = SelectObject(dc, previous_font);
+            let _ = SelectObject(dc, previous_pen);
+            let _ = SelectObject(dc, previous_brush);
+            let _ = SelectObject(dc, previous_bitmap);
+
+            // The mask is ignored for icons with an alpha channel, but it
+            // must still be provided
+            let mask_bits = [0u8; (SIZE * SIZE / 8) as usize];
+            let mask =
+                CreateBitmap(SIZE, SIZE, 1, 1, mask_bits.as_ptr().cast());
+
+            let icon = CreateIconIndirect(&ICONINFO {
+                fIcon: 1,
+                xHotspot: 0,
+                yHotspot: 0,
+                hbmMask: mask,
+                hbmColor: color,
+            });
+
+            let _ = DeleteObject(mask);
+            let _ = DeleteObject(color);
+            let _ = DeleteObject(brush);
+            let _ = DeleteDC(dc);
+
+            (!icon.is_null()).then_some(icon)
+        }
+    }
+
+    pub fn hwnd(window: &Window) -> Option<HWND> {
+        let handle = window.window_handle().ok()?;
+
+        let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+            return None;
+        };
+
+        Some(HWND(handle.hwnd.get() as *mut _))
+    }
+}
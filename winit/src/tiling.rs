@@ -0,0 +1,107 @@
+//! Tile windows into the work area of their monitor.
+use crate::core::Rectangle;
+use crate::core::window::Layout;
+
+use winit::monitor::MonitorHandle;
+use winit::window::Window;
+
+/// Moves and resizes the [`Window`] into the given [`Layout`] of its current
+/// monitor.
+pub fn tile(window: &Window, layout: Layout) {
+    let Some(area) = work_area(window) else {
+        return;
+    };
+
+    let bounds = layout.bounds(area);
+
+    window.set_maximized(false);
+
+    // The bounds include the decorations of the window
+    let outer = window.outer_size();
+    let inner = window.inner_size();
+
+    let _ = window.request_inner_size(winit::dpi::PhysicalSize {
+        width: (bounds.width as u32)
+            .saturating_sub(outer.width.saturating_sub(inner.width)),
+        height: (bounds.height as u32)
+            .saturating_sub(outer.height.saturating_sub(inner.height)),
+    });
+
+    window.set_outer_position(winit::dpi::PhysicalPosition {
+        x: bounds.x.round() as i32,
+        y: bounds.y.round() as i32,
+    });
+}
+
+/// Returns the physical area of the monitor of the [`Window`] that is not
+/// covered by taskbars or docks.
+#[cfg(target_os = "windows")]
+pub fn work_area(window: &Window) -> Option<Rectangle> {
+    use windows_sys::Win32::Foundation::RECT;
+    use windows_sys::Win32::Graphics::Gdi::{
+        GetMonitorInfoW, MONITOR_DEFAULTTONEAREST, MONITORINFO,
+        MonitorFromWindow,
+    };
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    let handle = window.window_handle().ok()?;
+
+    let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+        return monitor_area(window.current_monitor()?);
+    };
+
+    let empty = RECT {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    };
+
+    let mut info = MONITORINFO {
+        cbSize: size_of::<MONITORINFO>() as u32,
+        rcMonitor: empty,
+        rcWork: empty,
+        dwFlags: 0,
+    };
+
+    // SAFETY: The window handle is valid while the window is alive and
+    // the info pointer refers to a local that lives for the whole call.
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        let monitor =
+            MonitorFromWindow(handle.hwnd.get() as _, MONITOR_DEFAULTTONEAREST);
+
+        GetMonitorInfoW(monitor, &raw mut info)
+    };
+
+    if result == 0 {
+        return monitor_area(window.current_monitor()?);
+    }
+
+    Some(Rectangle {
+        x: info.rcWork.left as f32,
+        y: info.rcWork.top as f32,
+        width: (info.rcWork.right - info.rcWork.left) as f32,
+        height: (info.rcWork.bottom - info.rcWork.top) as f32,
+    })
+}
+
+/// Returns the physical area of the monitor of the [`Window`].
+///
+/// Taskbars and docks are not detected on this platform yet.
+#[cfg(not(target_os = "windows"))]
+pub fn work_area(window: &Window) -> Option<Rectangle> {
+    monitor_area(window.current_monitor()?)
+}
+
+fn monitor_area(monitor: MonitorHandle) -> Option<Rectangle> {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    Some(Rectangle {
+        x: position.x as f32,
+        y: position.y as f32,
+        width: size.width as f32,
+        height: size.height as f32,
+    })
+}
@@ -0,0 +1,150 @@
+//! Show icons in the system tray of the native system.
+use crate::runtime::tray::Action;
+
+/// Runs the given tray [`Action`].
+#[cfg(all(feature = "tray", any(target_os = "windows", target_os = "macos")))]
+pub fn run(action: Action) {
+    use crate::runtime::tray::{Event, Id, Item, notify};
+
+    use rustc_hash::FxHashMap;
+    use std::cell::RefCell;
+    use std::sync::Once;
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+    use tray_icon::{
+        MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+    };
+
+    thread_local! {
+        // Tray icons are not `Send` and must live in the event loop thread.
+        static TRAYS: RefCell<FxHashMap<Id, TrayIcon>> =
+            RefCell::new(FxHashMap::default());
+    }
+
+    static HANDLERS: Once = Once::new();
+
+    fn icon(icon: crate::core::window::Icon) -> Option<tray_icon::Icon> {
+        let (pixels, size) = icon.into_raw();
+
+        tray_icon::Icon::from_rgba(pixels, size.width, size.height).ok()
+    }
+
+    // Tray and menu identifiers are strings; so we encode the
+    // tray id in them to route the events back.
+    fn parse(id: &str) -> Option<(Id, &str)> {
+        let (tray, item) = id.split_once('/').unwrap_or((id, ""));
+
+        Some((tray.parse().ok()?, item))
+    }
+
+    HANDLERS.call_once(|| {
+        TrayIconEvent::set_event_handler(Some(|event: TrayIconEvent| {
+            let (id, event) = match event {
+                TrayIconEvent::Click {
+                    id,
+                    button: MouseButton::Left,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } => (id, Event::Clicked),
+                TrayIconEvent::DoubleClick {
+                    id,
+                    button: MouseButton::Left,
+                    ..
+                } => (id, Event::DoubleClicked),
+                _ => return,
+            };
+
+            if let Some((id, _)) = parse(id.as_ref()) {
+                notify(id, event);
+            }
+        }));
+
+        MenuEvent::set_event_handler(Some(|event: MenuEvent| {
+            if let Some((id, item)) = parse(event.id.as_ref()) {
+                notify(id, Event::MenuSelected(item.to_owned()));
+            }
+        }));
+    });
+
+    match action {
+        Action::Open(id, settings) => {
+            let menu = Menu::new();
+
+            for item in settings.menu {
+                let result = match item {
+                    Item::Entry {
+                        id: item,
+                        label,
+                        is_enabled,
+                    } => menu.append(&MenuItem::with_id(
+                        format!("{id}/{item}"),
+                        label,
+                        is_enabled,
+                        None,
+                    )),
+                    Item::Separator => {
+                        menu.append(&PredefinedMenuItem::separator())
+                    }
+                };
+
+                if let Err(error) = result {
+                    log::warn!("Error adding tray menu item: {error}");
+                }
+            }
+
+            let mut builder = TrayIconBuilder::new()
+                .with_id(id.to_string())
+                .with_menu(Box::new(menu))
+                .with_menu_on_left_click(false);
+
+            if let Some(icon) = icon(settings.icon) {
+                builder = builder.with_icon(icon);
+            }
+
+            if let Some(tooltip) = settings.tooltip {
+                builder = builder.with_tooltip(tooltip);
+            }
+
+            match builder.build() {
+                Ok(tray) => {
+                    let _ =
+                        TRAYS.with_borrow_mut(|trays| trays.insert(id, tray));
+                }
+                Err(error) => {
+                    log::error!("Error creating tray icon: {error}");
+                }
+            }
+        }
+        Action::Close(id) => {
+            let _ = TRAYS.with_borrow_mut(|trays| trays.remove(&id));
+        }
+        Action::SetIcon(id, new_icon) => {
+            TRAYS.with_borrow(|trays| {
+                if let Some(tray) = trays.get(&id) {
+                    if let Err(error) = tray.set_icon(icon(new_icon)) {
+                        log::warn!("Error changing tray icon: {error}");
+                    }
+                }
+            });
+        }
+        Action::SetTooltip(id, tooltip) => {
+            TRAYS.with_borrow(|trays| {
+                if let Some(tray) = trays.get(&id) {
+                    if let Err(error) = tray.set_tooltip(tooltip) {
+                        log::warn!("Error changing tray tooltip: {error}");
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Runs the given tray [`Action`].
+#[cfg(not(all(
+    feature = "tray",
+    any(target_os = "windows", target_os = "macos")
+)))]
+pub fn run(action: Action) {
+    if let Action::Open(id, _) = action {
+        log::warn!("Tray icons are not supported on this platform (tray {id})");
+    }
+}
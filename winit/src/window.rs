@@ -2,10 +2,16 @@ mod state;
 
 use state::State;
 
-pub use crate::core::window::{Event, Id, RedrawRequest, Settings};
+pub use crate::core::window::{
+    Edges, Event, FrameRate, Id, Level, Position, PresentMode, RedrawRequest,
+    Settings, Transition,
+};
 
+use crate::Control;
+use crate::accessibility;
 use crate::conversion;
 use crate::core::alignment;
+use crate::core::animation;
 use crate::core::input_method;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -13,14 +19,21 @@ use crate::core::text;
 use crate::core::theme;
 use crate::core::time::Instant;
 use crate::core::{
-    Color, InputMethod, Padding, Point, Rectangle, Size, Text, Vector,
+    Color, Font, InputMethod, Padding, Point, Rectangle, Size, Text,
+    Transformation, Vector,
 };
+use crate::futures::futures::channel::mpsc;
 use crate::graphics::Compositor;
+use crate::modal::Modal;
+use crate::persistence::Persistence;
+use crate::pip;
+use crate::popup::{self, Popup};
 use crate::program::{self, Program};
 
 use winit::dpi::{LogicalPosition, LogicalSize};
 use winit::monitor::MonitorHandle;
 
+use std::any::Any;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
@@ -55,8 +68,23 @@ where
         program: &program::Instance<P>,
         compositor: &mut C,
         exit_on_close_request: bool,
+        transition: Transition,
+        frame_rate: FrameRate,
+        level: Level,
+        aspect_ratio: Option<f32>,
+        resize_border: f32,
+        resizable_edges: Edges,
+        min_size: Option<Size>,
+        max_size: Option<Size>,
+        scale_factor: Option<f64>,
+        default_font: Option<Font>,
+        popups: bool,
+        owner: Option<Id>,
+        accessibility: accessibility::Adapter,
+        persistence: Option<Persistence>,
+        modal: Option<Modal>,
     ) -> &mut Window<P, C> {
-        let state = State::new(program, id, &window);
+        let state = State::new(program, id, &window, scale_factor);
         let viewport_version = state.viewport_version();
         let physical_size = state.physical_size();
         let surface = compositor.create_surface(
@@ -64,10 +92,28 @@ where
             physical_size.width,
             physical_size.height,
         );
-        let renderer = compositor.create_renderer();
+        let mut renderer = compositor.create_renderer();
+
+        if let Some(font) = default_font {
+            compositor.set_default_font(&mut renderer, Some(font));
+        }
+
+        {
+            use crate::core::Renderer as _;
+
+            renderer.hint(state.scale_factor() as f32);
+        }
 
         let _ = self.aliases.insert(window.id(), id);
 
+        let last_position = window.outer_position().ok();
+
+        let mut persistence = persistence;
+
+        if let Some(persistence) = &mut persistence {
+            persistence.track(&window);
+        }
+
         let _ = self.entries.insert(
             id,
             Window {
@@ -79,6 +125,39 @@ where
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
                 redraw_at: None,
+                present_mode: None,
+                frame_rate,
+                level,
+                aspect_ratio,
+                resize_border,
+                resizable_edges,
+                min_size,
+                max_size,
+                resize_direction: None,
+                pip: None,
+                popups,
+                popup: None,
+                is_popup_requested: false,
+                default_font,
+                owner,
+                last_position,
+                is_minimized: false,
+                accessibility,
+                modal: None,
+                parent: modal,
+                persistence,
+                last_redraw: None,
+                is_focused: true,
+                is_close_held: false,
+                is_requesting_attention: false,
+                transition,
+                presence: if transition == Transition::None
+                    || animation::is_motion_reduced()
+                {
+                    Presence::Shown
+                } else {
+                    Presence::Opening { start: None }
+                },
                 preedit: None,
                 ime_state: None,
             },
@@ -116,6 +195,13 @@ where
         self.entries.iter_mut().map(|(k, v)| (*k, v))
     }
 
+    /// Returns the focused window; or the latest one, if none is focused.
+    pub fn focused_mut(&mut self) -> Option<&mut Window<P, C>> {
+        self.entries
+            .values_mut()
+            .max_by_key(|window| window.is_focused)
+    }
+
     pub fn get(&self, id: Id) -> Option<&Window<P, C>> {
         self.entries.get(&id)
     }
@@ -137,12 +223,287 @@ where
         self.entries.values().last()?.raw.current_monitor()
     }
 
+    /// Resolves the [`Position`] of a new window with the given size that
+    /// depends on other windows, returning the monitor it should be placed
+    /// in.
+    pub fn resolve_position(
+        &self,
+        position: Position,
+        size: Size,
+    ) -> (Position, Option<MonitorHandle>) {
+        match position {
+            Position::CenteredOnParent(parent) => {
+                let Some(parent) = self.get(parent) else {
+                    return (Position::Centered, self.last_monitor());
+                };
+
+                let Some(origin) = parent.position() else {
+                    return (Position::Centered, parent.raw.current_monitor());
+                };
+
+                let parent_size: LogicalSize<f32> = parent
+                    .raw
+                    .outer_size()
+                    .to_logical(parent.raw.scale_factor());
+
+                (
+                    Position::Specific(Point::new(
+                        origin.x + (parent_size.width - size.width) / 2.0,
+                        origin.y + (parent_size.height - size.height) / 2.0,
+                    )),
+                    parent.raw.current_monitor(),
+                )
+            }
+            Position::AtCursor => {
+                let hovered = self
+                    .entries
+                    .values()
+                    .filter_map(|window| {
+                        Some((window, window.state.cursor().position()?))
+                    })
+                    .max_by_key(|(window, _)| window.is_focused);
+
+                let Some((window, cursor)) = hovered else {
+                    return (Position::Default, self.last_monitor());
+                };
+
+                let Ok(origin) = window.raw.inner_position() else {
+                    return (Position::Default, window.raw.current_monitor());
+                };
+
+                let origin: LogicalPosition<f32> =
+                    origin.to_logical(window.raw.scale_factor());
+
+                (
+                    Position::Specific(Point::new(
+                        origin.x + cursor.x,
+                        origin.y + cursor.y,
+                    )),
+                    window.raw.current_monitor(),
+                )
+            }
+            Position::RelativeTo(id, offset) => {
+                let Some(window) = self.get(id) else {
+                    return (Position::Default, self.last_monitor());
+                };
+
+                let Ok(origin) = window.raw.inner_position() else {
+                    return (Position::Default, window.raw.current_monitor());
+                };
+
+                let origin: LogicalPosition<f32> =
+                    origin.to_logical(window.raw.scale_factor());
+
+                (
+                    Position::Specific(Point::new(
+                        origin.x + offset.x,
+                        origin.y + offset.y,
+                    )),
+                    window.raw.current_monitor(),
+                )
+            }
+            Position::OnMonitor(id, _) => (
+                position,
+                self.get(id)
+                    .and_then(|window| window.raw.current_monitor())
+                    .or_else(|| self.last_monitor()),
+            ),
+            Position::Default
+            | Position::Centered
+            | Position::Specific(_)
+            | Position::SpecificWith(_) => (position, self.last_monitor()),
+        }
+    }
+
+    /// Finds the window, other than the one with the given [`Id`], whose
+    /// contents are under the given position relative to the contents of
+    /// the window with the [`Id`]; together with the position relative to
+    /// the found window.
+    pub fn window_at(&self, id: Id, position: Point) -> Option<(Id, Point)> {
+        let source = self.get(id)?;
+        let origin = source.raw.inner_position().ok()?;
+        let scale_factor = source.raw.scale_factor();
+
+        let x = f64::from(origin.x) + f64::from(position.x) * scale_factor;
+        let y = f64::from(origin.y) + f64::from(position.y) * scale_factor;
+
+        self.entries
+            .iter()
+            .filter(|(target, window)| {
+                **target != id && window.raw.is_visible().unwrap_or(true)
+            })
+            .filter_map(|(target, window)| {
+                let origin = window.raw.inner_position().ok()?;
+                let size = window.raw.inner_size();
+
+                let relative_x = x - f64::from(origin.x);
+                let relative_y = y - f64::from(origin.y);
+
+                let is_inside = relative_x >= 0.0
+                    && relative_y >= 0.0
+                    && relative_x < f64::from(size.width)
+                    && relative_y < f64::from(size.height);
+
+                is_inside.then(|| {
+                    let scale_factor = window.raw.scale_factor();
+
+                    (
+                        window,
+                        *target,
+                        Point::new(
+                            (relative_x / scale_factor) as f32,
+                            (relative_y / scale_factor) as f32,
+                        ),
+                    )
+                })
+            })
+            .max_by_key(|(window, _, _)| window.is_focused)
+            .map(|(_, target, position)| (target, position))
+    }
+
+    /// Attaches a new [`Popup`] with the given raw window and logical bounds
+    /// to the window with the given [`Id`].
+    pub fn insert_popup(
+        &mut self,
+        id: Id,
+        raw: Arc<winit::window::Window>,
+        compositor: &mut C,
+        bounds: Rectangle,
+    ) {
+        let Some(window) = self.entries.get_mut(&id) else {
+            return;
+        };
+
+        let _ = self.aliases.insert(raw.id(), id);
+
+        window.popup = Some(Popup::new(
+            raw,
+            compositor,
+            bounds,
+            window.state.scale_factor(),
+            window.default_font,
+        ));
+
+        window.raw.request_redraw();
+    }
+
+    /// Returns the [`Id`] of the windows owned by the window with the given
+    /// [`Id`].
+    pub fn owned(&self, id: Id) -> Vec<Id> {
+        self.entries
+            .iter()
+            .filter(|(_, window)| window.owner == Some(id))
+            .map(|(owned, _)| *owned)
+            .collect()
+    }
+
+    /// Moves, minimizes, and restores the windows owned by the raw window with
+    /// the given id together with it, according to the given event; unless
+    /// the platform does it already.
+    pub fn follow_owner(
+        &mut self,
+        window_id: winit::window::WindowId,
+        event: &winit::event::WindowEvent,
+    ) {
+        use winit::event::WindowEvent;
+
+        let Some(id) = self.aliases.get(&window_id).copied() else {
+            return;
+        };
+
+        let Some(owner) = self
+            .entries
+            .get_mut(&id)
+            .filter(|owner| owner.raw.id() == window_id)
+        else {
+            return;
+        };
+
+        match event {
+            // Child windows follow their parent on macOS
+            WindowEvent::Moved(position) if !cfg!(target_os = "macos") => {
+                let Some(last_position) =
+                    owner.last_position.replace(*position)
+                else {
+                    return;
+                };
+
+                let delta_x = position.x - last_position.x;
+                let delta_y = position.y - last_position.y;
+
+                if delta_x == 0 && delta_y == 0 {
+                    return;
+                }
+
+                for window in self
+                    .entries
+                    .values()
+                    .filter(|window| window.owner == Some(id))
+                {
+                    if let Ok(position) = window.raw.outer_position() {
+                        window.raw.set_outer_position(
+                            winit::dpi::PhysicalPosition::new(
+                                position.x + delta_x,
+                                position.y + delta_y,
+                            ),
+                        );
+                    }
+                }
+            }
+            // Owned windows are minimized together with their owner on
+            // Windows and macOS
+            WindowEvent::Resized(_) | WindowEvent::Occluded(_)
+                if !cfg!(any(target_os = "windows", target_os = "macos")) =>
+            {
+                let is_minimized = owner.raw.is_minimized().unwrap_or(false);
+
+                if owner.is_minimized == is_minimized {
+                    return;
+                }
+
+                owner.is_minimized = is_minimized;
+
+                for window in self
+                    .entries
+                    .values()
+                    .filter(|window| window.owner == Some(id))
+                {
+                    window.raw.set_minimized(is_minimized);
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn remove(&mut self, id: Id) -> Option<Window<P, C>> {
-        let window = self.entries.remove(&id)?;
+        let mut window = self.entries.remove(&id)?;
         let _ = self.aliases.remove(&window.raw.id());
 
+        if let Some(popup) = &window.popup {
+            let _ = self.aliases.remove(&popup.raw.id());
+        }
+
+        if let Some(modal) = window.parent.take() {
+            self.dismiss(modal, None);
+        }
+
         Some(window)
     }
+
+    /// Produces the result of a [`Modal`] and unblocks its parent window.
+    pub fn dismiss(
+        &mut self,
+        modal: Modal,
+        result: Option<Box<dyn Any + Send>>,
+    ) {
+        let _ = modal.result.send(result);
+
+        if let Some(parent) = self.get_mut(modal.parent) {
+            parent.modal = None;
+            parent.raw.focus_window();
+            parent.raw.request_redraw();
+        }
+    }
 }
 
 impl<P, C> Default for WindowManager<P, C>
@@ -171,6 +532,33 @@ where
     pub surface: C::Surface,
     pub renderer: P::Renderer,
     pub redraw_at: Option<Instant>,
+    pub present_mode: Option<PresentMode>,
+    pub frame_rate: FrameRate,
+    pub level: Level,
+    pub aspect_ratio: Option<f32>,
+    pub resize_border: f32,
+    pub resizable_edges: Edges,
+    pub min_size: Option<Size>,
+    pub max_size: Option<Size>,
+    pub resize_direction: Option<winit::window::ResizeDirection>,
+    pub pip: Option<pip::Restore>,
+    pub popups: bool,
+    pub popup: Option<Popup<C>>,
+    pub is_popup_requested: bool,
+    pub default_font: Option<Font>,
+    pub owner: Option<Id>,
+    pub last_position: Option<winit::dpi::PhysicalPosition<i32>>,
+    pub is_minimized: bool,
+    pub accessibility: accessibility::Adapter,
+    pub modal: Option<Id>,
+    pub parent: Option<Modal>,
+    pub persistence: Option<Persistence>,
+    pub last_redraw: Option<Instant>,
+    pub is_focused: bool,
+    pub is_close_held: bool,
+    pub is_requesting_attention: bool,
+    transition: Transition,
+    presence: Presence,
     preedit: Option<Preedit<P::Renderer>>,
     ime_state: Option<(Point, input_method::Purpose)>,
 }
@@ -181,6 +569,28 @@ where
     C: Compositor<Renderer = P::Renderer>,
     P::Theme: theme::Base,
 {
+    pub fn configure_surface(&mut self, compositor: &mut C) {
+        let size = self.state.physical_size();
+
+        match self.present_mode {
+            Some(present_mode) => {
+                compositor.configure_surface_with_present_mode(
+                    &mut self.surface,
+                    size.width,
+                    size.height,
+                    present_mode,
+                );
+            }
+            None => {
+                compositor.configure_surface(
+                    &mut self.surface,
+                    size.width,
+                    size.height,
+                );
+            }
+        }
+    }
+
     pub fn position(&self) -> Option<Point> {
         self.raw
             .outer_position()
@@ -198,16 +608,322 @@ where
         Size::new(size.width, size.height)
     }
 
+    /// Applies the minimum and maximum sizes of the window; fixing the axes
+    /// without any resizable edges to the given logical size.
+    pub fn update_size_limits(&self, size: Size) {
+        let is_width_fixed =
+            !self.resizable_edges.intersects(Edges::HORIZONTAL);
+        let is_height_fixed = !self.resizable_edges.intersects(Edges::VERTICAL);
+
+        let min_size = self.min_size.unwrap_or(Size::ZERO);
+        let max_size = self.max_size.unwrap_or(Size::INFINITY);
+
+        let min_size = Size::new(
+            if is_width_fixed {
+                size.width
+            } else {
+                min_size.width
+            },
+            if is_height_fixed {
+                size.height
+            } else {
+                min_size.height
+            },
+        );
+
+        let max_size = Size::new(
+            if is_width_fixed {
+                size.width
+            } else {
+                max_size.width
+            },
+            if is_height_fixed {
+                size.height
+            } else {
+                max_size.height
+            },
+        );
+
+        self.raw
+            .set_min_inner_size((min_size != Size::ZERO).then_some(
+                LogicalSize {
+                    width: min_size.width,
+                    height: min_size.height,
+                },
+            ));
+
+        self.raw
+            .set_max_inner_size((max_size != Size::INFINITY).then_some(
+                LogicalSize {
+                    width: max_size.width,
+                    height: max_size.height,
+                },
+            ));
+    }
+
+    pub fn keep_aspect_ratio(&self, size: winit::dpi::PhysicalSize<u32>) {
+        let Some(ratio) = self.aspect_ratio.filter(|ratio| *ratio > 0.0) else {
+            return;
+        };
+
+        if size.width == 0 || size.height == 0 || self.raw.is_maximized() {
+            return;
+        }
+
+        // We keep the dimension that changed the most; so both horizontal
+        // and vertical resizing work
+        let previous = self.state.physical_size();
+
+        let target = if size.width.abs_diff(previous.width)
+            >= size.height.abs_diff(previous.height)
+        {
+            winit::dpi::PhysicalSize::new(
+                size.width,
+                (size.width as f32 / ratio).round() as u32,
+            )
+        } else {
+            winit::dpi::PhysicalSize::new(
+                (size.height as f32 * ratio).round() as u32,
+                size.height,
+            )
+        };
+
+        if target != size {
+            let _ = self.raw.request_inner_size(target);
+        }
+    }
+
     pub fn request_redraw(&mut self, redraw_request: RedrawRequest) {
-        match redraw_request {
-            RedrawRequest::NextFrame => {
+        // The earliest instant allowed by the frame rate of the window
+        let next_frame = self
+            .frame_rate
+            .interval(self.is_focused)
+            .zip(self.last_redraw)
+            .map(|(interval, last_redraw)| last_redraw + interval)
+            .filter(|next_frame| *next_frame > Instant::now());
+
+        match (redraw_request, next_frame) {
+            (RedrawRequest::NextFrame, None) => {
                 self.raw.request_redraw();
                 self.redraw_at = None;
             }
-            RedrawRequest::At(at) => {
-                self.redraw_at = Some(at);
+            (RedrawRequest::NextFrame, Some(next_frame)) => {
+                self.redraw_at = Some(next_frame);
+            }
+            (RedrawRequest::At(at), next_frame) => {
+                self.redraw_at = Some(
+                    next_frame.map_or(at, |next_frame| at.max(next_frame)),
+                );
+            }
+            (RedrawRequest::Wait, _) => {}
+        }
+    }
+
+    /// Starts the closing [`Transition`] of the [`Window`], if any.
+    ///
+    /// Returns true if the [`Window`] must be kept open until the
+    /// [`Transition`] finishes.
+    pub fn start_closing(&mut self) -> bool {
+        match self.presence {
+            Presence::Closing { .. } => true,
+            Presence::Closed => false,
+            Presence::Opening { .. } | Presence::Shown => {
+                if self.transition == Transition::None
+                    || animation::is_motion_reduced()
+                {
+                    return false;
+                }
+
+                self.presence = Presence::Closing { start: None };
+                self.raw.request_redraw();
+
+                true
+            }
+        }
+    }
+
+    /// Returns true if the closing [`Transition`] of the [`Window`] has
+    /// finished.
+    pub fn is_closed(&self) -> bool {
+        matches!(self.presence, Presence::Closed)
+    }
+
+    /// Draws the contents of the [`Window`] with the given closure, applying
+    /// its opening or closing [`Transition`] at the given time.
+    ///
+    /// The closure receives the renderer, theme, and style of the [`Window`].
+    pub fn draw_with_transition(
+        &mut self,
+        now: Instant,
+        draw: impl FnOnce(&mut P::Renderer, &P::Theme, &renderer::Style),
+    ) {
+        use crate::core::Renderer as _;
+
+        let progress = match &mut self.presence {
+            Presence::Shown | Presence::Closed => None,
+            Presence::Opening { start } => {
+                let progress = linear_progress(*start.get_or_insert(now), now);
+
+                if progress >= 1.0 {
+                    self.presence = Presence::Shown;
+                }
+
+                Some(progress)
+            }
+            Presence::Closing { start } => {
+                let progress = linear_progress(*start.get_or_insert(now), now);
+
+                if progress >= 1.0 {
+                    self.presence = Presence::Closed;
+                }
+
+                Some(1.0 - progress)
+            }
+        };
+
+        let style = renderer::Style {
+            text_color: self.state.text_color(),
+        };
+
+        let Some(progress) = progress else {
+            draw(&mut self.renderer, self.state.theme(), &style);
+            return;
+        };
+
+        let (opacity, scale) = self.transition.apply(progress);
+        let center = self.state.logical_size() * 0.5;
+
+        self.renderer.start_opacity(opacity);
+        self.renderer.start_transformation(
+            Transformation::translate(center.width, center.height)
+                * Transformation::scale(scale)
+                * Transformation::translate(-center.width, -center.height),
+        );
+
+        draw(&mut self.renderer, self.state.theme(), &style);
+
+        self.renderer.end_transformation();
+        self.renderer.end_opacity();
+
+        if !self.is_closed() {
+            self.raw.request_redraw();
+        }
+    }
+
+    /// Returns the logical bounds available to the overlays of the
+    /// [`Window`]; which overflow it when they can be drawn in a [`Popup`].
+    pub fn overlay_bounds(&self) -> Size {
+        let size = self.state.logical_size();
+
+        if !self.popups || !popup::is_supported(&self.raw) {
+            return size;
+        }
+
+        popup::available_space(&self.raw, size, self.state.scale_factor())
+    }
+
+    /// Shows the [`Popup`] of the [`Window`] at the given logical bounds of
+    /// its overlay, if they overflow the [`Window`]; requesting its creation
+    /// if needed. Otherwise, the [`Popup`] is hidden.
+    ///
+    /// Returns true if the overlay must be drawn in the [`Popup`].
+    pub fn update_popup(
+        &mut self,
+        id: Id,
+        overlay: Option<Rectangle>,
+        control_sender: &mut mpsc::UnboundedSender<Control>,
+    ) -> bool {
+        let viewport = Rectangle::with_size(self.state.logical_size());
+
+        let Some(bounds) = overlay.filter(|bounds| {
+            self.popups
+                && !bounds.is_within(&viewport)
+                && popup::is_supported(&self.raw)
+        }) else {
+            if let Some(popup) = &mut self.popup {
+                popup.hide();
+            }
+
+            return false;
+        };
+
+        match &mut self.popup {
+            Some(popup) => {
+                popup.show(&self.raw, bounds, self.state.scale_factor());
+
+                true
+            }
+            None => {
+                // The overlay is drawn inside of the window until the
+                // popup is created; or forever, if its creation fails
+                if !self.is_popup_requested {
+                    control_sender
+                        .start_send(Control::CreatePopup {
+                            id,
+                            owner: self.raw.clone(),
+                            bounds,
+                            scale_factor: self.state.scale_factor(),
+                        })
+                        .expect("Send control action");
+
+                    self.is_popup_requested = true;
+                }
+
+                false
             }
-            RedrawRequest::Wait => {}
+        }
+    }
+
+    /// Draws the contents of the [`Popup`] of the [`Window`] with the given
+    /// closure, and presents them.
+    ///
+    /// The closure receives the renderer, theme, and style of the [`Window`];
+    /// translated so the overlay can be drawn as if it were in the [`Window`].
+    pub fn draw_popup(
+        &mut self,
+        compositor: &mut C,
+        draw: impl FnOnce(&mut P::Renderer, &P::Theme, &renderer::Style),
+    ) {
+        use crate::core::Renderer as _;
+
+        let Some(popup) = &mut self.popup else {
+            return;
+        };
+
+        if !popup.is_visible() {
+            return;
+        }
+
+        let style = renderer::Style {
+            text_color: self.state.text_color(),
+        };
+
+        popup.renderer.clear();
+        popup.renderer.with_translation(
+            Vector::new(-popup.bounds.x, -popup.bounds.y),
+            |renderer| {
+                draw(renderer, self.state.theme(), &style);
+            },
+        );
+
+        popup.present(compositor, self.state.scale_factor());
+    }
+
+    /// Routes the given [`winit::event::WindowEvent`] of the raw window with
+    /// the given id to the [`Window`].
+    ///
+    /// The pointer events of its [`Popup`] are moved to the coordinates of
+    /// the [`Window`], while the rest are dropped.
+    pub fn route_event(
+        &self,
+        window_id: winit::window::WindowId,
+        event: winit::event::WindowEvent,
+    ) -> Option<winit::event::WindowEvent> {
+        match &self.popup {
+            Some(popup) if popup.raw.id() == window_id => {
+                popup::forward(event, popup.offset(self.state.scale_factor()))
+            }
+            _ => Some(event),
         }
     }
 
@@ -246,15 +962,122 @@ where
         }
     }
 
-    pub fn update_mouse(&mut self, interaction: mouse::Interaction) {
+    pub fn update_mouse(
+        &mut self,
+        interaction: mouse::Interaction,
+        control_sender: &mut mpsc::UnboundedSender<Control>,
+    ) {
+        // The resize border takes precedence over the contents
+        if self.resize_direction.is_some() {
+            return;
+        }
+
         if interaction != self.mouse_interaction {
-            self.raw
-                .set_cursor(conversion::mouse_interaction(interaction));
+            if let mouse::Interaction::Custom(cursor) = interaction {
+                // Custom cursors can only be created by the event loop
+                control_sender
+                    .start_send(Control::SetCursor {
+                        window: self.raw.clone(),
+                        cursor,
+                    })
+                    .expect("Send control action");
+            } else {
+                self.raw
+                    .set_cursor(conversion::mouse_interaction(interaction));
+
+                if let Some(popup) = &self.popup {
+                    popup
+                        .raw
+                        .set_cursor(conversion::mouse_interaction(interaction));
+                }
+            }
 
             self.mouse_interaction = interaction;
         }
     }
 
+    pub fn update_resize_border(
+        &mut self,
+        position: Option<winit::dpi::PhysicalPosition<f64>>,
+    ) {
+        use winit::window::ResizeDirection;
+
+        let border =
+            f64::from(self.resize_border.max(0.0)) * self.raw.scale_factor();
+
+        let position = position.filter(|_| {
+            border > 0.0
+                && !self.raw.is_decorated()
+                && self.raw.is_resizable()
+                && !self.raw.is_maximized()
+        });
+
+        let direction = position.and_then(|position| {
+            let size = self.raw.inner_size();
+
+            let edges = self.resizable_edges;
+
+            let left = edges.contains(Edges::LEFT) && position.x < border;
+            let right = edges.contains(Edges::RIGHT)
+                && position.x >= f64::from(size.width) - border;
+            let top = edges.contains(Edges::TOP) && position.y < border;
+            let bottom = edges.contains(Edges::BOTTOM)
+                && position.y >= f64::from(size.height) - border;
+
+            Some(match (left, right, top, bottom) {
+                (true, _, true, _) => ResizeDirection::NorthWest,
+                (_, true, true, _) => ResizeDirection::NorthEast,
+                (true, _, _, true) => ResizeDirection::SouthWest,
+                (_, true, _, true) => ResizeDirection::SouthEast,
+                (true, _, _, _) => ResizeDirection::West,
+                (_, true, _, _) => ResizeDirection::East,
+                (_, _, true, _) => ResizeDirection::North,
+                (_, _, _, true) => ResizeDirection::South,
+                _ => return None,
+            })
+        });
+
+        if direction == self.resize_direction {
+            return;
+        }
+
+        self.resize_direction = direction;
+
+        match direction {
+            Some(direction) => {
+                self.raw
+                    .set_cursor(winit::window::CursorIcon::from(direction));
+            }
+            None => {
+                // The contents will set their own cursor on the next update
+                self.raw.set_cursor(winit::window::CursorIcon::Default);
+                self.mouse_interaction = mouse::Interaction::None;
+            }
+        }
+    }
+
+    /// Dims the contents of the window while a modal is open on top.
+    pub fn draw_modal_overlay(&mut self) {
+        use crate::core::Renderer as _;
+
+        if self.modal.is_none() {
+            return;
+        }
+
+        let bounds =
+            Rectangle::new(Point::ORIGIN, self.state.viewport().logical_size());
+
+        self.renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                Color::BLACK.scale_alpha(0.4),
+            );
+        });
+    }
+
     pub fn draw_preedit(&mut self) {
         if let Some(preedit) = &self.preedit {
             preedit.draw(
@@ -434,3 +1257,17 @@ where
         });
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Presence {
+    Opening { start: Option<Instant> },
+    Shown,
+    Closing { start: Option<Instant> },
+    Closed,
+}
+
+fn linear_progress(start: Instant, now: Instant) -> f32 {
+    (now.saturating_duration_since(start).as_secs_f32()
+        / Transition::DURATION.as_secs_f32())
+    .min(1.0)
+}
@@ -16,6 +16,7 @@ where
 {
     title: String,
     scale_factor: f64,
+    scale_factor_override: Option<f64>,
     viewport: Viewport,
     viewport_version: u64,
     cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
@@ -49,9 +50,11 @@ where
         program: &program::Instance<P>,
         window_id: window::Id,
         window: &Window,
+        scale_factor_override: Option<f64>,
     ) -> Self {
         let title = program.title(window_id);
-        let scale_factor = program.scale_factor(window_id);
+        let scale_factor = scale_factor_override
+            .unwrap_or_else(|| program.scale_factor(window_id));
         let theme = program.theme(window_id);
         let style = program.style(&theme);
 
@@ -67,6 +70,7 @@ where
         Self {
             title,
             scale_factor,
+            scale_factor_override,
             viewport,
             viewport_version: 0,
             cursor_position: None,
@@ -198,7 +202,9 @@ where
         }
 
         // Update scale factor and size
-        let new_scale_factor = program.scale_factor(window_id);
+        let new_scale_factor = self
+            .scale_factor_override
+            .unwrap_or_else(|| program.scale_factor(window_id));
         let new_size = window.inner_size();
         let current_size = self.viewport.physical_size();
 